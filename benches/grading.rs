@@ -0,0 +1,40 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use deutschland_tui::grade::{Grader, Strictness};
+use std::hint::black_box;
+
+/// Responses and the forms they are graded against, right and wrong
+const RESPONSES: [(&str, &str); 6] = [
+    ("ich esse", "ich esse"),
+    ("Ich  esse", "ich esse"),
+    ("du isst!", "du isst"),
+    ("wir assen", "wir aßen"),
+    ("sie isst", "er/sie/es isst"),
+    ("ihr habt gegesen", "ihr habt gegessen"),
+];
+
+fn grading(c: &mut Criterion) {
+    for strictness in [Strictness::Exact, Strictness::Normal, Strictness::Lenient] {
+        let grader = Grader::new().strictness(strictness);
+        c.bench_function(&format!("grade, {}", strictness), |b| {
+            b.iter(|| {
+                for (response, answer) in RESPONSES {
+                    black_box(grader.grade(black_box(response), black_box(answer)));
+                }
+            })
+        });
+    }
+    let grader = Grader::new()
+        .strictness(Strictness::Lenient)
+        .fold_umlauts(true)
+        .pronoun_optional(true);
+    c.bench_function("grade, most forgiving", |b| {
+        b.iter(|| {
+            for (response, answer) in RESPONSES {
+                black_box(grader.grade(black_box(response), black_box(answer)));
+            }
+        })
+    });
+}
+
+criterion_group!(benches, grading);
+criterion_main!(benches);
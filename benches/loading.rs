@@ -0,0 +1,47 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use deutschland_tui::conjugation::parse_conjugations;
+use deutschland_tui::verb::Verb;
+use std::{fs, hint::black_box, path::PathBuf};
+
+/// How many verb files the data directory of the benchmarks has
+const VERBS: usize = 2000;
+
+/// A data directory with `VERBS` copies of the starter verbs, as a big pack
+/// would make it
+fn data_dir() -> PathBuf {
+    let data_dir = std::env::temp_dir().join("deutschland-bench");
+    let verbs_dir = data_dir.join("verbs");
+    let _ = fs::remove_dir_all(&data_dir);
+    fs::create_dir_all(&verbs_dir).unwrap();
+    let starters: Vec<PathBuf> = fs::read_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/verbs"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    for i in 0..VERBS {
+        let starter = &starters[i % starters.len()];
+        fs::copy(starter, verbs_dir.join(format!("verb{}.csv", i))).unwrap();
+    }
+    data_dir
+}
+
+fn loading(c: &mut Criterion) {
+    let data_dir = data_dir();
+    c.bench_function("find all verbs", |b| {
+        b.iter(|| Verb::find_all(black_box(&data_dir)).unwrap())
+    });
+    let verbs = Verb::find_all(&data_dir).unwrap();
+    c.bench_function("parse the conjugations of every verb", |b| {
+        b.iter(|| {
+            for verb in &verbs {
+                black_box(parse_conjugations(verb, "English").unwrap());
+            }
+        })
+    });
+    c.bench_function("parse the conjugations of a verb", |b| {
+        b.iter(|| parse_conjugations(black_box(&verbs[0]), "English").unwrap())
+    });
+    let _ = fs::remove_dir_all(&data_dir);
+}
+
+criterion_group!(benches, loading);
+criterion_main!(benches);
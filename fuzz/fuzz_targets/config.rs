@@ -0,0 +1,10 @@
+#![no_main]
+
+use deutschland_tui::config::Config;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    if let Ok(config) = Config::parse(text) {
+        let _ = config.keymap.shortcuts();
+    }
+});
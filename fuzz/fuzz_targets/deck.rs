@@ -0,0 +1,8 @@
+#![no_main]
+
+use deutschland_tui::vocab::Deck;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = Deck::parse("fuzz".to_string(), data, "English");
+});
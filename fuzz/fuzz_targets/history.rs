@@ -0,0 +1,8 @@
+#![no_main]
+
+use deutschland_tui::history;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = history::parse(data);
+});
@@ -0,0 +1,16 @@
+#![no_main]
+
+use deutschland_tui::{srs::Srs, vocab::VocabSrs};
+use libfuzzer_sys::fuzz_target;
+use std::path::PathBuf;
+
+// the verb forms' schedule and the vocabulary's, as their keys are parsed apart
+fuzz_target!(|data: &[u8]| {
+    if let Ok(srs) = Srs::parse(PathBuf::from("srs.csv"), data) {
+        let _ = srs.due_count();
+        let _ = srs.leeches(1);
+    }
+    if let Ok(srs) = VocabSrs::parse(PathBuf::from("vocab_srs.csv"), data) {
+        let _ = srs.due_count();
+    }
+});
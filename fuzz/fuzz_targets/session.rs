@@ -0,0 +1,13 @@
+#![no_main]
+
+use deutschland_tui::session;
+use libfuzzer_sys::fuzz_target;
+
+// a lesson read back is picked up again, so its answers have to be readable
+fuzz_target!(|text: &str| {
+    if let Ok(session) = session::parse(text) {
+        session
+            .answers()
+            .expect("The answers of a parsed session are readable");
+    }
+});
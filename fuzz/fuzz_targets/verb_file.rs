@@ -0,0 +1,18 @@
+#![no_main]
+
+use deutschland_tui::{conjugation, verb::Verb};
+use libfuzzer_sys::fuzz_target;
+use std::path::PathBuf;
+
+// a verb file is read as it is loaded, and as `validate` checks it
+fuzz_target!(|data: &[u8]| {
+    let verb = Verb::from_path(PathBuf::from("machen.csv"), None).unwrap();
+    if let Ok(conjugations) = conjugation::parse_conjugations_from(&verb, "English", data.to_vec())
+    {
+        for conj in &conjugations {
+            let _ = conj.prompt_hint();
+            let _ = conj.stressed_parts();
+        }
+    }
+    let _ = conjugation::validate_bytes(data.to_vec());
+});
@@ -0,0 +1,120 @@
+use chrono::Local;
+use csv::{ReaderBuilder, WriterBuilder};
+use std::{error::Error, path::Path};
+
+use crate::lesson::Lesson;
+use crate::tense::Tense;
+
+/// The best score of each combination of verbs and tenses, in
+/// `<data_dir>/high_scores.csv`
+const HIGH_SCORES_FILE: &str = "high_scores.csv";
+
+const HEADERS: [&str; 4] = ["Verbs", "Tenses", "Score", "Time"];
+
+/// The points of a right answer, before the multiplier
+const POINTS: u32 = 10;
+
+/// The multiplier stops growing here
+const MAX_MULTIPLIER: u32 = 8;
+
+/// The score of a lesson with --arcade: every right answer in a row raises
+/// the multiplier by one, and a miss resets it
+#[derive(Debug, Clone, Default)]
+pub struct Arcade {
+    pub score: u32,
+    pub combo: u32,        // the right answers in a row
+    pub best: Option<u32>, // the high score of the lesson's verbs and tenses
+    pub new_best: bool,    // once the lesson is over, if it beat the high score
+}
+
+impl Arcade {
+    /// What the next right answer is multiplied by
+    pub fn multiplier(&self) -> u32 {
+        (self.combo + 1).min(MAX_MULTIPLIER)
+    }
+
+    pub fn answer(&mut self, correct: bool) {
+        if correct {
+            self.score += POINTS * self.multiplier();
+            self.combo += 1;
+        } else {
+            self.combo = 0;
+        }
+    }
+
+    /// Starts the score of a lesson again, keeping the high score
+    pub fn reset(&mut self) {
+        self.score = 0;
+        self.combo = 0;
+        self.new_best = false;
+    }
+}
+
+/// The lesson's verbs and tenses as the high scores file has them, in the
+/// same order however they were picked
+fn combination(lesson: &Lesson) -> (String, String) {
+    let mut verbs = lesson.verbs.clone();
+    verbs.sort();
+    let tenses: Vec<String> = Tense::ALL
+        .iter()
+        .filter(|t| lesson.tenses.contains(t))
+        .map(|t| t.to_string())
+        .collect();
+    (verbs.join(", "), tenses.join(", "))
+}
+
+/// Every high score: the verbs, the tenses, the score and when it was made.
+/// Two synced machines can leave a row each for a lesson, of which the
+/// highest is kept.
+fn load(data_dir: &Path) -> Result<Vec<[String; 4]>, Box<dyn Error>> {
+    let path = data_dir.join(HIGH_SCORES_FILE);
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    let mut rdr = ReaderBuilder::new().has_headers(true).from_path(path)?;
+    let mut scores: Vec<[String; 4]> = Vec::new();
+    for result in rdr.records() {
+        let record = result?;
+        let field = |i: usize| record.get(i).unwrap_or_default().to_string();
+        let row = [field(0), field(1), field(2), field(3)];
+        let score: u32 = row[2].parse()?;
+        match scores.iter_mut().find(|s| s[0] == row[0] && s[1] == row[1]) {
+            Some(best) if best[2].parse::<u32>()? >= score => {}
+            Some(best) => *best = row,
+            None => scores.push(row),
+        }
+    }
+    Ok(scores)
+}
+
+/// The high score of the lesson's verbs and tenses, None if it wasn't played
+pub fn high_score(data_dir: &Path, lesson: &Lesson) -> Result<Option<u32>, Box<dyn Error>> {
+    let (verbs, tenses) = combination(lesson);
+    for [v, t, score, _] in load(data_dir)? {
+        if v == verbs && t == tenses {
+            return Ok(Some(score.parse()?));
+        }
+    }
+    Ok(None)
+}
+
+/// Keeps the score as the lesson's high score if it beats the one there is,
+/// returning true if it did
+pub fn record(data_dir: &Path, lesson: &Lesson, score: u32) -> Result<bool, Box<dyn Error>> {
+    let (verbs, tenses) = combination(lesson);
+    let mut scores = load(data_dir)?;
+    let row = [verbs, tenses, score.to_string(), Local::now().to_rfc3339()];
+    match scores.iter_mut().find(|s| s[0] == row[0] && s[1] == row[1]) {
+        Some(best) if best[2].parse::<u32>()? >= score => return Ok(false),
+        Some(best) => *best = row,
+        None => scores.push(row),
+    }
+
+    let mut wtr = WriterBuilder::new().from_path(data_dir.join(HIGH_SCORES_FILE))?;
+    wtr.write_record(HEADERS)?;
+    for row in scores {
+        wtr.write_record(row)?;
+    }
+    wtr.flush()?;
+    Ok(true)
+}
@@ -0,0 +1,31 @@
+use rand::{rngs::StdRng, seq::SliceRandom};
+
+use crate::conjugation::Conjugation;
+use crate::tense::Tense;
+
+/// How many words a form can have to be put in order with the digit keys
+pub const MAX_WORDS: usize = 9;
+
+/// Whether the German form can be put back together from its words: a
+/// perfect tense, where the auxiliary goes before the participle
+pub fn askable(conj: &Conjugation) -> bool {
+    let words = conj.german.split_whitespace().count();
+    matches!(
+        conj.tense,
+        Tense::PerfectPresent | Tense::PerfectPast | Tense::PerfectFuture
+    ) && (2..=MAX_WORDS).contains(&words)
+}
+
+/// The words of the German form, in a random order other than its own,
+/// e.g. "aufgewacht", "ich" and "bin" for "ich bin aufgewacht"
+pub fn shuffled(german: &str, rng: &mut StdRng) -> Vec<String> {
+    let words: Vec<String> = german.split_whitespace().map(String::from).collect();
+    let mut shuffled = words.clone();
+    // the same words twice may leave no other order
+    if words.iter().any(|w| *w != words[0]) {
+        while shuffled == words {
+            shuffled.shuffle(rng);
+        }
+    }
+    shuffled
+}
@@ -0,0 +1,72 @@
+use std::{
+    env, io,
+    path::Path,
+    process::{Child, Command, Stdio},
+};
+
+/// The text-to-speech programs to try, in order, with the arguments that pick
+/// a German voice
+const PROGRAMS: [(&str, &[&str]); 3] = [
+    ("espeak-ng", &["-v", "de"]),
+    ("espeak", &["-v", "de"]),
+    ("say", &["-v", "Anna"]), // macOS
+];
+
+/// Speaks German through the first text-to-speech program found on the PATH.
+///
+/// Speaking runs in the background so the TUI keeps responding, and starting
+/// to speak cuts off whatever was still being said.
+#[derive(Debug)]
+pub struct Speaker {
+    program: &'static str,
+    args: &'static [&'static str],
+    speaking: Option<Child>,
+}
+
+impl Speaker {
+    /// Finds a text-to-speech program, None if none is installed
+    pub fn find() -> Option<Speaker> {
+        let path = env::var_os("PATH")?;
+        let dirs: Vec<_> = env::split_paths(&path).collect();
+        PROGRAMS
+            .iter()
+            .find(|(program, _)| dirs.iter().any(|dir| is_program(&dir.join(program))))
+            .map(|&(program, args)| Speaker {
+                program,
+                args,
+                speaking: None,
+            })
+    }
+
+    pub fn speak(&mut self, text: &str) -> io::Result<()> {
+        if let Some(mut child) = self.speaking.take() {
+            // it has usually finished already, so this failing is fine
+            let _ = child.kill();
+            child.wait()?;
+        }
+        let child = Command::new(self.program)
+            .args(self.args)
+            // a form starting with a dash is still read out, not taken as an option
+            .arg("--")
+            .arg(text)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()?;
+        self.speaking = Some(child);
+        Ok(())
+    }
+}
+
+impl Drop for Speaker {
+    fn drop(&mut self) {
+        if let Some(mut child) = self.speaking.take() {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+}
+
+fn is_program(path: &Path) -> bool {
+    path.is_file() || path.with_extension("exe").is_file()
+}
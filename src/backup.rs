@@ -0,0 +1,186 @@
+use chrono::Local;
+use clap::Args;
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use std::{
+    error::Error,
+    fs::{self, File},
+    path::{Component, Path, PathBuf},
+};
+
+use crate::config::CONFIG_FILE;
+use crate::paths::Dirs;
+
+/// The directories of the data directory that are backed up whole: the
+/// verbs (with any added or fixed), installed packs and vocabulary decks
+const DATA_DIRS: [&str; 3] = ["verbs", "packs", "vocab"];
+
+/// The files at the top of the data directory that are backed up, i.e. the
+/// history, review schedules, notes, flags and scores
+const DATA_EXTENSIONS: [&str; 2] = ["csv", "jsonl"];
+
+/// Where the files go in the archive, so they can be put back in the right
+/// directory however it is set up on the other machine
+const DATA_PREFIX: &str = "data";
+const CONFIG_PREFIX: &str = "config";
+
+#[derive(Args, Debug)]
+pub struct BackupArgs {
+    /// The archive to write. Defaults to deutschland-backup-<date>.tar.gz in
+    /// the working directory
+    file: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+pub struct RestoreArgs {
+    /// The archive written by `backup`
+    file: PathBuf,
+}
+
+/// Bundles the settings, progress and verb files into a .tar.gz
+pub fn backup(args: BackupArgs, dirs: &Dirs) -> Result<(), Box<dyn Error>> {
+    let file = args.file.unwrap_or_else(|| {
+        PathBuf::from(format!(
+            "deutschland-backup-{}.tar.gz",
+            Local::now().format("%Y-%m-%d")
+        ))
+    });
+    let encoder = GzEncoder::new(File::create(&file)?, Compression::default());
+    let mut archive = tar::Builder::new(encoder);
+    let mut count = 0;
+
+    let config = dirs.config.join(CONFIG_FILE);
+    if config.is_file() {
+        archive.append_path_with_name(&config, Path::new(CONFIG_PREFIX).join(CONFIG_FILE))?;
+        count += 1;
+    }
+    for entry in fs::read_dir(&dirs.data)? {
+        let path = entry?.path();
+        let (Some(name), Some(extension)) = (path.file_name(), path.extension()) else {
+            continue;
+        };
+        if path.is_file() && DATA_EXTENSIONS.iter().any(|e| extension == *e) {
+            archive.append_path_with_name(&path, Path::new(DATA_PREFIX).join(name))?;
+            count += 1;
+        }
+    }
+    for dir in DATA_DIRS {
+        let path = dirs.data.join(dir);
+        if path.is_dir() {
+            archive.append_dir_all(Path::new(DATA_PREFIX).join(dir), &path)?;
+            count += files_in(&path)?;
+        }
+    }
+
+    archive.into_inner()?.finish()?;
+    println!("Backed up {} files to {}", count, file.display());
+    Ok(())
+}
+
+/// How many files there are in the directory, and the ones in it
+fn files_in(dir: &Path) -> Result<usize, Box<dyn Error>> {
+    let mut count = 0;
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            count += files_in(&path)?;
+        } else {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+/// Puts the files of a backup back, over the ones there are. Files that
+/// aren't in the backup are left as they are.
+pub fn restore(args: RestoreArgs, dirs: &Dirs) -> Result<(), Box<dyn Error>> {
+    let file = File::open(&args.file)
+        .map_err(|e| format!("Could not open {}: {}", args.file.display(), e))?;
+    let mut archive = tar::Archive::new(GzDecoder::new(file));
+    let mut count = 0;
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let path = entry.path()?.into_owned();
+        let Some(to) = destination(&path, dirs) else {
+            return Err(format!("Not a backup: {} has no place to go", path.display()).into());
+        };
+        if let Some(parent) = to.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        entry.unpack(&to)?;
+        count += 1;
+    }
+    println!(
+        "Restored {} files to {} and {}",
+        count,
+        dirs.data.display(),
+        dirs.config.display()
+    );
+    Ok(())
+}
+
+/// Where a file of the archive goes, None if it isn't in one of the two
+/// directories or would go outside of them
+fn destination(path: &Path, dirs: &Dirs) -> Option<PathBuf> {
+    let mut components = path.components();
+    let dir = match components.next()? {
+        Component::Normal(c) if c == DATA_PREFIX => &dirs.data,
+        Component::Normal(c) if c == CONFIG_PREFIX => &dirs.config,
+        _ => return None,
+    };
+    let rest = components.as_path();
+    let inside = rest.components().next().is_some()
+        && rest.components().all(|c| matches!(c, Component::Normal(_)));
+    inside.then(|| dir.join(rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dirs() -> Dirs {
+        Dirs {
+            data: PathBuf::from("/home/data"),
+            config: PathBuf::from("/home/config"),
+        }
+    }
+
+    #[test]
+    fn files_go_back_to_the_directory_they_came_from() {
+        assert_eq!(
+            destination(Path::new("data/verbs/gehen.csv"), &dirs()),
+            Some(PathBuf::from("/home/data/verbs/gehen.csv"))
+        );
+        assert_eq!(
+            destination(Path::new("config/config.toml"), &dirs()),
+            Some(PathBuf::from("/home/config/config.toml"))
+        );
+    }
+
+    #[test]
+    fn files_outside_the_two_directories_have_nowhere_to_go() {
+        for path in [
+            "other/history.csv",
+            "data",
+            "config/",
+            "./data/history.csv",
+            "",
+        ] {
+            assert_eq!(destination(Path::new(path), &dirs()), None, "{}", path);
+        }
+    }
+
+    #[test]
+    fn files_cant_climb_out_of_their_directory() {
+        for path in [
+            "data/../config.toml",
+            "data/verbs/../../../etc/passwd",
+            "/data/history.csv",
+            "config/..",
+        ] {
+            assert_eq!(destination(Path::new(path), &dirs()), None, "{}", path);
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use font8x8::{UnicodeFonts, BASIC_FONTS, LATIN_FONTS};
+use ratatui::{buffer::Buffer, layout::Rect, style::Style, widgets::Widget};
+
+/// Each glyph is 8x8 pixels, drawn with half blocks so it takes 8 columns and
+/// 4 rows
+const GLYPH_WIDTH: u16 = 8;
+const GLYPH_HEIGHT: u16 = 4;
+
+/// Large, figlet-style text, centered and wrapped at spaces to fit the width.
+///
+/// Uses the basic and Latin-1 glyphs of font8x8, so umlauts and ß work.
+pub struct BigText<'a> {
+    text: &'a str,
+    style: Style,
+}
+
+impl<'a> BigText<'a> {
+    pub fn new(text: &'a str, style: Style) -> BigText<'a> {
+        BigText { text, style }
+    }
+
+    /// The number of rows the text takes when wrapped to `width` columns
+    pub fn height(&self, width: u16) -> u16 {
+        self.lines(width).len() as u16 * GLYPH_HEIGHT
+    }
+
+    /// Wraps the text at spaces so each line fits in `width` columns. Words
+    /// that are too long on their own get cut off.
+    fn lines(&self, width: u16) -> Vec<String> {
+        let max_chars = (width / GLYPH_WIDTH).max(1) as usize;
+        let mut lines: Vec<String> = Vec::new();
+        for word in self.text.split_whitespace() {
+            match lines.last_mut() {
+                Some(line) if line.chars().count() + 1 + word.chars().count() <= max_chars => {
+                    line.push(' ');
+                    line.push_str(word);
+                }
+                _ => lines.push(word.to_string()),
+            }
+        }
+        if lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines
+    }
+}
+
+fn glyph(c: char) -> [u8; 8] {
+    BASIC_FONTS
+        .get(c)
+        .or_else(|| LATIN_FONTS.get(c))
+        .unwrap_or([0; 8])
+}
+
+impl Widget for BigText<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        for (i, line) in self.lines(area.width).iter().enumerate() {
+            let y = area.y + i as u16 * GLYPH_HEIGHT;
+            let line_width = line.chars().count() as u16 * GLYPH_WIDTH;
+            let x = area.x + area.width.saturating_sub(line_width) / 2;
+
+            for (n, c) in line.chars().enumerate() {
+                let glyph = glyph(c);
+                for row in 0..GLYPH_HEIGHT {
+                    let (top, bottom) = (glyph[row as usize * 2], glyph[row as usize * 2 + 1]);
+                    for col in 0..GLYPH_WIDTH {
+                        let pos = (x + n as u16 * GLYPH_WIDTH + col, y + row);
+                        if pos.0 >= area.right() || pos.1 >= area.bottom() {
+                            continue;
+                        }
+                        // the lowest bit is the leftmost pixel
+                        let symbol = match (top >> col & 1 == 1, bottom >> col & 1 == 1) {
+                            (true, true) => "█",
+                            (true, false) => "▀",
+                            (false, true) => "▄",
+                            (false, false) => continue,
+                        };
+                        buf[pos].set_symbol(symbol).set_style(self.style);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,262 @@
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Style, Styled, Stylize},
+    symbols::border,
+    text::Line,
+    widgets::{
+        block::{Position, Title},
+        Block, List, ListState, Paragraph, StatefulWidget, Widget,
+    },
+};
+
+use crate::lesson::Lesson;
+use crate::mode::Mode;
+use crate::person::Person;
+use crate::tense::Tense;
+use crate::theme::Theme;
+
+/// The columns of the builder, in the order they are shown
+const COLUMNS: [&str; 3] = ["Verbs", "Tenses", "Persons"];
+
+/// What the builder wants done after a key press
+pub enum BuilderEvent {
+    None,
+    Close,
+    Start(Lesson),
+    Save(String, Lesson), // save the lesson as a template with the name
+}
+
+/// The screen to build a lesson from any verbs, tenses and persons
+pub struct LessonBuilder {
+    verbs: Vec<String>,
+    forms: Vec<Vec<(Tense, Person)>>, // the forms each verb has that the mode can ask
+    mode: Mode,
+    checked: [Vec<bool>; 3], // per column, which items are selected
+    column: usize,
+    selected: [usize; 3],   // per column, the highlighted item
+    naming: Option<String>, // the name being written to save the lesson as
+    saved: Option<String>,  // the name the lesson was last saved as
+}
+
+impl LessonBuilder {
+    /// Opens the builder with `verb`, the `tenses` and every person checked
+    pub fn new(
+        verbs: Vec<String>,
+        forms: Vec<Vec<(Tense, Person)>>,
+        mode: Mode,
+        verb: usize,
+        tenses: &[Tense],
+    ) -> Self {
+        let mut checked_verbs = vec![false; verbs.len()];
+        if let Some(checked) = checked_verbs.get_mut(verb) {
+            *checked = true;
+        }
+        LessonBuilder {
+            verbs,
+            forms,
+            mode,
+            checked: [
+                checked_verbs,
+                Tense::ALL.iter().map(|t| tenses.contains(t)).collect(),
+                vec![true; Person::ALL.len()],
+            ],
+            column: 0,
+            selected: [verb, 0, 0],
+            naming: None,
+            saved: None,
+        }
+    }
+
+    /// Checks what `lesson` asks instead, to go back to the lesson it built
+    pub fn check(&mut self, lesson: &Lesson) {
+        self.checked = [
+            self.verbs
+                .iter()
+                .map(|v| lesson.verbs.contains(v))
+                .collect(),
+            Tense::ALL
+                .iter()
+                .map(|t| lesson.tenses.contains(t))
+                .collect(),
+            Person::ALL
+                .iter()
+                .map(|p| lesson.persons.contains(p))
+                .collect(),
+        ];
+        if let Some(first) = self.checked[0].iter().position(|&c| c) {
+            self.selected[0] = first;
+        }
+    }
+
+    /// The lesson as selected so far
+    pub fn lesson(&self) -> Lesson {
+        Lesson {
+            verbs: picked(&self.checked[0], &self.verbs),
+            tenses: picked(&self.checked[1], &Tense::ALL),
+            persons: picked(&self.checked[2], &Person::ALL),
+            forms: vec![],
+        }
+    }
+
+    /// How many different questions the lesson can ask
+    fn pool_size(&self) -> usize {
+        let lesson = self.lesson();
+        self.forms
+            .iter()
+            .zip(&self.checked[0])
+            .filter(|(_, &checked)| checked)
+            .flat_map(|(forms, _)| forms)
+            .filter(|(tense, person)| match self.mode {
+                // the principal parts are read off the verb's forms in several tenses
+                Mode::PrincipalParts => true,
+                // the tables have every person
+                Mode::Table => lesson.tenses.contains(tense),
+                _ => lesson.tenses.contains(tense) && lesson.persons.contains(person),
+            })
+            .count()
+    }
+
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> BuilderEvent {
+        if let Some(name) = &mut self.naming {
+            match key_event.code {
+                KeyCode::Enter if !name.trim().is_empty() => {
+                    let name = name.trim().to_string();
+                    self.naming = None;
+                    self.saved = Some(name.clone());
+                    return BuilderEvent::Save(name, self.lesson());
+                }
+                KeyCode::Esc => self.naming = None,
+                KeyCode::Backspace => {
+                    name.pop();
+                }
+                KeyCode::Char(c) => name.push(c),
+                _ => {}
+            }
+            return BuilderEvent::None;
+        }
+
+        let len = self.checked[self.column].len();
+        let selected = self.selected[self.column];
+        match key_event.code {
+            KeyCode::Esc | KeyCode::Char('q') => return BuilderEvent::Close,
+            KeyCode::Enter if self.pool_size() > 0 => return BuilderEvent::Start(self.lesson()),
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected[self.column] = selected.saturating_sub(1)
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected[self.column] = (selected + 1).min(len.saturating_sub(1))
+            }
+            KeyCode::Left | KeyCode::Char('h') | KeyCode::BackTab => {
+                self.column = (self.column + COLUMNS.len() - 1) % COLUMNS.len()
+            }
+            KeyCode::Right | KeyCode::Char('l') | KeyCode::Tab => {
+                self.column = (self.column + 1) % COLUMNS.len()
+            }
+            KeyCode::Char(' ') if len > 0 => {
+                let checked = &mut self.checked[self.column][selected];
+                *checked = !*checked;
+                self.saved = None;
+            }
+            KeyCode::Char('s') if self.pool_size() > 0 => self.naming = Some(String::new()),
+            // everything in the column, or nothing if it already is
+            KeyCode::Char('a') => {
+                let checked = &mut self.checked[self.column];
+                let all = checked.iter().all(|&c| c);
+                checked.iter_mut().for_each(|c| *c = !all);
+                self.saved = None;
+            }
+            _ => {}
+        }
+        BuilderEvent::None
+    }
+
+    pub fn render(&self, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        let title = Title::from(" Build a Lesson ".bold());
+        let instructions = Title::from(Line::from(match self.naming {
+            Some(_) => vec![
+                " Save ".into(),
+                "<Enter> ".set_style(theme.key),
+                " Cancel ".into(),
+                "<Esc> ".set_style(theme.key),
+            ],
+            None => vec![
+                " Move ".into(),
+                "<Arrows> ".set_style(theme.key),
+                " Select ".into(),
+                "<Space> ".set_style(theme.key),
+                " All ".into(),
+                "<a> ".set_style(theme.key),
+                " Save ".into(),
+                "<s> ".set_style(theme.key),
+                " Start ".into(),
+                "<Enter> ".set_style(theme.key),
+                " Back ".into(),
+                "<Esc> ".set_style(theme.key),
+            ],
+        }));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let [columns_area, pool_area] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).areas(inner);
+        let columns_areas: [Rect; 3] =
+            Layout::horizontal([Constraint::Ratio(1, 3); 3]).areas(columns_area);
+
+        let names: [Vec<String>; 3] = [
+            self.verbs.clone(),
+            Tense::ALL.iter().map(|t| t.to_string()).collect(),
+            Person::ALL.iter().map(|p| p.to_string()).collect(),
+        ];
+        for (i, area) in columns_areas.into_iter().enumerate() {
+            let items = names[i]
+                .iter()
+                .zip(&self.checked[i])
+                .map(|(name, &checked)| format!("[{}] {}", if checked { "x" } else { " " }, name));
+            let mut block = Block::bordered().title(COLUMNS[i]);
+            let mut state = ListState::default();
+            if i == self.column {
+                block = block.border_style(theme.key);
+                state.select(Some(self.selected[i]));
+            }
+            let list = List::new(items)
+                .block(block)
+                .highlight_style(Style::new().reversed());
+            StatefulWidget::render(list, area, buf, &mut state);
+        }
+
+        let mut pool = Line::from(match self.pool_size() {
+            0 => "Nothing to ask: select a verb, a tense and a person".set_style(theme.notice),
+            1 => "1 question in the pool".into(),
+            n => format!("{} questions in the pool", n).into(),
+        });
+        if let Some(name) = &self.naming {
+            pool = Line::from(vec![
+                "Save as: ".into(),
+                format!("{}_", name).set_style(theme.input),
+            ]);
+        } else if let Some(name) = &self.saved {
+            pool.push_span(format!(", saved as {}", name).set_style(theme.notice));
+        }
+        Paragraph::new(pool).centered().render(pool_area, buf);
+    }
+}
+
+/// The items of a column that are checked
+fn picked<T: Clone>(checked: &[bool], items: &[T]) -> Vec<T> {
+    items
+        .iter()
+        .zip(checked)
+        .filter(|(_, &c)| c)
+        .map(|(item, _)| item.clone())
+        .collect()
+}
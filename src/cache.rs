@@ -0,0 +1,202 @@
+use bincode::Options;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    error::Error,
+    fs::{self, File},
+    io::{BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+    process,
+    time::UNIX_EPOCH,
+};
+
+use crate::conjugation::{parse_conjugations, Conjugation};
+use crate::verb::Verb;
+
+/// Bump this whenever `Conjugation` (or anything in it) changes shape, so old
+/// caches are thrown away instead of failing to deserialize.
+const CACHE_VERSION: u32 = 7;
+
+/// When a verb file was last parsed. If either changes the file is re-parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct Stamp {
+    modified: u128, // nanoseconds since the epoch
+    len: u64,
+}
+
+impl Stamp {
+    fn of(path: &Path) -> Option<Stamp> {
+        let meta = fs::metadata(path).ok()?;
+        let modified = meta.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+        Some(Stamp {
+            modified: modified.as_nanos(),
+            len: meta.len(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Entry {
+    stamp: Stamp,
+    from: String, // the source language the prompts were read from
+    conjugations: Vec<Conjugation>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CacheFile {
+    version: u32,
+    entries: HashMap<PathBuf, Entry>,
+}
+
+/// A cache of the parsed verb files, stored with bincode in
+/// `<data_dir>/.cache/conjugations.bin`.
+///
+/// Entries are keyed by the verb file and invalidated automatically when the
+/// file's modification time or size changes.
+#[derive(Debug)]
+pub struct Cache {
+    path: PathBuf,
+    entries: HashMap<PathBuf, Entry>,
+    dirty: bool, // whether there are entries that have not been saved yet
+}
+
+impl Cache {
+    /// Loads the cache. A missing, corrupt or outdated cache just starts empty.
+    pub fn load(data_dir: &Path) -> Cache {
+        let path = data_dir.join(".cache").join("conjugations.bin");
+        let entries = File::open(&path)
+            .ok()
+            .and_then(|f| {
+                // nothing in the file can be longer than the file, so a
+                // corrupt length fails instead of allocating that much
+                let options = bincode::DefaultOptions::new().with_limit(f.metadata().ok()?.len());
+                // the version is read on its own first, as entries of another
+                // shape can be misread as huge lengths rather than failing
+                let mut reader = BufReader::new(f);
+                let version: u32 = options.deserialize_from(&mut reader).ok()?;
+                (version == CACHE_VERSION).then_some(())?;
+                options.deserialize_from(reader).ok()
+            })
+            .unwrap_or_default();
+        Cache {
+            path,
+            entries,
+            dirty: false,
+        }
+    }
+
+    /// Gets the conjugations of the verb, only parsing the file if it changed
+    /// since it was cached
+    pub fn conjugations(
+        &mut self,
+        verb: &Verb,
+        from: &str,
+    ) -> Result<Vec<Conjugation>, Box<dyn Error>> {
+        let stamp = Stamp::of(verb.path());
+        if let Some(entry) = self.entries.get(verb.path()) {
+            if Some(entry.stamp) == stamp && entry.from == from {
+                return Ok(entry.conjugations.clone());
+            }
+        }
+
+        let conjugations = parse_conjugations(verb, from)?;
+        if let Some(stamp) = stamp {
+            self.entries.insert(
+                verb.path().to_path_buf(),
+                Entry {
+                    stamp,
+                    from: from.to_string(),
+                    conjugations: conjugations.clone(),
+                },
+            );
+            self.dirty = true;
+        }
+        Ok(conjugations)
+    }
+
+    /// Writes the cache to disk if anything was added, dropping the entries of
+    /// verb files that no longer exist
+    pub fn save(&mut self) -> Result<(), Box<dyn Error>> {
+        if !self.dirty {
+            return Ok(());
+        }
+        let entries = std::mem::take(&mut self.entries)
+            .into_iter()
+            .filter(|(path, _)| path.exists())
+            .collect();
+        let file = CacheFile {
+            version: CACHE_VERSION,
+            entries,
+        };
+
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let result = write(&self.path, &file);
+        self.entries = file.entries;
+        result?;
+        self.dirty = false;
+        Ok(())
+    }
+}
+
+/// Writes the cache aside and moves it over, so a crash while saving, or
+/// another instance saving at the same time, can't leave half a cache
+fn write(path: &Path, file: &CacheFile) -> Result<(), Box<dyn Error>> {
+    let temp = path.with_extension(format!("bin.{}.tmp", process::id()));
+    let written = File::create(&temp)
+        .map_err(Box::<dyn Error>::from)
+        .and_then(|f| {
+            let mut writer = BufWriter::new(f);
+            bincode::DefaultOptions::new().serialize_into(&mut writer, file)?;
+            writer.flush()?;
+            Ok(fs::rename(&temp, path)?)
+        });
+    if written.is_err() {
+        let _ = fs::remove_file(&temp);
+    }
+    written
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A data directory of its own, with the cache directory made
+    fn data_dir(test: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("deutschland-cache-{}", test));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join(".cache")).unwrap();
+        dir
+    }
+
+    #[test]
+    fn saved_entries_are_loaded_back() {
+        let dir = data_dir("saved");
+        let path = dir.join("gehen.csv");
+        fs::write(
+            &path,
+            "Tense,Person,English,German\nPresent,I,I go,ich gehe\n",
+        )
+        .unwrap();
+        let verb = Verb::from_path(path, None).unwrap();
+        let mut cache = Cache::load(&dir);
+        cache.conjugations(&verb, "English").unwrap();
+        cache.save().unwrap();
+
+        let cache = Cache::load(&dir);
+        assert_eq!(cache.entries.len(), 1);
+        let files = fs::read_dir(dir.join(".cache")).unwrap().count();
+        assert_eq!(files, 1, "the temporary file was left");
+    }
+
+    #[test]
+    fn a_corrupt_length_starts_empty_instead_of_allocating_it() {
+        let dir = data_dir("corrupt");
+        // the version, then a map claiming u64::MAX entries
+        let mut bytes = vec![CACHE_VERSION as u8, 0xfd];
+        bytes.extend(u64::MAX.to_le_bytes());
+        fs::write(dir.join(".cache").join("conjugations.bin"), bytes).unwrap();
+        assert!(Cache::load(&dir).entries.is_empty());
+    }
+}
@@ -0,0 +1,539 @@
+use chrono::Local;
+use clap::Args;
+use deutschland_tui::grade::{Grader, Strictness};
+use hmac::{Hmac, Mac};
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::{
+    collections::HashMap,
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::assemble;
+use crate::conjugation::{self, Conjugation};
+use crate::ending;
+use crate::grid::Grid;
+use crate::lesson::Lesson;
+use crate::mode::Mode;
+use crate::paths::Dirs;
+use crate::person::Person;
+use crate::principal_parts::PrincipalParts;
+use crate::tense::Tense;
+use crate::verb::Verb;
+
+/// How many of the most missed forms `grade` lists
+const MOST_MISSED: usize = 10;
+
+/// The teacher's secret in the config directory, made by the first `assign`.
+/// The assignments are signed with a key made from it, so only the teacher's
+/// own are graded.
+const TEACHER_KEY_FILE: &str = "teacher.key";
+
+/// The options of the `assign` subcommand
+#[derive(Args, Debug)]
+pub struct AssignArgs {
+    /// What the students see the assignment as, e.g. "Homework 3"
+    #[arg(long)]
+    title: String,
+
+    /// The verbs to ask, by name
+    #[arg(short, long, required = true, num_args = 1..)]
+    verbs: Vec<String>,
+
+    /// The tenses to ask, every tense if none are given
+    #[arg(short, long, num_args = 1..)]
+    tenses: Vec<String>,
+
+    /// The persons to ask, every person if none are given
+    #[arg(short, long, num_args = 1..)]
+    persons: Vec<String>,
+
+    /// Number of questions
+    #[arg(short, long, default_value_t = 10)]
+    number: u8,
+
+    /// What the questions ask for, as with --mode
+    #[arg(short, long, value_enum, default_value_t = Mode::Translate)]
+    mode: Mode,
+
+    /// How strictly the answers are graded, the same for every student
+    #[arg(long, value_enum, default_value_t = Strictness::Normal)]
+    strictness: Strictness,
+
+    /// Seed the question order, random if not given. Every student is asked
+    /// the same questions in the same order
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// The file to write, assignment-<id>.json in the working directory if
+    /// not given
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+/// The options of the `grade` subcommand
+#[derive(Args, Debug)]
+pub struct GradeArgs {
+    /// The assignment the results are for, as written by `assign`
+    assignment: PathBuf,
+
+    /// The results handed in by the students
+    #[arg(required = true)]
+    results: Vec<PathBuf>,
+}
+
+/// A lesson set by a teacher, for every student to take the same way
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Assignment {
+    pub id: String,
+    pub title: String,
+    pub lesson: Lesson,
+    pub mode: Mode,
+    pub questions: u8,
+    pub strictness: Strictness,
+    pub seed: u64,
+    #[serde(default)]
+    signature: String, // by the teacher's key, in hex
+}
+
+impl Assignment {
+    pub fn load(path: &Path) -> Result<Assignment, Box<dyn Error>> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+        let assignment = serde_json::from_str(&text)
+            .map_err(|e| format!("Could not parse {}: {}", path.display(), e))?;
+        Ok(assignment)
+    }
+
+    /// The signature of the assignment, by the key the teacher's secret
+    /// makes for its id, so one edited by hand (e.g. to grade leniently) is
+    /// found out
+    fn sign(&self, secret: &str) -> Result<String, Box<dyn Error>> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())?;
+        mac.update(self.id.as_bytes());
+        let key = mac.finalize().into_bytes();
+        let unsigned = Assignment {
+            signature: String::new(),
+            ..self.clone()
+        };
+        let mut mac = Hmac::<Sha256>::new_from_slice(&key)?;
+        mac.update(serde_json::to_string(&unsigned)?.as_bytes());
+        Ok(hex(&mac.finalize().into_bytes()))
+    }
+
+    /// The signature of results, by the assignment's own signature, so ones
+    /// edited by hand are found out. The students have it, so their answers
+    /// are graded again all the same.
+    fn sign_results(&self, results: &Results) -> Result<String, Box<dyn Error>> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.signature.as_bytes())?;
+        mac.update(serde_json::to_string(results)?.as_bytes());
+        Ok(hex(&mac.finalize().into_bytes()))
+    }
+
+    /// The conjugations of the lesson, in the order the app loads them, to
+    /// draw the questions from and grade the answers with
+    pub fn conjugations(&self, data_dir: &Path) -> Result<Vec<Conjugation>, Box<dyn Error>> {
+        let verbs = Verb::find_all(data_dir)?;
+        let mut conjugations = vec![];
+        for name in &self.lesson.verbs {
+            let verb = verbs
+                .iter()
+                .find(|v| v.name() == name)
+                .ok_or_else(|| format!("Verb not matched: {}", name))?;
+            // only the German is looked at, so any language will do
+            let languages = conjugation::languages(verb.path())?;
+            let from = languages.first().ok_or("A verb file has no languages")?;
+            let mut verb_conjugations = conjugation::parse_conjugations(verb, from)?;
+            self.lesson.keep(self.mode, &mut verb_conjugations);
+            conjugations.append(&mut verb_conjugations);
+        }
+        Ok(conjugations)
+    }
+
+    /// The questions every student is asked, in order, as indices into the
+    /// lesson's conjugations: drawn from the seed out of the ones the mode can
+    /// ask, each once before any comes up again
+    pub fn questions(&self, conjugations: &[Conjugation]) -> Vec<usize> {
+        let askable: Vec<usize> = (0..conjugations.len())
+            .filter(|&i| {
+                let conj = &conjugations[i];
+                match self.mode {
+                    Mode::PrincipalParts => {
+                        conj.tense == Tense::Future
+                            && conj.person == Person::I
+                            && PrincipalParts::of(&conj.verb, conjugations).is_some()
+                    }
+                    Mode::Ending => ending::blank(conj).is_some(),
+                    Mode::Assemble => assemble::askable(conj),
+                    // a table is asked once, through the first of its forms
+                    Mode::Table => !conjugations[..i]
+                        .iter()
+                        .any(|c| c.verb == conj.verb && c.tense == conj.tense),
+                    _ => true,
+                }
+            })
+            .collect();
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let mut questions = Vec::new();
+        let mut deck: Vec<usize> = Vec::new();
+        while !askable.is_empty() && questions.len() < usize::from(self.questions) {
+            if deck.is_empty() {
+                deck = askable.clone();
+                deck.shuffle(&mut rng);
+            }
+            questions.extend(deck.pop());
+        }
+        questions
+    }
+
+    /// The table of the `n`th question in table mode, with the same forms
+    /// given for every student
+    pub fn grid(&self, conjugations: &[Conjugation], n: usize, of: usize) -> Grid {
+        let mut rng = StdRng::seed_from_u64(self.seed.wrapping_add(n as u64 + 1));
+        Grid::new(conjugations, of, &mut rng)
+    }
+}
+
+/// The teacher's secret, made if there is none yet
+fn teacher_secret(config_dir: &Path) -> Result<String, Box<dyn Error>> {
+    let path = config_dir.join(TEACHER_KEY_FILE);
+    if !path.exists() {
+        fs::create_dir_all(config_dir)?;
+        fs::write(&path, hex(&rand::random::<[u8; 32]>()) + "\n")?;
+    }
+    Ok(fs::read_to_string(path)?.trim().to_string())
+}
+
+/// A question of an assignment, as a student answered it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Graded {
+    verb: String,
+    tense: Tense,
+    person: Person,
+    #[serde(default)]
+    mode: Mode, // of the question, as a mixed lesson asks several
+    response: String,
+    skipped: bool,
+    seconds: f64,
+}
+
+impl Graded {
+    /// Whether the answer is right for the `n`th question, graded again from
+    /// the verb files: only the response is taken from the results, which the
+    /// student could have edited
+    fn regrade(
+        &self,
+        assignment: &Assignment,
+        conjugations: &[Conjugation],
+        n: usize,
+        question: usize,
+    ) -> bool {
+        let conj = &conjugations[question];
+        let asked =
+            self.verb == conj.verb.name() && self.tense == conj.tense && self.person == conj.person;
+        if self.skipped || !asked {
+            return false;
+        }
+        // a mixed lesson asks each question in one of the ways of the mix
+        let mode = match (assignment.mode, self.mode) {
+            (Mode::Mixed, mode @ (Mode::Translate | Mode::Recognize | Mode::Ending)) => mode,
+            (Mode::Mixed, _) => return false,
+            (mode, _) => mode,
+        };
+        let grader = Grader::new().strictness(assignment.strictness);
+        match mode {
+            // any choice with the same form as the one asked is right
+            Mode::Recognize => self
+                .response
+                .split_once(" | ")
+                .and_then(|(t, p)| {
+                    let (tense, person): (Tense, Person) = (t.parse().ok()?, p.parse().ok()?);
+                    conjugations
+                        .iter()
+                        .find(|c| c.verb == conj.verb && c.tense == tense && c.person == person)
+                })
+                .is_some_and(|chosen| chosen.german == conj.german),
+            Mode::Ending => {
+                ending::blank(conj).is_some_and(|blank| grader.grade(&self.response, &blank.ending))
+            }
+            Mode::PrincipalParts => {
+                PrincipalParts::of(&conj.verb, conjugations).is_some_and(|parts| {
+                    let responses: Vec<&str> = self.response.split(", ").collect();
+                    responses.len() == parts.parts.len()
+                        && responses
+                            .iter()
+                            .zip(&parts.parts)
+                            .all(|(r, part)| grader.grade(r, part))
+                })
+            }
+            Mode::Table => {
+                let mut grid = assignment.grid(conjugations, n, question);
+                grid.fill(&self.response);
+                self.response.split(", ").count() == grid.blanks().count()
+                    && grid.grade(conjugations, grader)
+            }
+            _ => grader.grade(&self.response, &conj.german),
+        }
+    }
+}
+
+/// What a student handed in for an assignment
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Results {
+    assignment: String, // the id
+    student: String,
+    finished: String, // when, e.g. "2024-05-02 14:30"
+    answers: Vec<Graded>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Signed {
+    #[serde(flatten)]
+    results: Results,
+    signature: String,
+}
+
+impl Results {
+    /// Which of the assignment's questions were answered right, graded
+    /// teacher-side against the questions drawn from its seed. Answers past
+    /// the number of questions count for nothing.
+    pub fn regrade(&self, assignment: &Assignment, conjugations: &[Conjugation]) -> Vec<bool> {
+        assignment
+            .questions(conjugations)
+            .into_iter()
+            .zip(&self.answers)
+            .enumerate()
+            .map(|(n, (question, answer))| answer.regrade(assignment, conjugations, n, question))
+            .collect()
+    }
+}
+
+/// An assignment being taken by a student
+pub struct Attempt {
+    pub assignment: Assignment,
+    student: String,
+    questions: Vec<usize>, // into the lesson's conjugations, once it started
+    answers: Vec<Graded>,
+}
+
+impl Attempt {
+    pub fn new(assignment: Assignment, student: String) -> Attempt {
+        Attempt {
+            assignment,
+            student,
+            questions: vec![],
+            answers: vec![],
+        }
+    }
+
+    /// Draws the questions out of the lesson's conjugations, once loaded
+    pub fn start(&mut self, conjugations: &[Conjugation]) {
+        self.questions = self.assignment.questions(conjugations);
+    }
+
+    /// The conjugation asked by the `n`th question
+    pub fn question(&self, n: usize) -> Option<usize> {
+        self.questions.get(n).copied()
+    }
+
+    /// Keeps the answer to a question, to hand in
+    pub fn answer(
+        &mut self,
+        conj: &Conjugation,
+        mode: Mode,
+        response: &str,
+        skipped: bool,
+        took: Duration,
+    ) {
+        self.answers.push(Graded {
+            verb: conj.verb.name().to_string(),
+            tense: conj.tense,
+            person: conj.person,
+            mode,
+            response: response.to_string(),
+            skipped,
+            seconds: took.as_secs_f64(),
+        });
+    }
+
+    /// Forgets the answers, to take the assignment again
+    pub fn restart(&mut self) {
+        self.answers.clear();
+    }
+
+    /// The results to hand in
+    pub fn results(&self) -> Results {
+        Results {
+            assignment: self.assignment.id.clone(),
+            student: self.student.clone(),
+            finished: Local::now().format("%Y-%m-%d %H:%M").to_string(),
+            answers: self.answers.clone(),
+        }
+    }
+
+    /// Writes the signed results to the working directory, returning where.
+    /// Taking the assignment again writes over them.
+    pub fn submit(&self) -> Result<PathBuf, Box<dyn Error>> {
+        let results = self.results();
+        let signature = self.assignment.sign_results(&results)?;
+        let path = PathBuf::from(format!(
+            "results-{}-{}.json",
+            self.assignment.id,
+            slug(&self.student)
+        ));
+        let json = serde_json::to_string_pretty(&Signed { results, signature })? + "\n";
+        fs::write(&path, json)?;
+        Ok(path)
+    }
+}
+
+/// Writes an assignment for the students to take with `--assignment`
+pub fn assign(args: AssignArgs, dirs: &Dirs) -> Result<(), Box<dyn Error>> {
+    let data_dir = dirs.data.as_path();
+    if !(1..100).contains(&args.number) {
+        return Err("The number of questions has to be from 1 to 99".into());
+    }
+    let verbs = Verb::find_all(data_dir)?;
+    for name in &args.verbs {
+        if !verbs.iter().any(|v| v.name() == name.to_lowercase()) {
+            return Err(format!("Verb not matched: {}", name).into());
+        }
+    }
+    let mut lesson = Lesson::new(args.verbs.iter().map(|v| v.to_lowercase()).collect());
+    if !args.tenses.is_empty() {
+        lesson.tenses = parse_all(&args.tenses)?;
+    }
+    if !args.persons.is_empty() {
+        lesson.persons = parse_all(&args.persons)?;
+    }
+
+    let mut assignment = Assignment {
+        id: hex(&rand::random::<[u8; 4]>()),
+        title: args.title,
+        lesson,
+        mode: args.mode,
+        questions: args.number,
+        strictness: args.strictness,
+        seed: args.seed.unwrap_or_else(rand::random),
+        signature: String::new(),
+    };
+    assignment.signature = assignment.sign(&teacher_secret(&dirs.config)?)?;
+    let path = args
+        .output
+        .unwrap_or_else(|| PathBuf::from(format!("assignment-{}.json", assignment.id)));
+    fs::write(&path, serde_json::to_string_pretty(&assignment)? + "\n")?;
+    println!(
+        "Wrote {} to {}. Students take it with --assignment {} --student <name>",
+        assignment.title,
+        path.display(),
+        path.display()
+    );
+    Ok(())
+}
+
+/// Lists the score of each student's results and the forms the class missed
+/// most. The answers are graded again against the questions drawn from the
+/// assignment's seed, so results edited by hand only score what their
+/// responses are worth.
+pub fn grade(args: GradeArgs, dirs: &Dirs) -> Result<(), Box<dyn Error>> {
+    let assignment = Assignment::load(&args.assignment)?;
+    if assignment.sign(&teacher_secret(&dirs.config)?)? != assignment.signature {
+        return Err(format!(
+            "{} was edited, or made with another {} than {}",
+            args.assignment.display(),
+            TEACHER_KEY_FILE,
+            dirs.config.join(TEACHER_KEY_FILE).display()
+        )
+        .into());
+    }
+    let conjugations = assignment.conjugations(&dirs.data)?;
+    let questions = assignment.questions(&conjugations);
+    println!("{} ({} questions)", assignment.title, assignment.questions);
+    println!();
+
+    let mut missed: HashMap<(String, Tense, Person), usize> = HashMap::new();
+    for path in &args.results {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+        let signed: Signed = serde_json::from_str(&text)
+            .map_err(|e| format!("Could not parse {}: {}", path.display(), e))?;
+        let results = &signed.results;
+        if results.assignment != assignment.id {
+            println!(
+                "{}: {} is for another assignment",
+                results.student,
+                path.display()
+            );
+            continue;
+        }
+        let status = if assignment.sign_results(results)? == signed.signature {
+            "signed"
+        } else {
+            "EDITED, the signature doesn't match"
+        };
+        let right = results.regrade(&assignment, &conjugations);
+
+        let answered = right.len();
+        let correct = right.iter().filter(|r| **r).count();
+        let seconds: f64 = results
+            .answers
+            .iter()
+            .take(answered)
+            .map(|a| a.seconds)
+            .sum();
+        println!(
+            "{}: {} of {} right ({:.0}%), {} unanswered, {:.0}s, finished {} ({})",
+            results.student,
+            correct,
+            answered,
+            100.0 * correct as f64 / answered.max(1) as f64,
+            usize::from(assignment.questions).saturating_sub(answered),
+            seconds,
+            results.finished,
+            status
+        );
+        for (&question, _) in questions.iter().zip(&right).filter(|(_, r)| !**r) {
+            let conj = &conjugations[question];
+            *missed
+                .entry((conj.verb.name().to_string(), conj.tense, conj.person))
+                .or_default() += 1;
+        }
+    }
+
+    let mut missed: Vec<_> = missed.into_iter().collect();
+    missed.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0 .0.cmp(&b.0 .0)));
+    if !missed.is_empty() {
+        println!();
+        println!("Most missed:");
+        for ((verb, tense, person), count) in missed.iter().take(MOST_MISSED) {
+            println!(
+                "  {} | {} | {}: missed {} times",
+                verb, tense, person, count
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Parses each of the tenses or persons given
+fn parse_all<T: std::str::FromStr<Err = String>>(names: &[String]) -> Result<Vec<T>, String> {
+    names.iter().map(|n| n.parse()).collect()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// The student's name as it goes in a file name, e.g. "anna-schmidt"
+fn slug(name: &str) -> String {
+    let slug: Vec<String> = name
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    slug.join("-")
+}
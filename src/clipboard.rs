@@ -0,0 +1,19 @@
+use std::error::Error;
+
+/// The system clipboard, opened on the first copy and then kept open: on X11
+/// what was copied only lasts as long as the clipboard does
+#[derive(Default)]
+pub struct Clipboard {
+    inner: Option<arboard::Clipboard>,
+}
+
+impl Clipboard {
+    pub fn copy(&mut self, text: &str) -> Result<(), Box<dyn Error>> {
+        let clipboard = match &mut self.inner {
+            Some(clipboard) => clipboard,
+            None => self.inner.insert(arboard::Clipboard::new()?),
+        };
+        clipboard.set_text(text)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,200 @@
+use rand::{rngs::StdRng, Rng};
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Alignment, Rect},
+    style::{Styled, Stylize},
+    symbols::border,
+    text::{Line, Text},
+    widgets::{
+        block::{Position, Title},
+        Block, Paragraph, Widget,
+    },
+};
+
+use crate::collocations::{self, Collocation};
+use crate::theme::Theme;
+
+/// The drill on the prepositions verbs go with: the verb is shown with a
+/// blank, and its preposition and case are picked from a list or typed, as
+/// Tab switches
+pub struct CollocationQuiz {
+    collocations: Vec<Collocation>,
+    rng: StdRng,
+    current: usize,
+    typing: bool,         // if the answer is typed rather than picked
+    choices: Vec<String>, // when picking, the answers to pick from
+    response: String,
+    correct: Option<bool>, // once answered, if the response was right
+    right: u32,
+    answered: u32,
+}
+
+impl CollocationQuiz {
+    /// Starts the drill, None if there are no collocations to ask
+    pub fn new(collocations: Vec<Collocation>, mut rng: StdRng) -> Option<CollocationQuiz> {
+        if collocations.is_empty() {
+            return None;
+        }
+        let current = rng.gen_range(0..collocations.len());
+        let choices = collocations::choices(&collocations[current], &collocations, &mut rng);
+        Some(CollocationQuiz {
+            collocations,
+            rng,
+            current,
+            typing: false,
+            choices,
+            response: String::new(),
+            correct: None,
+            right: 0,
+            answered: 0,
+        })
+    }
+
+    /// Handles a key press, returning true when the drill should be closed
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> bool {
+        if self.correct.is_some() {
+            match key_event.code {
+                KeyCode::Esc => return true,
+                KeyCode::Enter => self.next(),
+                _ => {}
+            }
+            return false;
+        }
+
+        match key_event.code {
+            KeyCode::Esc => return true,
+            KeyCode::Tab => {
+                self.typing = !self.typing;
+                self.response.clear();
+            }
+            KeyCode::Char(c @ '1'..='9') if !self.typing => {
+                if let Some(choice) = self.choices.get(c as usize - '1' as usize) {
+                    self.response = choice.clone();
+                    self.check();
+                }
+            }
+            KeyCode::Enter if self.typing && !self.response.trim().is_empty() => self.check(),
+            KeyCode::Backspace if self.typing => {
+                self.response.pop();
+            }
+            KeyCode::Char(c) if self.typing => self.response.push(c),
+            _ => {}
+        }
+        false
+    }
+
+    fn check(&mut self) {
+        let correct = collocations::is_right(&self.response, &self.collocations[self.current]);
+        if correct {
+            self.right += 1;
+        }
+        self.answered += 1;
+        self.correct = Some(correct);
+    }
+
+    /// Asks another collocation, not the same one twice in a row
+    fn next(&mut self) {
+        let mut next = self.rng.gen_range(0..self.collocations.len());
+        if self.collocations.len() > 1 {
+            while next == self.current {
+                next = self.rng.gen_range(0..self.collocations.len());
+            }
+        }
+        self.current = next;
+        self.choices =
+            collocations::choices(&self.collocations[next], &self.collocations, &mut self.rng);
+        self.response.clear();
+        self.correct = None;
+    }
+
+    pub fn render(&self, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        let title = Title::from(" Verbs and Prepositions ".bold());
+        let instructions = Title::from(Line::from(match (self.correct, self.typing) {
+            (Some(_), _) => vec![
+                " Next ".into(),
+                "<Enter> ".set_style(theme.key),
+                " Back ".into(),
+                "<Esc> ".set_style(theme.key),
+            ],
+            (None, false) => vec![
+                " Pick ".into(),
+                format!("<1-{}> ", self.choices.len()).set_style(theme.key),
+                " Type Instead ".into(),
+                "<Tab> ".set_style(theme.key),
+                " Back ".into(),
+                "<Esc> ".set_style(theme.key),
+            ],
+            (None, true) => vec![
+                " Input Answer ".into(),
+                "<Chars> ".set_style(theme.key),
+                " Submit ".into(),
+                "<Enter> ".set_style(theme.key),
+                " Pick Instead ".into(),
+                "<Tab> ".set_style(theme.key),
+                " Back ".into(),
+                "<Esc> ".set_style(theme.key),
+            ],
+        }));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+
+        let asked = &self.collocations[self.current];
+        let mut text = Text::from(vec![
+            Line::from(""),
+            Line::from(""),
+            Line::from(format!("{} ___", asked.verb).set_style(theme.prompt)),
+            Line::from(format!("({})", asked.english)),
+            Line::from(""),
+        ]);
+        match self.correct {
+            None if self.typing => {
+                text.push_line(Line::from("The preposition and case, e.g. \"mit dat\":"));
+                text.push_line(Line::from(vec![
+                    "Your input: ".into(),
+                    format!("{}_", self.response).set_style(theme.input),
+                ]));
+            }
+            None => {
+                let mut choices = Line::default();
+                for (n, choice) in self.choices.iter().enumerate() {
+                    choices.push_span(format!(" {} ", n + 1).set_style(theme.key));
+                    choices.push_span(format!("{}  ", choice));
+                }
+                text.push_line(choices);
+            }
+            Some(correct) => {
+                let mut told = format!("{} {}", asked.verb, asked.answer());
+                if let Some(example) = &asked.example {
+                    told += &format!(": {}", example);
+                }
+                text.push_line(if correct {
+                    Line::from(format!("{}{}", theme.correct_mark, told).set_style(theme.correct))
+                } else {
+                    Line::from(
+                        format!("{}{} (not {})", theme.incorrect_mark, told, self.response)
+                            .set_style(theme.incorrect),
+                    )
+                });
+            }
+        }
+        text.push_line(Line::from(""));
+        if self.answered > 0 {
+            text.push_line(Line::from(format!(
+                "{} right out of {}",
+                self.right, self.answered
+            )));
+        }
+
+        Paragraph::new(text)
+            .centered()
+            .block(block)
+            .render(area, buf);
+    }
+}
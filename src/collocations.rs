@@ -0,0 +1,116 @@
+use csv::ReaderBuilder;
+use deutschland_tui::grade::{Grader, Strictness};
+use rand::{rngs::StdRng, seq::SliceRandom};
+use std::{error::Error, path::Path};
+
+use crate::prepositions::Case;
+
+/// The verbs that go with a fixed preposition, kept in
+/// `<data_dir>/collocations.csv` with the columns
+/// `Verb,Preposition,Case,Example,English`
+const COLLOCATIONS_FILE: &str = "collocations.csv";
+
+/// How many answers a multiple-choice question offers
+pub const NUM_CHOICES: usize = 4;
+
+/// The prepositions taking the accusative or the dative as the verb has it,
+/// so that the other case makes a likely wrong choice
+const TWO_WAY: [&str; 9] = [
+    "an", "auf", "hinter", "in", "neben", "über", "unter", "vor", "zwischen",
+];
+
+/// A verb and the preposition it goes with, e.g. "warten", "auf" and the
+/// accusative
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Collocation {
+    pub verb: String,
+    pub preposition: String,
+    pub case: Case,
+    pub example: Option<String>,
+    pub english: String,
+}
+
+impl Collocation {
+    /// The preposition with its case, as the choices show it, e.g. "auf + Accusative"
+    pub fn answer(&self) -> String {
+        label(&self.preposition, self.case)
+    }
+}
+
+fn label(preposition: &str, case: Case) -> String {
+    format!("{} + {}", preposition, case)
+}
+
+/// Loads the collocations from the collocations file, in the order they are listed
+pub fn load(data_dir: &Path) -> Result<Vec<Collocation>, Box<dyn Error>> {
+    let path = data_dir.join(COLLOCATIONS_FILE);
+    if !path.exists() {
+        return Err(format!("No collocations file: {}", path.display()).into());
+    }
+    let mut rdr = ReaderBuilder::new().has_headers(true).from_path(path)?;
+    let mut collocations = Vec::new();
+    for result in rdr.records() {
+        let record = result?;
+        let line = record.position().map_or(0, |p| p.line());
+        let field = |i: usize| {
+            record
+                .get(i)
+                .map(str::trim)
+                .ok_or_else(|| format!("Missing column {} on line {}", i + 1, line))
+        };
+        collocations.push(Collocation {
+            verb: field(0)?.to_string(),
+            preposition: field(1)?.to_string(),
+            case: field(2)?.parse()?,
+            example: Some(field(3)?.to_string()).filter(|e| !e.is_empty()),
+            english: field(4)?.to_string(),
+        });
+    }
+    Ok(collocations)
+}
+
+/// The answers to pick from for the collocation: the right one, the other
+/// case of a two-way preposition, and the prepositions of other verbs, in a
+/// random order
+pub fn choices(asked: &Collocation, all: &[Collocation], rng: &mut StdRng) -> Vec<String> {
+    let answer = asked.answer();
+    let mut others: Vec<String> = all.iter().map(Collocation::answer).collect();
+    others.sort();
+    others.dedup();
+    others.retain(|other| *other != answer);
+    others.shuffle(rng);
+
+    let mut choices = vec![answer];
+    if TWO_WAY.contains(&asked.preposition.as_str()) {
+        let other_case = match asked.case {
+            Case::Accusative => Case::Dative,
+            _ => Case::Accusative,
+        };
+        choices.push(label(&asked.preposition, other_case));
+    }
+    for other in others {
+        if choices.len() >= NUM_CHOICES {
+            break;
+        }
+        if !choices.contains(&other) {
+            choices.push(other);
+        }
+    }
+    choices.shuffle(rng);
+    choices
+}
+
+/// Whether the typed response gives the preposition and case of the
+/// collocation, e.g. "auf akk" or "auf + Akkusativ"
+pub fn is_right(response: &str, asked: &Collocation) -> bool {
+    let mut words = response.split_whitespace().filter(|w| *w != "+");
+    let Some(preposition) = words.next() else {
+        return false;
+    };
+    let case = words.collect::<Vec<_>>().join(" ");
+    let grader = Grader::new()
+        .strictness(Strictness::Lenient)
+        .fold_umlauts(true);
+    grader.grade(preposition, &asked.preposition)
+        && case.trim_start_matches('+').parse::<Case>() == Ok(asked.case)
+}
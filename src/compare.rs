@@ -0,0 +1,114 @@
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Styled, Stylize},
+    symbols::border,
+    text::{Line, Text},
+    widgets::{
+        block::{Position, Title},
+        Block, Paragraph, Widget,
+    },
+};
+
+use crate::conjugation::Conjugation;
+use crate::person::Person;
+use crate::tense::Tense;
+use crate::theme::Theme;
+
+/// The screen showing the conjugation of a verb a tense at a time, or of two
+/// verbs side by side to compare how they are conjugated
+pub struct Compare {
+    verbs: Vec<(String, Vec<Conjugation>)>,
+    tense: usize,              // the index of the tense shown in `Tense::ALL`
+    highlight: Option<Person>, // the form to pick out, e.g. one searched for
+}
+
+impl Compare {
+    pub fn new(verbs: Vec<(String, Vec<Conjugation>)>, tense: Tense) -> Compare {
+        Compare {
+            verbs,
+            tense: Tense::ALL.iter().position(|&t| t == tense).unwrap_or(0),
+            highlight: None,
+        }
+    }
+
+    /// Picks out the person's form of the first tense shown
+    pub fn highlight(mut self, person: Person) -> Compare {
+        self.highlight = Some(person);
+        self
+    }
+
+    /// Handles a key press, returning true when the screen should be closed
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> bool {
+        match key_event.code {
+            KeyCode::Esc | KeyCode::Char('q') => return true,
+            KeyCode::Left | KeyCode::Char('h') => {
+                self.tense = (self.tense + Tense::ALL.len() - 1) % Tense::ALL.len();
+                self.highlight = None;
+            }
+            KeyCode::Right | KeyCode::Char('l') => {
+                self.tense = (self.tense + 1) % Tense::ALL.len();
+                self.highlight = None;
+            }
+            _ => {}
+        }
+        false
+    }
+
+    pub fn render(&self, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        let tense = Tense::ALL[self.tense];
+        let title = match self.verbs.len() {
+            1 => Title::from(format!(" Conjugation | {} ", tense).bold()),
+            _ => Title::from(format!(" Compare | {} ", tense).bold()),
+        };
+        let instructions = Title::from(Line::from(vec![
+            " Tense ".into(),
+            "<Left/Right> ".set_style(theme.key),
+            " Back ".into(),
+            "<Esc> ".set_style(theme.key),
+        ]));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let columns = Layout::horizontal(vec![Constraint::Fill(1); self.verbs.len()]).split(inner);
+        for ((name, conjugations), &area) in self.verbs.iter().zip(columns.iter()) {
+            let mut text = Text::from(Line::from(""));
+            for person in Person::ALL {
+                let Some(conj) = conjugations
+                    .iter()
+                    .find(|c| c.tense == tense && c.person == person)
+                else {
+                    continue;
+                };
+                let german = Line::from(conj.german.clone().set_style(theme.prompt));
+                if self.highlight == Some(person) {
+                    text.push_line(german.reversed());
+                } else {
+                    text.push_line(german);
+                }
+                text.push_line(Line::from(conj.prompt.clone().dim()));
+                text.push_line(Line::from(""));
+            }
+            if text.lines.len() == 1 {
+                text.push_line(Line::from("Not in the verb file".dim()));
+            }
+            Paragraph::new(text)
+                .centered()
+                .block(
+                    Block::bordered().title(
+                        Title::from(format!(" {} ", name).bold()).alignment(Alignment::Center),
+                    ),
+                )
+                .render(area, buf);
+        }
+    }
+}
@@ -0,0 +1,319 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, error::Error, fmt, fs, path::Path};
+
+use crate::grade::Strictness;
+
+use crate::hooks::Hooks;
+use crate::keymap::Keymap;
+use crate::lesson::Lesson;
+use crate::mode::{Mix, Mode, MAX_WEIGHT};
+use crate::sync::GitSync;
+use crate::tense::Tense;
+use crate::theme::ThemeChoice;
+
+/// The settings, kept in `<config_dir>/config.toml`
+pub const CONFIG_FILE: &str = "config.toml";
+
+/// The longest grace period the config can set, an hour
+const MAX_GRACE_SECONDS: u64 = 3600;
+
+/// The longest a right answer can be shown before moving on, a minute
+const MAX_AUTO_ADVANCE_MS: u64 = 60_000;
+
+/// The user's settings. Missing fields take their default, so older config
+/// files keep working as settings are added.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub theme: ThemeChoice,
+    pub accessible: bool,  // colourblind safe colours, and ✓/✗ marks on answers
+    pub show_ipa: bool,    // the pronunciation with the answers, for verb files that have it
+    pub show_stress: bool, // the stressed syllables of the answers, for verb files that mark them
+    pub hints: HeaderHints,
+    pub auxiliary_hint: bool, // haben or sein next to the perfect tenses in the header
+    pub autocomplete: bool,   // the next letter of the answer shown as it is typed, to study with
+    pub difficulty: Difficulty, // the preset the strictness and hints were last set from
+    pub warm_up: bool,        // lessons start with up to three of their most missed forms
+    pub curriculum: bool,     // new lessons ask the tenses unlocked so far, over the difficulty's
+    pub strictness: Strictness, // how the answers of the lessons are graded
+    pub direction: Direction, // the way the lessons go, unless --mode is given
+    pub leech_lapses: u32,    // how many times a form is missed before it is a leech
+    pub grace_seconds: u64, // with a time limit, how long the question being answered is left once it's up
+    pub auto_advance_ms: u64, // how long a right answer is shown before moving on by itself, 0 to wait for Enter
+    pub keymap: Keymap,       // the shortcuts typing ä, ö, ü and ß
+    pub keymap_bar: bool,     // the shortcuts listed below the questions
+    pub score_header: bool,   // the right and wrong answers so far, and the streak, in the header
+    pub encouragement: bool,  // a line from the encouragement file under right answers
+    pub mix: Mix,             // how often each kind of question comes up with --mode mixed
+    pub hooks: Hooks,
+    pub sync: GitSync,
+    pub lessons: BTreeMap<String, Lesson>, // the lesson templates saved from the builder
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            theme: ThemeChoice::default(),
+            accessible: false,
+            show_ipa: true,
+            show_stress: true,
+            hints: HeaderHints::default(),
+            auxiliary_hint: false,
+            autocomplete: false,
+            difficulty: Difficulty::default(),
+            warm_up: true,
+            curriculum: false,
+            strictness: Strictness::default(),
+            direction: Direction::default(),
+            leech_lapses: 8,
+            grace_seconds: 5,
+            auto_advance_ms: 0,
+            keymap: Keymap::default(),
+            keymap_bar: true,
+            score_header: false,
+            encouragement: true,
+            mix: Mix::default(),
+            hooks: Hooks::default(),
+            sync: GitSync::default(),
+            lessons: BTreeMap::new(),
+        }
+    }
+}
+
+/// How much the question header gives away. Without the hints the learner
+/// has to infer the person and tense from the prompt alone.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum HeaderHints {
+    /// The verb, tense and person
+    #[default]
+    All,
+    /// The verb and tense
+    NoPerson,
+    /// Just the verb
+    VerbOnly,
+    /// Nothing but the question number
+    Hidden,
+}
+
+impl HeaderHints {
+    pub fn next(self) -> HeaderHints {
+        match self {
+            HeaderHints::All => HeaderHints::NoPerson,
+            HeaderHints::NoPerson => HeaderHints::VerbOnly,
+            HeaderHints::VerbOnly => HeaderHints::Hidden,
+            HeaderHints::Hidden => HeaderHints::All,
+        }
+    }
+}
+
+impl fmt::Display for HeaderHints {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HeaderHints::All => write!(f, "Verb, tense and person"),
+            HeaderHints::NoPerson => write!(f, "Verb and tense"),
+            HeaderHints::VerbOnly => write!(f, "Verb only"),
+            HeaderHints::Hidden => write!(f, "None"),
+        }
+    }
+}
+
+/// Which way the questions of a lesson go when no --mode is given
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Direction {
+    /// From the prompt to the German form
+    #[default]
+    ToGerman,
+    /// From the German form to its tense and person
+    FromGerman,
+}
+
+impl Direction {
+    pub fn next(self) -> Direction {
+        match self {
+            Direction::ToGerman => Direction::FromGerman,
+            Direction::FromGerman => Direction::ToGerman,
+        }
+    }
+
+    /// The mode the lessons are in
+    pub fn mode(self) -> Mode {
+        match self {
+            Direction::ToGerman => Mode::Translate,
+            Direction::FromGerman => Mode::Recognize,
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Direction::ToGerman => write!(f, "Prompt → German"),
+            Direction::FromGerman => write!(f, "German → tense and person"),
+        }
+    }
+}
+
+/// How hard the lessons are: sets the strictness and header hints, and
+/// which tenses new lessons ask and whether they are timed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum Difficulty {
+    /// Lenient grading, every hint including the auxiliary, autocomplete,
+    /// and only the present, past, perfect and future
+    Beginner,
+    /// Normal grading, the verb, tense and person in the header, and every tense
+    #[default]
+    Intermediate,
+    /// Exact grading, only the verb in the header, every tense, and lessons
+    /// timed to five minutes
+    Advanced,
+}
+
+impl Difficulty {
+    pub fn next(self) -> Difficulty {
+        match self {
+            Difficulty::Beginner => Difficulty::Intermediate,
+            Difficulty::Intermediate => Difficulty::Advanced,
+            Difficulty::Advanced => Difficulty::Beginner,
+        }
+    }
+
+    /// Sets the settings that are part of the preset
+    pub fn apply(self, config: &mut Config) {
+        config.difficulty = self;
+        config.strictness = self.strictness();
+        config.hints = self.hints();
+        config.auxiliary_hint = self.auxiliary_hint();
+        config.autocomplete = self.autocomplete();
+    }
+
+    pub fn strictness(self) -> Strictness {
+        match self {
+            Difficulty::Beginner => Strictness::Lenient,
+            Difficulty::Intermediate => Strictness::Normal,
+            Difficulty::Advanced => Strictness::Exact,
+        }
+    }
+
+    pub fn hints(self) -> HeaderHints {
+        match self {
+            Difficulty::Beginner | Difficulty::Intermediate => HeaderHints::All,
+            Difficulty::Advanced => HeaderHints::VerbOnly,
+        }
+    }
+
+    pub fn auxiliary_hint(self) -> bool {
+        self == Difficulty::Beginner
+    }
+
+    pub fn autocomplete(self) -> bool {
+        self == Difficulty::Beginner
+    }
+
+    /// The tenses of a new lesson, unless some are picked
+    pub fn tenses(self) -> Vec<Tense> {
+        match self {
+            Difficulty::Beginner => vec![
+                Tense::Present,
+                Tense::PerfectPresent,
+                Tense::Past,
+                Tense::Future,
+            ],
+            Difficulty::Intermediate | Difficulty::Advanced => Tense::ALL.to_vec(),
+        }
+    }
+
+    /// How many minutes a lesson runs for, unless a number of questions or
+    /// minutes is given. None for a set number of questions
+    pub fn minutes(self) -> Option<u16> {
+        match self {
+            Difficulty::Advanced => Some(5),
+            Difficulty::Beginner | Difficulty::Intermediate => None,
+        }
+    }
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Difficulty::Beginner => write!(f, "Beginner"),
+            Difficulty::Intermediate => write!(f, "Intermediate"),
+            Difficulty::Advanced => write!(f, "Advanced"),
+        }
+    }
+}
+
+impl Config {
+    /// Loads the config, or the defaults if there is no config file yet
+    pub fn load(config_dir: &Path) -> Result<Config, Box<dyn Error>> {
+        let path = config_dir.join(CONFIG_FILE);
+        if !path.exists() {
+            return Ok(Config::default());
+        }
+        let config = Config::parse(&fs::read_to_string(&path)?)
+            .map_err(|e| format!("Could not parse {}: {}", path.display(), e))?;
+        Ok(config)
+    }
+
+    /// Reads the config in the contents of a config file
+    pub fn parse(text: &str) -> Result<Config, Box<dyn Error>> {
+        let config: Config = toml::from_str(text)?;
+        if config.grace_seconds > MAX_GRACE_SECONDS {
+            return Err(format!(
+                "grace_seconds is {}, but can be at most {}",
+                config.grace_seconds, MAX_GRACE_SECONDS
+            )
+            .into());
+        }
+        if config.auto_advance_ms > MAX_AUTO_ADVANCE_MS {
+            return Err(format!(
+                "auto_advance_ms is {}, but can be at most {}",
+                config.auto_advance_ms, MAX_AUTO_ADVANCE_MS
+            )
+            .into());
+        }
+        for (kind, weight) in [
+            ("typed", config.mix.typed),
+            ("choice", config.mix.choice),
+            ("cloze", config.mix.cloze),
+        ] {
+            if weight > MAX_WEIGHT {
+                return Err(format!(
+                    "mix.{} is {}, but can be at most {}",
+                    kind, weight, MAX_WEIGHT
+                )
+                .into());
+            }
+        }
+        if config.mix.total() == 0 {
+            return Err("mix has no kind of question to ask".into());
+        }
+        Ok(config)
+    }
+
+    pub fn save(&self, config_dir: &Path) -> Result<(), Box<dyn Error>> {
+        fs::create_dir_all(config_dir)?;
+        fs::write(config_dir.join(CONFIG_FILE), toml::to_string(self)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mixes_that_would_overflow_are_rejected() {
+        let error = Config::parse("[mix]\ntyped = 4294967295\nchoice = 1\n").unwrap_err();
+        assert!(error.to_string().contains("mix.typed"), "{}", error);
+        assert!(Config::parse("[mix]\ntyped = 1000\nchoice = 1000\ncloze = 1000\n").is_ok());
+    }
+
+    #[test]
+    fn mixes_with_nothing_to_ask_are_rejected() {
+        assert!(Config::parse("[mix]\ntyped = 0\nchoice = 0\ncloze = 0\n").is_err());
+    }
+}
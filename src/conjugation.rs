@@ -0,0 +1,306 @@
+use csv::{ByteRecord, Position, Reader, ReaderBuilder, StringRecord};
+use serde::{Deserialize, Serialize};
+use std::{
+    cell::RefCell,
+    error::Error,
+    fs,
+    io::{Cursor, SeekFrom},
+    path::Path,
+    str,
+    sync::Arc,
+};
+
+use crate::person::Person;
+use crate::tense::Tense;
+use crate::verb::Verb;
+
+/// The columns every verb file must have, next to at least one source language
+const REQUIRED_COLUMNS: [&str; 3] = ["Tense", "Person", "German"];
+
+/// Columns that are not a source language
+const OPTIONAL_COLUMNS: [&str; 5] = ["example_de", "example_en", "ipa", "hint", "distractors"];
+
+/// Put around the stressed syllables of the German, e.g. `ich habe ge*ges*sen`
+pub const STRESS_MARK: char = '*';
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Conjugation {
+    pub person: Person,
+    pub tense: Tense,
+    pub verb: Arc<Verb>, // shared by the conjugations of the verb
+    pub prompt: String,  // The form in the source language (see `Args::from`)
+    pub german: String,
+    pub stress: Option<String>, // The German with its stressed syllables marked, if they are
+    pub example_de: Option<String>, // An example sentence using the form, if given
+    pub example_en: Option<String>, // The translation of the example sentence
+    pub ipa: Option<String>,    // How the German is pronounced, in IPA, if given
+    pub hint: Option<String>,   // Tells the prompt from the same one in another tense, e.g. Perfekt
+    pub distractors: Vec<(Tense, Person)>, // The wrong choices to offer first when recognizing it
+}
+
+thread_local! {
+    /// Reads every verb file parsed on the thread, as setting up a reader
+    /// takes longer than reading a verb file with it
+    static READER: RefCell<Reader<Cursor<Vec<u8>>>> = RefCell::new(new_reader());
+}
+
+/// A reader each verb file is read into from the start, its headers being
+/// its first row. Flexible, as the rows of one file aren't compared to
+/// another's (`Rows` compares them to their headers).
+fn new_reader() -> Reader<Cursor<Vec<u8>>> {
+    let mut rdr = ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(Cursor::new(Vec::new()));
+    // the reader reads its headers on the first seek, so those are had (and
+    // empty) before any verb file is read
+    let _ = rdr.seek_raw(SeekFrom::Start(0), Position::new());
+    rdr
+}
+
+/// The rows of a verb file after its headers
+struct Rows<'a> {
+    rdr: &'a mut Reader<Cursor<Vec<u8>>>,
+    columns: usize, // in the headers
+}
+
+impl Rows<'_> {
+    /// Reads the next row into `record`, returning false at the end of the
+    /// file. A row with more or fewer fields than the headers is an error.
+    fn next(&mut self, record: &mut ByteRecord) -> Result<bool, Box<dyn Error>> {
+        if !self.rdr.read_byte_record(record)? {
+            return Ok(false);
+        }
+        if record.len() != self.columns {
+            let line = record.position().map_or(0, |p| p.line());
+            return Err(format!(
+                "Line {} has {} fields, but the headers have {}",
+                line,
+                record.len(),
+                self.columns
+            )
+            .into());
+        }
+        Ok(true)
+    }
+}
+
+/// Reads the contents of a verb file with the thread's reader, giving its
+/// headers and rows
+fn read_verb_file<T>(
+    bytes: Vec<u8>,
+    read: impl FnOnce(&StringRecord, Rows) -> Result<T, Box<dyn Error>>,
+) -> Result<T, Box<dyn Error>> {
+    READER.with_borrow_mut(|rdr| {
+        *rdr.get_mut() = Cursor::new(bytes);
+        rdr.seek_raw(SeekFrom::Start(0), Position::new())?;
+        let mut headers = ByteRecord::new();
+        rdr.read_byte_record(&mut headers)?;
+        let headers = StringRecord::from_byte_record(headers)
+            .map_err(|e| format!("The headers aren't UTF-8: {}", e.utf8_error()))?;
+        let columns = headers.len();
+        read(&headers, Rows { rdr, columns })
+    })
+}
+
+/// Finds the index of the column named `name` (case insensitive) in the headers
+fn column_index(headers: &StringRecord, name: &str) -> Result<usize, Box<dyn Error>> {
+    headers
+        .iter()
+        .position(|h| h.trim().eq_ignore_ascii_case(name))
+        .ok_or_else(|| format!("No '{}' column in the verb file", name).into())
+}
+
+/// Whether the column holds a source language, i.e. isn't one of the others
+fn is_language(header: &str) -> bool {
+    !REQUIRED_COLUMNS
+        .iter()
+        .chain(OPTIONAL_COLUMNS.iter())
+        .any(|c| header.trim().eq_ignore_ascii_case(c))
+}
+
+/// Gets the non-empty field of an optional column
+fn optional_field(record: &ByteRecord, col: Option<usize>) -> Option<String> {
+    col.and_then(|c| record.get(c))
+        .and_then(|f| str::from_utf8(f).ok())
+        .map(str::trim)
+        .filter(|f| !f.is_empty())
+        .map(String::from)
+}
+
+/// Gets the field at `col`, erroring (with the line number) if the row is too
+/// short or the field isn't UTF-8
+fn field(record: &ByteRecord, col: usize) -> Result<&str, Box<dyn Error>> {
+    let line = || record.position().map_or(0, |p| p.line());
+    let bytes = record
+        .get(col)
+        .ok_or_else(|| format!("Missing column {} on line {}", col + 1, line()))?;
+    str::from_utf8(bytes)
+        .map_err(|_| format!("Column {} on line {} isn't UTF-8", col + 1, line()).into())
+}
+
+/// Parses the hand-picked distractors of a row, written as the choices show
+/// them and separated by semicolons, e.g. `Subjective II | I; Present | I`
+fn parse_distractors(field: Option<String>) -> Result<Vec<(Tense, Person)>, Box<dyn Error>> {
+    let Some(field) = field else {
+        return Ok(vec![]);
+    };
+    field
+        .split(';')
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+        .map(|d| {
+            let (tense, person) = d
+                .split_once('|')
+                .ok_or_else(|| format!("Distractor not written as Tense | Person: {}", d))?;
+            Ok((tense.trim().parse()?, person.trim().parse()?))
+        })
+        .collect()
+}
+
+/// Loads and parses the conjugations for the verb
+///
+/// The prompt is read from the column named after the source language `from`,
+/// so a file can carry several translations next to the German column, e.g.
+/// `Tense,Person,English,French,German`.
+///
+/// The `example_de` and `example_en` columns, with an example sentence for the
+/// form, are optional, and so are the `ipa` column with its pronunciation and
+/// the `hint` column, shown with prompts that are the same in several tenses.
+/// The `distractors` column hand-picks the wrong choices of recognition
+/// questions, the rest being picked at random.
+/// The stressed syllables of the German can be marked with `*`s, e.g.
+/// `ich habe ge*ges*sen`; they are left out of the answer.
+pub fn parse_conjugations(verb: &Verb, from: &str) -> Result<Vec<Conjugation>, Box<dyn Error>> {
+    parse_conjugations_from(verb, from, fs::read(verb.path())?)
+}
+
+/// Parses the conjugations for the verb out of the contents of its file
+pub fn parse_conjugations_from(
+    verb: &Verb,
+    from: &str,
+    bytes: Vec<u8>,
+) -> Result<Vec<Conjugation>, Box<dyn Error>> {
+    read_verb_file(bytes, |headers, rows| {
+        conjugations_of(verb, from, headers, rows)
+    })
+}
+
+/// Parses the rows of a verb file into the conjugations of the verb
+fn conjugations_of(
+    verb: &Verb,
+    from: &str,
+    headers: &StringRecord,
+    mut rows: Rows,
+) -> Result<Vec<Conjugation>, Box<dyn Error>> {
+    let tense_col = column_index(headers, "Tense")?;
+    let person_col = column_index(headers, "Person")?;
+    let prompt_col = column_index(headers, from)?;
+    let german_col = column_index(headers, "German")?;
+    let example_de_col = column_index(headers, "example_de").ok();
+    let example_en_col = column_index(headers, "example_en").ok();
+    let ipa_col = column_index(headers, "ipa").ok();
+    let hint_col = column_index(headers, "hint").ok();
+    let distractors_col = column_index(headers, "distractors").ok();
+
+    // most verb files have the whole table, and the rows are read into the
+    // same record, as parsing thousands of verbs adds up
+    let mut conjugations = Vec::with_capacity(Tense::ALL.len() * Person::ALL.len());
+    let verb = Arc::new(verb.clone());
+    let mut record = ByteRecord::new();
+    while rows.next(&mut record)? {
+        let tense = field(&record, tense_col)?.parse()?;
+        let person = field(&record, person_col)?.parse()?;
+        let prompt = field(&record, prompt_col)?.to_string();
+        let marked = field(&record, german_col)?;
+        let (german, stress) = match marked.contains(STRESS_MARK) {
+            true => (marked.replace(STRESS_MARK, ""), Some(marked.to_string())),
+            false => (marked.to_string(), None),
+        };
+        let con = Conjugation {
+            verb: Arc::clone(&verb),
+            tense,
+            person,
+            prompt,
+            german,
+            stress,
+            example_de: optional_field(&record, example_de_col),
+            example_en: optional_field(&record, example_en_col),
+            ipa: optional_field(&record, ipa_col),
+            hint: optional_field(&record, hint_col),
+            distractors: parse_distractors(optional_field(&record, distractors_col))?,
+        };
+        conjugations.push(con);
+    }
+
+    Ok(conjugations)
+}
+
+/// The source languages a verb file has prompts in, e.g. English and French
+pub fn languages(path: &Path) -> Result<Vec<String>, Box<dyn Error>> {
+    let mut rdr = ReaderBuilder::new().has_headers(true).from_path(path)?;
+    Ok(rdr
+        .headers()?
+        .iter()
+        .filter(|h| is_language(h))
+        .map(|h| h.trim().to_string())
+        .collect())
+}
+
+impl Conjugation {
+    /// The hint shown after the prompt, e.g. "(Perfekt)", if the verb file
+    /// gives one
+    pub fn prompt_hint(&self) -> Option<String> {
+        let hint = self.hint.as_ref()?;
+        Some(format!("({})", hint.trim_matches(['(', ')'])))
+    }
+
+    /// The parts of the German and whether each is stressed, None if the
+    /// stress isn't marked
+    pub fn stressed_parts(&self) -> Option<Vec<(&str, bool)>> {
+        let stress = self.stress.as_ref()?;
+        Some(
+            stress
+                .split(STRESS_MARK)
+                .enumerate()
+                .filter(|(_, part)| !part.is_empty())
+                .map(|(i, part)| (part, i % 2 == 1))
+                .collect(),
+        )
+    }
+}
+
+/// Checks that a verb file follows the schema: the required columns plus at
+/// least one source language column, and a known tense and person on every row
+/// and in its distractors.
+pub fn validate(path: &Path) -> Result<(), Box<dyn Error>> {
+    validate_bytes(fs::read(path)?)
+}
+
+/// Checks the contents of a verb file against the schema, as `validate` does
+pub fn validate_bytes(bytes: Vec<u8>) -> Result<(), Box<dyn Error>> {
+    read_verb_file(bytes, |headers, mut rows| {
+        for column in REQUIRED_COLUMNS {
+            column_index(headers, column)?;
+        }
+        if !headers.iter().any(is_language) {
+            return Err("No source language column in the verb file".into());
+        }
+
+        let tense_col = column_index(headers, "Tense")?;
+        let person_col = column_index(headers, "Person")?;
+        let distractors_col = column_index(headers, "distractors").ok();
+        let mut count = 0;
+        let mut record = ByteRecord::new();
+        while rows.next(&mut record)? {
+            field(&record, tense_col)?.parse::<Tense>()?;
+            field(&record, person_col)?.parse::<Person>()?;
+            parse_distractors(optional_field(&record, distractors_col))?;
+            count += 1;
+        }
+        if count == 0 {
+            return Err("The verb file has no conjugations".into());
+        }
+        Ok(())
+    })
+}
@@ -0,0 +1,284 @@
+use crate::morphology;
+use crate::person::Person;
+use crate::tense::Tense;
+
+/// The pronouns, in the order of `Person::ALL`
+const PRONOUNS: [&str; 6] = ["ich", "du", "er/sie/es", "wir", "ihr", "sie"];
+
+const HABEN: [[&str; 6]; 2] = [
+    ["habe", "hast", "hat", "haben", "habt", "haben"],
+    ["hatte", "hattest", "hatte", "hatten", "hattet", "hatten"],
+];
+const SEIN: [[&str; 6]; 2] = [
+    ["bin", "bist", "ist", "sind", "seid", "sind"],
+    ["war", "warst", "war", "waren", "wart", "waren"],
+];
+const WERDEN: [&str; 6] = ["werde", "wirst", "wird", "werden", "werdet", "werden"];
+
+/// The verb the perfect tenses are made with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Auxiliary {
+    Haben,
+    Sein,
+}
+
+impl Auxiliary {
+    /// The auxiliary a compound form was made with, e.g. sein for "ich bin
+    /// gegangen"
+    pub fn of(german: &str) -> Option<Auxiliary> {
+        let word = german.split_whitespace().nth(1)?;
+        if HABEN.iter().any(|forms| forms.contains(&word)) {
+            Some(Auxiliary::Haben)
+        } else if SEIN.iter().any(|forms| forms.contains(&word)) {
+            Some(Auxiliary::Sein)
+        } else {
+            None
+        }
+    }
+
+    /// The auxiliary a form of one of the perfect tenses was made with, None
+    /// for the other tenses
+    pub fn of_perfect(tense: Tense, german: &str) -> Option<Auxiliary> {
+        match tense {
+            Tense::PerfectPresent | Tense::PerfectPast => Auxiliary::of(german),
+            // the auxiliary comes last, e.g. "ich werde gegessen haben"
+            Tense::PerfectFuture => match german.split_whitespace().last()? {
+                "haben" => Some(Auxiliary::Haben),
+                "sein" => Some(Auxiliary::Sein),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// The auxiliary a verb's Perfect Present forms use, haben if none say
+    pub fn used_in<'a>(forms: impl IntoIterator<Item = (Tense, &'a str)>) -> Auxiliary {
+        forms
+            .into_iter()
+            .filter(|(tense, _)| *tense == Tense::PerfectPresent)
+            .find_map(|(_, german)| Auxiliary::of(german))
+            .unwrap_or(Auxiliary::Haben)
+    }
+
+    fn forms(self, past: bool) -> &'static [&'static str; 6] {
+        let forms = match self {
+            Auxiliary::Haben => &HABEN,
+            Auxiliary::Sein => &SEIN,
+        };
+        &forms[past as usize]
+    }
+
+    pub fn infinitive(self) -> &'static str {
+        match self {
+            Auxiliary::Haben => "haben",
+            Auxiliary::Sein => "sein",
+        }
+    }
+}
+
+/// How a form compares to the one the rules give
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Difference {
+    Regular,
+    StemChange, // only the vowels differ, as in "du isst"
+    Irregular,
+}
+
+/// Conjugates the verb as a regular (weak) verb would be, e.g. "du machst",
+/// "ich habe gemacht". Strong and mixed verbs come out wrong, which is what
+/// makes them stand out.
+pub fn conjugate(infinitive: &str, tense: Tense, person: Person, auxiliary: Auxiliary) -> String {
+    let p = Person::ALL.iter().position(|&p| p == person).unwrap_or(0);
+    let sep = morphology::separable_prefix(infinitive).unwrap_or_default();
+    let base = &infinitive[sep.len()..];
+    let (stem, plural) = match base.strip_suffix("en") {
+        Some(stem) => (stem, "en"),
+        None => (base.strip_suffix('n').unwrap_or(base), "n"),
+    };
+    // "arbeitest", not "arbeitst"
+    let e = if needs_e(stem) { "e" } else { "" };
+    let hissing = stem.ends_with(['s', 'ß', 'x', 'z']);
+
+    let participle = {
+        let ge = if morphology::inseparable_prefix(base).is_some() || base.ends_with("ieren") {
+            ""
+        } else {
+            "ge"
+        };
+        format!("{}{}{}{}t", sep, ge, stem, e)
+    };
+    let finite = |ending: &str| match sep {
+        "" => format!("{}{}", stem, ending),
+        _ => format!("{}{} {}", stem, ending, sep),
+    };
+    let verb = match tense {
+        Tense::Present => finite(&match p {
+            0 => "e".to_string(),
+            1 if hissing => "t".to_string(),
+            1 => format!("{}st", e),
+            2 | 4 => format!("{}t", e),
+            _ => plural.to_string(),
+        }),
+        // the Konjunktiv II of weak verbs is the same as their Präteritum
+        Tense::Past | Tense::SubjectiveII => {
+            finite(&format!("{}te{}", e, ["", "st", "", "n", "t", "n"][p]))
+        }
+        Tense::SubjectiveI => finite(["e", "est", "e", plural, "et", plural][p]),
+        Tense::PerfectPresent => format!("{} {}", auxiliary.forms(false)[p], participle),
+        Tense::PerfectPast => format!("{} {}", auxiliary.forms(true)[p], participle),
+        Tense::Future => format!("{} {}", WERDEN[p], infinitive),
+        Tense::PerfectFuture => {
+            format!("{} {} {}", WERDEN[p], participle, auxiliary.infinitive())
+        }
+    };
+    format!("{} {}", PRONOUNS[p], verb)
+}
+
+/// Whether an e goes between the stem and a t or st ending, as in "arbeitet"
+/// and "atmest"
+fn needs_e(stem: &str) -> bool {
+    let mut chars = stem.chars().rev();
+    match (chars.next(), chars.next()) {
+        (Some('d' | 't'), _) => true,
+        (Some('m' | 'n'), Some(c)) => !is_vowel(c) && !"lrmnh".contains(c),
+        _ => false,
+    }
+}
+
+fn is_vowel(c: char) -> bool {
+    "aeiouäöüy".contains(c.to_ascii_lowercase())
+}
+
+/// Compares a form with the one the rules give, leaving out the pronouns as
+/// they are written in different ways
+pub fn compare(form: &str, regular: &str) -> Difference {
+    let words = |s: &str| -> Vec<String> {
+        s.split_whitespace()
+            .skip(1)
+            .map(|w| w.to_lowercase())
+            .collect()
+    };
+    let (form, regular) = (words(form), words(regular));
+    if form == regular {
+        return Difference::Regular;
+    }
+    let consonants = |w: &str| -> String { w.chars().filter(|&c| !is_vowel(c)).collect() };
+    let same_consonants = form.len() == regular.len()
+        && form
+            .iter()
+            .zip(&regular)
+            .all(|(a, b)| consonants(a) == consonants(b));
+    if same_consonants {
+        Difference::StemChange
+    } else {
+        Difference::Irregular
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weak_verbs_take_the_regular_endings() {
+        let conj = |tense, person| conjugate("machen", tense, person, Auxiliary::Haben);
+        assert_eq!(conj(Tense::Present, Person::You), "du machst");
+        assert_eq!(conj(Tense::Past, Person::We), "wir machten");
+        assert_eq!(conj(Tense::SubjectiveI, Person::You), "du machest");
+        assert_eq!(conj(Tense::PerfectPast, Person::I), "ich hatte gemacht");
+        assert_eq!(conj(Tense::Future, Person::They), "sie werden machen");
+        assert_eq!(
+            conj(Tense::PerfectFuture, Person::I),
+            "ich werde gemacht haben"
+        );
+    }
+
+    #[test]
+    fn an_e_goes_before_the_t_after_a_d_t_or_hard_m_or_n() {
+        let present = |verb, person| conjugate(verb, Tense::Present, person, Auxiliary::Haben);
+        assert_eq!(present("arbeiten", Person::HeSheIt), "er/sie/es arbeitet");
+        assert_eq!(present("atmen", Person::You), "du atmest");
+        assert_eq!(present("lernen", Person::You), "du lernst");
+        assert_eq!(
+            conjugate(
+                "arbeiten",
+                Tense::PerfectPresent,
+                Person::I,
+                Auxiliary::Haben
+            ),
+            "ich habe gearbeitet"
+        );
+    }
+
+    #[test]
+    fn a_hissing_stem_only_takes_a_t_for_du() {
+        assert_eq!(
+            conjugate("tanzen", Tense::Present, Person::You, Auxiliary::Haben),
+            "du tanzt"
+        );
+    }
+
+    #[test]
+    fn prefixes_split_off_or_take_the_place_of_the_ge() {
+        assert_eq!(
+            conjugate("aufwachen", Tense::Present, Person::I, Auxiliary::Sein),
+            "ich wache auf"
+        );
+        assert_eq!(
+            conjugate(
+                "aufwachen",
+                Tense::PerfectPresent,
+                Person::I,
+                Auxiliary::Sein
+            ),
+            "ich bin aufgewacht"
+        );
+        assert_eq!(
+            conjugate(
+                "besuchen",
+                Tense::PerfectPresent,
+                Person::I,
+                Auxiliary::Haben
+            ),
+            "ich habe besucht"
+        );
+        assert_eq!(
+            conjugate(
+                "studieren",
+                Tense::PerfectPresent,
+                Person::I,
+                Auxiliary::Haben
+            ),
+            "ich habe studiert"
+        );
+    }
+
+    #[test]
+    fn the_auxiliary_is_read_off_the_perfect_forms() {
+        assert_eq!(Auxiliary::of("ich bin gegangen"), Some(Auxiliary::Sein));
+        assert_eq!(Auxiliary::of("ich gehe"), None);
+        assert_eq!(Auxiliary::of(""), None);
+        assert_eq!(
+            Auxiliary::of_perfect(Tense::PerfectFuture, "ich werde gegangen sein"),
+            Some(Auxiliary::Sein)
+        );
+        assert_eq!(Auxiliary::of_perfect(Tense::Past, "ich hatte"), None);
+        let forms = [
+            (Tense::Present, "ich bin"),
+            (Tense::PerfectPresent, "ich habe gehabt"),
+        ];
+        assert_eq!(Auxiliary::used_in(forms), Auxiliary::Haben);
+        assert_eq!(Auxiliary::used_in([]), Auxiliary::Haben);
+    }
+
+    #[test]
+    fn forms_differ_by_their_vowels_or_more() {
+        assert_eq!(compare("sie macht", "er/sie/es macht"), Difference::Regular);
+        assert_eq!(compare("du isst", "du esst"), Difference::StemChange);
+        assert_eq!(compare("ich ging", "ich gehte"), Difference::Irregular);
+        assert_eq!(
+            compare("ich bin gegangen", "ich bin gegeht"),
+            Difference::Irregular
+        );
+    }
+}
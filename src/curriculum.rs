@@ -0,0 +1,107 @@
+use csv::{ReaderBuilder, WriterBuilder};
+use std::{error::Error, path::Path, path::PathBuf};
+
+use crate::history::Answer;
+use crate::tense::Tense;
+
+/// How far the curriculum has got, kept in `<data_dir>/curriculum.csv`
+const CURRICULUM_FILE: &str = "curriculum.csv";
+
+/// The stages of the curriculum, in the order they are unlocked, with the
+/// tenses each one brings in
+pub const STAGES: [(&str, &[Tense]); 5] = [
+    ("Present", &[Tense::Present]),
+    ("Perfect", &[Tense::PerfectPresent]),
+    ("Preterite", &[Tense::Past, Tense::PerfectPast]),
+    ("Future", &[Tense::Future, Tense::PerfectFuture]),
+    ("Subjunctive", &[Tense::SubjectiveI, Tense::SubjectiveII]),
+];
+
+/// How many of the latest answers in the newest tenses decide if the next
+/// stage is unlocked
+const RECENT_ANSWERS: usize = 20;
+
+/// The share of those answers that have to be right to move on
+const PASS_MARK: f64 = 0.8;
+
+/// Brings in the tenses a stage at a time, across lessons: lessons ask the
+/// tenses unlocked so far, and the next ones are unlocked once the newest
+/// are mostly answered right
+pub struct Curriculum {
+    path: PathBuf,
+    pub stage: usize, // the index of the newest stage unlocked in `STAGES`
+}
+
+impl Curriculum {
+    /// Loads how far the curriculum has got, the first stage if it was
+    /// never started
+    pub fn load(data_dir: &Path) -> Result<Curriculum, Box<dyn Error>> {
+        let path = data_dir.join(CURRICULUM_FILE);
+        let mut stage = 0;
+        if path.exists() {
+            let mut rdr = ReaderBuilder::new().has_headers(true).from_path(&path)?;
+            if let Some(record) = rdr.records().next() {
+                let name = record?.get(0).unwrap_or_default().to_string();
+                stage = STAGES
+                    .iter()
+                    .position(|(n, _)| *n == name)
+                    .ok_or_else(|| format!("Unknown curriculum stage: {}", name))?;
+            }
+        }
+        Ok(Curriculum { path, stage })
+    }
+
+    /// The name of the newest stage, e.g. "Perfect"
+    pub fn name(&self) -> &'static str {
+        STAGES[self.stage].0
+    }
+
+    /// The tenses of every stage unlocked so far
+    pub fn tenses(&self) -> Vec<Tense> {
+        STAGES[..=self.stage]
+            .iter()
+            .flat_map(|(_, tenses)| tenses.iter().copied())
+            .collect()
+    }
+
+    /// Unlocks the next stage if enough of the latest answers in the newest
+    /// stage's tenses were right, returning true if it did
+    pub fn advance(&mut self, answers: &[Answer]) -> Result<bool, Box<dyn Error>> {
+        if self.stage + 1 == STAGES.len() {
+            return Ok(false);
+        }
+        let newest = STAGES[self.stage].1;
+        let recent: Vec<bool> = answers
+            .iter()
+            .rev()
+            .filter(|a| newest.contains(&a.tense))
+            .take(RECENT_ANSWERS)
+            .map(|a| a.correct_unaided())
+            .collect();
+        if recent.len() < RECENT_ANSWERS {
+            return Ok(false);
+        }
+        let right = recent.iter().filter(|&&c| c).count();
+        if (right as f64) < PASS_MARK * recent.len() as f64 {
+            return Ok(false);
+        }
+        self.stage += 1;
+        self.save()?;
+        Ok(true)
+    }
+
+    /// Starts the curriculum from the stage, as a placement test found it,
+    /// whether that is ahead of where it got or behind
+    pub fn place(&mut self, stage: usize) -> Result<(), Box<dyn Error>> {
+        self.stage = stage.min(STAGES.len() - 1);
+        self.save()
+    }
+
+    fn save(&self) -> Result<(), Box<dyn Error>> {
+        let mut wtr = WriterBuilder::new().from_path(&self.path)?;
+        wtr.write_record(["Stage"])?;
+        wtr.write_record([self.name()])?;
+        wtr.flush()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,360 @@
+use csv::{ReaderBuilder, WriterBuilder};
+use ratatui::{
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Alignment, Constraint},
+    style::{Style, Styled, Stylize},
+    symbols::border,
+    text::Line,
+    widgets::{
+        block::{Position, Title},
+        Block, Cell, Row, Table, TableState,
+    },
+    Frame,
+};
+use std::{error::Error, fs};
+
+use crate::conjugation::{self, Conjugation, STRESS_MARK};
+use crate::conjugator::{self, Auxiliary, Difference};
+use crate::person::Person;
+use crate::tense::Tense;
+use crate::theme::Theme;
+use crate::verb::Verb;
+
+/// An editor for the rows of a verb file, so data errors found mid-quiz can be
+/// fixed (and new rows added) without leaving the app.
+pub struct Editor {
+    verb: Verb,
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+    table_state: TableState, // the selected row
+    col: usize,              // the selected column
+
+    editing: Option<String>, // the new value of the selected cell, while editing it
+    dirty: bool,             // whether there are unsaved changes
+    confirm_close: bool,     // whether Esc was pressed with unsaved changes
+    message: Option<String>, // the result of the last save
+}
+
+impl Editor {
+    /// Opens the verb file, with the cursor on the first row
+    pub fn open(verb: &Verb) -> Result<Editor, Box<dyn Error>> {
+        let mut rdr = ReaderBuilder::new()
+            .has_headers(true)
+            .from_path(verb.path())?;
+        let headers = rdr.headers()?.iter().map(String::from).collect();
+        let mut rows = Vec::new();
+        for result in rdr.records() {
+            rows.push(result?.iter().map(String::from).collect());
+        }
+
+        Ok(Editor {
+            verb: verb.clone(),
+            headers,
+            rows,
+            table_state: TableState::default().with_selected(0),
+            col: 0,
+            editing: None,
+            dirty: false,
+            confirm_close: false,
+            message: None,
+        })
+    }
+
+    fn column(&self, name: &str) -> Option<usize> {
+        self.headers
+            .iter()
+            .position(|h| h.trim().eq_ignore_ascii_case(name))
+    }
+
+    /// How each row's German compares to the regular form, None for rows
+    /// without a known tense and person (e.g. one being added)
+    fn differences(&self) -> Vec<Option<Difference>> {
+        let (Some(tense_col), Some(person_col), Some(german_col)) = (
+            self.column("Tense"),
+            self.column("Person"),
+            self.column("German"),
+        ) else {
+            return vec![None; self.rows.len()];
+        };
+        let parsed: Vec<Option<(Tense, Person, String)>> = self
+            .rows
+            .iter()
+            .map(|row| {
+                Some((
+                    row.get(tense_col)?.parse().ok()?,
+                    row.get(person_col)?.parse().ok()?,
+                    row.get(german_col)?.replace(STRESS_MARK, ""),
+                ))
+            })
+            .collect();
+        // the perfect tenses are compared with the auxiliary the file uses
+        let auxiliary =
+            Auxiliary::used_in(parsed.iter().flatten().map(|(t, _, g)| (*t, g.as_str())));
+        parsed
+            .into_iter()
+            .map(|row| {
+                let (tense, person, german) = row?;
+                let regular = conjugator::conjugate(self.verb.name(), tense, person, auxiliary);
+                Some(conjugator::compare(&german, &regular))
+            })
+            .collect()
+    }
+
+    /// Moves the cursor to the row of the conjugation
+    pub fn select_conjugation(&mut self, conj: &Conjugation) {
+        let (tense_col, person_col, german_col) = (
+            self.column("Tense"),
+            self.column("Person"),
+            self.column("German"),
+        );
+        let found = self.rows.iter().position(|row| {
+            let cell = |c: Option<usize>| c.and_then(|c| row.get(c)).map(|s| s.as_str());
+            cell(tense_col).and_then(|t| t.parse::<Tense>().ok()) == Some(conj.tense)
+                && cell(person_col).and_then(|p| p.parse::<Person>().ok()) == Some(conj.person)
+                && cell(german_col).map(|g| g.replace(STRESS_MARK, "")) == Some(conj.german.clone())
+        });
+        if let Some(i) = found {
+            self.table_state.select(Some(i));
+        }
+    }
+
+    /// Handles a key press, returning true when the editor should be closed
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> bool {
+        if let Some(value) = &mut self.editing {
+            match key_event.code {
+                KeyCode::Enter => self.commit_cell(),
+                KeyCode::Esc => self.editing = None,
+                KeyCode::Backspace => {
+                    value.pop();
+                }
+                KeyCode::Char(c) => value.push(c),
+                _ => {}
+            }
+            return false;
+        }
+
+        if key_event.code != KeyCode::Esc {
+            self.confirm_close = false;
+        }
+        match key_event.code {
+            KeyCode::Esc => {
+                if !self.dirty || self.confirm_close {
+                    return true;
+                }
+                self.confirm_close = true;
+            }
+            KeyCode::Enter => self.editing = self.cell().cloned(),
+            KeyCode::Char('a') => self.add_row(),
+            KeyCode::Char('d') => self.delete_row(),
+            KeyCode::Char('s') => self.save(),
+            KeyCode::Up | KeyCode::Char('k') => self.table_state.select_previous(),
+            KeyCode::Down | KeyCode::Char('j') => self.move_down(),
+            KeyCode::Left | KeyCode::Char('h') => self.col = self.col.saturating_sub(1),
+            KeyCode::Right | KeyCode::Char('l') => {
+                self.col = (self.col + 1).min(self.headers.len().saturating_sub(1))
+            }
+            _ => {}
+        }
+        false
+    }
+
+    fn cell(&self) -> Option<&String> {
+        self.rows.get(self.table_state.selected()?)?.get(self.col)
+    }
+
+    fn move_down(&mut self) {
+        if let Some(i) = self.table_state.selected() {
+            self.table_state
+                .select(Some((i + 1).min(self.rows.len().saturating_sub(1))));
+        }
+    }
+
+    fn commit_cell(&mut self) {
+        let (Some(value), Some(i)) = (self.editing.take(), self.table_state.selected()) else {
+            return;
+        };
+        if let Some(cell) = self.rows.get_mut(i).and_then(|r| r.get_mut(self.col)) {
+            if *cell != value {
+                *cell = value;
+                self.dirty = true;
+            }
+        }
+    }
+
+    /// Adds a row below the cursor, with the same tense and person as the
+    /// current row, and starts editing its first translation
+    fn add_row(&mut self) {
+        let i = self.table_state.selected().map_or(0, |i| i + 1);
+        let mut row = vec![String::new(); self.headers.len()];
+        if let Some(current) = self.table_state.selected().and_then(|i| self.rows.get(i)) {
+            for (col, header) in self.headers.iter().enumerate() {
+                if ["tense", "person"].contains(&header.trim().to_lowercase().as_str()) {
+                    row[col] = current[col].clone();
+                }
+            }
+        }
+        self.rows.insert(i.min(self.rows.len()), row);
+        self.table_state.select(Some(i.min(self.rows.len() - 1)));
+        self.col = 2.min(self.headers.len().saturating_sub(1));
+        self.editing = Some(String::new());
+        self.dirty = true;
+    }
+
+    fn delete_row(&mut self) {
+        if let Some(i) = self.table_state.selected().filter(|i| *i < self.rows.len()) {
+            self.rows.remove(i);
+            self.table_state
+                .select(Some(i.min(self.rows.len().saturating_sub(1))));
+            self.dirty = true;
+        }
+    }
+
+    /// Writes the rows back to the verb file. They are written to a temporary
+    /// file and checked against the schema first, so a mistake can't break the
+    /// verb file.
+    fn save(&mut self) {
+        self.message = Some(match self.write() {
+            Ok(()) => {
+                self.dirty = false;
+                format!("Saved {}", self.verb.path().display())
+            }
+            Err(e) => format!("Not saved: {}", e),
+        });
+    }
+
+    fn write(&self) -> Result<(), Box<dyn Error>> {
+        let tmp = self.verb.path().with_extension("csv.tmp");
+        let result = (|| -> Result<(), Box<dyn Error>> {
+            let mut wtr = WriterBuilder::new().from_path(&tmp)?;
+            wtr.write_record(&self.headers)?;
+            for row in &self.rows {
+                wtr.write_record(row)?;
+            }
+            wtr.flush()?;
+            drop(wtr);
+            conjugation::validate(&tmp)
+        })();
+        match result {
+            Ok(()) => Ok(fs::rename(&tmp, self.verb.path())?),
+            Err(e) => {
+                let _ = fs::remove_file(&tmp);
+                Err(e)
+            }
+        }
+    }
+
+    pub fn render(&mut self, theme: &Theme, frame: &mut Frame) {
+        let title = Title::from(
+            format!(
+                " Edit {}{} ",
+                self.verb,
+                if self.dirty { " (modified)" } else { "" }
+            )
+            .bold(),
+        );
+        let instructions = if self.editing.is_some() {
+            Title::from(Line::from(vec![
+                " Done ".into(),
+                "<Enter> ".set_style(theme.key),
+                " Cancel ".into(),
+                "<Esc> ".set_style(theme.key),
+            ]))
+        } else if self.confirm_close {
+            Title::from(Line::from(vec![
+                " Unsaved changes! Save ".into(),
+                "<s> ".set_style(theme.key),
+                " Discard ".into(),
+                "<Esc> ".set_style(theme.key),
+            ]))
+        } else {
+            Title::from(Line::from(vec![
+                " Move ".into(),
+                "<Arrows> ".set_style(theme.key),
+                " Edit ".into(),
+                "<Enter> ".set_style(theme.key),
+                " Add Row ".into(),
+                "<a> ".set_style(theme.key),
+                " Delete Row ".into(),
+                "<d> ".set_style(theme.key),
+                " Save ".into(),
+                "<s> ".set_style(theme.key),
+                " Close ".into(),
+                "<Esc> ".set_style(theme.key),
+            ]))
+        };
+        let mut block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+        // what the colours of the German column mean
+        block = block.title(
+            Title::from(Line::from(vec![
+                " Stem change ".set_style(theme.notice),
+                " Irregular ".set_style(theme.prompt.bold()),
+            ]))
+            .alignment(Alignment::Right),
+        );
+        if let Some(message) = &self.message {
+            block = block.title(
+                Title::from(format!(" {} ", message).set_style(theme.notice))
+                    .alignment(Alignment::Right)
+                    .position(Position::Bottom),
+            );
+        }
+
+        let selected = self.table_state.selected();
+        let german_col = self.column("German");
+        let differences = self.differences();
+        let rows: Vec<Row> = self
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                Row::new(row.iter().enumerate().map(|(col, cell)| {
+                    if Some(i) != selected || col != self.col {
+                        let style = match differences[i] {
+                            _ if Some(col) != german_col => Style::new(),
+                            Some(Difference::StemChange) => theme.notice,
+                            Some(Difference::Irregular) => theme.prompt.bold(),
+                            _ => Style::new(),
+                        };
+                        return Cell::from(cell.as_str().set_style(style));
+                    }
+                    match &self.editing {
+                        Some(value) => Cell::from(format!("{}_", value).set_style(theme.input)),
+                        None => Cell::from(cell.as_str()).style(Style::new().bold().underlined()),
+                    }
+                }))
+            })
+            .collect();
+
+        // size the columns to fit their longest cell
+        let widths: Vec<Constraint> = (0..self.headers.len())
+            .map(|col| {
+                let longest = self
+                    .rows
+                    .iter()
+                    .filter_map(|r| r.get(col))
+                    .chain(std::iter::once(&self.headers[col]))
+                    .map(|c| c.chars().count())
+                    .max()
+                    .unwrap_or(0);
+                Constraint::Length(longest as u16 + 2)
+            })
+            .collect();
+
+        let table = Table::new(rows, widths)
+            .header(Row::new(
+                self.headers.iter().map(|h| Cell::from(h.as_str().bold())),
+            ))
+            .highlight_style(Style::new().reversed())
+            .highlight_symbol(">>")
+            .block(block);
+
+        frame.render_stateful_widget(table, frame.area(), &mut self.table_state)
+    }
+}
@@ -0,0 +1,134 @@
+use std::{
+    collections::HashMap,
+    io,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, Receiver, Sender},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+use tokio::{
+    runtime::{Builder, Runtime},
+    task,
+};
+
+use crate::message::Message;
+
+/// The work that waits on the network, run in the background
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Task {
+    /// Pushing the progress to the remote in the `[sync]` config
+    Sync,
+}
+
+/// How a task run in the background is going
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Progress {
+    Started,
+    Done,
+    Failed(String),
+}
+
+type Work = Box<dyn FnOnce() -> Result<(), String> + Send>;
+
+/// Runs the effects that wait on the network on a tokio runtime, so the
+/// screen keeps being drawn meanwhile. How they go comes back to the app as
+/// messages.
+pub struct Effects {
+    runtime: Runtime,
+    sender: Sender<Message>,
+    receiver: Receiver<Message>,
+    running: Arc<AtomicUsize>, // the tasks not finished yet
+    // The tasks in flight, with the work to run once they're done if asked
+    // for again meanwhile
+    queued: Arc<Mutex<HashMap<Task, Option<Work>>>>,
+}
+
+impl Effects {
+    pub fn new() -> io::Result<Effects> {
+        let runtime = Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("effects")
+            .build()?;
+        let (sender, receiver) = mpsc::channel();
+        Ok(Effects {
+            runtime,
+            sender,
+            receiver,
+            running: Arc::default(),
+            queued: Arc::default(),
+        })
+    }
+
+    /// Runs the work in the background, reporting when it starts and how it
+    /// ended. If the task is already running, the work waits for it to end
+    /// instead, taking the place of any work that was waiting, so e.g. two
+    /// pushes never run at once.
+    pub fn spawn<F>(&self, task: Task, work: F)
+    where
+        F: FnOnce() -> Result<(), String> + Send + 'static,
+    {
+        {
+            let mut queued = self.queued.lock().unwrap();
+            if let Some(next) = queued.get_mut(&task) {
+                *next = Some(Box::new(work));
+                return;
+            }
+            queued.insert(task, None);
+        }
+        self.running.fetch_add(1, Ordering::SeqCst);
+        let _ = self.sender.send(Message::Progress(task, Progress::Started));
+        let sender = self.sender.clone();
+        let running = Arc::clone(&self.running);
+        let queued = Arc::clone(&self.queued);
+        let mut work: Work = Box::new(work);
+        self.runtime.spawn(async move {
+            loop {
+                // git and the like block, so they get a thread of their own
+                let progress = match task::spawn_blocking(work).await {
+                    Ok(Ok(())) => Progress::Done,
+                    Ok(Err(e)) => Progress::Failed(e),
+                    Err(e) => Progress::Failed(e.to_string()),
+                };
+                let _ = sender.send(Message::Progress(task, progress));
+                let mut queued = queued.lock().unwrap();
+                match queued.get_mut(&task).and_then(Option::take) {
+                    Some(next) => {
+                        let _ = sender.send(Message::Progress(task, Progress::Started));
+                        work = next;
+                    }
+                    None => {
+                        queued.remove(&task);
+                        break;
+                    }
+                }
+            }
+            // counted until the last report is sent, so the loop is still
+            // awake for it
+            running.fetch_sub(1, Ordering::SeqCst);
+        });
+    }
+
+    /// What the tasks reported since last asked
+    pub fn messages(&self) -> Vec<Message> {
+        self.receiver.try_iter().collect()
+    }
+
+    /// Whether any task is still running
+    pub fn busy(&self) -> bool {
+        self.running.load(Ordering::SeqCst) > 0
+    }
+
+    /// Drops the work waiting to run and waits for the tasks still running,
+    /// e.g. before the push made when quitting
+    pub fn finish(&self) {
+        for next in self.queued.lock().unwrap().values_mut() {
+            *next = None;
+        }
+        while self.busy() {
+            thread::sleep(Duration::from_millis(50));
+        }
+    }
+}
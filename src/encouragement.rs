@@ -0,0 +1,83 @@
+use csv::ReaderBuilder;
+use std::{collections::BTreeMap, error::Error, fs, path::Path};
+
+/// The lines cheering on right answers, kept in `<data_dir>/encouragement.csv`
+/// with a `Streak` column and one column per source language (e.g. English,
+/// French). Lines with no streak are said in turn, and those with one when a
+/// streak of right answers reaches it, `{streak}` standing for its length.
+const ENCOURAGEMENT_FILE: &str = "encouragement.csv";
+
+/// The lines used when the data directory has none of its own
+const DEFAULT: &str = include_str!("../encouragement.csv");
+
+/// The language used for lines not given in the source language
+const FALLBACK: &str = "English";
+
+/// The encouragement in one language
+#[derive(Debug, Default)]
+pub struct Encouragement {
+    lines: Vec<String>,
+    milestones: BTreeMap<usize, String>,
+}
+
+impl Encouragement {
+    /// Loads the lines in the source language from the encouragement file,
+    /// or the ones that come with the app if there is none
+    pub fn load(data_dir: &Path, language: &str) -> Result<Encouragement, Box<dyn Error>> {
+        let path = data_dir.join(ENCOURAGEMENT_FILE);
+        if path.exists() {
+            parse(&fs::read_to_string(path)?, language)
+        } else {
+            parse(DEFAULT, language)
+        }
+    }
+
+    /// What to say about the `answered`th right answer of the lesson, which
+    /// makes a streak of `streak`: the milestone it reaches if any, else the
+    /// next of the lines
+    pub fn line(&self, answered: usize, streak: usize) -> Option<String> {
+        if let Some(line) = self.milestones.get(&streak) {
+            return Some(line.replace("{streak}", &streak.to_string()));
+        }
+        if self.lines.is_empty() {
+            return None;
+        }
+        Some(self.lines[answered % self.lines.len()].clone())
+    }
+}
+
+fn parse(csv: &str, language: &str) -> Result<Encouragement, Box<dyn Error>> {
+    let mut rdr = ReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(csv.as_bytes());
+    let headers = rdr.headers()?.clone();
+    let column = |name: &str| headers.iter().position(|h| h.trim() == name);
+    let streak = column("Streak").ok_or("The encouragement file has no Streak column")?;
+    let columns: Vec<usize> = column(language)
+        .into_iter()
+        .chain(column(FALLBACK))
+        .collect();
+    let mut encouragement = Encouragement::default();
+    for result in rdr.records() {
+        let record = result?;
+        // the first of the languages the line is given in
+        let Some(line) = columns
+            .iter()
+            .filter_map(|&i| record.get(i).map(str::trim))
+            .find(|l| !l.is_empty())
+        else {
+            continue;
+        };
+        match record.get(streak).map(str::trim).unwrap_or_default() {
+            "" => encouragement.lines.push(line.to_string()),
+            n => {
+                let line_no = record.position().map_or(0, |p| p.line());
+                let n = n
+                    .parse()
+                    .map_err(|_| format!("Bad streak {:?} on line {}", n, line_no))?;
+                encouragement.milestones.insert(n, line.to_string());
+            }
+        }
+    }
+    Ok(encouragement)
+}
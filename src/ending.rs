@@ -0,0 +1,58 @@
+use rand::{rngs::StdRng, seq::SliceRandom};
+
+use crate::conjugation::Conjugation;
+use crate::morphology::{self, Morpheme, Word};
+
+/// How many endings a question offers to pick from
+pub const NUM_ENDINGS: usize = 4;
+
+/// The endings of the verb forms, which the wrong choices are picked from
+const ENDINGS: [&str; 11] = [
+    "e", "st", "t", "en", "n", "et", "est", "te", "test", "ten", "tet",
+];
+
+/// A German form split around the ending to give, e.g. "ich wach", "e" and
+/// " auf" for "ich wache auf"
+pub struct Blank {
+    pub before: String,
+    pub ending: String,
+    pub after: String,
+}
+
+/// Splits the German form around the ending of its verb (the participle's
+/// in a compound tense), or None if it has none, as "ich bin" hasn't
+pub fn blank(conj: &Conjugation) -> Option<Blank> {
+    let words = morphology::analyze(conj);
+    let (w, p) = words.iter().enumerate().find_map(|(w, word)| {
+        let p = word.iter().position(|(_, m)| *m == Morpheme::Ending)?;
+        Some((w, p))
+    })?;
+    let text = |pieces: &[(String, Morpheme)]| -> String {
+        pieces.iter().map(|(text, _)| text.as_str()).collect()
+    };
+    let join = |words: &[Word]| -> Vec<String> { words.iter().map(|w| text(w)).collect() };
+
+    let mut before = join(&words[..w]);
+    before.push(text(&words[w][..p]));
+    let mut after = vec![text(&words[w][p + 1..])];
+    after.extend(join(&words[w + 1..]));
+    Some(Blank {
+        before: before.join(" "),
+        ending: words[w][p].0.clone(),
+        after: after.join(" "),
+    })
+}
+
+/// The endings to pick from: the right one and others, in a random order
+pub fn choices(ending: &str, rng: &mut StdRng) -> Vec<String> {
+    let mut others: Vec<&str> = ENDINGS.into_iter().filter(|&e| e != ending).collect();
+    others.shuffle(rng);
+    let mut choices: Vec<String> = others
+        .into_iter()
+        .take(NUM_ENDINGS - 1)
+        .map(String::from)
+        .collect();
+    choices.push(ending.to_string());
+    choices.shuffle(rng);
+    choices
+}
@@ -0,0 +1,99 @@
+use chrono::Local;
+use serde::Serialize;
+use std::{error::Error, fs::OpenOptions, io::Write, path::Path};
+
+use crate::history::Confidence;
+use crate::mode::Mode;
+use crate::person::Person;
+use crate::tense::Tense;
+
+/// The event log, see `Event` for its schema
+const EVENTS_FILE: &str = "events.jsonl";
+
+/// The question an event is about
+#[derive(Debug, Serialize)]
+pub struct Question<'a> {
+    pub verb: &'a str,
+    pub tense: Tense,
+    pub person: Person,
+    pub mode: Mode,
+    pub question: u32,
+    pub questions: Option<u32>,
+}
+
+/// An append-only log of what happens in the lessons, for running your own
+/// analyses. It is kept in `<data_dir>/events.jsonl` with one JSON object per
+/// line, and is never rewritten, only added to.
+///
+/// Every event has:
+///
+/// - `time`: when it happened, in RFC 3339 with the local offset
+/// - `event`: `question_shown` or `answer_submitted`
+/// - `verb`: the verb's file name, e.g. `essen`
+/// - `tense`: one of `Present`, `PerfectPresent`, `Past`, `PerfectPast`,
+///   `Future`, `PerfectFuture`, `SubjectiveI`, `SubjectiveII`
+/// - `person`: one of `I`, `You`, `HeSheIt`, `We`, `YouPl`, `They`
+/// - `mode`: `translate`, `recognize`, `dictation`, `principal-parts`,
+///   `table` or `hangman`
+/// - `question`: the number of the question in the lesson, from 1
+/// - `questions`: how many questions the lesson has, or null when it runs for
+///   a time instead (`--minutes`)
+///
+/// `question_shown` is logged when a question is asked, and has no other
+/// fields. `answer_submitted` is logged when it is answered, with:
+///
+/// - `response`: what was answered (the tense and person in recognize mode)
+/// - `expected`: the right answer
+/// - `correct`: whether the answer was graded as right
+/// - `skipped`: whether the question was skipped (with an empty `response`),
+///   which counts as wrong
+/// - `confidence`: `guess` or `sure` if the answer was tagged with how sure
+///   the learner was, else null
+/// - `latency_ms`: the milliseconds from the question being shown to the answer
+///
+/// Fields may be added in the future, so ignore the ones you don't know.
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event<'a> {
+    QuestionShown {
+        #[serde(flatten)]
+        question: Question<'a>,
+    },
+    AnswerSubmitted {
+        #[serde(flatten)]
+        question: Question<'a>,
+        response: &'a str,
+        expected: &'a str,
+        correct: bool,
+        skipped: bool,
+        confidence: Option<Confidence>,
+        latency_ms: u128,
+    },
+}
+
+#[derive(Serialize)]
+struct Record<'a> {
+    time: String,
+    #[serde(flatten)]
+    event: &'a Event<'a>,
+}
+
+/// The event as a line of the log, stamped with the current time (without the
+/// newline)
+pub fn to_json(event: &Event) -> serde_json::Result<String> {
+    serde_json::to_string(&Record {
+        time: Local::now().to_rfc3339(),
+        event,
+    })
+}
+
+/// Appends a line made by `to_json` to the log
+pub fn append(data_dir: &Path, line: &str) -> Result<(), Box<dyn Error>> {
+    let line = format!("{}\n", line);
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(data_dir.join(EVENTS_FILE))?
+        .write_all(line.as_bytes())?;
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use csv::{ReaderBuilder, WriterBuilder};
+use std::{error::Error, path::Path, path::PathBuf};
+
+use crate::history::Answer;
+
+/// The verbs starred in the verbs table, kept in `<data_dir>/favorites.csv`
+const FAVORITES_FILE: &str = "favorites.csv";
+
+/// How many of the verbs practiced last are listed as recent
+const RECENT_VERBS: usize = 5;
+
+/// The verbs starred to find them quickly, listed first in the verbs table
+pub struct Favorites {
+    path: PathBuf,
+    verbs: Vec<String>, // by name, in the order they were starred
+}
+
+impl Favorites {
+    /// Loads the favorites, or none if there is no favorites file yet
+    pub fn load(data_dir: &Path) -> Result<Favorites, Box<dyn Error>> {
+        let path = data_dir.join(FAVORITES_FILE);
+        let mut verbs = Vec::new();
+        if path.exists() {
+            let mut rdr = ReaderBuilder::new().has_headers(true).from_path(&path)?;
+            for result in rdr.records() {
+                verbs.push(result?.get(0).unwrap_or_default().to_string());
+            }
+        }
+        Ok(Favorites { path, verbs })
+    }
+
+    pub fn contains(&self, verb: &str) -> bool {
+        self.verbs.iter().any(|v| v == verb)
+    }
+
+    /// Stars the verb, or unstars it if it was, leaving the favorites file to
+    /// be saved
+    pub fn toggle(&mut self, verb: &str) {
+        match self.verbs.iter().position(|v| v == verb) {
+            Some(i) => {
+                self.verbs.remove(i);
+            }
+            None => self.verbs.push(verb.to_string()),
+        }
+    }
+
+    /// Writes the starred verbs to the favorites file
+    pub fn save(&self) -> Result<(), Box<dyn Error>> {
+        let mut wtr = WriterBuilder::new().from_path(&self.path)?;
+        wtr.write_record(["Verb"])?;
+        for verb in &self.verbs {
+            wtr.write_record([verb])?;
+        }
+        wtr.flush()?;
+        Ok(())
+    }
+}
+
+/// The verbs answered last in the history, the latest first
+pub fn recent(answers: &[Answer]) -> Vec<String> {
+    let mut verbs: Vec<String> = Vec::new();
+    for answer in answers.iter().rev() {
+        if !verbs.contains(&answer.verb) {
+            verbs.push(answer.verb.clone());
+            if verbs.len() == RECENT_VERBS {
+                break;
+            }
+        }
+    }
+    verbs
+}
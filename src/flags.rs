@@ -0,0 +1,68 @@
+use chrono::{DateTime, Local};
+use csv::{ReaderBuilder, WriterBuilder};
+use std::{error::Error, fs::OpenOptions, path::Path};
+
+use crate::conjugation::Conjugation;
+
+/// Conjugations the user flagged as "data looks wrong", kept in
+/// `<data_dir>/flags.csv` so they can be corrected later.
+const FLAGS_FILE: &str = "flags.csv";
+
+const HEADERS: [&str; 8] = [
+    "Time", "File", "Verb", "Tense", "Person", "Prompt", "German", "Response",
+];
+
+/// Appends the conjugation, with what the user answered, to the flags file
+pub fn flag(data_dir: &Path, conj: &Conjugation, response: &str) -> Result<(), Box<dyn Error>> {
+    let path = data_dir.join(FLAGS_FILE);
+    let is_new = !path.exists();
+    let file = OpenOptions::new().create(true).append(true).open(path)?;
+    let mut wtr = WriterBuilder::new().has_headers(false).from_writer(file);
+    if is_new {
+        wtr.write_record(HEADERS)?;
+    }
+    wtr.write_record([
+        Local::now().to_rfc3339(),
+        conj.verb.path().display().to_string(),
+        conj.verb.name().to_string(),
+        conj.tense.to_string(),
+        conj.person.to_string(),
+        conj.prompt.clone(),
+        conj.german.clone(),
+        response.to_string(),
+    ])?;
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Prints the flagged conjugations, oldest first
+pub fn list(data_dir: &Path) -> Result<(), Box<dyn Error>> {
+    let path = data_dir.join(FLAGS_FILE);
+    if !path.exists() {
+        println!("No conjugations flagged");
+        return Ok(());
+    }
+
+    let mut rdr = ReaderBuilder::new().has_headers(true).from_path(path)?;
+    let mut count = 0;
+    for result in rdr.records() {
+        let record = result?;
+        let field = |i: usize| record.get(i).unwrap_or_default();
+        let time = DateTime::parse_from_rfc3339(field(0))
+            .map(|t| t.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_else(|_| field(0).to_string());
+        println!(
+            "{} {} ({} | {}): \"{}\" -> \"{}\", answered \"{}\"",
+            time,
+            field(1),
+            field(3),
+            field(4),
+            field(5),
+            field(6),
+            field(7)
+        );
+        count += 1;
+    }
+    println!("{} flagged", count);
+    Ok(())
+}
@@ -0,0 +1,167 @@
+use rand::{rngs::StdRng, Rng};
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Alignment, Rect},
+    style::{Styled, Stylize},
+    symbols::border,
+    text::{Line, Text},
+    widgets::{
+        block::{Position, Title},
+        Block, Paragraph, Widget,
+    },
+};
+use std::time::{Duration, Instant};
+
+use crate::nouns::{Gender, Noun};
+use crate::theme::Theme;
+
+/// The rapid-fire der/die/das quiz: each key press answers and moves straight
+/// on to the next noun, with the answers per minute kept as the score
+pub struct GenderQuiz {
+    nouns: Vec<Noun>,
+    rng: StdRng,
+    current: usize,                // the noun being asked
+    last: Option<(usize, Gender)>, // the noun asked before, and what was answered
+    correct: u32,
+    incorrect: u32,
+    started_at: Option<Instant>, // from the first answer, so reading the screen is free
+    answered_at: Option<Instant>, // the latest answer
+}
+
+impl GenderQuiz {
+    /// Starts the quiz, None if there are no nouns to ask
+    pub fn new(nouns: Vec<Noun>, mut rng: StdRng) -> Option<GenderQuiz> {
+        if nouns.is_empty() {
+            return None;
+        }
+        let current = rng.gen_range(0..nouns.len());
+        Some(GenderQuiz {
+            nouns,
+            rng,
+            current,
+            last: None,
+            correct: 0,
+            incorrect: 0,
+            started_at: None,
+            answered_at: None,
+        })
+    }
+
+    /// Handles a key press, returning true when the quiz should be closed
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> bool {
+        let gender = match key_event.code {
+            KeyCode::Esc | KeyCode::Char('q') => return true,
+            KeyCode::Char('d') | KeyCode::Char('1') => Gender::Masculine,
+            KeyCode::Char('i') | KeyCode::Char('2') => Gender::Feminine,
+            KeyCode::Char('a') | KeyCode::Char('3') => Gender::Neuter,
+            _ => return false,
+        };
+        self.answer(gender);
+        false
+    }
+
+    fn answer(&mut self, gender: Gender) {
+        let now = Instant::now();
+        self.started_at.get_or_insert(now);
+        self.answered_at = Some(now);
+        if self.nouns[self.current].gender == gender {
+            self.correct += 1;
+        } else {
+            self.incorrect += 1;
+        }
+        self.last = Some((self.current, gender));
+
+        // never the same noun twice in a row, as the answer would be on screen
+        let mut next = self.rng.gen_range(0..self.nouns.len());
+        if self.nouns.len() > 1 {
+            while next == self.current {
+                next = self.rng.gen_range(0..self.nouns.len());
+            }
+        }
+        self.current = next;
+    }
+
+    /// How many answers (and right answers) there were per minute, from the
+    /// first answer to the latest
+    fn per_minute(&self) -> Option<(f64, f64)> {
+        let answers = self.correct + self.incorrect;
+        // a single answer has no pace yet
+        let took = self.answered_at? - self.started_at?;
+        if answers < 2 || took < Duration::from_secs(1) {
+            return None;
+        }
+        let minutes = took.as_secs_f64() / 60.0;
+        // the first answer starts the clock, so it isn't counted in the pace
+        let paced = f64::from(answers - 1);
+        let right = paced * f64::from(self.correct) / f64::from(answers);
+        Some((paced / minutes, right / minutes))
+    }
+
+    pub fn render(&self, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        let title = Title::from(" der, die, das? ".bold());
+        let instructions = Title::from(Line::from(vec![
+            " der ".into(),
+            "<d/1> ".set_style(theme.key),
+            " die ".into(),
+            "<i/2> ".set_style(theme.key),
+            " das ".into(),
+            "<a/3> ".set_style(theme.key),
+            " Back ".into(),
+            "<Esc> ".set_style(theme.key),
+        ]));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+
+        let noun = &self.nouns[self.current];
+        let mut text = Text::from(vec![
+            Line::from(""),
+            Line::from(""),
+            Line::from(vec![
+                "___ ".into(),
+                noun.german.as_str().set_style(theme.prompt),
+            ]),
+            Line::from(format!("({})", noun.english)),
+            Line::from(""),
+        ]);
+        if let Some((i, answered)) = self.last {
+            let last = &self.nouns[i];
+            text.push_line(if last.gender == answered {
+                Line::from(format!("{}{}", theme.correct_mark, last).set_style(theme.correct))
+            } else {
+                Line::from(
+                    format!("{}{} (not {})", theme.incorrect_mark, last, answered)
+                        .set_style(theme.incorrect),
+                )
+            });
+        } else {
+            text.push_line(Line::from(""));
+        }
+        text.push_line(Line::from(""));
+
+        let answers = self.correct + self.incorrect;
+        if answers > 0 {
+            let mut stats = format!(
+                "{} answered, {} right ({}%)",
+                answers,
+                self.correct,
+                self.correct * 100 / answers
+            );
+            if let Some((answers, right)) = self.per_minute() {
+                stats += &format!(" | {:.1}/min, {:.1} right/min", answers, right);
+            }
+            text.push_line(Line::from(stats));
+        }
+
+        Paragraph::new(text)
+            .centered()
+            .block(block)
+            .render(area, buf);
+    }
+}
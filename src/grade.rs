@@ -0,0 +1,156 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// How strictly a response is compared to the answer
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum Strictness {
+    /// Character for character
+    Exact,
+    /// Ignoring case, surrounding whitespace and repeated spaces
+    #[default]
+    Normal,
+    /// Like `Normal`, and also ignoring punctuation
+    Lenient,
+}
+
+impl Strictness {
+    /// The next strictness, from the most lenient to the most exact and round
+    pub fn next(self) -> Strictness {
+        match self {
+            Strictness::Lenient => Strictness::Normal,
+            Strictness::Normal => Strictness::Exact,
+            Strictness::Exact => Strictness::Lenient,
+        }
+    }
+}
+
+impl fmt::Display for Strictness {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Strictness::Exact => write!(f, "Exact"),
+            Strictness::Normal => write!(f, "Normal"),
+            Strictness::Lenient => write!(f, "Lenient"),
+        }
+    }
+}
+
+/// The pronouns a conjugated form can start with. Forms that fit more than one
+/// person list them with slashes, e.g. `er/sie/es isst`.
+const PRONOUNS: [&str; 7] = ["ich", "du", "er", "sie", "es", "wir", "ihr"];
+
+/// How umlauts and ß are written without a German keyboard
+const UMLAUTS: [(char, &str); 7] = [
+    ('ä', "ae"),
+    ('ö', "oe"),
+    ('ü', "ue"),
+    ('Ä', "Ae"),
+    ('Ö', "Oe"),
+    ('Ü', "Ue"),
+    ('ß', "ss"),
+];
+
+/// Grades responses against the conjugated forms they should be.
+///
+/// Built with chained options, starting from the defaults the app grades with:
+/// `Normal` strictness, umlauts have to be typed, and so does the pronoun.
+///
+/// ```
+/// use deutschland_tui::grade::{Grader, Strictness};
+///
+/// let grader = Grader::new();
+/// assert!(grader.grade("Ich  esse", "ich esse"));
+/// assert!(!grader.grade("esse", "ich esse"));
+///
+/// let grader = Grader::new()
+///     .strictness(Strictness::Lenient)
+///     .fold_umlauts(true)
+///     .pronoun_optional(true);
+/// assert!(grader.grade("du isst!", "du isst"));
+/// assert!(grader.grade("assen", "wir aßen"));
+/// assert!(grader.grade("er aesse", "er/sie/es äße"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Grader {
+    strictness: Strictness,
+    fold_umlauts: bool,
+    pronoun_optional: bool,
+}
+
+impl Grader {
+    pub fn new() -> Grader {
+        Grader::default()
+    }
+
+    pub fn strictness(mut self, strictness: Strictness) -> Grader {
+        self.strictness = strictness;
+        self
+    }
+
+    /// Whether ä, ö, ü and ß can also be typed as ae, oe, ue and ss
+    pub fn fold_umlauts(mut self, fold_umlauts: bool) -> Grader {
+        self.fold_umlauts = fold_umlauts;
+        self
+    }
+
+    /// Whether the pronoun can be left out, or be any one of the pronouns a
+    /// form lists (e.g. `sie isst` for `er/sie/es isst`)
+    pub fn pronoun_optional(mut self, pronoun_optional: bool) -> Grader {
+        self.pronoun_optional = pronoun_optional;
+        self
+    }
+
+    /// Normalizes the text the way responses and answers are compared
+    pub fn normalize(&self, text: &str) -> String {
+        let mut text = match self.strictness {
+            Strictness::Exact => text.to_string(),
+            Strictness::Normal => text.to_lowercase(),
+            Strictness::Lenient => text
+                .to_lowercase()
+                .chars()
+                // the slashes separate the pronouns of forms like er/sie/es
+                .filter(|c| !c.is_ascii_punctuation() || *c == '/')
+                .collect(),
+        };
+        if self.fold_umlauts {
+            for (umlaut, folded) in UMLAUTS {
+                text = text.replace(umlaut, folded);
+            }
+        }
+        if self.strictness == Strictness::Exact {
+            return text;
+        }
+        text.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    /// Whether the response is right for the answer
+    pub fn grade(&self, response: &str, answer: &str) -> bool {
+        let (response, answer) = (self.normalize(response), self.normalize(answer));
+        if response == answer {
+            return true;
+        }
+        if !self.pronoun_optional {
+            return false;
+        }
+
+        let Some((pronouns, form)) = split_pronouns(&answer) else {
+            return false;
+        };
+        match split_pronouns(&response) {
+            Some((pronoun, rest)) => pronouns.split('/').any(|p| p == pronoun) && rest == form,
+            None => response == form,
+        }
+    }
+}
+
+/// Splits a form into its pronoun(s) and the rest, if it starts with a pronoun
+fn split_pronouns(form: &str) -> Option<(&str, &str)> {
+    let (first, rest) = form.split_once(' ')?;
+    let is_pronoun = first.split('/').all(|p| {
+        PRONOUNS
+            .iter()
+            .any(|pronoun| pronoun.eq_ignore_ascii_case(p))
+    });
+    is_pronoun.then_some((first, rest))
+}
@@ -0,0 +1,116 @@
+use deutschland_tui::grade::Grader;
+use rand::{rngs::StdRng, seq::index};
+
+use crate::conjugation::Conjugation;
+use crate::person::Person;
+
+/// A form of the table, by its index in the lesson's conjugations
+#[derive(Debug, Clone)]
+pub struct Cell {
+    pub conjugation: usize,
+    pub given: bool, // shown filled in, rather than asked
+    pub response: String,
+    pub correct: Option<bool>, // once graded, if the response was right
+}
+
+/// The conjugation table of a verb in one tense, in table mode: a few of the
+/// forms are given and the others are typed in, each graded on its own
+#[derive(Debug, Clone, Default)]
+pub struct Grid {
+    pub cells: Vec<Cell>, // in the order of `Person::ALL`
+    pub field: usize,     // the cell being typed, always one that is asked
+}
+
+impl Grid {
+    /// The table of the verb and tense of the conjugation at `of`, with about
+    /// a third of the forms given (at least one, unless there is only one)
+    pub fn new(conjugations: &[Conjugation], of: usize, rng: &mut StdRng) -> Grid {
+        let asked = &conjugations[of];
+        let mut cells: Vec<Cell> = (0..conjugations.len())
+            .filter(|&i| conjugations[i].verb == asked.verb && conjugations[i].tense == asked.tense)
+            .map(|conjugation| Cell {
+                conjugation,
+                given: false,
+                response: String::new(),
+                correct: None,
+            })
+            .collect();
+        cells.sort_by_key(|c| {
+            Person::ALL
+                .iter()
+                .position(|&p| p == conjugations[c.conjugation].person)
+        });
+        let given = (cells.len() / 3).max(1).min(cells.len() - 1);
+        for i in index::sample(rng, cells.len(), given) {
+            cells[i].given = true;
+        }
+        let field = cells.iter().position(|c| !c.given).unwrap_or_default();
+        Grid { cells, field }
+    }
+
+    /// The cells to be typed in
+    pub fn blanks(&self) -> impl Iterator<Item = &Cell> {
+        self.cells.iter().filter(|c| !c.given)
+    }
+
+    /// Whether every blank cell has something typed in it
+    pub fn is_filled(&self) -> bool {
+        self.blanks().all(|c| !c.response.trim().is_empty())
+    }
+
+    /// Whether the field is the last blank cell
+    pub fn at_last(&self) -> bool {
+        self.cells[self.field + 1..].iter().all(|c| c.given)
+    }
+
+    /// Moves the field to the next blank cell, if there is one
+    pub fn next(&mut self) {
+        if let Some(i) = self.cells[self.field + 1..].iter().position(|c| !c.given) {
+            self.field += i + 1;
+        }
+    }
+
+    /// Moves the field to the blank cell before, if there is one
+    pub fn prev(&mut self) {
+        if let Some(i) = self.cells[..self.field].iter().rposition(|c| !c.given) {
+            self.field = i;
+        }
+    }
+
+    /// The response being typed
+    pub fn response(&mut self) -> &mut String {
+        &mut self.cells[self.field].response
+    }
+
+    /// Fills the blank cells in order from the forms separated by commas
+    pub fn fill(&mut self, answer: &str) {
+        let blanks = self.cells.iter_mut().filter(|c| !c.given);
+        for (cell, form) in blanks.zip(answer.split(',')) {
+            cell.response = form.trim().to_string();
+        }
+    }
+
+    /// Grades each blank cell, returning true if all of them are right
+    pub fn grade(&mut self, conjugations: &[Conjugation], grader: Grader) -> bool {
+        for cell in self.cells.iter_mut().filter(|c| !c.given) {
+            let german = &conjugations[cell.conjugation].german;
+            cell.correct = Some(grader.grade(&cell.response, german));
+        }
+        self.blanks().all(|c| c.correct == Some(true))
+    }
+
+    /// The responses of the blank cells, separated by commas
+    pub fn responses(&self) -> String {
+        let responses: Vec<&str> = self.blanks().map(|c| c.response.as_str()).collect();
+        responses.join(", ")
+    }
+
+    /// The forms of the blank cells, separated by commas
+    pub fn answer(&self, conjugations: &[Conjugation]) -> String {
+        let forms: Vec<&str> = self
+            .blanks()
+            .map(|c| conjugations[c.conjugation].german.as_str())
+            .collect();
+        forms.join(", ")
+    }
+}
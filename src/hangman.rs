@@ -0,0 +1,30 @@
+/// How many wrong letters a hangman question allows
+pub const MAX_MISSES: usize = 6;
+
+/// The German form with the letters not yet guessed hidden behind
+/// underscores, e.g. "i__ _ss_" once i and s are guessed
+pub fn masked(german: &str, guesses: &[char]) -> String {
+    german
+        .chars()
+        .map(|c| {
+            let guessed = guesses
+                .iter()
+                .any(|g| c.to_lowercase().eq(g.to_lowercase()));
+            if c.is_alphabetic() && !guessed {
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+/// The guesses that aren't letters of the German form
+pub fn misses(german: &str, guesses: &[char]) -> Vec<char> {
+    let german = german.to_lowercase();
+    guesses
+        .iter()
+        .filter(|g| !g.to_lowercase().all(|c| german.contains(c)))
+        .copied()
+        .collect()
+}
@@ -0,0 +1,161 @@
+use chrono::{DateTime, FixedOffset, Local};
+use csv::{ReaderBuilder, WriterBuilder};
+use serde::{Deserialize, Serialize};
+use std::{
+    error::Error,
+    fmt,
+    fs::{File, OpenOptions},
+    io::Read,
+    path::Path,
+    time::Duration,
+};
+
+use crate::conjugation::Conjugation;
+use crate::person::Person;
+use crate::srs::Key;
+use crate::tense::Tense;
+
+/// Every answered question, kept in `<data_dir>/history.csv` for the reports
+const HISTORY_FILE: &str = "history.csv";
+
+const HEADERS: [&str; 9] = [
+    "Time",
+    "Verb",
+    "Tense",
+    "Person",
+    "Correct",
+    "Seconds",
+    "Confidence",
+    "Partial",
+    "Assisted",
+];
+
+/// How sure the learner said they were of an answer, before submitting it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Confidence {
+    Guess,
+    Sure,
+}
+
+impl fmt::Display for Confidence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Confidence::Guess => write!(f, "guess"),
+            Confidence::Sure => write!(f, "sure"),
+        }
+    }
+}
+
+/// An answered question
+#[derive(Debug, Clone, PartialEq)]
+pub struct Answer {
+    pub time: DateTime<FixedOffset>,
+    pub verb: String,
+    pub tense: Tense,
+    pub person: Person,
+    pub correct: bool,
+    pub seconds: f64,                   // how long the question took to answer
+    pub confidence: Option<Confidence>, // if it was tagged before submitting
+    pub partial: bool,                  // if the lesson was left before its end
+    pub assisted: bool,                 // if autocomplete showed the next letters as it was typed
+}
+
+impl Answer {
+    /// The answer to the conjugation, given just now
+    pub fn new(
+        conj: &Conjugation,
+        correct: bool,
+        took: Duration,
+        confidence: Option<Confidence>,
+    ) -> Answer {
+        Answer {
+            time: Local::now().fixed_offset(),
+            verb: conj.verb.name().to_string(),
+            tense: conj.tense,
+            person: conj.person,
+            correct,
+            seconds: took.as_secs_f64(),
+            confidence,
+            partial: false,
+            assisted: false,
+        }
+    }
+
+    /// Whether it was right without autocomplete's help, as only those show
+    /// the form is known
+    pub fn correct_unaided(&self) -> bool {
+        self.correct && !self.assisted
+    }
+
+    /// The form that was asked, as the review schedule knows it
+    pub fn key(&self) -> Key {
+        (self.verb.clone(), self.tense, self.person)
+    }
+}
+
+/// Appends the answers to the history file
+pub fn record(data_dir: &Path, answers: &[Answer]) -> Result<(), Box<dyn Error>> {
+    let path = data_dir.join(HISTORY_FILE);
+    let is_new = !path.exists();
+    let file = OpenOptions::new().create(true).append(true).open(path)?;
+    let mut wtr = WriterBuilder::new().has_headers(false).from_writer(file);
+    if is_new {
+        wtr.write_record(HEADERS)?;
+    }
+    for answer in answers {
+        wtr.write_record([
+            answer.time.to_rfc3339(),
+            answer.verb.clone(),
+            format!("{:?}", answer.tense),
+            answer.person.to_string(),
+            answer.correct.to_string(),
+            format!("{:.1}", answer.seconds),
+            answer.confidence.map(|c| c.to_string()).unwrap_or_default(),
+            answer.partial.to_string(),
+            answer.assisted.to_string(),
+        ])?;
+    }
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Reads all the answers, oldest first. No history file means no answers.
+pub fn load(data_dir: &Path) -> Result<Vec<Answer>, Box<dyn Error>> {
+    let path = data_dir.join(HISTORY_FILE);
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    parse(File::open(path)?)
+}
+
+/// Reads the answers in the contents of a history file, oldest first
+pub fn parse(data: impl Read) -> Result<Vec<Answer>, Box<dyn Error>> {
+    // older files have answers without the confidence, partial or assisted
+    // columns
+    let mut rdr = ReaderBuilder::new()
+        .has_headers(true)
+        .flexible(true)
+        .from_reader(data);
+    let mut answers = Vec::new();
+    for result in rdr.records() {
+        let record = result?;
+        let field = |i: usize| record.get(i).unwrap_or_default();
+        answers.push(Answer {
+            time: DateTime::parse_from_rfc3339(field(0))?,
+            verb: field(1).to_string(),
+            tense: field(2).parse()?,
+            person: field(3).parse()?,
+            correct: field(4).parse()?,
+            seconds: field(5).parse()?,
+            confidence: match field(6) {
+                "guess" => Some(Confidence::Guess),
+                "sure" => Some(Confidence::Sure),
+                _ => None,
+            },
+            partial: field(7) == "true",
+            assisted: field(8) == "true",
+        });
+    }
+    Ok(answers)
+}
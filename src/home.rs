@@ -0,0 +1,159 @@
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Alignment, Rect},
+    style::{Style, Styled, Stylize},
+    symbols::border,
+    text::{Line, Text},
+    widgets::{
+        block::{Position, Title},
+        Block, Paragraph, Widget,
+    },
+};
+
+use crate::theme::Theme;
+
+/// Where the home screen leads
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Destination {
+    Verbs,
+    Nouns,
+    Prepositions,
+    Cases,
+    Collocations,
+    Politeness,
+    Numbers,
+    Vocab,
+    Match,
+    Stats,
+    Scores,
+    Settings,
+}
+
+impl Destination {
+    /// Every destination, in the order they are listed
+    pub const ALL: [Destination; 12] = [
+        Destination::Verbs,
+        Destination::Nouns,
+        Destination::Prepositions,
+        Destination::Cases,
+        Destination::Collocations,
+        Destination::Politeness,
+        Destination::Numbers,
+        Destination::Vocab,
+        Destination::Match,
+        Destination::Stats,
+        Destination::Scores,
+        Destination::Settings,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Destination::Verbs => "Verbs",
+            Destination::Nouns => "Nouns",
+            Destination::Prepositions => "Prepositions",
+            Destination::Cases => "Cases",
+            Destination::Collocations => "Collocations",
+            Destination::Politeness => "Politeness",
+            Destination::Numbers => "Numbers",
+            Destination::Vocab => "Vocab",
+            Destination::Match => "Match",
+            Destination::Stats => "Stats",
+            Destination::Scores => "Scores",
+            Destination::Settings => "Settings",
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Destination::Verbs => "conjugate verbs, in lessons you build",
+            Destination::Nouns => "der, die or das, as fast as you can",
+            Destination::Prepositions => "which case each preposition takes",
+            Destination::Cases => "which case each verb takes",
+            Destination::Collocations => "the prepositions verbs go with, e.g. warten auf",
+            Destination::Politeness => "polite requests in the Konjunktiv II",
+            Destination::Numbers => "spell out numbers, times and dates",
+            Destination::Vocab => "flashcards from the vocabulary decks",
+            Destination::Match => "pair prompts with German forms, for a break",
+            Destination::Stats => "how the last week of practice went",
+            Destination::Scores => "the lessons to beat, best first",
+            Destination::Settings => "theme, hints and more",
+        }
+    }
+}
+
+/// What the home screen wants done after a key press
+pub enum HomeEvent {
+    None,
+    Quit,
+    Open(Destination),
+    Search,
+}
+
+/// The top-level screen, listing what there is to practice
+#[derive(Default)]
+pub struct Home {
+    selected: usize,
+}
+
+impl Home {
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> HomeEvent {
+        match key_event.code {
+            KeyCode::Esc | KeyCode::Char('q') => return HomeEvent::Quit,
+            KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected = (self.selected + 1).min(Destination::ALL.len() - 1)
+            }
+            KeyCode::Enter => return HomeEvent::Open(Destination::ALL[self.selected]),
+            KeyCode::Char('/') => return HomeEvent::Search,
+            _ => {}
+        }
+        HomeEvent::None
+    }
+
+    /// Renders the list, with `error` (e.g. a data file that didn't load)
+    /// under it
+    pub fn render(&self, theme: &Theme, error: Option<&str>, area: Rect, buf: &mut Buffer) {
+        let title = Title::from(" Deutschland ".bold());
+        let instructions = Title::from(Line::from(vec![
+            " Move ".into(),
+            "<Up/Down> ".set_style(theme.key),
+            " Open ".into(),
+            "<Enter> ".set_style(theme.key),
+            " Search ".into(),
+            "</> ".set_style(theme.key),
+            " Quit ".into(),
+            "<Esc> ".set_style(theme.key),
+        ]));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+
+        let mut text = Text::from(vec![Line::from(""), Line::from("")]);
+        for (i, destination) in Destination::ALL.iter().enumerate() {
+            let line = Line::from(vec![
+                format!("{:<14}", destination.name()).bold(),
+                format!("{:<40}", destination.description()).into(),
+            ]);
+            if i == self.selected {
+                text.push_line(line.style(Style::new().reversed()));
+            } else {
+                text.push_line(line);
+            }
+        }
+        if let Some(error) = error {
+            text.push_line(Line::from(""));
+            text.push_line(Line::from(error.set_style(theme.incorrect)));
+        }
+
+        Paragraph::new(text)
+            .centered()
+            .block(block)
+            .render(area, buf);
+    }
+}
@@ -0,0 +1,82 @@
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use std::{
+    error::Error,
+    io::Write,
+    process::{Command, Stdio},
+    thread,
+};
+
+use crate::mode::Mode;
+
+/// Shell commands to run when things happen in the lessons, set in the
+/// `[hooks]` table of the config. Each gets a JSON object on its stdin.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Hooks {
+    /// After every answer, with the `answer_submitted` event of the event log
+    pub on_answer: Option<String>,
+    /// After the last question of a lesson, with a `LessonEnd`
+    pub on_lesson_end: Option<String>,
+}
+
+/// What the `on_lesson_end` hook is given
+#[derive(Debug, Serialize)]
+pub struct LessonEnd<'a> {
+    pub time: String,
+    pub verb: &'a str,
+    pub mode: Mode,
+    pub questions: u32,
+    pub correct: u32,
+    pub incorrect: u32,
+    pub skipped: u32, // also counted as incorrect
+}
+
+impl<'a> LessonEnd<'a> {
+    pub fn new(
+        verb: &'a str,
+        mode: Mode,
+        questions: u32,
+        correct: u32,
+        incorrect: u32,
+        skipped: u32,
+    ) -> Self {
+        LessonEnd {
+            time: Local::now().to_rfc3339(),
+            verb,
+            mode,
+            questions,
+            correct,
+            incorrect,
+            skipped,
+        }
+    }
+}
+
+/// Runs the hook's command with `json` on its stdin, in the background so a
+/// slow hook doesn't hold up the lesson. Its output is thrown away, as it
+/// would mess up the TUI.
+pub fn run(command: &str, json: String) -> Result<(), Box<dyn Error>> {
+    let mut child = if cfg!(windows) {
+        let mut shell = Command::new("cmd");
+        shell.args(["/C", command]);
+        shell
+    } else {
+        let mut shell = Command::new("sh");
+        shell.args(["-c", command]);
+        shell
+    }
+    .stdin(Stdio::piped())
+    .stdout(Stdio::null())
+    .stderr(Stdio::null())
+    .spawn()?;
+
+    let mut stdin = child.stdin.take().ok_or("Could not write to the hook")?;
+    thread::spawn(move || {
+        // the hook may not read its input, which is fine
+        let _ = stdin.write_all(json.as_bytes());
+        drop(stdin);
+        let _ = child.wait();
+    });
+    Ok(())
+}
@@ -0,0 +1,338 @@
+use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+use clap::{Args, ValueEnum};
+use deutschland_tui::grade::Grader;
+use serde_json::Value;
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::conjugation;
+use crate::srs::{self, Card, Srs};
+use crate::verb::Verb;
+use crate::vocab::{self, Direction};
+
+/// The days until the next review of a Duolingo word, by how many of its
+/// strength bars are filled
+const STRENGTH_INTERVALS: [f64; 5] = [0.0, 1.0, 3.0, 7.0, 16.0];
+
+/// Where the progress comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Source {
+    /// Anki's "Notes/Cards in Plain Text" export: tab separated, with the
+    /// German in one of the fields and, if added as columns, the Interval,
+    /// Ease, Reviews, Lapses and Last Review of each card
+    Anki,
+    /// Duolingo's vocabulary JSON, with the `word_string`, `strength_bars`
+    /// and `last_practiced_ms` of each word
+    Duolingo,
+}
+
+/// The options of the `import-progress` subcommand
+#[derive(Args, Debug)]
+pub struct ImportArgs {
+    /// The app the progress is exported from
+    #[arg(value_enum)]
+    source: Source,
+
+    /// The exported file
+    file: PathBuf,
+
+    /// The source language of the verb files and vocab decks to match against
+    #[arg(short, long, default_value = "English")]
+    from: String,
+}
+
+/// How well a German word or form was known in the other app
+#[derive(Debug, Clone)]
+struct Progress {
+    german: Vec<String>, // what could be the German, e.g. every field of an Anki note
+    reps: u32,
+    interval: f64, // days
+    ease: f64,
+    lapses: u32,
+    reviewed: Option<DateTime<FixedOffset>>,
+}
+
+impl Progress {
+    fn card(&self) -> Card {
+        Card::imported(
+            self.reps,
+            self.interval,
+            self.ease,
+            self.lapses,
+            self.reviewed,
+        )
+    }
+}
+
+/// Gives the verb forms and vocabulary known in Anki or Duolingo a head start
+/// in the review schedules. Forms and words already practiced here keep
+/// their schedule.
+pub fn run(args: ImportArgs, data_dir: &Path) -> Result<(), Box<dyn Error>> {
+    let text = fs::read_to_string(&args.file)
+        .map_err(|e| format!("Could not read {}: {}", args.file.display(), e))?;
+    let progress = match args.source {
+        Source::Anki => parse_anki(&text)?,
+        Source::Duolingo => parse_duolingo(&text)?,
+    };
+
+    // the pronoun can be left out, as the other apps often do
+    let grader = Grader::new().pronoun_optional(true);
+    let mut conjugations = Vec::new();
+    for verb in Verb::find_all(data_dir)? {
+        // a verb without the source language can't be matched, but the
+        // others still can
+        if let Ok(mut c) = conjugation::parse_conjugations(&verb, &args.from) {
+            conjugations.append(&mut c);
+        }
+    }
+    let decks = vocab::find_decks(data_dir, &args.from)?;
+
+    let mut forms = Vec::new();
+    let mut words = Vec::new();
+    let mut unmatched = 0;
+    for p in &progress {
+        let before = (forms.len(), words.len());
+        for conj in &conjugations {
+            if p.german.iter().any(|g| grader.grade(g, &conj.german)) {
+                forms.push((srs::key(conj), p.card()));
+            }
+        }
+        for deck in &decks {
+            let known = |w: &&vocab::Word| {
+                // the other apps often leave out the article of a noun
+                let noun = ["der ", "die ", "das "]
+                    .iter()
+                    .find_map(|a| w.german.strip_prefix(a));
+                p.german
+                    .iter()
+                    .any(|g| grader.grade(g, &w.german) || noun.is_some_and(|n| grader.grade(g, n)))
+            };
+            for word in deck.words.iter().filter(known) {
+                for direction in [Direction::ToGerman, Direction::FromGerman] {
+                    words.push((word.key(deck, direction), p.card()));
+                }
+            }
+        }
+        if (forms.len(), words.len()) == before {
+            unmatched += 1;
+        }
+    }
+
+    let (found_forms, found_words) = (forms.len(), words.len());
+    let added_forms = Srs::load(data_dir)?.import(forms)?;
+    let added_words = vocab::load_srs(data_dir)?.import(words)?;
+    println!(
+        "Matched {} of {} entries, adding {} verb forms and {} vocab cards ({} already had a schedule), {} not matched",
+        progress.len() - unmatched,
+        progress.len(),
+        added_forms,
+        added_words,
+        found_forms + found_words - added_forms - added_words,
+        unmatched
+    );
+    Ok(())
+}
+
+/// Reads the cards of an Anki plain text export. Its header lines start with
+/// a #, and `#columns:` names the columns if the export has them. Without a
+/// German column, the fields are all tried as the German.
+fn parse_anki(text: &str) -> Result<Vec<Progress>, Box<dyn Error>> {
+    let mut columns: Vec<String> = Vec::new();
+    let mut separator = '\t';
+    let mut progress = Vec::new();
+    for (n, line) in text.lines().enumerate() {
+        if let Some(header) = line.strip_prefix('#') {
+            match header.split_once(':') {
+                Some(("columns", names)) => {
+                    columns = names
+                        .split(separator)
+                        .map(|c| c.trim().to_lowercase())
+                        .collect()
+                }
+                Some(("separator", "comma")) => separator = ',',
+                Some(("separator", "semicolon")) => separator = ';',
+                _ => {}
+            }
+            continue;
+        }
+        if line.trim().is_empty() {
+            continue;
+        }
+        let fields: Vec<String> = line.split(separator).map(strip_html).collect();
+        let column = |names: &[&str]| -> Option<&str> {
+            let i = columns.iter().position(|c| names.contains(&c.as_str()))?;
+            fields.get(i).map(String::as_str)
+        };
+        let bad = |what: &str, value: &str| format!("Bad {} {:?} on line {}", what, value, n + 1);
+        let interval = match column(&["interval", "ivl"]) {
+            Some(i) => {
+                let days: f64 = i
+                    .trim_end_matches(['d', ' '])
+                    .parse()
+                    .map_err(|_| bad("interval", i))?;
+                if !days.is_finite() || days < 0.0 {
+                    return Err(bad("interval", i).into());
+                }
+                Some(days.min(srs::MAX_INTERVAL))
+            }
+            None => None,
+        };
+        // Anki gives the ease as a percentage, or in permille as its factor
+        let ease = match column(&["ease", "factor"]) {
+            Some(e) => {
+                let ease = match e.strip_suffix('%') {
+                    Some(percent) => percent.trim().parse::<f64>().map(|p| p / 100.0),
+                    None => e
+                        .parse::<f64>()
+                        .map(|f| if f > 10.0 { f / 1000.0 } else { f }),
+                }
+                .map_err(|_| bad("ease", e))?;
+                if !ease.is_finite() || ease <= 0.0 {
+                    return Err(bad("ease", e).into());
+                }
+                ease
+            }
+            None => srs::START_EASE,
+        };
+        let reps = column(&["reviews", "reps"]).and_then(|r| r.parse().ok());
+        let lapses = column(&["lapses"]).and_then(|l| l.parse().ok());
+        let reviewed = column(&["last review", "reviewed"]).and_then(|r| {
+            DateTime::parse_from_rfc3339(r).ok().or_else(|| {
+                let date = chrono::NaiveDate::parse_from_str(r, "%Y-%m-%d").ok()?;
+                Some(
+                    Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0)?)
+                        .fixed_offset(),
+                )
+            })
+        });
+
+        // without the German named, every field is a candidate
+        let german = match columns.iter().position(|c| c == "german") {
+            Some(i) => fields.get(i).cloned().into_iter().collect(),
+            None => fields.iter().filter(|f| !f.is_empty()).cloned().collect(),
+        };
+        progress.push(Progress {
+            german,
+            reps: reps.unwrap_or(1),
+            interval: interval.unwrap_or(1.0),
+            ease,
+            lapses: lapses.unwrap_or(0),
+            reviewed,
+        });
+    }
+    Ok(progress)
+}
+
+/// Reads the words of Duolingo's vocabulary JSON, either the whole response
+/// (with a `vocab_overview` list) or just the list
+fn parse_duolingo(text: &str) -> Result<Vec<Progress>, Box<dyn Error>> {
+    let json: Value = serde_json::from_str(text)?;
+    let words = json
+        .get("vocab_overview")
+        .unwrap_or(&json)
+        .as_array()
+        .ok_or("No list of words in the Duolingo export")?;
+    let mut progress = Vec::new();
+    for word in words {
+        let Some(german) = word.get("word_string").and_then(Value::as_str) else {
+            continue;
+        };
+        let bars = word
+            .get("strength_bars")
+            .and_then(Value::as_u64)
+            .or_else(|| {
+                // the strength from 0 to 1, in older exports
+                let strength = word.get("strength").and_then(Value::as_f64)?;
+                Some((strength * 4.0).round() as u64)
+            })
+            .unwrap_or(0)
+            .min(4) as usize;
+        let reviewed = word
+            .get("last_practiced_ms")
+            .and_then(Value::as_i64)
+            .and_then(|ms| Utc.timestamp_millis_opt(ms).single())
+            .map(|t| t.fixed_offset());
+        progress.push(Progress {
+            german: vec![german.to_string()],
+            reps: bars as u32,
+            interval: STRENGTH_INTERVALS[bars],
+            ease: srs::START_EASE,
+            lapses: 0,
+            reviewed,
+        });
+    }
+    Ok(progress)
+}
+
+/// The text of an Anki field, without the HTML it can have
+fn strip_html(field: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in field.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&nbsp;", " ").trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COLUMNS: &str = "#separator:tab\n#columns:German\tEnglish\tInterval\tEase\n";
+
+    #[test]
+    fn anki_cards_are_read_with_their_schedule() {
+        let text = format!("{}<b>ich gehe</b>\tI go\t12d\t250%\n", COLUMNS);
+        let progress = parse_anki(&text).unwrap();
+        assert_eq!(progress.len(), 1);
+        assert_eq!(progress[0].german, vec!["ich gehe"]);
+        assert_eq!((progress[0].interval, progress[0].ease), (12.0, 2.5));
+    }
+
+    #[test]
+    fn anki_factors_are_in_permille() {
+        let text = format!("{}ich gehe\tI go\t1\t2300\n", COLUMNS);
+        assert_eq!(parse_anki(&text).unwrap()[0].ease, 2.3);
+    }
+
+    #[test]
+    fn huge_intervals_are_capped() {
+        let text = format!("{}ich gehe\tI go\t1e300\t250%\n", COLUMNS);
+        assert_eq!(parse_anki(&text).unwrap()[0].interval, srs::MAX_INTERVAL);
+    }
+
+    #[test]
+    fn bad_intervals_and_eases_are_errors() {
+        for row in [
+            "ich gehe\tI go\tinf\t250%",
+            "ich gehe\tI go\tNaN\t250%",
+            "ich gehe\tI go\t-3\t250%",
+            "ich gehe\tI go\tsoon\t250%",
+            "ich gehe\tI go\t3\tNaN",
+            "ich gehe\tI go\t3\t-1",
+        ] {
+            let error = parse_anki(&format!("{}{}\n", COLUMNS, row)).unwrap_err();
+            assert!(error.to_string().contains("line 3"), "{}", error);
+        }
+    }
+
+    #[test]
+    fn duolingo_strength_sets_the_interval() {
+        let text = r#"{"vocab_overview": [{"word_string": "gehen", "strength_bars": 9}]}"#;
+        let progress = parse_duolingo(text).unwrap();
+        assert_eq!(progress[0].interval, STRENGTH_INTERVALS[4]);
+    }
+
+    #[test]
+    fn html_is_stripped_from_fields() {
+        assert_eq!(strip_html("<div>ich&nbsp;gehe</div> "), "ich gehe");
+    }
+}
@@ -0,0 +1,30 @@
+/// Keys that swap places between the German QWERTZ and the US QWERTY
+/// layouts. Typing on one while thinking in the other gives these mix-ups.
+const QWERTZ_QWERTY: [(char, char); 5] =
+    [('z', 'y'), ('ü', '['), ('ö', ';'), ('ä', '\''), ('ß', '-')];
+
+/// Swaps a character for the one on the same key of the other layout
+fn swap_layout(c: char) -> char {
+    for (qwertz, qwerty) in QWERTZ_QWERTY {
+        if c == qwertz {
+            return qwerty;
+        } else if c == qwerty {
+            return qwertz;
+        }
+    }
+    c
+}
+
+/// Whether the wrong response would be right if it had been typed with the
+/// other keyboard layout, e.g. "ich yahle" for "ich zahle".
+pub fn is_layout_mixup(response: &str, answer: &str) -> bool {
+    let response = response.to_lowercase();
+    let answer = answer.to_lowercase();
+    if response == answer || response.chars().count() != answer.chars().count() {
+        return false;
+    }
+    response
+        .chars()
+        .zip(answer.chars())
+        .all(|(r, a)| r == a || swap_layout(r) == a)
+}
@@ -0,0 +1,91 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The shortcuts typing the German letters a US keyboard doesn't have, set
+/// in the `[keymap]` table of the config, e.g. `"ä" = "alt+a"`. A shortcut is
+/// a key with `alt` and/or `ctrl`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Keymap(BTreeMap<String, String>);
+
+impl Default for Keymap {
+    fn default() -> Keymap {
+        let shortcuts = [
+            ("ä", "alt+a"),
+            ("ö", "alt+o"),
+            ("ü", "alt+u"),
+            ("ß", "alt+s"),
+            ("Ä", "alt+A"),
+            ("Ö", "alt+O"),
+            ("Ü", "alt+U"),
+        ];
+        Keymap(
+            shortcuts
+                .into_iter()
+                .map(|(letter, shortcut)| (letter.to_string(), shortcut.to_string()))
+                .collect(),
+        )
+    }
+}
+
+impl Keymap {
+    /// The letters and the shortcuts that type them, the lowercase ones
+    /// first. Entries that aren't a letter and a shortcut are left out.
+    pub fn shortcuts(&self) -> Vec<(char, KeyModifiers, char)> {
+        let mut shortcuts: Vec<_> = self
+            .0
+            .iter()
+            .filter_map(|(letter, shortcut)| {
+                let mut letters = letter.chars();
+                let letter = letters.next().filter(|_| letters.next().is_none())?;
+                let (modifiers, key) = parse(shortcut)?;
+                Some((letter, modifiers, key))
+            })
+            .collect();
+        shortcuts.sort_by_key(|&(letter, ..)| (letter.is_uppercase(), letter));
+        shortcuts
+    }
+
+    /// The letter the key press is the shortcut of, if any
+    pub fn letter(&self, key_event: &KeyEvent) -> Option<char> {
+        let KeyCode::Char(c) = key_event.code else {
+            return None;
+        };
+        // shift is part of the character, e.g. alt+A
+        let modifiers = key_event.modifiers - KeyModifiers::SHIFT;
+        self.shortcuts()
+            .into_iter()
+            .find(|&(_, m, key)| m == modifiers && key == c)
+            .map(|(letter, ..)| letter)
+    }
+}
+
+/// Reads a shortcut like "alt+a" or "ctrl+alt+s" into its modifiers and key
+fn parse(shortcut: &str) -> Option<(KeyModifiers, char)> {
+    let (modifiers, key) = shortcut.rsplit_once('+')?;
+    let mut keys = key.chars();
+    let key = keys.next().filter(|_| keys.next().is_none())?;
+    let mut parsed = KeyModifiers::NONE;
+    for modifier in modifiers.split('+') {
+        parsed |= match modifier.trim().to_lowercase().as_str() {
+            "alt" => KeyModifiers::ALT,
+            "ctrl" => KeyModifiers::CONTROL,
+            _ => return None,
+        };
+    }
+    Some((parsed, key))
+}
+
+/// A shortcut as the hint bar shows it, e.g. "Alt+a"
+pub fn label(modifiers: KeyModifiers, key: char) -> String {
+    let mut label = String::new();
+    if modifiers.contains(KeyModifiers::CONTROL) {
+        label += "Ctrl+";
+    }
+    if modifiers.contains(KeyModifiers::ALT) {
+        label += "Alt+";
+    }
+    label.push(key);
+    label
+}
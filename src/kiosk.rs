@@ -0,0 +1,187 @@
+use rand::{rngs::StdRng, Rng};
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Alignment, Rect},
+    style::{Styled, Stylize},
+    symbols::border,
+    text::{Line, Text},
+    widgets::{
+        block::{Position, Title},
+        Block, Paragraph, Widget,
+    },
+};
+use std::time::{Duration, Instant};
+
+use crate::conjugation::Conjugation;
+use crate::person::Person;
+use crate::theme::Theme;
+
+/// How many sample questions are shown between two tables
+const QUESTIONS_PER_TABLE: usize = 3;
+
+/// What the kiosk is showing
+enum Slide {
+    /// The conjugation table of a verb in a tense, by the index of one of its forms
+    Table(usize),
+    /// A sample question, with its answer once it is revealed
+    Question { conjugation: usize, revealed: bool },
+}
+
+/// A read-only demo that goes through conjugation tables and sample
+/// questions on its own, e.g. for a screen at an open day. Nothing shown is
+/// recorded.
+pub struct Kiosk {
+    conjugations: Vec<Conjugation>,
+    slide: Slide,
+    shown: usize, // how many slides were shown, to put a table between the questions
+    shown_at: Instant,
+    interval: Duration,
+    paused: bool,
+    rng: StdRng,
+}
+
+impl Kiosk {
+    pub fn new(conjugations: Vec<Conjugation>, seconds: u64, mut rng: StdRng) -> Kiosk {
+        let first = rng.gen_range(0..conjugations.len());
+        Kiosk {
+            conjugations,
+            slide: Slide::Table(first),
+            shown: 0,
+            shown_at: Instant::now(),
+            interval: Duration::from_secs(seconds.max(1)),
+            paused: false,
+            rng,
+        }
+    }
+
+    /// Handles a key press, returning true when the kiosk should be quit
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> bool {
+        match key_event.code {
+            KeyCode::Esc | KeyCode::Char('q') => return true,
+            KeyCode::Char(' ') => {
+                self.paused = !self.paused;
+                self.shown_at = Instant::now();
+            }
+            KeyCode::Right | KeyCode::Enter => self.next(),
+            _ => {}
+        }
+        false
+    }
+
+    /// Moves on once the slide was shown long enough, revealing the answer
+    /// of a question halfway through. Returns true if anything changed.
+    pub fn advance(&mut self) -> bool {
+        if self.paused {
+            return false;
+        }
+        let elapsed = self.shown_at.elapsed();
+        if elapsed >= self.interval {
+            self.next();
+            return true;
+        }
+        if let Slide::Question { revealed, .. } = &mut self.slide {
+            if !*revealed && elapsed >= self.interval / 2 {
+                *revealed = true;
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Shows the next slide: a table after every few questions
+    fn next(&mut self) {
+        self.shown += 1;
+        let i = self.rng.gen_range(0..self.conjugations.len());
+        self.slide = if self.shown.is_multiple_of(QUESTIONS_PER_TABLE + 1) {
+            Slide::Table(i)
+        } else {
+            Slide::Question {
+                conjugation: i,
+                revealed: false,
+            }
+        };
+        self.shown_at = Instant::now();
+    }
+
+    /// The forms of the table of the conjugation's verb and tense, in the
+    /// order of the persons
+    fn table(&self, i: usize) -> Vec<&Conjugation> {
+        let conj = &self.conjugations[i];
+        Person::ALL
+            .iter()
+            .filter_map(|&person| {
+                self.conjugations
+                    .iter()
+                    .find(|c| c.verb == conj.verb && c.tense == conj.tense && c.person == person)
+            })
+            .collect()
+    }
+
+    pub fn render(&self, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        let title = Title::from(" Deutschland: German Conjugations ".bold());
+        let pause = if self.paused { " Resume " } else { " Pause " };
+        let instructions = Title::from(Line::from(vec![
+            pause.into(),
+            "<Space> ".set_style(theme.key),
+            " Next ".into(),
+            "<Right> ".set_style(theme.key),
+            " Quit ".into(),
+            "<Q> ".set_style(theme.key),
+        ]));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+
+        let mut text = Text::from(vec![Line::from(""), Line::from("")]);
+        match self.slide {
+            Slide::Table(i) => {
+                let forms = self.table(i);
+                let conj = &self.conjugations[i];
+                text.push_line(Line::from(
+                    format!("{} in the {}", conj.verb, conj.tense).bold(),
+                ));
+                text.push_line(Line::from(""));
+                // padded to the same width, so the columns line up once centered
+                let width = forms.iter().map(|c| c.prompt.chars().count()).max();
+                let german = forms.iter().map(|c| c.german.chars().count()).max();
+                for c in forms {
+                    text.push_line(Line::from(vec![
+                        format!("{:<1$}", c.prompt, width.unwrap_or(0) + 4).into(),
+                        format!("{:<1$}", c.german, german.unwrap_or(0)).set_style(theme.correct),
+                    ]));
+                }
+            }
+            Slide::Question {
+                conjugation,
+                revealed,
+            } => {
+                let conj = &self.conjugations[conjugation];
+                text.push_line(Line::from(format!("{} | {}", conj.verb, conj.tense).dim()));
+                text.push_line(Line::from(""));
+                text.push_line(Line::from("How do you say"));
+                text.push_line(Line::from(
+                    conj.prompt.clone().set_style(theme.prompt).bold(),
+                ));
+                text.push_line(Line::from(""));
+                if revealed {
+                    text.push_line(Line::from(
+                        conj.german.clone().set_style(theme.correct).bold(),
+                    ));
+                } else {
+                    text.push_line(Line::from("..."));
+                }
+            }
+        }
+
+        Paragraph::new(text)
+            .centered()
+            .block(block)
+            .render(area, buf);
+    }
+}
@@ -0,0 +1,156 @@
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Alignment, Constraint, Rect},
+    style::{Style, Styled, Stylize},
+    symbols::border,
+    text::{Line, Text},
+    widgets::{
+        block::{Position, Title},
+        Block, Cell, Paragraph, Row, StatefulWidget, Table, TableState, Widget,
+    },
+};
+use std::cmp::Ordering;
+
+use crate::scores::Score;
+use crate::theme::Theme;
+
+/// The columns, which are also what the scores can be sorted by
+const COLUMNS: [&str; 5] = ["Date", "Mode", "Verbs", "Score", "Accuracy"];
+
+/// The scores of the lessons finished, best first, sortable by any column
+pub struct Leaderboard {
+    scores: Vec<Score>,
+    column: usize,    // the column sorted by
+    descending: bool, // highest (or latest) first
+    state: TableState,
+}
+
+impl Leaderboard {
+    pub fn new(scores: Vec<Score>) -> Leaderboard {
+        let mut leaderboard = Leaderboard {
+            scores,
+            column: 3,
+            descending: true,
+            state: TableState::default().with_selected(Some(0)),
+        };
+        leaderboard.sort();
+        leaderboard
+    }
+
+    fn sort(&mut self) {
+        let column = self.column;
+        self.scores.sort_by(|a, b| {
+            let order = match column {
+                0 => a.time.cmp(&b.time),
+                1 => a.mode.cmp(&b.mode),
+                2 => a.verbs.cmp(&b.verbs),
+                3 => a.score.cmp(&b.score),
+                _ => a
+                    .accuracy()
+                    .partial_cmp(&b.accuracy())
+                    .unwrap_or(Ordering::Equal),
+            };
+            // the latest first among equals, so a new record shows on top
+            let order = order.then(a.time.cmp(&b.time));
+            if self.descending {
+                order.reverse()
+            } else {
+                order
+            }
+        });
+        self.state.select(Some(0));
+    }
+
+    /// Handles a key press, returning true when the screen should be closed
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> bool {
+        match key_event.code {
+            KeyCode::Esc | KeyCode::Char('q') => return true,
+            KeyCode::Up | KeyCode::Char('k') => self.state.select_previous(),
+            KeyCode::Down | KeyCode::Char('j') => self.state.select_next(),
+            KeyCode::Left | KeyCode::Char('h') => {
+                self.column = (self.column + COLUMNS.len() - 1) % COLUMNS.len();
+                self.sort();
+            }
+            KeyCode::Right | KeyCode::Char('l') => {
+                self.column = (self.column + 1) % COLUMNS.len();
+                self.sort();
+            }
+            KeyCode::Char('r') => {
+                self.descending = !self.descending;
+                self.sort();
+            }
+            _ => {}
+        }
+        false
+    }
+
+    pub fn render(&mut self, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        let title = Title::from(" Scores ".bold());
+        let instructions = Title::from(Line::from(vec![
+            " Move ".into(),
+            "<Up/Down> ".set_style(theme.key),
+            " Sort By ".into(),
+            "<Left/Right> ".set_style(theme.key),
+            " Reverse ".into(),
+            "<r> ".set_style(theme.key),
+            " Back ".into(),
+            "<Esc> ".set_style(theme.key),
+        ]));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+
+        let header = Row::new(COLUMNS.iter().enumerate().map(|(i, &name)| {
+            if i == self.column {
+                let arrow = if self.descending { "▼" } else { "▲" };
+                Cell::from(format!("{} {}", name, arrow).set_style(theme.key))
+            } else {
+                Cell::from(name.bold())
+            }
+        }));
+        let rows = self.scores.iter().map(|s| {
+            Row::new(vec![
+                Cell::from(s.time.format("%Y-%m-%d %H:%M").to_string()),
+                Cell::from(s.mode.clone()),
+                Cell::from(s.verbs.clone()),
+                Cell::from(s.score.to_string()),
+                Cell::from(format!(
+                    "{:.0}% ({}/{})",
+                    100.0 * s.accuracy(),
+                    s.correct,
+                    s.answered
+                )),
+            ])
+        });
+        let widths = [
+            Constraint::Length(18),
+            Constraint::Length(24),
+            Constraint::Fill(1),
+            Constraint::Length(9),
+            Constraint::Length(16),
+        ];
+        if self.scores.is_empty() {
+            let text = Text::from(vec![
+                Line::from(""),
+                Line::from(""),
+                Line::from("No scores yet: finish a lesson to get one."),
+            ]);
+            Paragraph::new(text)
+                .centered()
+                .block(block)
+                .render(area, buf);
+            return;
+        }
+        let table = Table::new(rows, widths)
+            .header(header)
+            .highlight_style(Style::new().reversed())
+            .block(block);
+        StatefulWidget::render(table, area, buf, &mut self.state);
+    }
+}
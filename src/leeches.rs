@@ -0,0 +1,158 @@
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Alignment, Rect},
+    style::{Style, Styled, Stylize},
+    symbols::border,
+    text::{Line, Text},
+    widgets::{
+        block::{Position, Title},
+        Block, Paragraph, Widget,
+    },
+};
+
+use crate::notes::Notes;
+use crate::srs::{Key, Srs};
+use crate::theme::Theme;
+
+/// A form missed often enough to be a leech
+struct Leech {
+    form: Key,
+    lapses: u32,
+    suspended: bool,
+}
+
+/// The screen listing the problem forms (the leeches), to add notes to them
+/// or suspend them
+pub struct Leeches {
+    leeches: Vec<Leech>,
+    lapses: u32, // how many misses make a leech
+    selected: usize,
+    editing_note: Option<String>, // the note being written for the selected form
+}
+
+impl Leeches {
+    pub fn new(srs: &Srs, lapses: u32) -> Leeches {
+        Leeches {
+            leeches: srs
+                .leeches(lapses)
+                .into_iter()
+                .map(|(form, card)| Leech {
+                    form,
+                    lapses: card.lapses,
+                    suspended: card.suspended,
+                })
+                .collect(),
+            lapses,
+            selected: 0,
+            editing_note: None,
+        }
+    }
+
+    /// Handles a key press, returning true when the screen should be closed
+    pub fn handle_key_event(
+        &mut self,
+        key_event: KeyEvent,
+        srs: &mut Srs,
+        notes: &mut Notes,
+    ) -> bool {
+        if let Some(note) = &mut self.editing_note {
+            match key_event.code {
+                KeyCode::Enter => {
+                    let form = self.leeches[self.selected].form.clone();
+                    notes
+                        .set_form(form, note)
+                        .expect("Could not save the notes");
+                    self.editing_note = None;
+                }
+                KeyCode::Esc => self.editing_note = None,
+                KeyCode::Backspace => {
+                    note.pop();
+                }
+                KeyCode::Char(c) => note.push(c),
+                _ => {}
+            }
+            return false;
+        }
+
+        match key_event.code {
+            KeyCode::Esc | KeyCode::Char('q') => return true,
+            KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected = (self.selected + 1).min(self.leeches.len().saturating_sub(1))
+            }
+            KeyCode::Char('n') if !self.leeches.is_empty() => {
+                let form = &self.leeches[self.selected].form;
+                self.editing_note = Some(notes.get_form(form).unwrap_or_default().to_string());
+            }
+            KeyCode::Char('x') if !self.leeches.is_empty() => {
+                let leech = &mut self.leeches[self.selected];
+                leech.suspended = !leech.suspended;
+                srs.set_suspended(leech.form.clone(), leech.suspended)
+                    .expect("Could not save the review schedule");
+            }
+            _ => {}
+        }
+        false
+    }
+
+    pub fn render(&self, theme: &Theme, notes: &Notes, area: Rect, buf: &mut Buffer) {
+        let title = Title::from(" Problem Forms ".bold());
+        let instructions = Title::from(Line::from(vec![
+            " Move ".into(),
+            "<Up/Down> ".set_style(theme.key),
+            " Note ".into(),
+            "<n> ".set_style(theme.key),
+            " Suspend ".into(),
+            "<x> ".set_style(theme.key),
+            " Back ".into(),
+            "<Esc> ".set_style(theme.key),
+        ]));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+
+        let mut text = Text::from(vec![Line::from(""), Line::from("")]);
+        if self.leeches.is_empty() {
+            text.push_line(Line::from(format!(
+                "No problem forms. A form becomes one once it is missed {} times.",
+                self.lapses
+            )));
+        }
+        for (i, leech) in self.leeches.iter().enumerate() {
+            let (verb, tense, person) = &leech.form;
+            let mut line = Line::from(format!(
+                "{} | {} | {}  missed {} times",
+                verb, tense, person, leech.lapses
+            ));
+            if leech.suspended {
+                line.push_span("  (suspended)".set_style(theme.notice));
+            }
+            if i == self.selected {
+                text.push_line(line.style(Style::new().reversed()));
+            } else {
+                text.push_line(line);
+            }
+
+            let note = match &self.editing_note {
+                Some(note) if i == self.selected => {
+                    Some(format!("{}_", note).set_style(theme.input))
+                }
+                _ => notes.get_form(&leech.form).map(|n| n.to_string().italic()),
+            };
+            if let Some(note) = note {
+                text.push_line(Line::from(vec!["Note: ".dim(), note]));
+            }
+        }
+
+        Paragraph::new(text)
+            .centered()
+            .block(block)
+            .render(area, buf);
+    }
+}
@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+
+use crate::conjugation::Conjugation;
+use crate::mode::Mode;
+use crate::person::Person;
+use crate::srs::{self, Key};
+use crate::tense::Tense;
+
+/// What a lesson asks: the conjugations of its verbs, in its tenses and for
+/// its persons (and if it has any forms, only those)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Lesson {
+    pub verbs: Vec<String>, // by name
+    pub tenses: Vec<Tense>,
+    pub persons: Vec<Person>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub forms: Vec<Key>,
+}
+
+impl Lesson {
+    /// A lesson on every conjugation of the verbs
+    pub fn new(verbs: Vec<String>) -> Lesson {
+        Lesson {
+            verbs,
+            tenses: Tense::ALL.to_vec(),
+            persons: Person::ALL.to_vec(),
+            forms: vec![],
+        }
+    }
+
+    /// A lesson on just these forms, e.g. to practice the weak ones
+    pub fn forms(forms: Vec<Key>) -> Lesson {
+        let mut verbs: Vec<String> = Vec::new();
+        for (verb, _, _) in &forms {
+            if !verbs.contains(verb) {
+                verbs.push(verb.clone());
+            }
+        }
+        Lesson {
+            forms,
+            ..Lesson::new(verbs)
+        }
+    }
+
+    pub fn includes(&self, conj: &Conjugation) -> bool {
+        self.tenses.contains(&conj.tense)
+            && self.persons.contains(&conj.person)
+            && (self.forms.is_empty() || self.forms.contains(&srs::key(conj)))
+    }
+
+    /// Keeps the conjugations of a verb the lesson asks in the mode
+    pub fn keep(&self, mode: Mode, conjugations: &mut Vec<Conjugation>) {
+        match mode {
+            // the principal parts are read off the verb's forms in several tenses
+            Mode::PrincipalParts => {}
+            // the tables have every person
+            Mode::Table => conjugations.retain(|c| self.tenses.contains(&c.tense)),
+            _ => conjugations.retain(|c| self.includes(c)),
+        }
+    }
+
+    /// The verbs of the lesson, e.g. "essen, gehen"
+    pub fn name(&self) -> String {
+        self.verbs.join(", ")
+    }
+}
@@ -0,0 +1,32 @@
+/// The settings, kept in config.toml
+pub mod config;
+/// The conjugations of a verb, as read from its CSV file
+pub mod conjugation;
+/// Normalizing and grading answers as the lessons do, for other tools to reuse
+pub mod grade;
+/// Every answered question, kept in history.csv
+pub mod history;
+/// Shell commands run when things happen in the lessons
+pub mod hooks;
+/// The shortcuts typing the German letters a US keyboard doesn't have
+pub mod keymap;
+/// What a lesson asks: its verbs, tenses and persons
+pub mod lesson;
+/// What the questions of a lesson ask for
+pub mod mode;
+/// The persons a verb is conjugated for
+pub mod person;
+/// The lesson in progress, kept in session.json to recover it after a crash
+pub mod session;
+/// When each form is due for review, kept in srs.csv
+pub mod srs;
+/// Syncing the data directory with a git remote
+pub mod sync;
+/// The tenses a verb is conjugated in
+pub mod tense;
+/// The colours the screens are drawn in
+pub mod theme;
+/// The verbs, found in the data directory and the installed packs
+pub mod verb;
+/// The vocabulary decks and when each card is due, kept in vocab_srs.csv
+pub mod vocab;
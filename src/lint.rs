@@ -0,0 +1,147 @@
+use csv::ReaderBuilder;
+use std::{collections::HashMap, error::Error, path::Path, process};
+
+use crate::conjugation;
+use crate::conjugator::{self, Auxiliary, Difference};
+use crate::person::Person;
+use crate::tense::Tense;
+use crate::verb::Verb;
+
+/// The forms known to be irregular, kept in `<data_dir>/irregular.csv` with
+/// the columns `Verb,Tense,Person`. A blank tense or person stands for all of
+/// them, so `essen,,` lists every form of essen.
+const IRREGULAR_FILE: &str = "irregular.csv";
+
+/// A line of the irregular forms file
+struct Irregular {
+    verb: String,
+    tense: Option<Tense>,
+    person: Option<Person>,
+}
+
+impl Irregular {
+    fn covers(&self, verb: &str, tense: Tense, person: Person) -> bool {
+        self.verb == verb
+            && self.tense.is_none_or(|t| t == tense)
+            && self.person.is_none_or(|p| p == person)
+    }
+}
+
+fn load_irregular(data_dir: &Path) -> Result<Vec<Irregular>, Box<dyn Error>> {
+    let path = data_dir.join(IRREGULAR_FILE);
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    let mut rdr = ReaderBuilder::new().has_headers(true).from_path(path)?;
+    let mut irregular = Vec::new();
+    for result in rdr.records() {
+        let record = result?;
+        let field = |i: usize| record.get(i).map(str::trim).unwrap_or_default();
+        irregular.push(Irregular {
+            verb: field(0).to_lowercase(),
+            tense: Some(field(1))
+                .filter(|t| !t.is_empty())
+                .map(str::parse)
+                .transpose()?,
+            person: Some(field(2))
+                .filter(|p| !p.is_empty())
+                .map(str::parse)
+                .transpose()?,
+        });
+    }
+    Ok(irregular)
+}
+
+/// Runs the `lint-data` subcommand: checks every verb file against the
+/// rule-based conjugator and lists the forms that differ, telling the ones
+/// listed as irregular from likely typos, and lists the prompts that are the
+/// same in several tenses without a hint. Exits with 1 if there are any
+/// likely typos, ambiguous prompts or broken files.
+pub fn run(data_dir: &Path) -> Result<(), Box<dyn Error>> {
+    let irregular = load_irregular(data_dir)?;
+    let (mut listed, mut typos, mut ambiguous, mut broken) = (0, 0, 0, 0);
+    for verb in Verb::find_all(data_dir)? {
+        let path = verb.path().display();
+        if let Err(e) = conjugation::validate(verb.path()) {
+            println!("{}: {}", path, e);
+            broken += 1;
+            continue;
+        }
+        // only the German is checked, so it stands in for the prompt too
+        let conjugations = conjugation::parse_conjugations(&verb, "German")?;
+        let auxiliary =
+            Auxiliary::used_in(conjugations.iter().map(|c| (c.tense, c.german.as_str())));
+        for conj in &conjugations {
+            let regular = conjugator::conjugate(verb.name(), conj.tense, conj.person, auxiliary);
+            let difference = conjugator::compare(&conj.german, &regular);
+            if difference == Difference::Regular {
+                continue;
+            }
+            let verdict = if irregular
+                .iter()
+                .any(|i| i.covers(verb.name(), conj.tense, conj.person))
+            {
+                listed += 1;
+                "irregular"
+            } else {
+                typos += 1;
+                match difference {
+                    Difference::StemChange => "likely typo, or an unlisted stem change",
+                    _ => "likely typo, or unlisted as irregular",
+                }
+            };
+            println!(
+                "{} ({} | {}): \"{}\", the rules give \"{}\" ({})",
+                path, conj.tense, conj.person, conj.german, regular, verdict
+            );
+        }
+        for language in conjugation::languages(verb.path())? {
+            ambiguous += lint_prompts(&verb, &language)?;
+        }
+    }
+
+    println!(
+        "{} irregular, {} likely typos, {} ambiguous prompts, {} broken files",
+        listed, typos, ambiguous, broken
+    );
+    if typos > 0 || ambiguous > 0 || broken > 0 {
+        process::exit(1);
+    }
+    Ok(())
+}
+
+/// Lists the prompts in the source language that are asked for the same
+/// person in several tenses (as "I ate" can be the Präteritum or Perfekt) but
+/// have no hint to tell them apart, returning how many there are
+fn lint_prompts(verb: &Verb, language: &str) -> Result<usize, Box<dyn Error>> {
+    let conjugations = conjugation::parse_conjugations(verb, language)?;
+    let mut tenses: HashMap<(Person, String), Vec<Tense>> = HashMap::new();
+    for conj in &conjugations {
+        tenses
+            .entry((conj.person, conj.prompt.trim().to_lowercase()))
+            .or_default()
+            .push(conj.tense);
+    }
+    let mut ambiguous = 0;
+    for conj in conjugations.iter().filter(|c| c.hint.is_none()) {
+        let others: Vec<String> = tenses[&(conj.person, conj.prompt.trim().to_lowercase())]
+            .iter()
+            .filter(|&&t| t != conj.tense)
+            .map(Tense::to_string)
+            .collect();
+        if others.is_empty() {
+            continue;
+        }
+        println!(
+            "{} ({} | {}): the {} \"{}\" is also asked in the {}, with no hint to tell them apart",
+            verb.path().display(),
+            conj.tense,
+            conj.person,
+            language,
+            conj.prompt,
+            others.join(", ")
+        );
+        ambiguous += 1;
+    }
+    Ok(ambiguous)
+}
@@ -0,0 +1,55 @@
+use std::{
+    error::Error,
+    fs::{self, OpenOptions},
+    panic,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::Instant,
+};
+use tracing::{error, info, Level};
+
+/// Where --verbose logs without --log-file, in the data directory. It's in
+/// the cache so it stays on the machine and isn't synced.
+const LOG_FILE: &str = ".cache/deutschland.log";
+
+/// Starts logging what the app does, as a JSON object a line, to the file of
+/// --log-file (or `<data_dir>/.cache/deutschland.log` with just --verbose). The
+/// screens shown, how long the data took to load and how each answer was
+/// graded are logged, and with --verbose the details too, e.g. the answers
+/// given and expected. Without either flag nothing is logged.
+pub fn init(
+    log_file: Option<PathBuf>,
+    verbose: bool,
+    data_dir: &Path,
+) -> Result<(), Box<dyn Error>> {
+    let Some(path) = log_file.or_else(|| verbose.then(|| data_dir.join(LOG_FILE))) else {
+        return Ok(());
+    };
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+    let file = OpenOptions::new().create(true).append(true).open(&path)?;
+    tracing_subscriber::fmt()
+        .json()
+        .with_max_level(if verbose { Level::DEBUG } else { Level::INFO })
+        .with_writer(Mutex::new(file))
+        .try_init()
+        .map_err(|e| e.to_string())?;
+
+    // a crash is what the log is most often wanted for
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        error!(panic = %info, "crashed");
+        previous(info);
+    }));
+    info!(version = env!("CARGO_PKG_VERSION"), "started");
+    Ok(())
+}
+
+/// Loads something, logging how long it took
+pub fn timed<T>(what: &str, load: impl FnOnce() -> T) -> T {
+    let start = Instant::now();
+    let loaded = load();
+    info!(what, ms = start.elapsed().as_millis() as u64, "loaded");
+    loaded
+}
@@ -1,32 +1,184 @@
-use clap::Parser;
-use csv::ReaderBuilder;
-use rand::Rng;
+use chrono::Local;
+use clap::{Parser, Subcommand};
+use deutschland_tui::grade::Grader;
+use deutschland_tui::{
+    config, conjugation, history, hooks, keymap, lesson, mode, person, session, srs, tense, theme,
+    verb, vocab,
+};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
 use ratatui::{
+    backend::Backend,
     buffer::Buffer,
-    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
+    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
     layout::{Alignment, Constraint, Rect},
-    style::{Style, Stylize},
+    style::{Style, Styled, Stylize},
     symbols::border,
-    text::{Line, Text},
+    text::{Line, Span, Text},
     widgets::{
         block::{Position, Title},
-        Block, Cell, Paragraph, Row, Table, TableState, Widget,
+        Block, Cell, Clear, Paragraph, Row, Table, TableState, Widget,
     },
-    DefaultTerminal, Frame,
+    Frame, Terminal,
 };
 use std::{
+    collections::HashMap,
     error::Error,
-    fs::{self, File},
     io,
+    path::PathBuf,
+    process,
+    time::{Duration, Instant},
 };
+use tracing::{debug, info, warn};
 
 // --- Use the person, tense, verb structs ---
-mod person;
-mod tense;
-mod verb;
+mod arcade;
+mod assemble;
+#[cfg(feature = "audio")]
+mod audio;
+mod backup;
+mod big_text;
+mod builder;
+mod cache;
+mod classroom;
+#[cfg(feature = "clipboard")]
+mod clipboard;
+mod collocation_quiz;
+mod collocations;
+mod compare;
+mod conjugator;
+mod curriculum;
+mod editor;
+#[cfg(feature = "async")]
+mod effects;
+mod encouragement;
+mod ending;
+mod events;
+mod favorites;
+mod flags;
+mod gender_quiz;
+mod grid;
+mod hangman;
+mod home;
+mod import;
+mod keyboard;
+mod kiosk;
+mod leaderboard;
+mod leeches;
+mod lint;
+mod logging;
+mod marathon;
+mod match_game;
+mod message;
+mod morphology;
+mod notes;
+mod nouns;
+mod packs;
+mod paths;
+#[cfg(feature = "pdf")]
+mod pdf;
+mod placement;
+mod placement_test;
+mod plan;
+mod politeness;
+mod politeness_quiz;
+mod preposition_quiz;
+mod prepositions;
+mod principal_parts;
+mod print;
+mod recommend;
+#[cfg(feature = "remind")]
+mod remind;
+mod report;
+mod scheduler;
+mod scores;
+mod screen_reader;
+#[cfg(test)]
+mod screen_tests;
+mod search;
+#[cfg(feature = "serve")]
+mod serve;
+mod settings;
+mod setup;
+mod share;
+mod spell;
+mod spelling_quiz;
+mod stats;
+mod status;
+mod suspended;
+mod templates;
+mod typing;
+#[cfg(test)]
+mod update_tests;
+mod valency;
+mod valency_quiz;
+mod verb_stats;
+mod vocab_quiz;
+mod watch;
+use arcade::Arcade;
+use backup::{BackupArgs, RestoreArgs};
+use big_text::BigText;
+use builder::{BuilderEvent, LessonBuilder};
+use cache::Cache;
+use classroom::{AssignArgs, Assignment, Attempt, GradeArgs};
+use collocation_quiz::CollocationQuiz;
+use compare::Compare;
+use config::{Config, Difficulty, HeaderHints};
+use conjugation::Conjugation;
+use conjugator::Auxiliary;
+use curriculum::Curriculum;
+use editor::Editor;
+use encouragement::Encouragement;
+use favorites::Favorites;
+use gender_quiz::GenderQuiz;
+use grid::Grid;
+use history::Confidence;
+use home::{Destination, Home, HomeEvent};
+use import::ImportArgs;
+use kiosk::Kiosk;
+use leaderboard::Leaderboard;
+use leeches::Leeches;
+use lesson::Lesson;
+use marathon::Marathon;
+use match_game::MatchGame;
+use message::Message;
+use mode::{Mix, Mode, NUM_CHOICES};
+use morphology::Morpheme;
+use notes::Notes;
+use packs::PacksCommand;
+use paths::Dirs;
 use person::Person;
+use placement_test::{PlacementEvent, PlacementTest};
+use plan::Plan;
+use politeness_quiz::PolitenessQuiz;
+use preposition_quiz::PrepositionQuiz;
+use principal_parts::PrincipalParts;
+use print::PrintArgs;
+use report::ReportArgs;
+use scheduler::Scheduler;
+use search::{Search, SearchEvent};
+use session::{SavedAnswer, Session};
+use settings::Settings;
+use setup::{Setup, SetupEvent};
+use spelling_quiz::SpellingQuiz;
+use srs::Srs;
+use stats::Stats;
+use status::StatusArgs;
+use suspended::Suspended;
+use templates::{Templates, TemplatesEvent};
 use tense::Tense;
+use theme::Theme;
+use typing::Typing;
+use valency_quiz::ValencyQuiz;
 use verb::Verb;
+use verb_stats::VerbStats;
+use vocab_quiz::VocabQuiz;
+use watch::VerbWatcher;
+
+/// How often to check for edits to the verb files when watching
+const WATCH_INTERVAL: Duration = Duration::from_millis(250);
+
+/// How long before the time limit the header turns to the notice colour
+const TIME_WARNING: Duration = Duration::from_secs(15);
 
 /// The possible arguments.
 ///
@@ -34,9 +186,22 @@ use verb::Verb;
 #[derive(Parser, Debug)]
 #[command(author = "Sebastian K.", version, about = "A simple CLI tool to help test on German conjugations of common verbs", long_about = None)]
 pub struct Args {
-    /// Number of questions in the lesson
-    #[arg(short, long, default_value_t = 10)]
-    number: u8,
+    /// Number of questions in the lesson, 10 if not given
+    #[arg(short, long)]
+    number: Option<u8>,
+
+    /// Run each lesson for this many minutes instead, asking as many
+    /// questions as fit. The question being answered when the time is up can
+    /// still be finished for the grace period of the settings. Space (before
+    /// typing) or Ctrl+P pauses the clock
+    #[arg(long, conflicts_with = "number")]
+    minutes: Option<u16>,
+
+    /// Set the strictness, header hints, tenses and timer of the lessons
+    /// from a preset (also in the settings). Advanced lessons run for five
+    /// minutes unless --number or --minutes is given
+    #[arg(long, value_enum)]
+    difficulty: Option<Difficulty>,
 
     /// The person to focus on
     #[arg(short, long)]
@@ -46,293 +211,3849 @@ pub struct Args {
     #[arg(short, long)]
     verb: Option<String>,
 
+    /// Start a lesson saved from the lesson builder, by its name
+    #[arg(long, conflicts_with = "verb")]
+    lesson: Option<String>,
+
+    /// Take the steps of a lesson plan, <data_dir>/lessons/<name>.toml, in
+    /// order: the intros to read and the questions to pass to go on
+    #[arg(long, conflicts_with_all = ["verb", "lesson", "number", "minutes"])]
+    plan: Option<String>,
+
+    /// Take an assignment written by `assign`. The results are saved to the
+    /// working directory at the end, to hand in
+    #[arg(
+        long,
+        requires = "student",
+        conflicts_with_all = ["verb", "lesson", "plan", "number", "minutes", "mode", "seed"]
+    )]
+    assignment: Option<PathBuf>,
+
+    /// The name the results of an assignment are handed in under
+    #[arg(long, requires = "assignment")]
+    student: Option<String>,
+
     /// The tense (to focus one specifically)
     #[arg(short, long)]
     tense: Option<String>,
-}
 
-#[derive(Debug, Clone)]
-struct Conjugation {
-    person: Person,
-    tense: Tense,
-    verb: Verb,
-    english: String,
-    german: String,
+    /// The source language to quiz from, i.e. the name of the CSV column
+    /// holding the prompt (e.g. English, French, Spanish, Turkish)
+    #[arg(short, long, default_value = "English")]
+    from: String,
+
+    /// Translate the prompts, recognize the tense and person of German forms,
+    /// give the principal parts of verbs, fill in conjugation tables, guess
+    /// the German forms letter by letter, give just their endings, put the
+    /// words of perfect forms in order, or (with the audio feature) type the
+    /// German forms that are spoken. By default, translate or recognize as
+    /// the direction chosen in the setup
+    #[arg(short, long, value_enum)]
+    mode: Option<Mode>,
+
+    /// Score the lessons like an arcade game: each right answer in a row
+    /// raises the multiplier and a miss resets it. The best score of each
+    /// combination of verbs and tenses is kept. Pairs well with --minutes
+    #[arg(long)]
+    arcade: bool,
+
+    /// Use colourblind safe colours and mark answers with ✓/✗ (also in the settings)
+    #[arg(long)]
+    accessible: bool,
+
+    /// How much of the verb, tense and person to show in the question header
+    /// (also in the settings)
+    #[arg(long, value_enum)]
+    hints: Option<HeaderHints>,
+
+    /// Seed the question order, so a session can be repeated (e.g. a shared
+    /// challenge, or to reproduce a bug). Random if not given
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// Plain line-by-line output for screen readers, without boxes or redraws
+    #[arg(long)]
+    screen_reader: bool,
+
+    /// Go through conjugation tables and sample questions on their own, a
+    /// slide every few seconds (8 if not given), e.g. for a screen at an open
+    /// day. Nothing is recorded. Takes the verbs of --verb or --lesson, or
+    /// every verb
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        num_args = 0..=1,
+        default_missing_value = "8",
+        conflicts_with_all = ["screen_reader", "plan", "assignment", "mode"]
+    )]
+    kiosk: Option<u64>,
+
+    /// Practice every verb in turn, back to back: a short lesson of this many
+    /// questions (5 if not given) on each, then a report of them all
+    #[arg(
+        long,
+        value_name = "QUESTIONS",
+        num_args = 0..=1,
+        default_missing_value = "5",
+        conflicts_with_all = ["verb", "lesson", "plan", "assignment", "kiosk", "number", "minutes"]
+    )]
+    marathon: Option<u8>,
+
+    /// Take a placement test: a few questions of each level on the verbs,
+    /// nouns, prepositions and the other drills, placing you at a level from
+    /// A1 to C1. The level is kept, and the curriculum starts lessons from
+    /// the tenses of it
+    #[arg(
+        long,
+        conflicts_with_all = ["verb", "lesson", "plan", "assignment", "kiosk", "marathon", "screen_reader"]
+    )]
+    placement: bool,
+
+    /// Print a spoiler-free summary of the last lesson to share on exit: the
+    /// score, and a grid of the right and wrong answers
+    #[arg(long)]
+    share: bool,
+
+    /// Watch the verb files and reload them when they are edited
+    #[arg(short, long)]
+    watch: bool,
+
+    /// Log the screens shown, how long the data took to load and how the
+    /// answers were graded to this file, a JSON object a line, e.g. to
+    /// attach to a bug report
+    #[arg(long, value_name = "PATH")]
+    log_file: Option<PathBuf>,
+
+    /// Log the details too, e.g. the answers given and expected, to
+    /// --log-file or else to <data_dir>/.cache/deutschland.log
+    #[arg(long)]
+    verbose: bool,
+
+    #[command(subcommand)]
+    command: Option<Command>,
 }
 
-/// Loads and parses the conjugations for the verb
-fn parse_conjugations(verb: &Verb) -> Result<Vec<Conjugation>, Box<dyn Error>> {
-    let file_path = format!("./verbs/{}.csv", verb);
-    let file = File::open(file_path)?;
-    let mut rdr = ReaderBuilder::new().has_headers(true).from_reader(file);
-
-    let mut conjugations: Vec<Conjugation> = Vec::new();
-    for result in rdr.records() {
-        let record = result?;
-        let tense = Tense::from_str(record.get(0).unwrap());
-        let person = Person::from_str(record.get(1).unwrap());
-        let english = record.get(2).unwrap().to_string();
-        let german = record.get(3).unwrap().to_string();
-        let con = Conjugation {
-            verb: *verb,
-            tense,
-            person,
-            english,
-            german,
-        };
-        conjugations.push(con);
-    }
-
-    Ok(conjugations)
+/// Subcommands that run instead of the TUI
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Manage verb data packs
+    #[command(subcommand)]
+    Packs(PacksCommand),
+
+    /// List the conjugations flagged as wrong during lessons
+    Flags,
+
+    /// Summarise the last days of practice: accuracy by day, the weakest
+    /// cells and the time practiced
+    Report(ReportArgs),
+
+    /// Print how many forms are due for review, e.g. for a shell prompt. Exits
+    /// with 1 if more than the threshold are due
+    Due {
+        /// How many forms can be due before exiting with 1
+        #[arg(short, long, default_value_t = 0)]
+        threshold: usize,
+    },
+
+    /// Print a one line summary (streak, due reviews, ...) for a status bar
+    Status(StatusArgs),
+
+    /// Print the conjugation table of a verb as plain text or Markdown (or,
+    /// with the pdf feature, write it as a PDF), e.g. for a study sheet
+    Print(PrintArgs),
+
+    /// Check the verb files against the rules of regular conjugation, listing
+    /// the forms that differ. Exits with 1 if any aren't listed in
+    /// irregular.csv, as they are likely typos
+    LintData,
+
+    /// Bundle the settings, progress, review schedules and verb files into
+    /// a .tar.gz, e.g. to move them to another machine
+    Backup(BackupArgs),
+
+    /// Put the files of a backup back in place, over the ones there are
+    Restore(RestoreArgs),
+
+    /// Give the forms and words already known in Anki or Duolingo a head
+    /// start in the review schedules, from their exports
+    ImportProgress(ImportArgs),
+
+    /// Run the lessons with a small web server alongside, so a phone's
+    /// browser can be used to answer
+    #[cfg(feature = "serve")]
+    Serve(serve::ServeArgs),
+
+    /// Send a desktop notification when forms are due for review
+    #[cfg(feature = "remind")]
+    Remind(remind::RemindArgs),
+
+    /// Write an assignment for students to take with --assignment: a lesson
+    /// asked in the same order for everyone, graded the same way
+    Assign(AssignArgs),
+
+    /// Score the results handed in for an assignment, grading their answers
+    /// again, and list the forms the class missed most
+    Grade(GradeArgs),
 }
 
 /// The application state
 pub struct App {
-    cur_question: u8,
-    total_questions: u8,
-    total_correct: u8,   // Total correct answers
-    total_incorrect: u8, // Total incorrect answers
+    cur_question: u32,
+    total_questions: u32,
+    total_correct: u32,           // Total correct answers
+    total_incorrect: u32,         // Total incorrect answers
+    total_skipped: u32,           // Questions skipped, also counted as incorrect
+    total_assisted: u32,          // Correct answers typed with autocomplete
+    time_limit: Option<Duration>, // With --minutes, how long a lesson runs (instead of a count)
+    started_at: Instant,          // When the lesson started, for the time limit
+    grace_until: Option<Instant>, // Once the time is up, until when the current question can be answered
+    paused_at: Option<Instant>,   // When the clock of a timed lesson was paused, if it is
+    advance_at: Option<Instant>,  // After a right answer, when to move on without Enter
+    arcade: Option<Arcade>,       // With --arcade, the score of the lesson
 
     // if None, then show the select screen. Can choose to be specific or to be open to all
     table_state: TableState,
-    verbs: Vec<String>, // the list of all the verbs (german + english)
-    verb: Option<Verb>, // the chosen verb
+    verbs: Vec<Verb>,       // the list of all the verbs (built in and from packs)
+    favorites: Favorites,   // the verbs starred, listed first in the table
+    recent: Vec<String>,    // the verbs practiced last, listed after the favorites
+    lesson: Option<Lesson>, // the chosen verbs, tenses and persons
+    from: String,           // the source language the prompts are shown in
+    mode: Mode,             // what the questions ask for, in a mixed lesson the current one
+    mix: Option<Mix>,       // in a mixed lesson, how often each kind of question comes up
+    mode_given: bool,       // if --mode was given, over the direction in the settings
 
     cur_conjugation: usize,         // Index to the conjugation that we are on
     conjugations: Vec<Conjugation>, // All the conjugations we are allowed to ask
+    seed: u64,                      // What the question order was seeded with
+    seeded: bool,                   // if true, the seed was given, so the order is repeated
+    rng: StdRng,                    // Picks the questions
     cur_response: String,           // The current response from the user
+    part_responses: [String; 3],    // In principal parts mode, the response for each part
+    part_field: usize,              // The part being typed
+    grid: Grid,                     // In table mode, the table being filled in
+    guesses: Vec<char>,             // In hangman mode, the letters guessed so far
     cur_response_incorrect: Option<bool>, // If entered, then if the response was correct
+    asked_at: Instant,              // When the current question was asked, to time the answer
+    layout_mixup: bool, // If the incorrect response was right on the other keyboard layout
+    skipped: bool,      // If the current question was skipped rather than answered
+    confidence: Option<Confidence>, // How sure the user said they are of the current answer
+    tagged: Vec<(Confidence, bool)>, // The answers of the lesson tagged with a confidence, and if right
+    results: Vec<bool>,              // If each answer of the lesson was right, in order
+    typing: Typing,                  // The keystrokes of the lesson's typed answers
+    verb_turns: HashMap<PathBuf, usize>, // How many questions of the lesson each verb (by its file) was asked in
+    shared: Option<String>,              // The summary of the last lesson finished, to share
+    copied: Option<String>,              // If something was copied to the clipboard, the outcome
+    #[cfg(feature = "clipboard")]
+    clipboard: clipboard::Clipboard,
+    recommended: Vec<srs::Key>, // Once the lesson is over, the forms to practice next
+    warm_up: Vec<usize>,        // The missed forms still to ask first, last first
+    warming_up: bool,           // If the current question is one of the warm-up
+    curriculum: Curriculum,     // The tenses unlocked so far, if the curriculum is on
+    plan: Option<Plan>,         // With --plan, the plan being taken
+    marathon: Option<Marathon>, // With --marathon, the verbs practiced in turn and how they went
+    attempt: Option<Attempt>,   // With --assignment, the answers to hand in
+    submitted: Option<String>,  // Where the results of the assignment were saved, or why not
+    unlocked: bool,             // If the lesson just finished unlocked more tenses
+    flagged: Option<String>,    // If the current conjugation was flagged as wrong, the outcome
+    cheer: Option<String>,      // What is said about the right answer to the current question
+    notes: Notes,               // The user's notes on the conjugations
+    encouragement: Encouragement, // The lines cheering on right answers, in the source language
+    srs: Srs,                   // When each form is due for review
+    editing_note: Option<String>, // The note being written for the current conjugation
+    cache: Cache,               // The parsed verb files
+    choices: Vec<usize>,        // In recognition mode, the conjugations to choose from
+    selected_choice: usize,     // The highlighted choice
+    endings: Vec<String>,       // In ending mode, the endings to pick from
+    words: Vec<String>,         // In assemble mode, the words to put in order, shuffled
+    placed: Vec<usize>,         // The words put in order so far
 
+    data_dir: PathBuf,            // Where the verbs (and packs) are read from
+    config_dir: PathBuf,          // Where the settings are saved
+    watcher: Option<VerbWatcher>, // Set when watching the verb files for edits
+    reload_error: Option<String>, // Why the last reload of the verb file failed
+
+    editor: Option<Editor>, // if Some, then show the verb editor on top of everything
+
+    config: Config,                            // The user's settings
+    light_background: bool, // If the terminal was detected to have a light background
+    accessible: bool,       // If --accessible was given, whatever the settings say
+    hints: Option<HeaderHints>, // The header hints given with --hints, over the settings
+    difficulty: Option<Difficulty>, // The preset given with --difficulty, over the settings
+    theme: Theme,           // The styles to render with, picked from the settings
+    settings: Option<Settings>, // if Some, then show the settings screen
+    setup: Option<Setup>,   // if Some, then show the first run's setup
+    suspended: Option<Suspended>, // if Some, then show the suspended forms
+    leeches: Option<Leeches>, // if Some, then show the problem forms
+    builder: Option<LessonBuilder>, // if Some, then show the lesson builder
+    templates: Option<Templates>, // if Some, then show the saved lessons
+    compare: Option<Compare>, // if Some, then show two verbs side by side
+    search: Option<Search>, // if Some, then show the search through all the data
+    comparing: Option<usize>, // The verb picked to compare, while the second is picked
+    verb_stats: Option<VerbStats>, // if Some, then show how the selected verb's practice went
+    gender_quiz: Option<GenderQuiz>, // if Some, then show the der/die/das quiz
+    preposition_quiz: Option<PrepositionQuiz>, // if Some, then show the preposition case quiz
+    politeness_quiz: Option<PolitenessQuiz>, // if Some, then show the polite requests drill
+    valency_quiz: Option<ValencyQuiz>, // if Some, then show the verb case quiz
+    collocation_quiz: Option<CollocationQuiz>, // if Some, then show the verb and preposition drill
+    placement: Option<PlacementTest>, // With --placement, the placement test
+    spelling_quiz: Option<SpellingQuiz>, // if Some, then show the numbers, times and dates drill
+    vocab_quiz: Option<VocabQuiz>, // if Some, then show the vocabulary flashcards
+    match_game: Option<MatchGame>, // if Some, then show the memory game
+    stats: Option<Stats>,   // if Some, then show the report of the last week
+    leaderboard: Option<Leaderboard>, // if Some, then show the scores of the lessons
+    home: Option<Home>,     // if Some, then show the home screen instead of the verbs
+    kiosk: Option<Kiosk>,   // if Some, then show the demo of --kiosk over everything
+    drill_error: Option<String>, // Why what was picked on the home screen could not be opened
+    lesson_error: Option<String>, // Why the lesson last picked could not be started
+    zen: bool,              // Focus mode: only the prompt and input, in large text
+    confirm_quit: bool,     // if true, then ask if the app should be quit
+    confirm_leave: bool,    // if true, then ask what to do with the lesson left
+    recovery: Option<Session>, // if Some, then ask if the lesson left by a crash should be picked back up
+    screen: String,            // the breadcrumb of the screen last logged, to log the changes
+    pending: Vec<history::Answer>, // The lesson's answers, recorded once it ends
+    #[cfg(feature = "audio")]
+    speaker: Option<audio::Speaker>, // Says the questions in dictation mode
+    #[cfg(feature = "serve")]
+    remote: Option<serve::Remote>, // Set when answering from a browser too
+    #[cfg(feature = "async")]
+    effects: effects::Effects, // Runs what waits on the network in the background
+    #[cfg(feature = "async")]
+    synced: Option<effects::Progress>, // How the last push of the progress went, if any
+
+    commands: Vec<message::Command>, // what the updates asked to be done, not yet done
+    scheduler: Scheduler,            // when the screen is drawn
+    drawn_left: Option<u64>,         // the seconds left the countdown showed when last drawn
     exit: Option<bool>,
 }
 
 // Based mostly off of the example in the ratatui repo:
 // https://ratatui.rs/tutorials/counter-app/basic-app/
 impl App {
-    /// runs the application's main loop until the user quits
-    pub fn run(&mut self, terminal: &mut DefaultTerminal) -> io::Result<u8> {
-        while self.exit.is_none() || self.exit.is_some_and(|x| x == false) {
-            terminal.draw(|frame| self.draw(frame))?;
-            self.handle_events()?;
+    /// Sets the app up from the arguments: finds the verbs, and starts the
+    /// lesson, plan, marathon or demo asked for (or shows the home screen).
+    /// Fails if what was asked for doesn't exist or can't be read.
+    fn new(args: &Args, dirs: &Dirs, config: Config) -> Result<App, Box<dyn Error>> {
+        let data_dir = dirs.data.as_path();
+        let n = args.number.unwrap_or(10);
+        if !(1..100).contains(&n) {
+            return Err("n is either too small or too large".into());
         }
-        Ok(self.total_correct)
-    }
 
-    fn draw(&mut self, frame: &mut Frame) {
-        // if we are rendering table we pass in different arguments than to render_widget
-        if self.verb.is_none() {
-            self.render_verbs_table(frame);
-            return;
+        // The possible verbs, from the verbs directory and the installed
+        // packs. On the first run there are none until the setup installs them,
+        // which the screen reader, kiosk and marathon do straight away as they
+        // can't show the setup (or have nothing to go through without verbs).
+        let mut first_run = setup::is_first_run(dirs);
+        if first_run
+            && (args.screen_reader
+                || args.kiosk.is_some()
+                || args.marathon.is_some()
+                || args.placement)
+        {
+            setup::install(dirs).expect("Could not install the starter verbs");
+            println!("Installed the starter verbs in {}", data_dir.display());
+            first_run = false;
+        }
+        let verbs = if first_run {
+            vec![]
+        } else {
+            logging::timed("verbs", || Verb::find_all(data_dir)).unwrap_or_else(|e| {
+                panic!(
+                    "Could not find/read the verbs directory in {}: {}",
+                    data_dir.display(),
+                    e
+                )
+            })
+        };
+        let setup = first_run.then(|| Setup::new(&config, dirs.clone()));
+        let curriculum = Curriculum::load(data_dir).expect("Could not read the curriculum");
+        let mode = args.mode.unwrap_or(config.direction.mode());
+        let mut lesson = None;
+        if let Some(v) = &args.verb {
+            let verb = verbs
+                .iter()
+                .find(|verb| verb.name() == v.to_lowercase())
+                .ok_or_else(|| format!("Verb not matched: {}", v))?;
+            let mut verb_lesson = Lesson::new(vec![verb.name().to_string()]);
+            verb_lesson.tenses = lesson_tenses(&config, args.difficulty, &curriculum);
+            if let Some(tense) = &args.tense {
+                verb_lesson.tenses = vec![tense.parse::<Tense>()?];
+            }
+            if let Some(person) = &args.person {
+                verb_lesson.persons = vec![person.parse::<Person>()?];
+            }
+            lesson = Some(verb_lesson);
+        } else if let Some(name) = &args.lesson {
+            let saved = config
+                .lessons
+                .get(name)
+                .ok_or_else(|| format!("Lesson not matched: {}", name))?;
+            lesson = Some(saved.clone());
         }
+        let plan = match &args.plan {
+            Some(name) => Some(Plan::load(data_dir, name, &verbs)?),
+            None => None,
+        };
+        let attempt = match &args.assignment {
+            Some(path) => Some(Attempt::new(
+                Assignment::load(path)?,
+                args.student.clone().unwrap_or_default(),
+            )),
+            None => None,
+        };
+        let lesson = lesson.or_else(|| Some(attempt.as_ref()?.assignment.lesson.clone()));
+        let mode = attempt.as_ref().map_or(mode, |a| a.assignment.mode);
 
-        frame.render_widget(&*self, frame.area());
-    }
+        let watcher = if args.watch {
+            Some(VerbWatcher::new(data_dir).expect("Could not watch the verbs directory"))
+        } else {
+            None
+        };
 
-    fn handle_events(&mut self) -> io::Result<()> {
-        match event::read()? {
-            // it's important to check that the event is a key press event as
-            // crossterm also emits key release and repeat events on Windows.
-            Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
-                self.handle_key_event(key_event)
-            }
-            _ => {}
+        #[cfg(feature = "audio")]
+        let speaker = if mode == Mode::Dictation {
+            let Some(speaker) = audio::Speaker::find() else {
+                eprintln!("Error: dictation needs espeak-ng, espeak or say to be installed");
+                process::exit(1);
+            };
+            Some(speaker)
+        } else {
+            None
         };
-        Ok(())
-    }
 
-    fn handle_key_event(&mut self, key_event: KeyEvent) {
-        if self.verb.is_none() {
-            self.handle_key_event_select_verb(key_event);
-            return;
-        }
+        // every student of an assignment is asked the same questions
+        let seed = match &attempt {
+            Some(attempt) => attempt.assignment.seed,
+            None => args.seed.unwrap_or_else(rand::random),
+        };
 
-        if self.exit.is_some() {
-            self.handle_key_event_game_over(key_event);
-            return;
+        let mut app = App {
+            cur_question: 0,
+            total_questions: u32::from(attempt.as_ref().map_or(n, |a| a.assignment.questions)),
+            time_limit: args
+                .minutes
+                .or_else(|| {
+                    let difficulty = args.difficulty.unwrap_or(config.difficulty);
+                    // a plan's steps, assignments and the marathon's verbs each
+                    // ask their number of questions
+                    let counted = args.number.is_some()
+                        || plan.is_some()
+                        || attempt.is_some()
+                        || args.marathon.is_some();
+                    difficulty.minutes().filter(|_| !counted)
+                })
+                .map(|m| Duration::from_secs(60 * u64::from(m))),
+            arcade: args.arcade.then(Arcade::default),
+            started_at: Instant::now(),
+            grace_until: None,
+            paused_at: None,
+            advance_at: None,
+            total_correct: 0,
+            total_incorrect: 0,
+            total_skipped: 0,
+            total_assisted: 0,
+            table_state: TableState::default().with_selected(0),
+            verbs,
+            favorites: Favorites::load(data_dir).expect("Could not read the favorites"),
+            recent: favorites::recent(
+                &logging::timed("history", || history::load(data_dir)).unwrap_or_default(),
+            ),
+            lesson: None,
+            from: args.from.clone(),
+            mode: if mode == Mode::Mixed {
+                Mode::Translate
+            } else {
+                mode
+            },
+            mix: (mode == Mode::Mixed).then_some(config.mix),
+            mode_given: args.mode.is_some() || attempt.is_some(),
+            cur_conjugation: usize::MAX, // so that things definitely panic if not updated
+            conjugations: vec![],
+            seed,
+            seeded: args.seed.is_some(),
+            rng: StdRng::seed_from_u64(seed),
+            cur_response: String::new(),
+            part_responses: Default::default(),
+            part_field: 0,
+            grid: Grid::default(),
+            guesses: Vec::new(),
+            cur_response_incorrect: None,
+            asked_at: Instant::now(),
+            layout_mixup: false,
+            skipped: false,
+            confidence: None,
+            tagged: vec![],
+            recommended: vec![],
+            warm_up: vec![],
+            warming_up: false,
+            curriculum,
+            unlocked: false,
+            plan,
+            marathon: None,
+            attempt,
+            submitted: None,
+            flagged: None,
+            cheer: None,
+            notes: Notes::load(data_dir).expect("Could not read the notes"),
+            encouragement: Encouragement::load(data_dir, &args.from)
+                .expect("Could not read the encouragement"),
+            srs: logging::timed("review schedule", || Srs::load(data_dir))
+                .expect("Could not read the review schedule"),
+            editing_note: None,
+            cache: logging::timed("cache", || Cache::load(data_dir)),
+            choices: vec![],
+            selected_choice: 0,
+            endings: vec![],
+            words: vec![],
+            placed: vec![],
+            data_dir: dirs.data.clone(),
+            config_dir: dirs.config.clone(),
+            watcher,
+            reload_error: None,
+            editor: None,
+            theme: Theme::dark(),
+            accessible: args.accessible,
+            hints: args.hints,
+            difficulty: args.difficulty,
+            config,
+            light_background: false,
+            settings: None,
+            setup,
+            suspended: None,
+            leeches: None,
+            builder: None,
+            templates: None,
+            compare: None,
+            comparing: None,
+            verb_stats: None,
+            search: None,
+            gender_quiz: None,
+            preposition_quiz: None,
+            politeness_quiz: None,
+            valency_quiz: None,
+            collocation_quiz: None,
+            placement: None,
+            spelling_quiz: None,
+            vocab_quiz: None,
+            match_game: None,
+            stats: None,
+            leaderboard: None,
+            // the home screen is skipped when a lesson is given
+            home: (lesson.is_none()
+                && args.plan.is_none()
+                && args.kiosk.is_none()
+                && args.marathon.is_none()
+                && !args.placement)
+                .then(Home::default),
+            kiosk: None,
+            drill_error: None,
+            lesson_error: None,
+            zen: false,
+            confirm_quit: false,
+            confirm_leave: false,
+            recovery: None,
+            screen: String::new(),
+            pending: vec![],
+            results: vec![],
+            typing: Typing::default(),
+            verb_turns: HashMap::new(),
+            shared: None,
+            copied: None,
+            #[cfg(feature = "clipboard")]
+            clipboard: Default::default(),
+            #[cfg(feature = "audio")]
+            speaker,
+            #[cfg(feature = "serve")]
+            remote: None,
+            #[cfg(feature = "async")]
+            effects: effects::Effects::new().expect("Could not start the background tasks"),
+            #[cfg(feature = "async")]
+            synced: None,
+            commands: vec![],
+            scheduler: Default::default(),
+            drawn_left: None,
+            exit: None,
+        };
+        if let Some(seconds) = args.kiosk {
+            app.start_kiosk(lesson, seconds);
+        } else if let Some(lesson) = lesson {
+            app.open_lesson(lesson);
+        } else if let Some(questions) = args.marathon {
+            app.start_marathon(questions);
+        } else if args.placement {
+            app.start_placement();
         }
-
-        self.handle_key_event_learning(key_event);
+        app.take_step();
+        Ok(app)
     }
 
-    fn handle_key_event_select_verb(&mut self, key_event: KeyEvent) {
-        match key_event.code {
-            KeyCode::Esc => self.exit = Some(true),
-            KeyCode::Enter => {
-                // set the verb
-                if let Some(i) = self.table_state.selected() {
-                    self.verb = Some(Verb::from_str(
-                        self.verbs
-                            .get(i)
-                            .expect("Selected verb could not be getted"),
-                    ));
-                } else {
-                    panic!("No verb selected, but is being selected")
+    /// runs the application's main loop until the user quits
+    pub fn run<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> io::Result<u32> {
+        // what setting up the app asked for, e.g. saying the first question
+        self.perform_pending();
+        while self.exit.is_none() || self.exit.is_some_and(|x| !x) {
+            // only draw when something changed, as waking up to watch or
+            // count down mostly doesn't, which inside tmux or over ssh makes
+            // for flicker and wasted CPU. ratatui then only writes the cells
+            // that differ from the last frame.
+            let left = self.grace_left().or(self.time_left()).map(|t| t.as_secs());
+            if left != self.drawn_left {
+                self.scheduler.request();
+            }
+            if self.scheduler.due() {
+                terminal.draw(|frame| self.draw(frame))?;
+                self.scheduler.drawn();
+                self.drawn_left = left;
+            }
+            let mut messages: Vec<Message> = self.next_message()?.into_iter().collect();
+            messages.push(Message::Tick);
+            #[cfg(feature = "serve")]
+            if let Some(remote) = &self.remote {
+                messages.extend(remote.commands().into_iter().map(Message::Remote));
+            }
+            #[cfg(feature = "async")]
+            messages.extend(self.effects.messages());
+            for message in messages {
+                for command in self.update(message) {
+                    self.perform(command);
                 }
-
-                self.conjugations = parse_conjugations(&self.verb.unwrap())
-                    .expect("Could not parse the conjugations");
-                self.cur_conjugation = rand::thread_rng().gen_range(0..self.conjugations.len());
             }
-            KeyCode::Up => self.previous_table_item(),
-            KeyCode::Char('k') => self.previous_table_item(),
-            KeyCode::Down => self.next_table_item(),
-            KeyCode::Char('j') => self.next_table_item(),
-            _ => {}
+            // shows the remote the new state
+            #[cfg(feature = "serve")]
+            if let Some(remote) = &self.remote {
+                remote.publish(&self.remote_state());
+            }
+            self.log_screen();
         }
+        Ok(self.total_correct)
     }
 
-    fn handle_key_event_learning(&mut self, key_event: KeyEvent) {
-        match key_event.code {
-            KeyCode::Enter => {
-                if self.cur_response_incorrect.is_none() {
-                    self.check_answer();
-                } else {
-                    self.next_question();
+    /// Updates the state with what happened, giving back what is to be done
+    /// outside of it, in order
+    fn update(&mut self, message: Message) -> Vec<message::Command> {
+        match message {
+            Message::Key(key_event) => {
+                self.handle_key_event(key_event);
+                self.commands.push(message::Command::Redraw);
+            }
+            Message::Resize => self.commands.push(message::Command::Redraw),
+            Message::Tick => {
+                if self.kiosk.as_mut().is_some_and(Kiosk::advance) {
+                    self.commands.push(message::Command::Redraw);
                 }
+                self.reload_verbs();
+                self.check_time();
+                self.auto_advance();
             }
-            KeyCode::Esc => self.exit = Some(true),
-            KeyCode::Backspace => {
-                self.cur_response.pop();
+            #[cfg(feature = "serve")]
+            Message::Remote(command) => {
+                self.handle_remote(command);
+                self.commands.push(message::Command::Redraw);
+            }
+            #[cfg(feature = "async")]
+            Message::Progress(effects::Task::Sync, progress) => {
+                info!(?progress, "sync");
+                self.synced = Some(progress);
+                self.commands.push(message::Command::Redraw);
             }
-            KeyCode::Char(c) => self.cur_response.push(c),
-            _ => {}
         }
+        std::mem::take(&mut self.commands)
     }
 
-    fn handle_key_event_game_over(&mut self, key_event: KeyEvent) {
-        match key_event.code {
-            KeyCode::Enter => {
-                self.cur_question = 0;
-                self.total_correct = 0;
-                self.total_incorrect = 0;
-                self.exit = None;
+    /// Carries out what an update asked to be done
+    fn perform(&mut self, command: message::Command) {
+        match command {
+            message::Command::Redraw => self.scheduler.request(),
+            message::Command::SaveConfig => self
+                .config
+                .save(&self.config_dir)
+                .expect("Could not save the settings"),
+            message::Command::Autosave => self.save_session(),
+            message::Command::ClearSession => {
+                session::clear(&self.data_dir).expect("Could not clear the saved lesson")
             }
-            KeyCode::Esc => self.exit = Some(true),
-            // select a new verb and go again :)
-            _ => {
-                self.cur_question = 0;
-                self.total_correct = 0;
-                self.total_incorrect = 0;
-                self.verb = None;
-                self.exit = None;
+            message::Command::LoadVerbStats(verb) => {
+                let answers = history::load(&self.data_dir).unwrap_or_default();
+                self.verb_stats = Some(VerbStats::new(&verb, &answers));
             }
-        }
-    }
-
-    pub fn next_table_item(&mut self) {
-        let i = match self.table_state.selected() {
-            Some(i) => {
-                if i >= self.verbs.len() - 1 {
-                    0
-                } else {
-                    i + 1
+            message::Command::RecordAnswers(answers) => {
+                history::record(&self.data_dir, &answers).expect("Could not record the answers");
+                for answer in answers {
+                    // autocompleted, it isn't known well enough to be asked later
+                    if answer.assisted && answer.correct {
+                        continue;
+                    }
+                    self.srs
+                        .review_card(answer.key(), answer.correct)
+                        .expect("Could not save the review schedule");
                 }
             }
-            None => 0,
-        };
-        self.table_state.select(Some(i));
-    }
-
-    pub fn previous_table_item(&mut self) {
-        let i = match self.table_state.selected() {
-            Some(i) => {
-                if i == 0 {
-                    self.verbs.len() - 1
-                } else {
-                    i - 1
+            message::Command::ReviewLesson => {
+                // the history has the answers of this lesson too
+                let answers =
+                    logging::timed("history", || history::load(&self.data_dir)).unwrap_or_default();
+                self.recommended = recommend::recommend(&answers, &self.conjugations);
+                self.recent = favorites::recent(&answers);
+                if self.config.curriculum {
+                    self.unlocked = self
+                        .curriculum
+                        .advance(&answers)
+                        .expect("Could not save the curriculum");
                 }
             }
-            None => 0,
-        };
-        self.table_state.select(Some(i));
+            message::Command::RecordScore(score) => {
+                scores::record(&self.data_dir, &score).expect("Could not record the score")
+            }
+            message::Command::RecordHighScore(lesson, score) => {
+                let new_best = arcade::record(&self.data_dir, &lesson, score)
+                    .expect("Could not save the high score");
+                if let Some(arcade) = &mut self.arcade {
+                    arcade.new_best = new_best;
+                    if new_best {
+                        arcade.best = Some(score);
+                    }
+                }
+            }
+            message::Command::LogEvent(line) => {
+                events::append(&self.data_dir, &line).expect("Could not log the event")
+            }
+            message::Command::Flag(conj, response) => {
+                let conj = &self.conjugations[conj];
+                self.flagged = Some(match flags::flag(&self.data_dir, conj, &response) {
+                    Ok(()) => "Flagged".to_string(),
+                    Err(e) => format!("Could not flag: {}", e),
+                });
+            }
+            message::Command::SaveNote(form, note) => self
+                .notes
+                .set_form(form, &note)
+                .expect("Could not save the notes"),
+            message::Command::SaveSchedule => {
+                self.srs.save().expect("Could not save the review schedule")
+            }
+            message::Command::SaveFavorites => {
+                self.favorites.save().expect("Could not save the favorites")
+            }
+            // a cache that can't be written is just parsed again next time
+            message::Command::SaveCache => {
+                let _ = self.cache.save();
+            }
+            message::Command::SavePlacement {
+                level,
+                right,
+                asked,
+            } => {
+                placement::save(&self.data_dir, level, right, asked)
+                    .expect("Could not save the placement");
+                self.curriculum
+                    .place(level.stage())
+                    .expect("Could not save the curriculum");
+            }
+            #[cfg(feature = "audio")]
+            message::Command::Speak(german) => {
+                if let Some(speaker) = &mut self.speaker {
+                    speaker
+                        .speak(&german)
+                        .expect("Could not speak the question");
+                }
+            }
+            #[cfg(feature = "clipboard")]
+            message::Command::Copy(text) => {
+                self.copied = Some(match self.clipboard.copy(&text) {
+                    Ok(()) => "Copied".to_string(),
+                    Err(e) => format!("Could not copy: {}", e),
+                });
+            }
+            // a broken hook shouldn't get in the way of the lesson
+            message::Command::RunHook(hook, json) => {
+                let _ = hooks::run(&hook, json);
+            }
+            #[cfg(feature = "async")]
+            message::Command::Sync => {
+                let sync = self.config.sync.clone();
+                let data_dir = self.data_dir.clone();
+                self.effects.spawn(effects::Task::Sync, move || {
+                    sync.push_progress(&data_dir).map_err(|e| e.to_string())
+                });
+            }
+        }
     }
 
-    /// Checks the current answer and updates the state accordingly
-    /// It awaits another enter key, such that the next view will show
-    /// the correct answer or a great job message.
-    /// Does nothing if the input is empty
-    fn check_answer(&mut self) {
-        if self.cur_response.is_empty() {
-            return;
+    /// Carries out what was asked outside of an update, e.g. by the screen
+    /// reader or while setting up
+    fn perform_pending(&mut self) {
+        for command in std::mem::take(&mut self.commands) {
+            self.perform(command);
         }
+    }
 
-        let correct = self.conjugations.get(self.cur_conjugation).unwrap().german
-            == self.cur_response.to_lowercase();
-
-        if !correct {
-            self.total_incorrect += 1;
-            self.cur_response_incorrect = Some(true);
-        } else {
-            self.total_correct += 1;
-            self.cur_response_incorrect = Some(false);
+    /// Logs the screen shown if it changed, by its breadcrumb
+    fn log_screen(&mut self) {
+        let screen = self.breadcrumb().join(" › ");
+        if screen != self.screen {
+            info!(from = %self.screen, to = %screen, "screen");
+            self.screen = screen;
         }
     }
 
-    /// Moves to the next question
-    /// If there are no more questions, then it sets the exit flag to true
-    /// so that the application will exit.
-    fn next_question(&mut self) {
-        self.cur_response.clear();
-        self.cur_response_incorrect = None;
-        self.cur_question += 1;
-        if self.cur_question >= self.total_questions {
-            self.exit = Some(false);
+    fn draw(&mut self, frame: &mut Frame) {
+        self.draw_screen(frame);
+        let area = frame.area();
+        // the focus mode has no border to put it on
+        if !self.zen_shown() {
+            let breadcrumb = format!(" {} ", self.breadcrumb().join(" › "));
+            let width = area.width.saturating_sub(4) / 3;
+            frame.buffer_mut().set_stringn(
+                area.x + 2,
+                area.y,
+                breadcrumb,
+                width as usize,
+                self.theme.notice,
+            );
+        }
+        if self.confirm_leave {
+            let keys = vec![
+                " Save ".into(),
+                "<s> ".set_style(self.theme.key),
+                " Abandon ".into(),
+                "<a> ".set_style(self.theme.key),
+                " Resume ".into(),
+                "<Esc> ".set_style(self.theme.key),
+            ];
+            self.render_confirmation("Leave the lesson?", keys, area, frame.buffer_mut());
+        }
+        if self.paused_at.is_some() {
+            let keys = vec![" Resume ".into(), "<Any Key> ".set_style(self.theme.key)];
+            self.render_confirmation("Paused", keys, area, frame.buffer_mut());
+        }
+        if self.recovery.is_some() {
+            let keys = vec![
+                " Recover ".into(),
+                "<y> ".set_style(self.theme.key),
+                " Discard ".into(),
+                "<n> ".set_style(self.theme.key),
+            ];
+            self.render_confirmation(
+                "Recover the lesson left unfinished?",
+                keys,
+                area,
+                frame.buffer_mut(),
+            );
+        }
+        if self.confirm_quit {
+            let keys = vec![
+                " Quit ".into(),
+                "<y> ".set_style(self.theme.key),
+                " Stay ".into(),
+                "<n> ".set_style(self.theme.key),
+            ];
+            self.render_confirmation("Quit Deutschland?", keys, area, frame.buffer_mut());
         }
-        self.cur_conjugation = rand::thread_rng().gen_range(0..self.conjugations.len());
     }
-}
 
-impl Widget for &App {
-    /// Renders the application state
-    ///
-    /// For new questions, it will show the current question and the total number of questions
-    /// and also the current question number, and the currently entered response.
-    ///
-    /// For questions that have been answered, if the answer was correct, then it will show
-    /// good job.
-    ///
-    /// If the answer was incorrect, then it will show the correct answer.
-    fn render(self, area: Rect, buf: &mut Buffer) {
-        // we are ready to exit, just have to wait for the user to acknowledge
-        // the final score
-        if self.exit.is_some() {
-            self.render_score(area, buf);
+    fn draw_screen(&mut self, frame: &mut Frame) {
+        if let Some(kiosk) = &self.kiosk {
+            kiosk.render(&self.theme, frame.area(), frame.buffer_mut());
+            return;
+        }
+        if let Some(setup) = &self.setup {
+            setup.render(&self.theme, frame.area(), frame.buffer_mut());
+            return;
+        }
+        if let Some(editor) = &mut self.editor {
+            editor.render(&self.theme, frame);
+            return;
+        }
+        if let Some(settings) = &self.settings {
+            settings.render(&self.theme, frame.area(), frame.buffer_mut());
+            return;
+        }
+        if let Some(suspended) = &self.suspended {
+            suspended.render(&self.theme, frame.area(), frame.buffer_mut());
+            return;
+        }
+        if let Some(leeches) = &self.leeches {
+            leeches.render(&self.theme, &self.notes, frame.area(), frame.buffer_mut());
+            return;
+        }
+        if let Some(builder) = &self.builder {
+            builder.render(&self.theme, frame.area(), frame.buffer_mut());
+            return;
+        }
+        if let Some(templates) = &self.templates {
+            templates.render(&self.theme, frame.area(), frame.buffer_mut());
+            return;
+        }
+        if let Some(compare) = &self.compare {
+            compare.render(&self.theme, frame.area(), frame.buffer_mut());
+            return;
+        }
+        if let Some(search) = &self.search {
+            search.render(&self.theme, frame.area(), frame.buffer_mut());
+            return;
+        }
+        if let Some(placement) = &self.placement {
+            placement.render(&self.theme, frame.area(), frame.buffer_mut());
+            return;
+        }
+        if let Some(gender_quiz) = &self.gender_quiz {
+            gender_quiz.render(&self.theme, frame.area(), frame.buffer_mut());
+            return;
+        }
+        if let Some(preposition_quiz) = &self.preposition_quiz {
+            preposition_quiz.render(&self.theme, frame.area(), frame.buffer_mut());
+            return;
+        }
+        if let Some(valency_quiz) = &self.valency_quiz {
+            valency_quiz.render(&self.theme, frame.area(), frame.buffer_mut());
+            return;
+        }
+        if let Some(collocation_quiz) = &self.collocation_quiz {
+            collocation_quiz.render(&self.theme, frame.area(), frame.buffer_mut());
+            return;
+        }
+        if let Some(politeness_quiz) = &self.politeness_quiz {
+            politeness_quiz.render(&self.theme, frame.area(), frame.buffer_mut());
+            return;
+        }
+        if let Some(spelling_quiz) = &self.spelling_quiz {
+            spelling_quiz.render(&self.theme, frame.area(), frame.buffer_mut());
+            return;
+        }
+        if let Some(vocab_quiz) = &self.vocab_quiz {
+            vocab_quiz.render(&self.theme, frame.area(), frame.buffer_mut());
+            return;
+        }
+        if let Some(match_game) = &self.match_game {
+            match_game.render(&self.theme, frame.area(), frame.buffer_mut());
+            return;
+        }
+        if let Some(stats) = &self.stats {
+            stats.render(&self.theme, frame.area(), frame.buffer_mut());
+            return;
+        }
+        if let Some(leaderboard) = &mut self.leaderboard {
+            leaderboard.render(&self.theme, frame.area(), frame.buffer_mut());
+            return;
+        }
+        if let Some(marathon) = self.marathon.as_ref().filter(|m| m.done) {
+            marathon.render_report(&self.theme, frame.area(), frame.buffer_mut());
+            return;
+        }
+        if let Some(plan) = self.plan.as_ref().filter(|p| p.intro().is_some()) {
+            plan.render_intro(&self.theme, frame.area(), frame.buffer_mut());
+            return;
+        }
+        if let Some(home) = &self.home {
+            let error = self.drill_error.as_deref();
+            home.render(&self.theme, error, frame.area(), frame.buffer_mut());
             return;
         }
 
-        match self.cur_response_incorrect {
-            Some(true) => {
-                self.render_incorrect(area, buf);
-            }
-            Some(false) => {
-                self.render_correct(area, buf);
-            }
-            None => {
-                self.render_unanswered_question(area, buf);
+        // if we are rendering table we pass in different arguments than to render_widget
+        if self.lesson.is_none() {
+            self.render_verbs_table(frame);
+            if let Some(verb_stats) = &self.verb_stats {
+                verb_stats.render(&self.theme, frame.area(), frame.buffer_mut());
             }
+            return;
         }
+
+        frame.render_widget(&*self, frame.area());
     }
-}
+
+    /// Waits for a key press or resize, None if the loop woke up without one
+    fn next_message(&self) -> io::Result<Option<Message>> {
+        // when watching (or serving), wake up regularly to pick up edits to
+        // the verb files (or answers from the remote), and when a frame is
+        // held back by the cap, to draw it. Otherwise sleep until a key press.
+        let wake_up = self.wakes_up().then_some(WATCH_INTERVAL);
+        if let Some(timeout) = self.scheduler.timeout(wake_up) {
+            if !event::poll(timeout)? {
+                return Ok(None);
+            }
+        }
+        Ok(match event::read()? {
+            // it's important to check that the event is a key press event as
+            // crossterm also emits key release and repeat events on Windows.
+            Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
+                Some(Message::Key(key_event))
+            }
+            Event::Resize(..) => Some(Message::Resize),
+            _ => None,
+        })
+    }
+
+    /// Whether the event loop has to run without key presses
+    fn wakes_up(&self) -> bool {
+        // to move on to the next slide
+        if self.kiosk.is_some() {
+            return true;
+        }
+        // to move on from a right answer
+        if self.advance_at.is_some() {
+            return true;
+        }
+        // to count down, and end the lesson on time
+        if self.time_limit.is_some()
+            && self.lesson.is_some()
+            && self.exit.is_none()
+            && self.paused_at.is_none()
+        {
+            return true;
+        }
+        #[cfg(feature = "serve")]
+        if self.remote.is_some() {
+            return true;
+        }
+        // to hear back from the tasks in the background
+        #[cfg(feature = "async")]
+        if self.effects.busy() {
+            return true;
+        }
+        self.watcher.is_some()
+    }
+
+    /// Carries out what the remote sent
+    #[cfg(feature = "serve")]
+    fn handle_remote(&mut self, command: serve::RemoteCommand) {
+        let in_question = self.lesson.is_some()
+            && self.exit.is_none()
+            && self.editor.is_none()
+            && self.settings.is_none()
+            && self.editing_note.is_none();
+        match command {
+            serve::RemoteCommand::Answer(answer)
+                if in_question && self.cur_response_incorrect.is_none() =>
+            {
+                if self.mode == Mode::Recognize {
+                    if let Some(i) = answer.parse::<usize>().ok().and_then(|n| n.checked_sub(1)) {
+                        self.choose(i);
+                    }
+                } else if self.mode == Mode::PrincipalParts {
+                    // the parts separated by commas
+                    for (response, part) in self.part_responses.iter_mut().zip(answer.split(',')) {
+                        *response = part.trim().to_string();
+                    }
+                    self.submit_parts();
+                } else if self.mode == Mode::Table {
+                    // the blank forms separated by commas
+                    self.grid.fill(&answer);
+                    self.submit_grid();
+                } else if self.mode == Mode::Hangman {
+                    for c in answer.chars() {
+                        self.guess(c);
+                    }
+                } else {
+                    self.cur_response = answer;
+                    self.check_answer();
+                }
+            }
+            serve::RemoteCommand::Next if in_question && self.cur_response_incorrect.is_some() => {
+                self.next_question()
+            }
+            _ => {}
+        }
+    }
+
+    /// The state of the lesson for the remote, giving away as much as the
+    /// screen does
+    #[cfg(feature = "serve")]
+    fn remote_state(&self) -> serve::State {
+        if self.lesson.is_none() {
+            return serve::State {
+                screen: "select_verb",
+                ..Default::default()
+            };
+        }
+        let mut state = serve::State {
+            question: self.cur_question + 1,
+            questions: self.questions(),
+            seconds_left: self.time_left().map(|t| t.as_secs()),
+            total_correct: self.total_correct,
+            ..Default::default()
+        };
+        if self.exit.is_some() {
+            state.screen = "score";
+            state.questions = Some(self.answered());
+            return state;
+        }
+
+        let conj = self.conjugations.get(self.cur_conjugation).unwrap();
+        let answered = self.cur_response_incorrect.is_some();
+        let hints = if answered {
+            HeaderHints::All
+        } else {
+            self.header_hints()
+        };
+        state.screen = if answered { "answered" } else { "question" };
+        if hints != HeaderHints::Hidden {
+            state.verb = Some(conj.verb.to_string());
+        }
+        if matches!(hints, HeaderHints::All | HeaderHints::NoPerson) {
+            state.tense = Some(conj.tense.to_string());
+        }
+        if hints == HeaderHints::All {
+            state.person = Some(conj.person.to_string());
+        }
+        state.prompt = match self.mode {
+            Mode::Translate => Some(format!("{}: {}", self.from, conj.prompt)),
+            Mode::Recognize => Some(format!("German: {}", conj.german)),
+            #[cfg(feature = "audio")]
+            Mode::Dictation => None,
+            Mode::PrincipalParts => self.principal_parts().map(|parts| {
+                format!(
+                    "{}: {} ({}, separated by commas)",
+                    self.from,
+                    parts.prompt,
+                    principal_parts::NAMES.join(", ")
+                )
+            }),
+            Mode::Table => Some(format!(
+                "Fill in {} in the {} ({}, separated by commas)",
+                conj.verb,
+                conj.tense,
+                self.grid
+                    .blanks()
+                    .map(|c| self.conjugations[c.conjugation].person.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+            Mode::Hangman => Some(format!(
+                "{}: {} | German: {} | {} wrong guesses left",
+                self.from,
+                conj.prompt,
+                hangman::masked(&conj.german, &self.guesses),
+                self.guesses_left()
+            )),
+            Mode::Ending => ending::blank(conj).map(|blank| {
+                format!(
+                    "{}: {} | German: {}_{} | Endings: {}",
+                    self.from,
+                    conj.prompt,
+                    blank.before,
+                    blank.after,
+                    self.endings.join(", ")
+                )
+            }),
+            Mode::Assemble => Some(format!(
+                "{}: {} | Words: {}",
+                self.from,
+                conj.prompt,
+                self.words.join(", ")
+            )),
+            // each question of a mixed lesson is one of the others
+            Mode::Mixed => None,
+        };
+        if answered {
+            state.response = Some(self.cur_response.clone());
+            state.correct = self.cur_response_incorrect.map(|incorrect| !incorrect);
+            state.answer = Some(self.expected_answer());
+        } else {
+            state.choices = self
+                .choices
+                .iter()
+                .map(|&i| choice_label(&self.conjugations[i]))
+                .collect();
+        }
+        state
+    }
+
+    fn handle_key_event(&mut self, key_event: KeyEvent) {
+        // a key in the meantime is the learner taking over
+        self.advance_at = None;
+        if self.confirm_quit {
+            match key_event.code {
+                KeyCode::Char('y') | KeyCode::Enter => self.exit = Some(true),
+                _ => self.confirm_quit = false,
+            }
+            return;
+        }
+        if key_event.code == KeyCode::Char('c')
+            && key_event.modifiers.contains(KeyModifiers::CONTROL)
+        {
+            self.confirm_quit = true;
+            return;
+        }
+        if let Some(session) = self.recovery.take() {
+            match key_event.code {
+                KeyCode::Char('y') | KeyCode::Enter => self.recover(session),
+                KeyCode::Char('n') | KeyCode::Esc => self.discard_session(session),
+                _ => self.recovery = Some(session),
+            }
+            return;
+        }
+        if self.confirm_leave {
+            self.confirm_leave = false;
+            match key_event.code {
+                // the score so far is shown, as when the time is up
+                KeyCode::Char('s') => {
+                    self.save_answers(true);
+                    self.finish_early();
+                }
+                // what was answered still counts, just not as a whole lesson
+                KeyCode::Char('a') => {
+                    self.save_answers(true);
+                    if self.marathon.is_some() {
+                        self.finish_marathon();
+                    } else {
+                        self.back_to_builder();
+                    }
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        if let Some(kiosk) = &mut self.kiosk {
+            if kiosk.handle_key_event(key_event) {
+                self.exit = Some(true);
+            }
+            return;
+        }
+
+        if let Some(setup) = &mut self.setup {
+            match setup.handle_key_event(key_event) {
+                SetupEvent::None => {}
+                SetupEvent::Quit => self.exit = Some(true),
+                SetupEvent::Done => self.finish_setup(),
+            }
+            return;
+        }
+
+        if let Some(editor) = &mut self.editor {
+            if editor.handle_key_event(key_event) {
+                self.editor = None;
+                // pick up any fixes made to the verb of the lesson
+                self.reload_lesson();
+            }
+            return;
+        }
+
+        if let Some(settings) = &mut self.settings {
+            if settings.handle_key_event(key_event) {
+                self.close_settings();
+            }
+            return;
+        }
+
+        if let Some(suspended) = &mut self.suspended {
+            if suspended.handle_key_event(key_event, &mut self.srs) {
+                self.suspended = None;
+            }
+            return;
+        }
+
+        if let Some(leeches) = &mut self.leeches {
+            if leeches.handle_key_event(key_event, &mut self.srs, &mut self.notes) {
+                self.leeches = None;
+            }
+            return;
+        }
+
+        if let Some(builder) = &mut self.builder {
+            match builder.handle_key_event(key_event) {
+                BuilderEvent::None => {}
+                BuilderEvent::Close => self.builder = None,
+                BuilderEvent::Start(lesson) => {
+                    self.builder = None;
+                    self.open_lesson(lesson);
+                }
+                BuilderEvent::Save(name, lesson) => {
+                    self.config.lessons.insert(name, lesson);
+                    self.commands.push(message::Command::SaveConfig);
+                }
+            }
+            return;
+        }
+
+        if let Some(templates) = &mut self.templates {
+            match templates.handle_key_event(key_event) {
+                TemplatesEvent::None => {}
+                TemplatesEvent::Close => self.templates = None,
+                TemplatesEvent::Start(lesson) => {
+                    self.templates = None;
+                    self.open_lesson(lesson);
+                }
+                TemplatesEvent::Delete(name) => {
+                    self.config.lessons.remove(&name);
+                    self.commands.push(message::Command::SaveConfig);
+                }
+            }
+            return;
+        }
+
+        if let Some(compare) = &mut self.compare {
+            if compare.handle_key_event(key_event) {
+                self.compare = None;
+            }
+            return;
+        }
+
+        if let Some(search) = &mut self.search {
+            match search.handle_key_event(key_event) {
+                SearchEvent::None => {}
+                SearchEvent::Close => self.search = None,
+                SearchEvent::Open {
+                    verb,
+                    tense,
+                    person,
+                } => self.show_form(verb, tense, person),
+            }
+            return;
+        }
+
+        if let Some(placement) = &mut self.placement {
+            match placement.handle_key_event(key_event) {
+                PlacementEvent::None => {}
+                PlacementEvent::Placed {
+                    level,
+                    right,
+                    asked,
+                } => {
+                    self.commands.push(message::Command::SavePlacement {
+                        level,
+                        right,
+                        asked,
+                    });
+                    info!(%level, right, asked, "placed");
+                }
+                PlacementEvent::Close => {
+                    self.placement = None;
+                    self.home = Some(Home::default());
+                }
+            }
+            return;
+        }
+
+        if let Some(gender_quiz) = &mut self.gender_quiz {
+            if gender_quiz.handle_key_event(key_event) {
+                self.gender_quiz = None;
+            }
+            return;
+        }
+
+        if let Some(preposition_quiz) = &mut self.preposition_quiz {
+            if preposition_quiz.handle_key_event(key_event) {
+                self.preposition_quiz = None;
+            }
+            return;
+        }
+
+        if let Some(valency_quiz) = &mut self.valency_quiz {
+            if valency_quiz.handle_key_event(key_event) {
+                self.valency_quiz = None;
+            }
+            return;
+        }
+
+        if let Some(collocation_quiz) = &mut self.collocation_quiz {
+            if collocation_quiz.handle_key_event(key_event) {
+                self.collocation_quiz = None;
+            }
+            return;
+        }
+
+        if let Some(politeness_quiz) = &mut self.politeness_quiz {
+            if politeness_quiz.handle_key_event(key_event) {
+                self.politeness_quiz = None;
+            }
+            return;
+        }
+
+        if let Some(spelling_quiz) = &mut self.spelling_quiz {
+            if spelling_quiz.handle_key_event(key_event) {
+                self.spelling_quiz = None;
+            }
+            return;
+        }
+
+        if let Some(vocab_quiz) = &mut self.vocab_quiz {
+            if vocab_quiz.handle_key_event(key_event) {
+                self.vocab_quiz = None;
+            }
+            return;
+        }
+
+        if let Some(match_game) = &mut self.match_game {
+            if match_game.handle_key_event(key_event) {
+                self.match_game = None;
+            }
+            return;
+        }
+
+        if let Some(stats) = &mut self.stats {
+            if stats.handle_key_event(key_event) {
+                self.stats = None;
+            }
+            return;
+        }
+
+        if let Some(leaderboard) = &mut self.leaderboard {
+            if leaderboard.handle_key_event(key_event) {
+                self.leaderboard = None;
+            }
+            return;
+        }
+
+        if let Some(marathon) = self.marathon.as_mut().filter(|m| m.done) {
+            if marathon.handle_key_event(key_event) {
+                self.marathon = None;
+            }
+            return;
+        }
+
+        if let Some(plan) = self.plan.as_mut().filter(|p| p.intro().is_some()) {
+            match key_event.code {
+                KeyCode::Enter => {
+                    plan.intro_read = true;
+                    self.take_step();
+                }
+                KeyCode::Esc => self.leave_plan(),
+                _ => {}
+            }
+            return;
+        }
+
+        if let Some(home) = &mut self.home {
+            self.drill_error = None;
+            match home.handle_key_event(key_event) {
+                HomeEvent::None => {}
+                HomeEvent::Quit => self.exit = Some(true),
+                HomeEvent::Open(destination) => self.open(destination),
+                HomeEvent::Search => self.open_search(),
+            }
+            return;
+        }
+
+        if self.lesson.is_none() {
+            self.handle_key_event_select_verb(key_event);
+            return;
+        }
+
+        if self.exit.is_some() {
+            self.handle_key_event_game_over(key_event);
+            return;
+        }
+
+        self.handle_key_event_learning(key_event);
+    }
+
+    fn handle_key_event_select_verb(&mut self, key_event: KeyEvent) {
+        self.lesson_error = None;
+        // any key closes the popup
+        if self.verb_stats.take().is_some() {
+            return;
+        }
+        if let Some(first) = self.comparing {
+            match key_event.code {
+                KeyCode::Enter | KeyCode::Char('c') => self.open_compare(first),
+                KeyCode::Esc => self.comparing = None,
+                KeyCode::Up | KeyCode::Char('k') => self.previous_table_item(),
+                KeyCode::Down | KeyCode::Char('j') => self.next_table_item(),
+                _ => {}
+            }
+            return;
+        }
+        match key_event.code {
+            KeyCode::Esc => self.home = Some(Home::default()),
+            KeyCode::Char('q') => self.confirm_quit = true,
+            KeyCode::Enter => self.open_builder(),
+            KeyCode::Char('e') => {
+                if let Some(verb) = self.selected_verb().and_then(|i| self.verbs.get(i)) {
+                    self.editor = Some(Editor::open(verb).expect("Could not open the verb file"));
+                }
+            }
+            KeyCode::Char('c') => self.comparing = self.selected_verb(),
+            KeyCode::Char('*') => self.toggle_favorite(),
+            KeyCode::Char('i') => {
+                if let Some(verb) = self.selected_verb().and_then(|i| self.verbs.get(i)) {
+                    let verb = verb.name().to_string();
+                    self.commands.push(message::Command::LoadVerbStats(verb));
+                }
+            }
+            KeyCode::Char('s') => self.settings = Some(Settings::new(&self.config)),
+            KeyCode::Char('u') => self.suspended = Some(Suspended::new(&self.srs)),
+            KeyCode::Char('l') => {
+                let verbs = self.verbs.iter().map(|v| v.name().to_string()).collect();
+                self.templates = Some(Templates::new(&self.config, verbs))
+            }
+            KeyCode::Char('p') => {
+                self.leeches = Some(Leeches::new(&self.srs, self.config.leech_lapses))
+            }
+            KeyCode::Up => self.previous_table_item(),
+            KeyCode::Char('k') => self.previous_table_item(),
+            KeyCode::Down => self.next_table_item(),
+            KeyCode::Char('j') => self.next_table_item(),
+            _ => {}
+        }
+    }
+
+    fn handle_key_event_learning(&mut self, key_event: KeyEvent) {
+        if self.paused_at.is_some() {
+            self.resume();
+            return;
+        }
+        if self.editing_note.is_some() {
+            self.handle_key_event_note(key_event);
+            return;
+        }
+        // the shortcuts of the keymap type the letter they are for
+        let key_event = match self.config.keymap.letter(&key_event) {
+            Some(letter) => KeyEvent::new(KeyCode::Char(letter), KeyModifiers::NONE),
+            None => key_event,
+        };
+        // a space can't start an answer, so before typing it pauses too
+        let pause = match key_event.code {
+            KeyCode::Char('p') => key_event.modifiers.contains(KeyModifiers::CONTROL),
+            KeyCode::Char(' ') => self.cur_response_incorrect.is_some() || self.nothing_given(),
+            _ => false,
+        };
+        if pause && self.time_limit.is_some() {
+            self.paused_at = Some(Instant::now());
+            return;
+        }
+        if key_event.code == KeyCode::Char('s')
+            && key_event.modifiers.contains(KeyModifiers::CONTROL)
+            && self.cur_response_incorrect.is_none()
+        {
+            self.skip();
+            return;
+        }
+        if self.cur_response_incorrect.is_none() {
+            let confidence = match key_event.code {
+                KeyCode::F(1) => Some(Confidence::Guess),
+                KeyCode::F(2) => Some(Confidence::Sure),
+                _ => None,
+            };
+            if confidence.is_some() {
+                // pressing it again takes it back
+                self.confidence = if self.confidence == confidence {
+                    None
+                } else {
+                    confidence
+                };
+                return;
+            }
+        }
+        if self.mode == Mode::Recognize && self.cur_response_incorrect.is_none() {
+            self.handle_key_event_choice(key_event);
+            return;
+        }
+        if self.mode == Mode::PrincipalParts && self.cur_response_incorrect.is_none() {
+            self.handle_key_event_parts(key_event);
+            return;
+        }
+        if self.mode == Mode::Table && self.cur_response_incorrect.is_none() {
+            self.handle_key_event_grid(key_event);
+            return;
+        }
+        if self.mode == Mode::Ending && self.cur_response_incorrect.is_none() {
+            // the endings are letters, so the digits are free to pick one
+            if let KeyCode::Char(c @ '1'..='9') = key_event.code {
+                if let Some(ending) = self.endings.get(c as usize - '1' as usize) {
+                    self.cur_response = ending.clone();
+                    self.check_answer();
+                }
+                return;
+            }
+        }
+        if self.mode == Mode::Assemble && self.cur_response_incorrect.is_none() {
+            match key_event.code {
+                KeyCode::Char(c @ '1'..='9') => self.place(c as usize - '1' as usize),
+                KeyCode::Backspace => {
+                    self.placed.pop();
+                    self.cur_response = self.assembled();
+                }
+                KeyCode::Enter => self.check_answer(),
+                KeyCode::Esc => self.confirm_leave = true,
+                _ => {}
+            }
+            return;
+        }
+        if self.mode == Mode::Hangman && self.cur_response_incorrect.is_none() {
+            match key_event.code {
+                KeyCode::Esc => self.confirm_leave = true,
+                KeyCode::Char(c) => self.guess(c),
+                _ => {}
+            }
+            return;
+        }
+
+        match key_event.code {
+            KeyCode::Enter => {
+                if self.cur_response_incorrect.is_none() {
+                    self.check_answer();
+                } else {
+                    self.next_question();
+                }
+            }
+            KeyCode::Esc => self.confirm_leave = true,
+            // z can't start an answer, as they all start with the pronoun
+            KeyCode::Char('z')
+                if self.mode == Mode::Translate
+                    && (self.cur_response.is_empty() || self.cur_response_incorrect.is_some()) =>
+            {
+                self.zen = !self.zen
+            }
+            // once answered, keys are commands rather than input
+            KeyCode::Char('e') if self.cur_response_incorrect.is_some() => self.edit_conjugation(),
+            KeyCode::Char('n') if self.cur_response_incorrect.is_some() => {
+                let conj = self.conjugations.get(self.cur_conjugation).unwrap();
+                self.editing_note = Some(self.notes.get(conj).unwrap_or_default().to_string());
+            }
+            KeyCode::Char('f') if self.cur_response_incorrect == Some(true) => {
+                self.flag_conjugation()
+            }
+            KeyCode::Char('x') if self.cur_response_incorrect.is_some() => {
+                self.suspend_conjugation()
+            }
+            #[cfg(feature = "clipboard")]
+            KeyCode::Char('c') if self.cur_response_incorrect.is_some() => {
+                let answer = self.expected_answer();
+                self.commands.push(message::Command::Copy(answer))
+            }
+            #[cfg(feature = "clipboard")]
+            KeyCode::Char('C') if self.cur_response_incorrect.is_some() => {
+                let text = self.question_and_answer();
+                self.commands.push(message::Command::Copy(text))
+            }
+            #[cfg(feature = "audio")]
+            KeyCode::Tab if self.cur_response_incorrect.is_none() => self.speak(),
+            KeyCode::Right if self.cur_response_incorrect.is_none() => {
+                if let Some(next) = self.autocomplete() {
+                    self.cur_response.push_str(&next);
+                }
+            }
+            KeyCode::Backspace
+                if self.cur_response_incorrect.is_none() && !self.cur_response.is_empty() =>
+            {
+                self.cur_response.pop();
+                self.typing.correction();
+            }
+            KeyCode::Char(c) if self.cur_response_incorrect.is_none() => {
+                self.cur_response.push(c);
+                self.typing.key();
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_key_event_choice(&mut self, key_event: KeyEvent) {
+        match key_event.code {
+            KeyCode::Enter => self.choose(self.selected_choice),
+            KeyCode::Esc => self.confirm_leave = true,
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected_choice =
+                    (self.selected_choice + self.choices.len() - 1) % self.choices.len();
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected_choice = (self.selected_choice + 1) % self.choices.len();
+            }
+            KeyCode::Char(c @ '1'..='9') => {
+                let i = c as usize - '1' as usize;
+                if i < self.choices.len() {
+                    self.choose(i);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_key_event_parts(&mut self, key_event: KeyEvent) {
+        let last = self.part_responses.len() - 1;
+        match key_event.code {
+            KeyCode::Enter if self.part_field < last => self.part_field += 1,
+            KeyCode::Enter => self.submit_parts(),
+            KeyCode::Esc => self.confirm_leave = true,
+            KeyCode::Tab | KeyCode::Down => self.part_field = (self.part_field + 1).min(last),
+            KeyCode::BackTab | KeyCode::Up => self.part_field = self.part_field.saturating_sub(1),
+            // backspacing past the start goes back to the part before
+            KeyCode::Backspace if self.part_responses[self.part_field].is_empty() => {
+                self.part_field = self.part_field.saturating_sub(1)
+            }
+            KeyCode::Backspace => {
+                self.part_responses[self.part_field].pop();
+                self.typing.correction();
+            }
+            KeyCode::Char(c) => {
+                self.part_responses[self.part_field].push(c);
+                self.typing.key();
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_key_event_grid(&mut self, key_event: KeyEvent) {
+        match key_event.code {
+            KeyCode::Enter if !self.grid.at_last() => self.grid.next(),
+            KeyCode::Enter => self.submit_grid(),
+            KeyCode::Esc => self.confirm_leave = true,
+            KeyCode::Tab | KeyCode::Down => self.grid.next(),
+            KeyCode::BackTab | KeyCode::Up => self.grid.prev(),
+            // backspacing past the start goes back to the cell before
+            KeyCode::Backspace if self.grid.response().is_empty() => self.grid.prev(),
+            KeyCode::Backspace => {
+                self.grid.response().pop();
+                self.typing.correction();
+            }
+            KeyCode::Char(c) => {
+                self.grid.response().push(c);
+                self.typing.key();
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_key_event_note(&mut self, key_event: KeyEvent) {
+        let Some(note) = &mut self.editing_note else {
+            return;
+        };
+        match key_event.code {
+            KeyCode::Enter => {
+                let conj = self.conjugations.get(self.cur_conjugation).unwrap();
+                let key = notes::key(conj);
+                self.commands
+                    .push(message::Command::SaveNote(key, note.clone()));
+                self.editing_note = None;
+            }
+            KeyCode::Esc => self.editing_note = None,
+            KeyCode::Backspace => {
+                note.pop();
+            }
+            KeyCode::Char(c) => note.push(c),
+            _ => {}
+        }
+    }
+
+    fn handle_key_event_game_over(&mut self, key_event: KeyEvent) {
+        if self.marathon.is_some() {
+            match key_event.code {
+                KeyCode::Enter => self.continue_marathon(),
+                KeyCode::Esc => self.finish_marathon(),
+                _ => {}
+            }
+            return;
+        }
+        match key_event.code {
+            KeyCode::Enter if self.plan.is_some() => self.continue_plan(),
+            KeyCode::Enter => self.restart(false),
+            KeyCode::Esc => self.back_to_builder(),
+            KeyCode::Char('p') if !self.recommended.is_empty() => self.practice_recommended(),
+            #[cfg(feature = "clipboard")]
+            KeyCode::Char('c') if self.shared.is_some() => {
+                let shared = self.shared.clone().unwrap_or_default();
+                self.commands.push(message::Command::Copy(shared))
+            }
+            // select a new verb and go again :)
+            _ => self.restart(true),
+        }
+    }
+
+    /// The current question and its answer, to paste into notes
+    #[cfg(feature = "clipboard")]
+    fn question_and_answer(&self) -> String {
+        let conj = self.conjugations.get(self.cur_conjugation).unwrap();
+        format!(
+            "{} | {} | {}\n{}: {}\nGerman: {}",
+            conj.verb,
+            conj.tense,
+            conj.person,
+            self.from,
+            conj.prompt,
+            self.expected_answer()
+        )
+    }
+
+    /// Resets the score to attempt the lesson again, or to select a new verb
+    fn restart(&mut self, new_verb: bool) {
+        self.cur_question = 0;
+        self.started_at = Instant::now();
+        self.grace_until = None;
+        self.paused_at = None;
+        self.total_correct = 0;
+        self.total_incorrect = 0;
+        self.total_skipped = 0;
+        self.total_assisted = 0;
+        self.tagged.clear();
+        self.results.clear();
+        self.typing = Typing::default();
+        self.verb_turns.clear();
+        self.unlocked = false;
+        self.submitted = None;
+        self.commands.push(message::Command::ClearSession);
+        if let Some(arcade) = &mut self.arcade {
+            arcade.reset();
+        }
+        if let Some(attempt) = &mut self.attempt {
+            attempt.restart();
+            // taken again, it asks the same questions again
+            if !new_verb {
+                self.rng = StdRng::seed_from_u64(self.seed);
+                self.pick_question();
+            }
+        }
+        if new_verb {
+            self.lesson = None;
+            // the lessons after an assignment aren't part of it
+            self.attempt = None;
+        }
+        self.exit = None;
+        if !new_verb {
+            self.show_question();
+        }
+    }
+
+    /// Starts the questions of the plan's step once its intro is read, or
+    /// moves on from a step that is only read
+    fn take_step(&mut self) {
+        while let Some(plan) = &mut self.plan {
+            if plan.intro().is_some() {
+                return;
+            }
+            let step = plan.current();
+            if let Some(lesson) = step.lesson.clone() {
+                let questions = step.questions;
+                self.restart(true);
+                self.total_questions = u32::from(questions);
+                if let Err(e) = self.start_lesson(lesson) {
+                    self.lesson_error = Some(e.to_string());
+                    self.leave_plan();
+                }
+                return;
+            }
+            if !plan.next() {
+                self.leave_plan();
+            }
+        }
+    }
+
+    /// Goes on to the next step of the plan if enough answers were right,
+    /// or takes the step again
+    fn continue_plan(&mut self) {
+        let (correct, answered) = (self.total_correct, self.answered());
+        let Some(plan) = &mut self.plan else {
+            return;
+        };
+        if plan.passed(correct, answered) && !plan.next() {
+            self.leave_plan();
+            return;
+        }
+        self.take_step();
+    }
+
+    /// Ends the plan, going back to the verbs
+    fn leave_plan(&mut self) {
+        self.plan = None;
+        self.restart(true);
+    }
+
+    /// Starts a marathon through every verb, asking each the questions
+    fn start_marathon(&mut self, questions: u8) {
+        if self.verbs.is_empty() {
+            panic!("The marathon has no verbs to practice");
+        }
+        let verbs = self.verbs.iter().map(|v| v.name().to_string()).collect();
+        self.marathon = Some(Marathon::new(verbs, questions.clamp(1, 99)));
+        self.take_marathon_verb();
+    }
+
+    /// Starts the placement test, on every verb and every drill's data there is
+    fn start_placement(&mut self) {
+        let mut content = placement::Content::default();
+        for verb in self.verbs.clone() {
+            // a verb without the source language has nothing to ask
+            if let Ok(mut conjugations) = self.cache.conjugations(&verb, &self.from) {
+                content.conjugations.append(&mut conjugations);
+            }
+        }
+        self.commands.push(message::Command::SaveCache);
+        content.nouns = nouns::load(&self.data_dir).unwrap_or_default();
+        content.prepositions = prepositions::load(&self.data_dir).unwrap_or_default();
+        content.valency = valency::load(&self.data_dir).unwrap_or_default();
+        content.collocations = collocations::load(&self.data_dir).unwrap_or_default();
+        content.politeness = politeness::load(&self.data_dir).unwrap_or_default();
+
+        let questions = placement::questions(&content, &mut self.rng);
+        let previous = placement::latest(&self.data_dir).expect("Could not read the placements");
+        self.placement = PlacementTest::new(questions, previous);
+        if self.placement.is_none() {
+            panic!("The placement test has nothing to ask");
+        }
+    }
+
+    /// Starts the lesson on the marathon's verb, passing over the verbs with
+    /// nothing to ask (e.g. without the source language)
+    fn take_marathon_verb(&mut self) {
+        while let Some(marathon) = &self.marathon {
+            let mut lesson = Lesson::new(vec![marathon.current().to_string()]);
+            lesson.tenses = self.lesson_tenses();
+            let questions = marathon.questions;
+            self.restart(true);
+            self.total_questions = u32::from(questions);
+            if self.start_lesson(lesson).is_ok() {
+                return;
+            }
+            if !self.marathon.as_mut().is_some_and(|m| m.next()) {
+                self.finish_marathon();
+                return;
+            }
+        }
+    }
+
+    /// Goes on to the marathon's next verb, or to the report after the last
+    fn continue_marathon(&mut self) {
+        let Some(marathon) = &mut self.marathon else {
+            return;
+        };
+        if marathon.next() {
+            self.take_marathon_verb();
+        } else {
+            self.finish_marathon();
+        }
+    }
+
+    /// Stops the marathon, showing the report of the verbs practiced
+    fn finish_marathon(&mut self) {
+        if let Some(marathon) = &mut self.marathon {
+            marathon.done = true;
+        }
+        self.restart(true);
+    }
+
+    /// Starts a lesson on just the forms recommended to practice
+    fn practice_recommended(&mut self) {
+        let lesson = Lesson::forms(std::mem::take(&mut self.recommended));
+        self.restart(true);
+        self.open_lesson(lesson);
+    }
+
+    /// Picks the theme from the settings and the detected background
+    fn apply_theme(&mut self) {
+        self.theme = Theme::from_choice(
+            self.config.theme,
+            self.light_background,
+            self.accessible || self.config.accessible,
+        );
+    }
+
+    /// Takes on the settings picked in the setup, and the verbs it installed
+    fn finish_setup(&mut self) {
+        let Some(setup) = self.setup.take() else {
+            return;
+        };
+        self.config = setup.config;
+        if !self.mode_given {
+            self.mode = self.config.direction.mode();
+        }
+        self.apply_theme();
+        self.verbs = Verb::find_all(&self.data_dir).expect("Could not read the starter verbs");
+    }
+
+    /// Applies and saves the changed settings
+    fn close_settings(&mut self) {
+        let Some(settings) = self.settings.take() else {
+            return;
+        };
+        self.config = settings.config;
+        self.apply_theme();
+        self.commands.push(message::Command::SaveConfig);
+    }
+
+    /// Leaves the lesson for the builder, with what the lesson asked checked
+    fn back_to_builder(&mut self) {
+        let Some(lesson) = self.lesson.clone() else {
+            return;
+        };
+        self.restart(true);
+        self.open_builder();
+        if let Some(builder) = &mut self.builder {
+            builder.check(&lesson);
+        }
+    }
+
+    /// Shows the verb picked first next to the one selected in the table
+    fn open_compare(&mut self, first: usize) {
+        self.comparing = None;
+        let Some(second) = self.selected_verb() else {
+            return;
+        };
+        let verbs = [first, second].map(|i| {
+            let verb = &self.verbs[i];
+            // a broken verb file just has nothing to show
+            let conjugations = self
+                .cache
+                .conjugations(verb, &self.from)
+                .unwrap_or_default();
+            (verb.name().to_string(), conjugations)
+        });
+        self.commands.push(message::Command::SaveCache);
+        self.compare = Some(Compare::new(verbs.into(), Tense::ALL[0]));
+    }
+
+    /// Opens the lesson builder, with the verb selected in the table checked
+    fn open_builder(&mut self) {
+        let Some(selected) = self.selected_verb() else {
+            return;
+        };
+        let mode = self.lesson_mode();
+        let forms = self
+            .verbs
+            .iter()
+            .map(|verb| {
+                // a broken verb file just has nothing to ask
+                let conjugations = self
+                    .cache
+                    .conjugations(verb, &self.from)
+                    .unwrap_or_default();
+                conjugations
+                    .iter()
+                    .filter(|c| match mode {
+                        // a verb is asked once, by its Future ich form
+                        Mode::PrincipalParts => {
+                            c.tense == Tense::Future
+                                && c.person == Person::I
+                                && PrincipalParts::of(&c.verb, &conjugations).is_some()
+                        }
+                        Mode::Ending => ending::blank(c).is_some(),
+                        Mode::Assemble => assemble::askable(c),
+                        _ => true,
+                    })
+                    .map(|c| (c.tense, c.person))
+                    .collect()
+            })
+            .collect();
+        self.commands.push(message::Command::SaveCache);
+        let names = self.verbs.iter().map(|v| v.name().to_string()).collect();
+        let tenses = self.lesson_tenses();
+        self.builder = Some(LessonBuilder::new(names, forms, mode, selected, &tenses));
+    }
+
+    /// Opens what was picked on the home screen
+    fn open(&mut self, destination: Destination) {
+        match destination {
+            Destination::Verbs => self.home = None,
+            Destination::Nouns => self.open_gender_quiz(),
+            Destination::Prepositions => self.open_preposition_quiz(),
+            Destination::Cases => self.open_valency_quiz(),
+            Destination::Collocations => self.open_collocation_quiz(),
+            Destination::Politeness => self.open_politeness_quiz(),
+            Destination::Numbers => {
+                let rng = StdRng::seed_from_u64(self.rng.gen());
+                self.spelling_quiz = Some(SpellingQuiz::new(rng));
+            }
+            Destination::Vocab => self.open_vocab_quiz(),
+            Destination::Match => self.open_match_game(),
+            Destination::Stats => match report::summary(&self.data_dir, stats::DAYS) {
+                Ok(report) => self.stats = Some(Stats::new(report)),
+                Err(e) => self.drill_error = Some(format!("Could not read the history: {}", e)),
+            },
+            Destination::Scores => match scores::load(&self.data_dir) {
+                Ok(scores) => self.leaderboard = Some(Leaderboard::new(scores)),
+                Err(e) => self.drill_error = Some(format!("Could not read the scores: {}", e)),
+            },
+            Destination::Settings => self.settings = Some(Settings::new(&self.config)),
+        }
+    }
+
+    /// Opens the search, over the verbs' forms and whichever of the nouns,
+    /// prepositions and vocabulary there are
+    fn open_search(&mut self) {
+        let mut entries = Vec::new();
+        for (i, verb) in self.verbs.iter().enumerate() {
+            // a broken verb file just has nothing to find
+            for conj in self
+                .cache
+                .conjugations(verb, &self.from)
+                .unwrap_or_default()
+            {
+                entries.push(search::Entry {
+                    label: format!("{}, {}", verb.name(), conj.tense),
+                    source: search::Source::Form {
+                        verb: i,
+                        tense: conj.tense,
+                        person: conj.person,
+                    },
+                    german: conj.german,
+                    english: conj.prompt,
+                });
+            }
+        }
+        self.commands.push(message::Command::SaveCache);
+        for noun in nouns::load(&self.data_dir).unwrap_or_default() {
+            entries.push(search::Entry {
+                german: noun.to_string(),
+                english: noun.english,
+                source: search::Source::Noun,
+                label: "noun".to_string(),
+            });
+        }
+        for preposition in prepositions::load(&self.data_dir).unwrap_or_default() {
+            entries.push(search::Entry {
+                german: preposition.sentence.unwrap_or(preposition.preposition),
+                english: preposition.english,
+                source: search::Source::Preposition,
+                label: format!("preposition, {}", preposition.case),
+            });
+        }
+        for deck in vocab::find_decks(&self.data_dir, &self.from).unwrap_or_default() {
+            for word in deck.words {
+                entries.push(search::Entry {
+                    german: word.german,
+                    english: word.translation,
+                    source: search::Source::Word,
+                    label: format!("vocab, {}", deck.name),
+                });
+            }
+        }
+        self.search = Some(Search::new(entries));
+    }
+
+    /// Shows the verb's conjugation in the tense, from the verbs table with
+    /// the verb selected, picking out the person's form
+    fn show_form(&mut self, verb: usize, tense: Tense, person: Person) {
+        self.search = None;
+        self.home = None;
+        self.select_verb(verb);
+        let verb = &self.verbs[verb];
+        let conjugations = self
+            .cache
+            .conjugations(verb, &self.from)
+            .unwrap_or_default();
+        let compare = Compare::new(vec![(verb.name().to_string(), conjugations)], tense);
+        self.compare = Some(compare.highlight(person));
+    }
+
+    /// Opens the der/die/das quiz on the nouns, or says why it can't
+    fn open_gender_quiz(&mut self) {
+        match nouns::load(&self.data_dir) {
+            Ok(nouns) => {
+                let rng = StdRng::seed_from_u64(self.rng.gen());
+                self.gender_quiz = GenderQuiz::new(nouns, rng);
+                if self.gender_quiz.is_none() {
+                    self.drill_error = Some("There are no nouns to ask".to_string());
+                }
+            }
+            Err(e) => self.drill_error = Some(format!("Could not load the nouns: {}", e)),
+        }
+    }
+
+    /// Opens the vocabulary decks, or says why they can't be
+    fn open_vocab_quiz(&mut self) {
+        let loaded = vocab::find_decks(&self.data_dir, &self.from)
+            .and_then(|decks| Ok((decks, vocab::load_srs(&self.data_dir)?)));
+        match loaded {
+            Ok((decks, srs)) => {
+                let rng = StdRng::seed_from_u64(self.rng.gen());
+                self.vocab_quiz = Some(VocabQuiz::new(decks, srs, self.from.clone(), rng));
+            }
+            Err(e) => self.drill_error = Some(format!("Could not load the vocabulary: {}", e)),
+        }
+    }
+
+    /// Opens the memory game on the conjugations of every verb, or says why
+    /// it can't
+    fn open_match_game(&mut self) {
+        let mut pool = Vec::new();
+        for verb in &self.verbs {
+            match self.cache.conjugations(verb, &self.from) {
+                Ok(mut conjugations) => pool.append(&mut conjugations),
+                Err(e) => {
+                    self.drill_error = Some(format!("Could not load {}: {}", verb.name(), e));
+                    return;
+                }
+            }
+        }
+        self.commands.push(message::Command::SaveCache);
+        pool.retain(|c| !self.srs.is_suspended(c));
+        let rng = StdRng::seed_from_u64(self.rng.gen());
+        self.match_game = MatchGame::new(pool, rng);
+        if self.match_game.is_none() {
+            self.drill_error = Some("There are not enough forms to match".to_string());
+        }
+    }
+
+    /// Opens the quiz on the cases of the prepositions, or says why it can't
+    fn open_preposition_quiz(&mut self) {
+        let loaded = prepositions::load(&self.data_dir)
+            .and_then(|p| Ok((p, prepositions::stats(&self.data_dir)?)));
+        match loaded {
+            Ok((list, stats)) => {
+                let rng = StdRng::seed_from_u64(self.rng.gen());
+                self.preposition_quiz =
+                    PrepositionQuiz::new(list, stats, self.data_dir.clone(), rng);
+                if self.preposition_quiz.is_none() {
+                    self.drill_error = Some("There are no prepositions to ask".to_string());
+                }
+            }
+            Err(e) => self.drill_error = Some(format!("Could not load the prepositions: {}", e)),
+        }
+    }
+
+    /// Opens the quiz on the cases the verbs govern, or says why it can't
+    fn open_valency_quiz(&mut self) {
+        let loaded = valency::load(&self.data_dir)
+            .and_then(|v| Ok((v, valency::stats(&self.data_dir, None)?)));
+        match loaded {
+            Ok((verbs, stats)) => {
+                let rng = StdRng::seed_from_u64(self.rng.gen());
+                self.valency_quiz = ValencyQuiz::new(verbs, stats, self.data_dir.clone(), rng);
+                if self.valency_quiz.is_none() {
+                    self.drill_error = Some("There are no verb cases to ask".to_string());
+                }
+            }
+            Err(e) => self.drill_error = Some(format!("Could not load the verb cases: {}", e)),
+        }
+    }
+
+    /// Opens the drill on the prepositions verbs go with, or says why it can't
+    fn open_collocation_quiz(&mut self) {
+        match collocations::load(&self.data_dir) {
+            Ok(list) => {
+                let rng = StdRng::seed_from_u64(self.rng.gen());
+                self.collocation_quiz = CollocationQuiz::new(list, rng);
+                if self.collocation_quiz.is_none() {
+                    self.drill_error = Some("There are no collocations to ask".to_string());
+                }
+            }
+            Err(e) => self.drill_error = Some(format!("Could not load the collocations: {}", e)),
+        }
+    }
+
+    /// Opens the drill on polite requests, or says why it can't
+    fn open_politeness_quiz(&mut self) {
+        match politeness::load(&self.data_dir) {
+            Ok(requests) => {
+                let rng = StdRng::seed_from_u64(self.rng.gen());
+                self.politeness_quiz = PolitenessQuiz::new(requests, rng);
+                if self.politeness_quiz.is_none() {
+                    self.drill_error = Some("There are no requests to ask".to_string());
+                }
+            }
+            Err(e) => self.drill_error = Some(format!("Could not load the requests: {}", e)),
+        }
+    }
+
+    /// Starts the lesson, or shows on the verbs table why it can't be
+    fn open_lesson(&mut self, lesson: Lesson) {
+        if let Err(e) = self.start_lesson(lesson) {
+            self.lesson_error = Some(e.to_string());
+        }
+    }
+
+    /// Loads the conjugations of the lesson and asks the first question, or
+    /// says why the lesson has nothing to ask in the mode
+    fn start_lesson(&mut self, lesson: Lesson) -> Result<(), Box<dyn Error>> {
+        let conjugations = self.lesson_conjugations(&lesson)?;
+        if conjugations.is_empty() {
+            return Err(format!("{} has nothing to ask", lesson.name()).into());
+        }
+        let previous = std::mem::replace(&mut self.conjugations, conjugations);
+        if let Some(nothing) = self.nothing_to_ask() {
+            self.conjugations = previous;
+            return Err(nothing.into());
+        }
+        self.lesson_error = None;
+        let mode = self.lesson_mode();
+        if let Some(arcade) = &mut self.arcade {
+            arcade.best = arcade::high_score(&self.data_dir, &lesson)
+                .expect("Could not read the high scores");
+        }
+        self.warm_up = self.warm_up_questions(&lesson);
+        if let Some(attempt) = &mut self.attempt {
+            attempt.start(&self.conjugations);
+        }
+        info!(
+            lesson = lesson.name(),
+            %mode,
+            forms = self.conjugations.len(),
+            "lesson started"
+        );
+        self.lesson = Some(lesson);
+        self.started_at = Instant::now();
+        self.pick_question();
+        Ok(())
+    }
+
+    /// Why the mode can't ask any of the lesson's conjugations, if it can't
+    fn nothing_to_ask(&self) -> Option<&'static str> {
+        match self.lesson_mode() {
+            Mode::PrincipalParts if self.part_questions().is_empty() => {
+                Some("None of the lesson's verbs have a Future, Past and Perfect Present ich form")
+            }
+            Mode::Ending if self.ending_questions().is_empty() => {
+                Some("None of the lesson's forms have an ending to give")
+            }
+            Mode::Assemble if self.assemble_questions().is_empty() => {
+                Some("None of the lesson's forms are in a perfect tense")
+            }
+            _ => None,
+        }
+    }
+
+    /// The forms of the lesson most missed in the history, to ask before the
+    /// others, worst first
+    fn warm_up_questions(&self, lesson: &Lesson) -> Vec<usize> {
+        // a lesson on chosen forms is a review already, an assignment asks the
+        // same questions of every student, and a seeded lesson the same ones
+        // as last time, whatever was missed since
+        if !self.config.warm_up || !lesson.forms.is_empty() || self.attempt.is_some() || self.seeded
+        {
+            return vec![];
+        }
+        if matches!(self.mode, Mode::PrincipalParts | Mode::Table) {
+            return vec![];
+        }
+        let answers =
+            logging::timed("history", || history::load(&self.data_dir)).unwrap_or_default();
+        let askable = self.askable_questions();
+        recommend::recommend(&answers, &self.conjugations)
+            .iter()
+            .filter_map(|key| {
+                self.conjugations
+                    .iter()
+                    .position(|c| srs::key(c) == *key && !self.srs.is_suspended(c))
+            })
+            .filter(|i| askable.as_ref().is_none_or(|a| a.contains(i)))
+            .collect()
+    }
+
+    /// The conjugations the lesson can ask, from the verb files (or the cache)
+    fn lesson_conjugations(&mut self, lesson: &Lesson) -> Result<Vec<Conjugation>, Box<dyn Error>> {
+        let start = Instant::now();
+        let mut conjugations = Vec::new();
+        let mut skipped = Vec::new();
+        for name in &lesson.verbs {
+            let verb = self
+                .verbs
+                .iter()
+                .find(|v| v.name() == name)
+                .ok_or_else(|| format!("Verb not matched: {}", name))?;
+            // a verb without prompts in the source language is left out
+            let languages = conjugation::languages(verb.path())?;
+            if !languages.iter().any(|l| l.eq_ignore_ascii_case(&self.from)) {
+                warn!(
+                    verb = name,
+                    from = self.from,
+                    "no prompts in the source language"
+                );
+                skipped.push(name.as_str());
+                continue;
+            }
+            let mut verb_conjugations = self.cache.conjugations(verb, &self.from)?;
+            lesson.keep(self.mode, &mut verb_conjugations);
+            conjugations.append(&mut verb_conjugations);
+        }
+        // the cache is only an optimisation, so failing to write it is fine
+        self.commands.push(message::Command::SaveCache);
+        if conjugations.is_empty() && !skipped.is_empty() {
+            return Err(format!("{} has no '{}' column", skipped.join(", "), self.from).into());
+        }
+        info!(
+            what = "conjugations",
+            ms = start.elapsed().as_millis() as u64,
+            count = conjugations.len(),
+            "loaded"
+        );
+        Ok(conjugations)
+    }
+
+    /// Shows the demo of the lesson's conjugations, or of every verb's
+    fn start_kiosk(&mut self, lesson: Option<Lesson>, seconds: u64) {
+        let lesson = lesson.unwrap_or_else(|| {
+            Lesson::new(self.verbs.iter().map(|v| v.name().to_string()).collect())
+        });
+        let mut conjugations = Vec::new();
+        for name in &lesson.verbs {
+            let Some(verb) = self.verbs.iter().find(|v| v.name() == name) else {
+                continue;
+            };
+            // a verb without the source language is left out of the demo
+            if let Ok(mut c) = self.cache.conjugations(verb, &self.from) {
+                c.retain(|c| lesson.includes(c));
+                conjugations.append(&mut c);
+            }
+        }
+        self.commands.push(message::Command::SaveCache);
+        if conjugations.is_empty() {
+            panic!("The kiosk has nothing to show");
+        }
+        let rng = StdRng::seed_from_u64(self.seed);
+        self.kiosk = Some(Kiosk::new(conjugations, seconds, rng));
+    }
+
+    /// Records the current conjugation in the flags file, as its data looks wrong
+    fn flag_conjugation(&mut self) {
+        if self.flagged.is_some() {
+            return;
+        }
+        self.commands.push(message::Command::Flag(
+            self.cur_conjugation,
+            self.cur_response.clone(),
+        ));
+    }
+
+    /// Stops asking the current conjugation until it is unsuspended, and moves on
+    fn suspend_conjugation(&mut self) {
+        let conj = self.conjugations.get(self.cur_conjugation).unwrap();
+        self.srs.suspend(srs::key(conj), true);
+        self.commands.push(message::Command::SaveSchedule);
+        self.next_question();
+    }
+
+    /// Opens the editor on the row of the current conjugation
+    fn edit_conjugation(&mut self) {
+        let conj = self.conjugations.get(self.cur_conjugation).unwrap();
+        let mut editor = Editor::open(&conj.verb).expect("Could not open the verb file");
+        editor.select_conjugation(conj);
+        self.editor = Some(editor);
+    }
+
+    /// Picks up edits to the verb files when watching: refreshes the verbs
+    /// table and re-parses the verb of the current lesson if its file changed.
+    fn reload_verbs(&mut self) {
+        if !self.watcher.as_ref().is_some_and(|w| w.has_changes()) {
+            return;
+        }
+        self.commands.push(message::Command::Redraw);
+
+        if let Ok(verbs) = logging::timed("verbs", || Verb::find_all(&self.data_dir)) {
+            self.verbs = verbs;
+            if let Some(i) = self.table_state.selected() {
+                let rows = self.verb_rows().len();
+                self.table_state.select(Some(i.min(rows.saturating_sub(1))));
+            }
+        }
+
+        self.reload_lesson();
+    }
+
+    /// Re-parses the verbs of the current lesson if their files changed. If
+    /// an edited file is broken the old conjugations are kept.
+    fn reload_lesson(&mut self) {
+        let Some(lesson) = self.lesson.clone() else {
+            return;
+        };
+        match self.lesson_conjugations(&lesson) {
+            Ok(conjugations) if !conjugations.is_empty() => {
+                self.conjugations = conjugations;
+                // edited down to nothing the mode can ask, the lesson is over
+                if let Some(nothing) = self.nothing_to_ask() {
+                    self.restart(true);
+                    self.lesson_error = Some(nothing.to_string());
+                    return;
+                }
+                let len = self.conjugations.len();
+                if self.cur_conjugation >= len || self.choices.iter().any(|&i| i >= len) {
+                    self.pick_question();
+                }
+                self.reload_error = None;
+            }
+            Ok(_) => self.reload_error = Some(format!("{} has no conjugations", lesson.name())),
+            Err(e) => {
+                self.reload_error = Some(format!("Could not reload {}: {}", lesson.name(), e))
+            }
+        }
+    }
+
+    pub fn next_table_item(&mut self) {
+        let i = match self.table_state.selected() {
+            Some(i) => {
+                if i >= self.verb_rows().len() - 1 {
+                    0
+                } else {
+                    i + 1
+                }
+            }
+            None => 0,
+        };
+        self.table_state.select(Some(i));
+    }
+
+    /// The rows of the verbs table, by the index of their verb and with the
+    /// section they are in: the favorites and the verbs practiced recently,
+    /// then every verb
+    fn verb_rows(&self) -> Vec<(&'static str, usize)> {
+        let mut rows: Vec<(&'static str, usize)> = (0..self.verbs.len())
+            .filter(|&i| self.favorites.contains(self.verbs[i].name()))
+            .map(|i| ("Favorites", i))
+            .collect();
+        for name in &self.recent {
+            if let Some(i) = self.verbs.iter().position(|v| v.name() == name) {
+                rows.push(("Recent", i));
+            }
+        }
+        rows.extend((0..self.verbs.len()).map(|i| ("All Verbs", i)));
+        rows
+    }
+
+    /// The index of the verb selected in the verbs table
+    fn selected_verb(&self) -> Option<usize> {
+        let row = self.table_state.selected()?;
+        self.verb_rows().get(row).map(|&(_, i)| i)
+    }
+
+    /// Selects the verb's row among every verb
+    fn select_verb(&mut self, verb: usize) {
+        let row = self.verb_rows().iter().rposition(|&(_, i)| i == verb);
+        self.table_state.select(row);
+    }
+
+    /// Stars the selected verb, or unstars it, keeping it selected
+    fn toggle_favorite(&mut self) {
+        let Some(verb) = self.selected_verb() else {
+            return;
+        };
+        self.favorites.toggle(self.verbs[verb].name());
+        self.commands.push(message::Command::SaveFavorites);
+        self.select_verb(verb);
+    }
+
+    pub fn previous_table_item(&mut self) {
+        let i = match self.table_state.selected() {
+            Some(i) => {
+                if i == 0 {
+                    self.verb_rows().len() - 1
+                } else {
+                    i - 1
+                }
+            }
+            None => 0,
+        };
+        self.table_state.select(Some(i));
+    }
+
+    /// Checks the current answer and updates the state accordingly
+    /// It awaits another enter key, such that the next view will show
+    /// the correct answer or a great job message.
+    /// Does nothing if the input is empty
+    fn check_answer(&mut self) {
+        if self.cur_response.is_empty() {
+            return;
+        }
+        self.typing.submit();
+
+        let strictness = match &self.attempt {
+            // graded the same way for every student
+            Some(attempt) => attempt.assignment.strictness,
+            None => self
+                .difficulty
+                .map_or(self.config.strictness, Difficulty::strictness),
+        };
+        let grader = Grader::new().strictness(strictness);
+        let correct = match self.principal_parts() {
+            // every form has to be right
+            None if self.mode == Mode::Table => self.grid.grade(&self.conjugations, grader),
+            // only the ending is given
+            None if self.mode == Mode::Ending => {
+                let conj = self.conjugations.get(self.cur_conjugation).unwrap();
+                let ending = ending::blank(conj).map(|b| b.ending).unwrap_or_default();
+                grader.grade(&self.cur_response, &ending)
+            }
+            // every part has to be right
+            Some(parts) => parts
+                .parts
+                .iter()
+                .zip(&self.part_responses)
+                .all(|(part, response)| grader.grade(response, part)),
+            None => grader.grade(
+                &self.cur_response,
+                &self.conjugations.get(self.cur_conjugation).unwrap().german,
+            ),
+        };
+
+        if !correct
+            && !matches!(
+                self.mode,
+                Mode::PrincipalParts | Mode::Table | Mode::Hangman | Mode::Ending | Mode::Assemble
+            )
+        {
+            self.layout_mixup = keyboard::is_layout_mixup(
+                &self.cur_response,
+                &self.conjugations.get(self.cur_conjugation).unwrap().german,
+            );
+        }
+        let conj = &self.conjugations[self.cur_conjugation];
+        info!(
+            mode = %self.mode,
+            verb = conj.verb.name(),
+            tense = ?conj.tense,
+            person = ?conj.person,
+            ?strictness,
+            correct,
+            layout_mixup = self.layout_mixup,
+            "graded"
+        );
+        debug!(response = %self.cur_response, expected = %self.expected_answer(), "graded against");
+        if !correct {
+            self.total_incorrect += 1;
+            self.cur_response_incorrect = Some(true);
+        } else {
+            self.total_correct += 1;
+            self.cur_response_incorrect = Some(false);
+        }
+        self.record_answer(correct);
+    }
+
+    /// Checks the principal parts once they are all given
+    fn submit_parts(&mut self) {
+        if self.part_responses.iter().any(|r| r.trim().is_empty()) {
+            return;
+        }
+        self.cur_response = self.part_responses.join(", ");
+        self.check_answer();
+    }
+
+    /// Checks the table once every blank form is given
+    fn submit_grid(&mut self) {
+        if !self.grid.is_filled() {
+            return;
+        }
+        self.cur_response = self.grid.responses();
+        self.check_answer();
+    }
+
+    /// Guesses a letter of the hangman question. It is answered once every
+    /// letter is found, or too many guesses were wrong.
+    fn guess(&mut self, c: char) {
+        if !c.is_alphabetic() || self.guesses.contains(&c) || self.cur_response_incorrect.is_some()
+        {
+            return;
+        }
+        self.guesses.push(c);
+        let german = &self.conjugations.get(self.cur_conjugation).unwrap().german;
+        let masked = hangman::masked(german, &self.guesses);
+        if masked == *german || self.guesses_left() == 0 {
+            // what was found, which is only right if it is everything
+            self.cur_response = masked;
+            self.check_answer();
+        }
+    }
+
+    /// How many more letters can be guessed wrong in the hangman question
+    fn guesses_left(&self) -> usize {
+        let german = &self.conjugations.get(self.cur_conjugation).unwrap().german;
+        hangman::MAX_MISSES.saturating_sub(hangman::misses(german, &self.guesses).len())
+    }
+
+    /// The principal parts asked by the current question, in principal parts
+    /// mode
+    fn principal_parts(&self) -> Option<PrincipalParts> {
+        if self.mode != Mode::PrincipalParts {
+            return None;
+        }
+        let conj = self.conjugations.get(self.cur_conjugation)?;
+        PrincipalParts::of(&conj.verb, &self.conjugations)
+    }
+
+    /// In principal parts mode, the conjugations that stand in for each verb
+    /// that has all its parts: its Future ich form
+    fn part_questions(&self) -> Vec<usize> {
+        (0..self.conjugations.len())
+            .filter(|&i| {
+                let conj = &self.conjugations[i];
+                conj.tense == Tense::Future
+                    && conj.person == Person::I
+                    && PrincipalParts::of(&conj.verb, &self.conjugations).is_some()
+            })
+            .collect()
+    }
+
+    /// In ending mode, the conjugations that have an ending to give
+    fn ending_questions(&self) -> Vec<usize> {
+        (0..self.conjugations.len())
+            .filter(|&i| ending::blank(&self.conjugations[i]).is_some())
+            .collect()
+    }
+
+    /// In assemble mode, the conjugations in a perfect tense
+    fn assemble_questions(&self) -> Vec<usize> {
+        (0..self.conjugations.len())
+            .filter(|&i| assemble::askable(&self.conjugations[i]))
+            .collect()
+    }
+
+    /// The conjugations the mode can ask, when it can't ask every one: an
+    /// ending question needs a form with an ending, and an assemble question
+    /// a perfect form
+    fn askable_questions(&self) -> Option<Vec<usize>> {
+        match self.mode {
+            Mode::Ending => Some(self.ending_questions()),
+            Mode::Assemble => Some(self.assemble_questions()),
+            _ => None,
+        }
+    }
+
+    /// Puts the word of the assemble question next in the answer, unless it
+    /// is there already. Once every word is, the answer is checked.
+    fn place(&mut self, word: usize) {
+        if word >= self.words.len() || self.placed.contains(&word) {
+            return;
+        }
+        self.placed.push(word);
+        self.cur_response = self.assembled();
+        if self.placed.len() == self.words.len() {
+            self.check_answer();
+        }
+    }
+
+    /// The words of the assemble question put in order so far
+    fn assembled(&self) -> String {
+        let words: Vec<&str> = self
+            .placed
+            .iter()
+            .map(|&i| self.words[i].as_str())
+            .collect();
+        words.join(" ")
+    }
+
+    /// The right answer to the current question, as it is shown
+    fn expected_answer(&self) -> String {
+        let conj = self.conjugations.get(self.cur_conjugation).unwrap();
+        match self.mode {
+            Mode::Recognize => choice_label(conj),
+            Mode::PrincipalParts => self
+                .principal_parts()
+                .map(|p| p.answer())
+                .unwrap_or_default(),
+            Mode::Table => self.grid.answer(&self.conjugations),
+            _ => conj.german.clone(),
+        }
+    }
+
+    /// Moves to the next question
+    /// If there are no more questions, then it sets the exit flag to true
+    /// so that the application will exit.
+    fn next_question(&mut self) {
+        self.cur_response.clear();
+        self.part_responses = Default::default();
+        self.part_field = 0;
+        self.guesses.clear();
+        self.placed.clear();
+        self.cur_response_incorrect = None;
+        self.advance_at = None;
+        self.layout_mixup = false;
+        self.skipped = false;
+        self.confidence = None;
+        self.flagged = None;
+        self.cheer = None;
+        self.copied = None;
+        self.grace_until = None;
+        self.cur_question = self.cur_question.saturating_add(1);
+        let done = match self.time_limit {
+            Some(_) => self.time_up(),
+            None => self.cur_question >= self.total_questions,
+        };
+        if done {
+            self.exit = Some(false);
+            self.end_lesson();
+        }
+        self.pick_question();
+    }
+
+    /// What the lesson asks for as a whole: Mixed in a mixed lesson, else the
+    /// mode of its questions
+    fn lesson_mode(&self) -> Mode {
+        match self.mix {
+            Some(_) => Mode::Mixed,
+            None => self.mode,
+        }
+    }
+
+    /// Takes on the mode of a lesson, the kinds of question of the mix
+    /// taking turns in a mixed one
+    fn set_mode(&mut self, mode: Mode) {
+        self.mix = (mode == Mode::Mixed).then_some(self.config.mix);
+        if mode != Mode::Mixed {
+            self.mode = mode;
+        }
+    }
+
+    /// How many questions the lesson has, None when it runs for a time
+    fn questions(&self) -> Option<u32> {
+        match self.time_limit {
+            Some(_) => None,
+            None => Some(self.total_questions),
+        }
+    }
+
+    /// How many questions were answered so far in the lesson
+    fn answered(&self) -> u32 {
+        self.total_correct.saturating_add(self.total_incorrect)
+    }
+
+    /// How long the lesson still runs for, with --minutes
+    fn time_left(&self) -> Option<Duration> {
+        let limit = self.time_limit?;
+        Some(limit.saturating_sub(self.clock().saturating_duration_since(self.started_at)))
+    }
+
+    fn time_up(&self) -> bool {
+        self.time_left().is_some_and(|t| t.is_zero())
+    }
+
+    /// Once the time is up, how long the current question can still be
+    /// answered for
+    fn grace_left(&self) -> Option<Duration> {
+        let until = self.grace_until?;
+        Some(until.saturating_duration_since(self.clock()))
+    }
+
+    /// The time as the lesson's clock has it, stopped while paused
+    fn clock(&self) -> Instant {
+        self.paused_at.unwrap_or_else(Instant::now)
+    }
+
+    /// Starts the clock again, moving the times it is measured from on by
+    /// how long it was paused
+    fn resume(&mut self) {
+        let Some(paused_at) = self.paused_at.take() else {
+            return;
+        };
+        let paused = paused_at.elapsed();
+        self.started_at += paused;
+        self.asked_at += paused;
+        self.typing.shift(paused);
+        if let Some(until) = &mut self.grace_until {
+            *until += paused;
+        }
+    }
+
+    /// Whether the next letter of the answer is shown as it is typed: in
+    /// translate mode, if the settings (or --difficulty) have autocomplete
+    /// on. Assignments are done without.
+    fn autocompleting(&self) -> bool {
+        self.mode == Mode::Translate
+            && self.attempt.is_none()
+            && self
+                .difficulty
+                .map_or(self.config.autocomplete, Difficulty::autocomplete)
+    }
+
+    /// With autocomplete, the letter of the answer after what was typed (and
+    /// the space before it, between words). None if what was typed isn't how
+    /// the answer starts.
+    fn autocomplete(&self) -> Option<String> {
+        if !self.autocompleting() || self.cur_response_incorrect.is_some() {
+            return None;
+        }
+        let mut answer = self.conjugations.get(self.cur_conjugation)?.german.chars();
+        for typed in self.cur_response.chars() {
+            let expected = answer.next()?;
+            if !expected.to_lowercase().eq(typed.to_lowercase()) {
+                return None;
+            }
+        }
+        let next = answer.next()?;
+        let mut completion = next.to_string();
+        if next == ' ' {
+            completion.extend(answer.next());
+        }
+        Some(completion)
+    }
+
+    /// Whether nothing of the current question was typed (or guessed) yet
+    fn nothing_given(&self) -> bool {
+        self.cur_response.is_empty()
+            && self.part_responses.iter().all(|r| r.is_empty())
+            && self.grid.blanks().all(|c| c.response.is_empty())
+            && self.guesses.is_empty()
+    }
+
+    /// Ends the lesson once its time is up, even in the middle of a question.
+    /// The question being answered gets the grace period to be finished, and
+    /// then what was given of it is submitted. A question left blank doesn't
+    /// count.
+    fn check_time(&mut self) {
+        if self.lesson.is_none() || self.exit.is_some() || !self.time_up() {
+            return;
+        }
+        if self.cur_response_incorrect.is_none() && self.config.grace_seconds > 0 {
+            let grace = Duration::from_secs(self.config.grace_seconds);
+            let until = *self
+                .grace_until
+                .get_or_insert_with(|| Instant::now() + grace);
+            if self.clock() < until {
+                return;
+            }
+            self.auto_submit();
+        } else if self.grace_until.is_some() {
+            // answered in time, the feedback is shown until moving on
+            return;
+        }
+        self.finish_early();
+        self.commands.push(message::Command::Redraw);
+    }
+
+    /// Moves on from a right answer once it has been shown long enough, as
+    /// Enter would
+    fn auto_advance(&mut self) {
+        let Some(at) = self.advance_at else {
+            return;
+        };
+        if self.lesson.is_none()
+            || self.exit.is_some()
+            || self.cur_response_incorrect != Some(false)
+        {
+            self.advance_at = None;
+            return;
+        }
+        if Instant::now() < at || self.paused_at.is_some() {
+            return;
+        }
+        self.advance_at = None;
+        self.next_question();
+        self.commands.push(message::Command::Redraw);
+    }
+
+    /// Grades what was given of the current question as it is, as its grace
+    /// period is over
+    fn auto_submit(&mut self) {
+        match self.mode {
+            Mode::PrincipalParts if self.part_responses.iter().any(|r| !r.trim().is_empty()) => {
+                self.cur_response = self.part_responses.join(", ");
+            }
+            Mode::Table if self.grid.blanks().any(|c| !c.response.trim().is_empty()) => {
+                self.cur_response = self.grid.responses();
+            }
+            Mode::Hangman if !self.guesses.is_empty() => {
+                let german = &self.conjugations.get(self.cur_conjugation).unwrap().german;
+                self.cur_response = hangman::masked(german, &self.guesses);
+            }
+            // what was typed, or for a choice nothing as it wasn't made
+            _ => {}
+        }
+        self.check_answer();
+    }
+
+    /// Ends the lesson with the questions answered so far
+    fn finish_early(&mut self) {
+        self.confirm_leave = false;
+        self.cur_response.clear();
+        self.cur_response_incorrect = None;
+        self.editing_note = None;
+        self.exit = Some(false);
+        self.end_lesson();
+    }
+
+    /// The progress through the lesson: the question number, the questions
+    /// there are or the time left, and the score with --arcade
+    fn progress(&self) -> String {
+        let progress = match (self.grace_left(), self.time_left()) {
+            (Some(grace), _) => format!(
+                "Q{} | Time's up, {}s to answer",
+                self.cur_question + 1,
+                grace.as_secs()
+            ),
+            (None, Some(left)) => format!(
+                "Q{} | {}:{:02} left",
+                self.cur_question + 1,
+                left.as_secs() / 60,
+                left.as_secs() % 60
+            ),
+            (None, None) => format!("Q{}/{}", self.cur_question + 1, self.total_questions),
+        };
+        let progress = if self.warming_up {
+            format!("{} | Warm-up", progress)
+        } else {
+            progress
+        };
+        match &self.arcade {
+            Some(arcade) => format!(
+                "{} | {} pts ×{}",
+                progress,
+                arcade.score,
+                arcade.multiplier()
+            ),
+            None => progress,
+        }
+    }
+
+    /// Picks the conjugation to ask next, the warm-up ones first, and in
+    /// recognition mode the choices to identify it from: the right one and up
+    /// to three others with a different German form (so there is only one
+    /// right answer)
+    fn pick_question(&mut self) {
+        // in a mixed lesson any form can come up, to be asked in a way that fits it
+        if self.mix.is_some() {
+            self.mode = Mode::Translate;
+        }
+        self.warming_up = !self.warm_up.is_empty();
+        if self.warming_up {
+            self.cur_conjugation = self.warm_up.remove(0);
+        } else {
+            self.pick_conjugation();
+        }
+        if let Some(mix) = self.mix {
+            let cloze = ending::blank(&self.conjugations[self.cur_conjugation]).is_some();
+            self.mode = mix.pick(cloze, &mut self.rng);
+        }
+        self.choices.clear();
+        self.selected_choice = 0;
+        if self.mode == Mode::Recognize {
+            let answer = &self.conjugations[self.cur_conjugation];
+            let mut others: Vec<usize> = (0..self.conjugations.len()).collect();
+            others.shuffle(&mut self.rng);
+            // the distractors the verb file picks come first, in its order,
+            // and random forms make up the rest
+            let picked = |i: usize| {
+                let conj = &self.conjugations[i];
+                answer
+                    .distractors
+                    .iter()
+                    .position(|&d| d == (conj.tense, conj.person))
+            };
+            others.sort_by_key(|&i| picked(i).unwrap_or(usize::MAX));
+            for i in others {
+                if self.choices.len() == NUM_CHOICES - 1 {
+                    break;
+                }
+                let conj = &self.conjugations[i];
+                // in a lesson on several verbs, the choices are forms of the same verb
+                if conj.verb != answer.verb {
+                    continue;
+                }
+                let duplicate = self.choices.iter().any(|&c| {
+                    self.conjugations[c].tense == conj.tense
+                        && self.conjugations[c].person == conj.person
+                });
+                if conj.german != answer.german && !duplicate {
+                    self.choices.push(i);
+                }
+            }
+            let at = self.rng.gen_range(0..=self.choices.len());
+            self.choices.insert(at, self.cur_conjugation);
+        }
+        self.endings.clear();
+        if self.mode == Mode::Ending {
+            if let Some(blank) = ending::blank(&self.conjugations[self.cur_conjugation]) {
+                self.endings = ending::choices(&blank.ending, &mut self.rng);
+            }
+        }
+        self.words.clear();
+        if self.mode == Mode::Assemble {
+            let german = &self.conjugations[self.cur_conjugation].german;
+            self.words = assemble::shuffled(german, &mut self.rng);
+        }
+
+        // after the last question the score is shown instead
+        if self.exit.is_none() {
+            self.show_question();
+        }
+    }
+
+    /// Picks a random conjugation to ask, of the ones not suspended
+    fn pick_conjugation(&mut self) {
+        // an assignment asks every student the same questions, whatever they
+        // suspended
+        if let Some(attempt) = &self.attempt {
+            let n = self.cur_question as usize;
+            if let Some(question) = attempt.question(n) {
+                self.cur_conjugation = question;
+                if self.mode == Mode::Table {
+                    self.grid = attempt.assignment.grid(&self.conjugations, n, question);
+                }
+                return;
+            }
+        }
+        let mut candidates: Vec<usize> = (0..self.conjugations.len())
+            .filter(|&i| !self.srs.is_suspended(&self.conjugations[i]))
+            .collect();
+        // with everything suspended there is nothing else to ask
+        if candidates.is_empty() {
+            candidates = (0..self.conjugations.len()).collect();
+        }
+        if self.mode == Mode::PrincipalParts {
+            candidates = self.part_questions();
+        }
+        if let Some(questions) = self.askable_questions() {
+            candidates.retain(|i| questions.contains(i));
+            if candidates.is_empty() {
+                candidates = questions;
+            }
+        }
+        // a table is asked once, through the first of its forms
+        if self.mode == Mode::Table {
+            let conjugations = &self.conjugations;
+            let same_table = |i: usize, j: usize| {
+                conjugations[i].verb == conjugations[j].verb
+                    && conjugations[i].tense == conjugations[j].tense
+            };
+            candidates = candidates
+                .iter()
+                .enumerate()
+                .filter(|&(n, &i)| !candidates[..n].iter().any(|&j| same_table(i, j)))
+                .map(|(_, &i)| i)
+                .collect();
+        }
+        self.interleave(&mut candidates);
+        self.cur_conjugation = candidates[self.rng.gen_range(0..candidates.len())];
+        if self.mode == Mode::Table {
+            self.grid = Grid::new(&self.conjugations, self.cur_conjugation, &mut self.rng);
+        }
+    }
+
+    /// Narrows the candidates of a lesson on several verbs down to the verbs
+    /// asked the least so far, other than the one just asked, so the verbs
+    /// take turns instead of coming up at random
+    fn interleave(&self, candidates: &mut Vec<usize>) {
+        // by its file, as two packs can have a verb of the same name
+        let verb = |i: usize| self.conjugations[i].verb.path();
+        // the question before is of the last lesson until one is asked
+        let last = self
+            .conjugations
+            .get(self.cur_conjugation)
+            .filter(|_| !self.verb_turns.is_empty())
+            .map(|c| c.verb.path());
+        if candidates.iter().all(|&i| Some(verb(i)) == last) {
+            return;
+        }
+        candidates.retain(|&i| Some(verb(i)) != last);
+        let turns = |i: usize| self.verb_turns.get(verb(i)).copied().unwrap_or(0);
+        if let Some(fewest) = candidates.iter().map(|&i| turns(i)).min() {
+            candidates.retain(|&i| turns(i) == fewest);
+        }
+    }
+
+    /// Starts timing the question just asked, counts its verb's turn, says it
+    /// in dictation mode and logs it
+    fn show_question(&mut self) {
+        self.asked_at = Instant::now();
+        let conj = &self.conjugations[self.cur_conjugation];
+        *self
+            .verb_turns
+            .entry(conj.verb.path().to_path_buf())
+            .or_default() += 1;
+        #[cfg(feature = "audio")]
+        self.speak();
+        let event = events::Event::QuestionShown {
+            question: self.event_question(),
+        };
+        let json = events::to_json(&event).expect("Could not log the question");
+        self.commands.push(message::Command::LogEvent(json));
+    }
+
+    /// The current question, as the event log describes it
+    fn event_question(&self) -> events::Question<'_> {
+        let conj = self.conjugations.get(self.cur_conjugation).unwrap();
+        events::Question {
+            verb: conj.verb.name(),
+            tense: conj.tense,
+            person: conj.person,
+            mode: self.mode,
+            question: self.cur_question + 1,
+            questions: self.questions(),
+        }
+    }
+
+    /// Says the German form of the current question, in dictation mode
+    #[cfg(feature = "audio")]
+    fn speak(&mut self) {
+        if self.speaker.is_some() {
+            let conj = self.conjugations.get(self.cur_conjugation).unwrap();
+            let german = conj.german.clone();
+            self.commands.push(message::Command::Speak(german));
+        }
+    }
+
+    /// Answers a recognition question with the choice at `i`. Any conjugation
+    /// with the same German form counts as right.
+    fn choose(&mut self, i: usize) {
+        let Some(&choice) = self.choices.get(i) else {
+            return;
+        };
+        let chosen = &self.conjugations[choice];
+        let correct = chosen.german == self.conjugations[self.cur_conjugation].german;
+        self.cur_response = choice_label(chosen);
+        if correct {
+            self.total_correct += 1;
+            self.cur_response_incorrect = Some(false);
+        } else {
+            self.total_incorrect += 1;
+            self.cur_response_incorrect = Some(true);
+        }
+        self.record_answer(correct);
+    }
+
+    /// Gives up on the current question when stuck: reveals the answer, and
+    /// counts it as incorrect
+    fn skip(&mut self) {
+        self.typing.submit();
+        self.cur_response.clear();
+        self.skipped = true;
+        self.total_incorrect += 1;
+        self.total_skipped += 1;
+        self.cur_response_incorrect = Some(true);
+        self.record_answer(false);
+    }
+
+    /// Adds the answer to the current question to the history and event log,
+    /// and reschedules its review
+    fn record_answer(&mut self, correct: bool) {
+        let conj = self.conjugations.get(self.cur_conjugation).unwrap();
+        let took = self.asked_at.elapsed();
+        match self.mode {
+            // the principal parts are about the verb, not the form that stands in for it
+            Mode::PrincipalParts => {}
+            // each form asked by the table is an answer of its own
+            Mode::Table => {
+                for cell in self.grid.blanks() {
+                    let conj = &self.conjugations[cell.conjugation];
+                    let correct = cell.correct.unwrap_or(false);
+                    self.pending
+                        .push(history::Answer::new(conj, correct, took, self.confidence));
+                }
+            }
+            _ => {
+                let mut answer = history::Answer::new(conj, correct, took, self.confidence);
+                answer.assisted = self.autocompleting();
+                self.pending.push(answer);
+            }
+        }
+        if correct && self.autocompleting() {
+            self.total_assisted += 1;
+        }
+        if correct && self.config.auto_advance_ms > 0 {
+            let delay = Duration::from_millis(self.config.auto_advance_ms);
+            self.advance_at = Some(Instant::now() + delay);
+        }
+        if let Some(confidence) = self.confidence {
+            self.tagged.push((confidence, correct));
+        }
+        self.results.push(correct);
+        if correct && self.config.encouragement {
+            self.cheer = self
+                .encouragement
+                .line(self.total_correct as usize, self.streak());
+        }
+        if let Some(arcade) = &mut self.arcade {
+            arcade.answer(correct);
+        }
+
+        let expected = self.expected_answer();
+        if let Some(attempt) = &mut self.attempt {
+            let conj = &self.conjugations[self.cur_conjugation];
+            let response = match self.mode {
+                Mode::PrincipalParts => self.part_responses.join(", "),
+                _ => self.cur_response.clone(),
+            };
+            attempt.answer(conj, self.mode, &response, self.skipped, took);
+        }
+        let event = events::Event::AnswerSubmitted {
+            question: self.event_question(),
+            response: &self.cur_response,
+            expected: &expected,
+            correct,
+            skipped: self.skipped,
+            confidence: self.confidence,
+            latency_ms: took.as_millis(),
+        };
+        let json = events::to_json(&event).expect("Could not log the answer");
+        self.commands.push(message::Command::LogEvent(json.clone()));
+        if let Some(hook) = self.config.hooks.on_answer.clone() {
+            self.run_hook(hook, Ok(json));
+        }
+        if self.results.len().is_multiple_of(session::AUTOSAVE_EVERY) {
+            self.commands.push(message::Command::Autosave);
+        }
+    }
+
+    /// Saves where the lesson is, to pick it back up if the app crashes. The
+    /// lessons of a plan, marathon, assignment or arcade aren't saved, as
+    /// there's more to those than the lesson.
+    fn save_session(&self) {
+        if self.plan.is_some()
+            || self.marathon.is_some()
+            || self.attempt.is_some()
+            || self.arcade.is_some()
+        {
+            return;
+        }
+        let Some(lesson) = &self.lesson else {
+            return;
+        };
+        let session = Session {
+            lesson: lesson.clone(),
+            mode: self.lesson_mode(),
+            from: self.from.clone(),
+            questions: self.total_questions,
+            asked: self.cur_question.saturating_add(1),
+            correct: self.total_correct,
+            incorrect: self.total_incorrect,
+            skipped: self.total_skipped,
+            limit: self.time_limit.map(|t| t.as_secs()),
+            elapsed: self
+                .clock()
+                .saturating_duration_since(self.started_at)
+                .as_secs(),
+            results: self.results.clone(),
+            answers: self.pending.iter().map(SavedAnswer::from).collect(),
+        };
+        session::save(&self.data_dir, &session).expect("Could not save the lesson in progress");
+    }
+
+    /// Picks the lesson left by a crash back up after its last save
+    fn recover(&mut self, session: Session) {
+        let answers = session
+            .answers()
+            .expect("Could not read the answers of the lesson left");
+        self.home = None;
+        self.restart(true);
+        self.set_mode(session.mode);
+        self.from = session.from;
+        self.encouragement = Encouragement::load(&self.data_dir, &self.from)
+            .expect("Could not read the encouragement");
+        self.total_questions = session.questions;
+        self.time_limit = session.limit.map(Duration::from_secs);
+        if let Err(e) = self.start_lesson(session.lesson) {
+            self.lesson_error = Some(format!("Could not pick the lesson left back up: {}", e));
+            return;
+        }
+        let elapsed = Duration::from_secs(session.elapsed);
+        self.started_at = Instant::now()
+            .checked_sub(elapsed)
+            .unwrap_or(self.started_at);
+        self.cur_question = session.asked;
+        self.total_correct = session.correct;
+        self.total_incorrect = session.incorrect;
+        self.total_skipped = session.skipped;
+        self.results = session.results;
+        self.pending = answers;
+        // saved right after its last answer, it has nothing left to ask
+        if self.time_limit.is_none() && self.cur_question >= self.total_questions {
+            self.finish_early();
+            return;
+        }
+        self.commands.push(message::Command::Autosave);
+    }
+
+    /// Keeps the answers of the lesson left by a crash, as partial, without
+    /// picking it back up
+    fn discard_session(&mut self, session: Session) {
+        self.pending = session
+            .answers()
+            .expect("Could not read the answers of the lesson left");
+        self.save_answers(true);
+        self.commands.push(message::Command::ClearSession);
+    }
+
+    /// Works out what to practice next, and runs the `on_lesson_end` hook if
+    /// there is one
+    fn end_lesson(&mut self) {
+        info!(
+            correct = self.total_correct,
+            answered = self.answered(),
+            skipped = self.total_skipped,
+            "lesson ended"
+        );
+        self.save_answers(false);
+        self.commands.push(message::Command::ClearSession);
+        if !self.results.is_empty() {
+            self.shared = Some(share::text(&self.results, self.lesson_mode(), Local::now()));
+        }
+        if self.answered() > 0 {
+            let mut mode = self.lesson_mode().to_string();
+            if self.arcade.is_some() {
+                mode += ", arcade";
+            }
+            let score = scores::Score {
+                time: Local::now(),
+                mode,
+                verbs: self.lesson.as_ref().map(|l| l.name()).unwrap_or_default(),
+                score: match &self.arcade {
+                    Some(arcade) => arcade.score,
+                    None => self.total_correct,
+                },
+                correct: self.total_correct,
+                answered: self.answered(),
+            };
+            self.commands.push(message::Command::RecordScore(score));
+        }
+        let (correct, answered) = (self.total_correct, self.answered());
+        if let Some(marathon) = &mut self.marathon {
+            marathon.record(correct, answered);
+        }
+        if let (Some(arcade), Some(lesson)) = (&self.arcade, &self.lesson) {
+            self.commands.push(message::Command::RecordHighScore(
+                lesson.clone(),
+                arcade.score,
+            ));
+        }
+        self.commands.push(message::Command::ReviewLesson);
+        if let Some(attempt) = &self.attempt {
+            self.submitted = Some(match attempt.submit() {
+                Ok(path) => format!("Results saved to {}, to hand in", path.display()),
+                Err(e) => format!("Could not save the results: {}", e),
+            });
+        }
+
+        #[cfg(feature = "async")]
+        if self.config.sync.remote.is_some() && self.answered() > 0 {
+            self.commands.push(message::Command::Sync);
+        }
+
+        let Some(hook) = self.config.hooks.on_lesson_end.clone() else {
+            return;
+        };
+        let verbs = self.lesson.as_ref().map(|l| l.name()).unwrap_or_default();
+        let end = hooks::LessonEnd::new(
+            &verbs,
+            self.lesson_mode(),
+            self.answered(),
+            self.total_correct,
+            self.total_incorrect,
+            self.total_skipped,
+        );
+        self.run_hook(hook, serde_json::to_string(&end));
+    }
+
+    /// Records the answers of the lesson so far in the history and the review
+    /// schedule, as `partial` if the lesson was left before its end
+    fn save_answers(&mut self, partial: bool) {
+        if self.pending.is_empty() {
+            return;
+        }
+        let mut answers = std::mem::take(&mut self.pending);
+        for answer in &mut answers {
+            answer.partial = partial;
+        }
+        self.commands.push(message::Command::RecordAnswers(answers));
+    }
+
+    fn run_hook(&mut self, hook: String, json: serde_json::Result<String>) {
+        if let Ok(json) = json {
+            self.commands.push(message::Command::RunHook(hook, json));
+        }
+    }
+}
+
+impl Widget for &App {
+    /// Renders the application state
+    ///
+    /// For new questions, it will show the current question and the total number of questions
+    /// and also the current question number, and the currently entered response.
+    ///
+    /// For questions that have been answered, if the answer was correct, then it will show
+    /// good job.
+    ///
+    /// If the answer was incorrect, then it will show the correct answer.
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        // we are ready to exit, just have to wait for the user to acknowledge
+        // the final score
+        if self.exit.is_some() {
+            self.render_score(area, buf);
+            return;
+        }
+
+        if self.zen && self.mode == Mode::Translate {
+            self.render_zen(area, buf);
+            return;
+        }
+
+        match self.cur_response_incorrect {
+            // the table shows how each form went
+            _ if self.mode == Mode::Table => self.render_grid(area, buf),
+            Some(true) => {
+                self.render_incorrect(area, buf);
+            }
+            Some(false) => {
+                self.render_correct(area, buf);
+            }
+            None => {
+                self.render_unanswered_question(area, buf);
+            }
+        }
+
+        if let Some(bar) = self.keymap_bar() {
+            let bar_area = Rect {
+                x: area.x + 1,
+                y: area.bottom().saturating_sub(2),
+                width: area.width.saturating_sub(2),
+                height: 1,
+            };
+            bar.centered().render(bar_area, buf);
+        }
+
+        // show why the watched verb file could not be reloaded, above the instructions
+        if let Some(error) = &self.reload_error {
+            let error_area = Rect {
+                x: area.x + 1,
+                y: area.bottom().saturating_sub(2),
+                width: area.width.saturating_sub(2),
+                height: 1,
+            };
+            Line::from(error.as_str().set_style(self.theme.incorrect))
+                .centered()
+                .render(error_area, buf);
+        }
+    }
+}
 
 impl App {
+    /// The focus mode: no header, stats or border, just the prompt and the
+    /// input in large text (plus the correct answer when it was wrong)
+    fn render_zen(&self, area: Rect, buf: &mut Buffer) {
+        let conj = self.conjugations.get(self.cur_conjugation).unwrap();
+        let (input, input_style, feedback) = match self.cur_response_incorrect {
+            None => (format!("{}_", self.cur_response), self.theme.input, None),
+            Some(false) => (
+                self.cur_response.clone(),
+                self.theme.correct,
+                Some(Line::from(
+                    format!("{}Correct", self.theme.correct_mark).set_style(self.theme.correct),
+                )),
+            ),
+            Some(true) => (
+                self.cur_response.clone(),
+                self.theme.incorrect,
+                Some(Line::from(vec![
+                    format!("{}Correct German: ", self.theme.incorrect_mark).into(),
+                    conj.german.as_str().set_style(self.theme.correct),
+                ])),
+            ),
+        };
+        let prompt = BigText::new(&conj.prompt, self.theme.prompt);
+        let input = BigText::new(&input, input_style);
+
+        let prompt_height = prompt.height(area.width);
+        let input_height = input.height(area.width);
+        let height = prompt_height + 2 + input_height + 2;
+        let mut y = area.y + area.height.saturating_sub(height) / 2;
+        let mut next_area = |height: u16| {
+            let rect = Rect {
+                y: y.min(area.bottom()),
+                height: height.min(area.bottom().saturating_sub(y)),
+                ..area
+            };
+            y += height;
+            rect
+        };
+
+        prompt.render(next_area(prompt_height + 2), buf);
+        input.render(next_area(input_height + 1), buf);
+        if let Some(feedback) = feedback {
+            feedback.centered().render(next_area(1), buf);
+        }
+    }
+
     fn render_unanswered_question(&self, area: Rect, buf: &mut Buffer) {
+        if self.mode == Mode::Recognize {
+            self.render_choices(area, buf);
+            return;
+        }
+        if self.mode == Mode::PrincipalParts {
+            self.render_parts(area, buf);
+            return;
+        }
+        if self.mode == Mode::Hangman {
+            self.render_hangman(area, buf);
+            return;
+        }
+        if self.mode == Mode::Ending {
+            self.render_ending(area, buf);
+            return;
+        }
+        if self.mode == Mode::Assemble {
+            self.render_assemble(area, buf);
+            return;
+        }
+
+        let title = self.question_title(false);
+        let mut instructions = vec![
+            " Input Answer ".into(),
+            "<Chars> ".set_style(self.theme.key),
+            " Submit ".into(),
+            "<Enter> ".set_style(self.theme.key),
+            " Skip ".into(),
+            "<Ctrl+S> ".set_style(self.theme.key),
+            " Guess/Sure ".into(),
+            "<F1/F2> ".set_style(self.theme.key),
+        ];
+        if self.autocompleting() {
+            instructions.push(" Autocomplete ".into());
+            instructions.push("<Right> ".set_style(self.theme.key));
+        }
+        if self.mode == Mode::Translate {
+            instructions.push(" Focus ".into());
+            instructions.push("<z> ".set_style(self.theme.key));
+        } else {
+            instructions.push(" Replay ".into());
+            instructions.push("<Tab> ".set_style(self.theme.key));
+        }
+        let instructions = Title::from(Line::from(instructions));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+
+        // in dictation mode the prompt would give the answer away
+        let prompt = if self.mode == Mode::Translate {
+            self.question_line()
+        } else {
+            Line::from("Type the German you hear".set_style(self.theme.prompt))
+        };
+        let mut text = Text::from(vec![
+            Line::from(""),
+            Line::from(""),
+            prompt,
+            Line::from(vec![
+                "Your input: ".into(),
+                self.cur_response.to_string().set_style(self.theme.input),
+                self.autocomplete()
+                    .unwrap_or_default()
+                    .set_style(self.theme.input.dim()),
+            ]),
+        ]);
+        text.extend(self.confidence_line());
+        text.extend(self.note_line());
+
+        Paragraph::new(text)
+            .centered()
+            .block(block)
+            .render(area, buf);
+    }
+
+    /// Lists the choices of a recognition question under the German form
+    fn render_choices(&self, area: Rect, buf: &mut Buffer) {
         let conj = self.conjugations.get(self.cur_conjugation).unwrap();
-        let title = Title::from(
-            format!(
-                " {} | {} | {} | Q{}/{} ",
-                conj.verb,
-                conj.tense,
-                conj.person,
-                self.cur_question + 1,
-                self.total_questions
+        let title = self.question_title(false);
+        let instructions = Title::from(Line::from(vec![
+            " Prev ".into(),
+            "<Up> ".set_style(self.theme.key),
+            " Next ".into(),
+            "<Down> ".set_style(self.theme.key),
+            " Choose ".into(),
+            format!("<1-{}> ", self.choices.len()).set_style(self.theme.key),
+            " Submit ".into(),
+            "<Enter> ".set_style(self.theme.key),
+            " Skip ".into(),
+            "<Ctrl+S> ".set_style(self.theme.key),
+            " Guess/Sure ".into(),
+            "<F1/F2> ".set_style(self.theme.key),
+        ]));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
             )
-            .bold(),
-        );
+            .border_set(border::THICK);
+
+        let mut text = Text::from(vec![
+            Line::from(""),
+            Line::from(""),
+            Line::from(vec![
+                "German: ".into(),
+                conj.german.as_str().set_style(self.theme.prompt),
+            ]),
+            Line::from("Which tense and person is it?"),
+            Line::from(""),
+        ]);
+        for (n, &i) in self.choices.iter().enumerate() {
+            let label = format!("{}. {}", n + 1, choice_label(&self.conjugations[i]));
+            if n == self.selected_choice {
+                text.push_line(Line::from(label.set_style(self.theme.input.reversed())));
+            } else {
+                text.push_line(Line::from(label));
+            }
+        }
+        text.extend(self.confidence_line());
+        text.extend(self.note_line());
+
+        Paragraph::new(text)
+            .centered()
+            .block(block)
+            .render(area, buf);
+    }
+
+    /// Asks for each principal part in its own field under the infinitive
+    fn render_parts(&self, area: Rect, buf: &mut Buffer) {
+        let title = self.question_title(false);
+        let instructions = Title::from(Line::from(vec![
+            " Input Answer ".into(),
+            "<Chars> ".set_style(self.theme.key),
+            " Next Field ".into(),
+            "<Tab/Enter> ".set_style(self.theme.key),
+            " Prev Field ".into(),
+            "<Shift+Tab> ".set_style(self.theme.key),
+            " Skip ".into(),
+            "<Ctrl+S> ".set_style(self.theme.key),
+            " Guess/Sure ".into(),
+            "<F1/F2> ".set_style(self.theme.key),
+        ]));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+
+        let mut text = Text::from(vec![
+            Line::from(""),
+            Line::from(""),
+            self.question_line(),
+            Line::from(""),
+        ]);
+        for (i, (name, response)) in principal_parts::NAMES
+            .iter()
+            .zip(&self.part_responses)
+            .enumerate()
+        {
+            let line = if i == self.part_field {
+                Line::from(vec![
+                    format!("{}: ", name).bold(),
+                    format!("{}_", response).set_style(self.theme.input),
+                ])
+            } else {
+                Line::from(vec![
+                    format!("{}: ", name).into(),
+                    response.as_str().set_style(self.theme.input),
+                ])
+            };
+            text.push_line(line);
+        }
+        text.extend(self.confidence_line());
+        text.extend(self.note_line());
+
+        Paragraph::new(text)
+            .centered()
+            .block(block)
+            .render(area, buf);
+    }
+
+    /// Shows the German form with the letters not yet guessed hidden, under
+    /// the prompt, and the wrong guesses so far
+    fn render_hangman(&self, area: Rect, buf: &mut Buffer) {
+        let conj = self.conjugations.get(self.cur_conjugation).unwrap();
+        let title = self.question_title(false);
+        let instructions = Title::from(Line::from(vec![
+            " Guess ".into(),
+            "<Letters> ".set_style(self.theme.key),
+            " Skip ".into(),
+            "<Ctrl+S> ".set_style(self.theme.key),
+            " Guess/Sure ".into(),
+            "<F1/F2> ".set_style(self.theme.key),
+        ]));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+
+        // spaced out, so the underscores can be counted
+        let masked: Vec<String> = hangman::masked(&conj.german, &self.guesses)
+            .chars()
+            .map(String::from)
+            .collect();
+        let misses: Vec<String> = hangman::misses(&conj.german, &self.guesses)
+            .iter()
+            .map(char::to_string)
+            .collect();
+        let mut text = Text::from(vec![
+            Line::from(""),
+            Line::from(""),
+            self.question_line(),
+            Line::from(""),
+            Line::from(masked.join(" ").set_style(self.theme.input)),
+            Line::from(""),
+            Line::from(vec![
+                "Wrong guesses: ".into(),
+                misses.join(", ").set_style(self.theme.incorrect),
+                format!(" ({} left)", self.guesses_left()).into(),
+            ]),
+        ]);
+        text.extend(self.confidence_line());
+        text.extend(self.note_line());
+
+        Paragraph::new(text)
+            .centered()
+            .block(block)
+            .render(area, buf);
+    }
+
+    /// Shows the German form without its ending, with the ending typed so
+    /// far in its place, above the endings to pick from
+    fn render_ending(&self, area: Rect, buf: &mut Buffer) {
+        let conj = self.conjugations.get(self.cur_conjugation).unwrap();
+        let title = self.question_title(false);
+        let instructions = Title::from(Line::from(vec![
+            " Type ".into(),
+            "<Chars> ".set_style(self.theme.key),
+            " Pick ".into(),
+            format!("<1-{}> ", self.endings.len()).set_style(self.theme.key),
+            " Submit ".into(),
+            "<Enter> ".set_style(self.theme.key),
+            " Skip ".into(),
+            "<Ctrl+S> ".set_style(self.theme.key),
+            " Guess/Sure ".into(),
+            "<F1/F2> ".set_style(self.theme.key),
+        ]));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+
+        let blank = ending::blank(conj).expect("The question has no ending");
+        let typed = match self.cur_response.as_str() {
+            "" => "_".to_string(),
+            typed => typed.to_string(),
+        };
+        let mut choices = Line::default();
+        for (n, ending) in self.endings.iter().enumerate() {
+            choices.push_span(format!(" {} ", n + 1).set_style(self.theme.key));
+            choices.push_span(format!("-{}  ", ending));
+        }
+        let mut text = Text::from(vec![
+            Line::from(""),
+            Line::from(""),
+            self.question_line(),
+            Line::from(""),
+            Line::from(vec![
+                blank.before.set_style(self.theme.prompt),
+                typed.set_style(self.theme.input),
+                blank.after.set_style(self.theme.prompt),
+            ]),
+            Line::from(""),
+            choices,
+        ]);
+        text.extend(self.confidence_line());
+        text.extend(self.note_line());
+
+        Paragraph::new(text)
+            .centered()
+            .block(block)
+            .render(area, buf);
+    }
+
+    /// Shows the words put in order so far, above the shuffled words to
+    /// pick from, the ones already put dimmed
+    fn render_assemble(&self, area: Rect, buf: &mut Buffer) {
+        let title = self.question_title(false);
         let instructions = Title::from(Line::from(vec![
-            " Input Answer ".into(),
-            "<Chars> ".blue().bold(),
+            " Put ".into(),
+            format!("<1-{}> ", self.words.len()).set_style(self.theme.key),
+            " Undo ".into(),
+            "<Backspace> ".set_style(self.theme.key),
             " Submit ".into(),
-            "<Enter> ".blue().bold(),
+            "<Enter> ".set_style(self.theme.key),
+            " Skip ".into(),
+            "<Ctrl+S> ".set_style(self.theme.key),
+            " Guess/Sure ".into(),
+            "<F1/F2> ".set_style(self.theme.key),
         ]));
         let block = Block::bordered()
             .title(title.alignment(Alignment::Center))
@@ -343,15 +4064,33 @@ impl App {
             )
             .border_set(border::THICK);
 
-        let text = Text::from(vec![
+        let assembled = match self.cur_response.as_str() {
+            "" => "_".to_string(),
+            assembled => format!("{} _", assembled),
+        };
+        let mut words = Line::default();
+        for (n, word) in self.words.iter().enumerate() {
+            words.push_span(format!(" {} ", n + 1).set_style(self.theme.key));
+            if self.placed.contains(&n) {
+                words.push_span(format!("{}  ", word).dim());
+            } else {
+                words.push_span(format!("{}  ", word));
+            }
+        }
+        let mut text = Text::from(vec![
+            Line::from(""),
             Line::from(""),
+            self.question_line(),
             Line::from(""),
-            Line::from(vec!["English: ".into(), conj.english.to_string().blue()]),
             Line::from(vec![
-                "Your input: ".into(),
-                self.cur_response.to_string().yellow(),
+                "German: ".into(),
+                assembled.set_style(self.theme.input),
             ]),
+            Line::from(""),
+            words,
         ]);
+        text.extend(self.confidence_line());
+        text.extend(self.note_line());
 
         Paragraph::new(text)
             .centered()
@@ -359,23 +4098,88 @@ impl App {
             .render(area, buf);
     }
 
-    fn render_correct(&self, area: Rect, buf: &mut Buffer) {
-        let conj = self.conjugations.get(self.cur_conjugation).unwrap();
-        let title = Title::from(
-            format!(
-                " {} | {} | {} | Q{}/{} ",
-                conj.verb,
-                conj.tense,
-                conj.person,
-                self.cur_question + 1,
-                self.total_questions
+    /// Lists the table of the verb in the tense, one person a line, with a
+    /// field for each blank form. Once answered, each form is marked right or
+    /// wrong, with the right one next to a wrong one.
+    fn render_grid(&self, area: Rect, buf: &mut Buffer) {
+        let title = self.question_title(false);
+        let instructions = if self.cur_response_incorrect.is_some() {
+            self.feedback_instructions(area.width)
+        } else {
+            Title::from(Line::from(vec![
+                " Input Answer ".into(),
+                "<Chars> ".set_style(self.theme.key),
+                " Next Field ".into(),
+                "<Tab/Enter> ".set_style(self.theme.key),
+                " Prev Field ".into(),
+                "<Shift+Tab> ".set_style(self.theme.key),
+                " Skip ".into(),
+                "<Ctrl+S> ".set_style(self.theme.key),
+                " Guess/Sure ".into(),
+                "<F1/F2> ".set_style(self.theme.key),
+            ]))
+        };
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
             )
-            .bold(),
-        );
-        let instructions = Title::from(Line::from(vec![
-            " Continue ".into(),
-            "<Enter> ".blue().bold(),
-        ]));
+            .border_set(border::THICK);
+
+        let mut text = Text::from(vec![
+            Line::from(""),
+            Line::from(""),
+            self.question_line(),
+            Line::from(""),
+        ]);
+        let answered = self.cur_response_incorrect.is_some();
+        for (i, cell) in self.grid.cells.iter().enumerate() {
+            let conj = &self.conjugations[cell.conjugation];
+            let label = format!("{:<16}", conj.person.to_string());
+            let mut spans = vec![if i == self.grid.field && !answered {
+                label.bold()
+            } else {
+                label.into()
+            }];
+            let form = match cell.correct {
+                _ if cell.given => conj.german.clone().into(),
+                None if answered => {
+                    format!("{}Skipped", self.theme.incorrect_mark).set_style(self.theme.incorrect)
+                }
+                None if i == self.grid.field => {
+                    format!("{}_", cell.response).set_style(self.theme.input)
+                }
+                None => cell.response.clone().set_style(self.theme.input),
+                Some(true) => format!("{}{}", self.theme.correct_mark, cell.response)
+                    .set_style(self.theme.correct),
+                Some(false) => format!("{}{}", self.theme.incorrect_mark, cell.response)
+                    .set_style(self.theme.incorrect),
+            };
+            let width = form.width();
+            spans.push(form);
+            if answered && cell.correct != Some(true) && !cell.given {
+                spans.push(" → ".into());
+                spans.push(conj.german.clone().set_style(self.theme.correct));
+            } else {
+                // keeps the labels lined up, as the lines are centered
+                spans.push(" ".repeat(24usize.saturating_sub(width)).into());
+            }
+            text.push_line(Line::from(spans));
+        }
+        text.extend(self.confidence_line());
+        text.extend(self.note_line());
+
+        Paragraph::new(text)
+            .centered()
+            .block(block)
+            .render(area, buf);
+    }
+
+    fn render_correct(&self, area: Rect, buf: &mut Buffer) {
+        let title = self.question_title(true);
+        let instructions = self.feedback_instructions(area.width);
         let block = Block::bordered()
             .title(title.alignment(Alignment::Center))
             .title(
@@ -385,15 +4189,22 @@ impl App {
             )
             .border_set(border::THICK);
 
-        let text = Text::from(vec![
+        let mut text = Text::from(vec![
             Line::from(""),
             Line::from(""),
-            Line::from(vec!["English: ".into(), conj.english.to_string().blue()]),
+            self.question_line(),
             Line::from(vec![
                 "Your input: ".into(),
-                self.cur_response.to_string().green(),
+                format!("{}{}", self.theme.correct_mark, self.cur_response)
+                    .set_style(self.theme.correct),
             ]),
         ]);
+        if let Some(cheer) = &self.cheer {
+            text.push_line(cheer.as_str().set_style(self.theme.notice));
+        }
+        text.extend(self.ipa_line());
+        text.extend(self.example_lines());
+        text.extend(self.note_line());
 
         Paragraph::new(text)
             .centered()
@@ -402,22 +4213,8 @@ impl App {
     }
 
     fn render_incorrect(&self, area: Rect, buf: &mut Buffer) {
-        let conj = self.conjugations.get(self.cur_conjugation).unwrap();
-        let title = Title::from(
-            format!(
-                " {} | {} | {} | Q{}/{} ",
-                conj.verb,
-                conj.tense,
-                conj.person,
-                self.cur_question + 1,
-                self.total_questions
-            )
-            .bold(),
-        );
-        let instructions = Title::from(Line::from(vec![
-            " Continue ".into(),
-            "<Enter> ".blue().bold(),
-        ]));
+        let title = self.question_title(true);
+        let instructions = self.feedback_instructions(area.width);
         let block = Block::bordered()
             .title(title.alignment(Alignment::Center))
             .title(
@@ -427,19 +4224,33 @@ impl App {
             )
             .border_set(border::THICK);
 
-        let text = Text::from(vec![
+        let mut text = Text::from(vec![
             Line::from(""),
             Line::from(""),
-            Line::from(vec!["English: ".into(), conj.english.to_string().blue()]),
-            Line::from(vec![
-                "Your input: ".into(),
-                self.cur_response.to_string().red(),
-            ]),
-            Line::from(vec![
-                "Correct German: ".into(),
-                conj.german.to_string().green(),
-            ]),
+            self.question_line(),
+            if self.skipped {
+                Line::from(
+                    format!("{}Skipped", self.theme.incorrect_mark).set_style(self.theme.incorrect),
+                )
+            } else {
+                Line::from(vec![
+                    "Your input: ".into(),
+                    format!("{}{}", self.theme.incorrect_mark, self.cur_response)
+                        .set_style(self.theme.incorrect),
+                ])
+            },
+            self.correct_answer_line(),
         ]);
+        text.extend(self.ipa_line());
+        text.extend(self.breakdown_line());
+        if self.layout_mixup {
+            text.push_line(Line::from(
+                "Looks like a keyboard layout mix-up (QWERTZ/QWERTY)".set_style(self.theme.notice),
+            ));
+        }
+        text.extend(self.leech_line());
+        text.extend(self.example_lines());
+        text.extend(self.note_line());
 
         Paragraph::new(text)
             .centered()
@@ -447,14 +4258,438 @@ impl App {
             .render(area, buf);
     }
 
+    /// The header of the question: the verb, tense and person (as far as the
+    /// header hints allow, unless `reveal`ed once answered) and the progress
+    fn question_title(&self, reveal: bool) -> Title<'_> {
+        let conj = self.conjugations.get(self.cur_conjugation).unwrap();
+        let hints = if reveal {
+            HeaderHints::All
+        } else {
+            self.header_hints()
+        };
+        let mut parts = Vec::new();
+        if hints != HeaderHints::Hidden {
+            parts.push(conj.verb.to_string());
+        }
+        if matches!(hints, HeaderHints::All | HeaderHints::NoPerson) {
+            match self.auxiliary_hint() {
+                Some(auxiliary) => parts.push(format!("{} with {}", conj.tense, auxiliary)),
+                None => parts.push(conj.tense.to_string()),
+            }
+        }
+        if hints == HeaderHints::All {
+            parts.push(conj.person.to_string());
+        }
+        parts.push(self.progress());
+        if let Some(score) = self.running_score() {
+            parts.push(score);
+        }
+        let title = format!(" {} ", parts.join(" | ")).bold();
+        match self.timer_style() {
+            Some(style) => Title::from(title.patch_style(style)),
+            None => Title::from(title),
+        }
+    }
+
+    /// With the score header on, the right and wrong answers of the lesson so
+    /// far and how many in a row were right
+    fn running_score(&self) -> Option<String> {
+        if !self.config.score_header {
+            return None;
+        }
+        Some(format!(
+            "{} right, {} wrong | Streak {}",
+            self.total_correct,
+            self.total_incorrect,
+            self.streak()
+        ))
+    }
+
+    /// How many answers in a row were right, up to the last one
+    fn streak(&self) -> usize {
+        self.results.iter().rev().take_while(|&&r| r).count()
+    }
+
+    /// The header's colour as the time runs out: the notice colour near the
+    /// end of the lesson, and the incorrect one in the grace period
+    fn timer_style(&self) -> Option<Style> {
+        if self.grace_until.is_some() {
+            return Some(self.theme.incorrect);
+        }
+        let left = self.time_left()?;
+        (left <= TIME_WARNING).then_some(self.theme.notice)
+    }
+
+    /// The header hints from --hints or --difficulty, or else the settings.
+    /// Recognition questions never give away the tense or person.
+    fn header_hints(&self) -> HeaderHints {
+        let hints = self
+            .hints
+            .or(self.difficulty.map(Difficulty::hints))
+            .unwrap_or(self.config.hints);
+        match (self.mode, hints) {
+            (Mode::Recognize, HeaderHints::All | HeaderHints::NoPerson) => HeaderHints::VerbOnly,
+            // the verb is the answer, and the tense and person don't apply
+            (Mode::PrincipalParts, _) => HeaderHints::Hidden,
+            // the table is of every person, so only the question says what it is of
+            (Mode::Table, _) => HeaderHints::Hidden,
+            _ => hints,
+        }
+    }
+
+    /// The auxiliary of the current form if it is in one of the perfect
+    /// tenses and the auxiliary hint is on
+    fn auxiliary_hint(&self) -> Option<&'static str> {
+        let shown = self
+            .difficulty
+            .map_or(self.config.auxiliary_hint, Difficulty::auxiliary_hint);
+        if !shown {
+            return None;
+        }
+        let conj = self.conjugations.get(self.cur_conjugation).unwrap();
+        Auxiliary::of_perfect(conj.tense, &conj.german).map(Auxiliary::infinitive)
+    }
+
+    /// What was asked: the prompt to translate, or the German form to recognize
+    fn question_line(&self) -> Line<'_> {
+        let conj = self.conjugations.get(self.cur_conjugation).unwrap();
+        match self.mode {
+            Mode::Recognize => Line::from(vec![
+                "German: ".into(),
+                conj.german.as_str().set_style(self.theme.prompt),
+            ]),
+            Mode::PrincipalParts => Line::from(vec![
+                format!("{}: ", self.from).into(),
+                self.principal_parts()
+                    .map(|p| p.prompt)
+                    .unwrap_or_default()
+                    .set_style(self.theme.prompt),
+            ]),
+            Mode::Table => Line::from(vec![
+                "Fill in the table of ".into(),
+                format!("{} in the {}", conj.verb, conj.tense).set_style(self.theme.prompt),
+            ]),
+            _ => {
+                let mut line = Line::from(vec![
+                    format!("{}: ", self.from).into(),
+                    conj.prompt.as_str().set_style(self.theme.prompt),
+                ]);
+                if let Some(hint) = conj.prompt_hint() {
+                    line.push_span(format!(" {}", hint).dim());
+                }
+                line
+            }
+        }
+    }
+
+    /// The right answer, shown when the question was answered incorrectly
+    fn correct_answer_line(&self) -> Line<'_> {
+        let label = match self.mode {
+            Mode::Recognize => "Correct answer: ",
+            Mode::PrincipalParts => "Correct parts: ",
+            _ => "Correct German: ",
+        };
+        let conj = self.conjugations.get(self.cur_conjugation).unwrap();
+        let stressed = match self.mode {
+            Mode::Recognize | Mode::PrincipalParts | Mode::Table => None,
+            _ => conj.stressed_parts().filter(|_| self.config.show_stress),
+        };
+        let Some(parts) = stressed else {
+            let answer = self.expected_answer();
+            return Line::from(vec![
+                label.into(),
+                format!("{}{}", self.theme.correct_mark, answer).set_style(self.theme.correct),
+            ]);
+        };
+        let mut spans = vec![
+            label.into(),
+            self.theme.correct_mark.set_style(self.theme.correct),
+        ];
+        for (part, stressed) in parts {
+            let span = part.set_style(self.theme.correct);
+            spans.push(if stressed {
+                span.bold().underlined()
+            } else {
+                span
+            });
+        }
+        Line::from(spans)
+    }
+
+    /// The instructions shown once the question has been answered, on a
+    /// screen `width` wide
+    fn feedback_instructions(&self, width: u16) -> Title<'_> {
+        if self.editing_note.is_some() {
+            return Title::from(Line::from(vec![
+                " Save Note ".into(),
+                "<Enter> ".set_style(self.theme.key),
+                " Cancel ".into(),
+                "<Esc> ".set_style(self.theme.key),
+            ]));
+        }
+
+        let mut instructions = vec![
+            " Continue ".into(),
+            "<Enter> ".set_style(self.theme.key),
+            " Edit Verb ".into(),
+            "<e> ".set_style(self.theme.key),
+            " Note ".into(),
+            "<n> ".set_style(self.theme.key),
+            " Suspend ".into(),
+            "<x> ".set_style(self.theme.key),
+        ];
+        if self.mode == Mode::Translate {
+            instructions.push(" Focus ".into());
+            instructions.push("<z> ".set_style(self.theme.key));
+        }
+        #[cfg(feature = "clipboard")]
+        match &self.copied {
+            Some(copied) => instructions.push(format!(" {} ", copied).set_style(self.theme.notice)),
+            None => {
+                instructions.push(" Copy Answer/All ".into());
+                instructions.push("<c/C> ".set_style(self.theme.key));
+            }
+        }
+        if self.cur_response_incorrect == Some(true) {
+            match &self.flagged {
+                Some(flagged) => {
+                    instructions.push(format!(" {} ", flagged).set_style(self.theme.notice))
+                }
+                None => {
+                    instructions.push(" Flag Data Error ".into());
+                    instructions.push("<f> ".set_style(self.theme.key));
+                }
+            }
+        }
+        key_bar(instructions, width)
+    }
+
+    /// How the answer is pronounced, if the verb file says and it is shown
+    fn ipa_line(&self) -> Option<Line<'_>> {
+        let conj = self.conjugations.get(self.cur_conjugation).unwrap();
+        let ipa = conj.ipa.as_ref().filter(|_| self.config.show_ipa)?;
+        Some(Line::from(vec![
+            "Pronunciation: ".into(),
+            format!("/{}/", ipa.trim_matches('/')).set_style(self.theme.prompt),
+        ]))
+    }
+
+    /// The example sentence of the current conjugation, if the verb file has one
+    fn example_lines(&self) -> Vec<Line<'_>> {
+        let conj = self.conjugations.get(self.cur_conjugation).unwrap();
+        let mut lines = Vec::new();
+        if let Some(example) = &conj.example_de {
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![
+                "Example: ".into(),
+                example.as_str().italic(),
+            ]));
+        }
+        if let Some(example) = &conj.example_en {
+            lines.push(Line::from(example.as_str().dim()));
+        }
+        lines
+    }
+
+    /// How sure the user said they are, once tagged
+    fn confidence_line(&self) -> Option<Line<'_>> {
+        let confidence = match self.confidence? {
+            Confidence::Guess => "Guess",
+            Confidence::Sure => "Sure",
+        };
+        Some(Line::from(vec![
+            "Confidence: ".into(),
+            confidence.set_style(self.theme.notice),
+        ]))
+    }
+
+    /// The correct German in its pieces, to show how the form is built
+    fn breakdown_line(&self) -> Option<Line<'_>> {
+        if matches!(self.mode, Mode::Recognize | Mode::PrincipalParts) {
+            return None;
+        }
+        let conj = self.conjugations.get(self.cur_conjugation).unwrap();
+        let mut spans = vec![Span::from("Structure: ")];
+        for (i, word) in morphology::analyze(conj).into_iter().enumerate() {
+            if i > 0 {
+                spans.push(" ".into());
+            }
+            for (j, (text, morpheme)) in word.into_iter().enumerate() {
+                if j > 0 {
+                    spans.push("|".into());
+                }
+                let style = match morpheme {
+                    Morpheme::Auxiliary => self.theme.notice.bold(),
+                    Morpheme::Prefix | Morpheme::Ending => self.theme.key,
+                    Morpheme::Stem => self.theme.correct,
+                    Morpheme::Pronoun | Morpheme::Other => Style::new(),
+                };
+                spans.push(text.set_style(style));
+            }
+        }
+        Some(Line::from(spans))
+    }
+
+    /// A warning when the current conjugation keeps being missed
+    fn leech_line(&self) -> Option<Line<'_>> {
+        let conj = self.conjugations.get(self.cur_conjugation).unwrap();
+        // the misses of this lesson are only recorded once it ends
+        let key = srs::key(conj);
+        let missed = self
+            .pending
+            .iter()
+            .filter(|a| !a.correct && a.key() == key)
+            .count() as u32;
+        if self.srs.lapses(conj) + missed < self.config.leech_lapses {
+            return None;
+        }
+        Some(Line::from(
+            "A problem form: add a note <n> to remember it, or suspend it <x>"
+                .set_style(self.theme.notice),
+        ))
+    }
+
+    /// The note on the current conjugation, or the note being written
+    fn note_line(&self) -> Option<Line<'_>> {
+        if let Some(note) = &self.editing_note {
+            return Some(Line::from(vec![
+                "Note: ".into(),
+                format!("{}_", note).set_style(self.theme.input),
+            ]));
+        }
+        let conj = self.conjugations.get(self.cur_conjugation).unwrap();
+        let note = self.notes.get(conj)?;
+        Some(Line::from(vec!["Note: ".into(), note.to_string().italic()]))
+    }
+
+    /// The name of the screen shown over the verbs or the lesson, if any
+    fn overlay(&self) -> Option<&'static str> {
+        [
+            (self.kiosk.is_some(), "Kiosk"),
+            (self.setup.is_some(), "Setup"),
+            (self.editor.is_some(), "Edit"),
+            (self.settings.is_some(), "Settings"),
+            (self.suspended.is_some(), "Suspended"),
+            (self.leeches.is_some(), "Problem Forms"),
+            (self.builder.is_some(), "Build a Lesson"),
+            (self.templates.is_some(), "Saved Lessons"),
+            (self.compare.is_some(), "Compare"),
+            (self.search.is_some(), "Search"),
+            (self.gender_quiz.is_some(), "Nouns"),
+            (self.preposition_quiz.is_some(), "Prepositions"),
+            (self.valency_quiz.is_some(), "Cases"),
+            (self.placement.is_some(), "Placement"),
+            (self.collocation_quiz.is_some(), "Collocations"),
+            (self.politeness_quiz.is_some(), "Politeness"),
+            (self.spelling_quiz.is_some(), "Numbers"),
+            (self.vocab_quiz.is_some(), "Vocab"),
+            (self.match_game.is_some(), "Match"),
+            (self.stats.is_some(), "Stats"),
+            (self.leaderboard.is_some(), "Scores"),
+            (self.marathon.as_ref().is_some_and(|m| m.done), "Marathon"),
+        ]
+        .into_iter()
+        .find(|(shown, _)| *shown)
+        .map(|(_, name)| name)
+    }
+
+    /// The way back from the screen shown, e.g. Home › Verbs › essen › Edit
+    fn breadcrumb(&self) -> Vec<String> {
+        let mut crumbs = vec!["Home".to_string()];
+        if self.home.is_none() {
+            crumbs.push("Verbs".to_string());
+            if let Some(lesson) = &self.lesson {
+                crumbs.push(lesson.name());
+            }
+        }
+        crumbs.extend(self.overlay().map(String::from));
+        crumbs
+    }
+
+    /// Whether the question is shown in the focus mode
+    fn zen_shown(&self) -> bool {
+        self.zen
+            && self.mode == Mode::Translate
+            && self.lesson.is_some()
+            && self.exit.is_none()
+            && self.home.is_none()
+            && self.overlay().is_none()
+    }
+
+    /// Asks the question over whatever is shown, with the keys to answer it
+    fn render_confirmation(&self, question: &str, keys: Vec<Span>, area: Rect, buf: &mut Buffer) {
+        let width = 48.min(area.width);
+        let height = 5.min(area.height);
+        let popup = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+        let instructions = Title::from(Line::from(keys));
+        let block = Block::bordered()
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+        Clear.render(popup, buf);
+        Paragraph::new(vec![Line::from(""), Line::from(question.bold())])
+            .centered()
+            .block(block)
+            .render(popup, buf);
+    }
+
     fn render_verbs_table(&mut self, frame: &mut Frame) {
-        let title = Title::from(" Select a Verb ".bold());
+        if let Some(first) = self.comparing {
+            let title = format!(" Compare {} with ", self.verbs[first].name());
+            let instructions = Title::from(Line::from(vec![
+                " Prev ".into(),
+                "<Up> ".set_style(self.theme.key),
+                " Next ".into(),
+                "<Down> ".set_style(self.theme.key),
+                " Compare ".into(),
+                "<Enter> ".set_style(self.theme.key),
+                " Cancel ".into(),
+                "<Esc> ".set_style(self.theme.key),
+            ]));
+            self.render_verbs(frame, Title::from(title.bold()), instructions);
+            return;
+        }
+        let title = match (&self.lesson_error, self.srs.due_count()) {
+            (Some(error), _) => Title::from(format!(" {} ", error).set_style(self.theme.incorrect)),
+            (None, 0) => Title::from(" Select a Verb ".bold()),
+            (None, due) => Title::from(format!(" Select a Verb ({} due for review) ", due).bold()),
+        };
         let instructions = Title::from(Line::from(vec![
             " Prev ".into(),
-            "<Up> ".blue().bold(),
+            "<Up> ".set_style(self.theme.key),
             " Next ".into(),
-            "<Down> ".blue().bold(),
+            "<Down> ".set_style(self.theme.key),
+            " Edit ".into(),
+            "<e> ".set_style(self.theme.key),
+            " Compare ".into(),
+            "<c> ".set_style(self.theme.key),
+            " Star ".into(),
+            "<*> ".set_style(self.theme.key),
+            " Stats ".into(),
+            "<i> ".set_style(self.theme.key),
+            " Settings ".into(),
+            "<s> ".set_style(self.theme.key),
+            " Suspended ".into(),
+            "<u> ".set_style(self.theme.key),
+            " Problem Forms ".into(),
+            "<p> ".set_style(self.theme.key),
+            " Saved Lessons ".into(),
+            "<l> ".set_style(self.theme.key),
+            " Home ".into(),
+            "<Esc> ".set_style(self.theme.key),
         ]));
+        self.render_verbs(frame, title, instructions);
+    }
+
+    fn render_verbs(&mut self, frame: &mut Frame, title: Title, instructions: Title) {
         let block = Block::bordered()
             .title(title.alignment(Alignment::Center))
             .title(
@@ -464,15 +4699,38 @@ impl App {
             )
             .border_set(border::THICK);
 
-        let rows: Vec<Row> = self
-            .verbs
+        let verb_rows = self.verb_rows();
+        let rows: Vec<Row> = verb_rows
             .iter()
-            .map(|s| Row::new(vec![Cell::from(s.as_str())]))
+            .enumerate()
+            .map(|(row, &(section, i))| {
+                let verb = &self.verbs[i];
+                // the section is named on its first row
+                let first = row == 0 || verb_rows[row - 1].0 != section;
+                let star = if self.favorites.contains(verb.name()) {
+                    "★ "
+                } else {
+                    "  "
+                };
+                Row::new(vec![
+                    Cell::from(if first { section } else { "" }.dim()),
+                    Cell::from(format!("{}{}", star, verb.name())),
+                    Cell::from(verb.pack().unwrap_or_default()),
+                ])
+            })
             .collect();
-        let widths = [Constraint::Length(20)];
+        let widths = [
+            Constraint::Length(12),
+            Constraint::Length(22),
+            Constraint::Length(20),
+        ];
 
         let table = Table::new(rows, widths)
-            .header(Row::new(vec![Cell::from("Verbs")]))
+            .header(Row::new(vec![
+                Cell::from(""),
+                Cell::from("Verbs"),
+                Cell::from("Pack"),
+            ]))
             .highlight_style(Style::new().reversed())
             .highlight_symbol(">>")
             .block(block);
@@ -482,14 +4740,44 @@ impl App {
 
     fn render_score(&self, area: Rect, buf: &mut Buffer) {
         let title = Title::from(" Lesson Completed ".bold());
-        let instructions = Title::from(Line::from(vec![
-            " Exit ".into(),
-            "<ESC> ".blue().bold(),
+        let mut instructions = vec![
+            " Back ".into(),
+            "<Esc> ".set_style(self.theme.key),
             " Attempt Again ".into(),
-            "<Enter> ".blue().bold(),
-            " Select New Verb ".into(),
-            "<Anything> ".blue().bold(),
-        ]));
+            "<Enter> ".set_style(self.theme.key),
+        ];
+        if !self.recommended.is_empty() {
+            instructions.push(" Practice These ".into());
+            instructions.push("<p> ".set_style(self.theme.key));
+        }
+        #[cfg(feature = "clipboard")]
+        if self.shared.is_some() {
+            match &self.copied {
+                Some(copied) => {
+                    instructions.push(format!(" {} ", copied).set_style(self.theme.notice))
+                }
+                None => {
+                    instructions.push(" Copy Result ".into());
+                    instructions.push("<c> ".set_style(self.theme.key));
+                }
+            }
+        }
+        instructions.push(" New Lesson ".into());
+        instructions.push("<Anything> ".set_style(self.theme.key));
+        // the marathon only goes on to its next verb, or stops
+        if let Some(marathon) = &self.marathon {
+            instructions = vec![
+                " Stop ".into(),
+                "<Esc> ".set_style(self.theme.key),
+                if marathon.last() {
+                    " Report ".into()
+                } else {
+                    " Next Verb ".into()
+                },
+                "<Enter> ".set_style(self.theme.key),
+            ];
+        }
+        let instructions = key_bar(instructions, area.width);
         let block = Block::bordered()
             .title(title.alignment(Alignment::Center))
             .title(
@@ -499,70 +4787,358 @@ impl App {
             )
             .border_set(border::THICK);
 
-        let text = Text::from(vec![
+        let mut text = Text::from(vec![
             Line::from(""),
             Line::from(""),
             Line::from(format!(
                 "You got {} correct out of {}!",
-                self.total_correct, self.total_questions
+                self.total_correct,
+                self.answered()
             )),
         ]);
+        if self.total_skipped > 0 {
+            text.push_line(Line::from(format!(
+                "Skipped {} (counted as incorrect)",
+                self.total_skipped
+            )));
+        }
+        text.extend(self.calibration_lines());
+        text.extend(self.typing_line());
+        text.extend(self.autocomplete_line());
+        text.extend(self.arcade_line());
+        text.extend(self.curriculum_line());
+        text.extend(self.plan_line());
+        text.extend(self.marathon_line());
+        text.extend(self.submitted_line());
+        #[cfg(feature = "async")]
+        text.extend(self.sync_line());
+        if !self.recommended.is_empty() {
+            text.push_line(Line::from(""));
+            text.push_line(Line::from("Practice next:".bold()));
+            for (verb, tense, person) in &self.recommended {
+                text.push_line(Line::from(format!("{} | {} | {}", verb, tense, person)));
+            }
+        }
+        text.push_line(Line::from(""));
+        text.push_line(Line::from(format!("Seed: {}", self.seed).dim()));
 
         Paragraph::new(text)
             .centered()
             .block(block)
             .render(area, buf);
     }
+
+    /// The score of the lesson with --arcade, and the high score to beat
+    fn arcade_line(&self) -> Option<Line<'_>> {
+        let arcade = self.arcade.as_ref()?;
+        let mut line = Line::from(format!("Score: {}", arcade.score).bold());
+        match arcade.best {
+            _ if arcade.new_best => {
+                line.push_span(" (a new high score)".set_style(self.theme.notice))
+            }
+            Some(best) => line.push_span(format!(" (high score: {})", best)),
+            None => {}
+        }
+        Some(line)
+    }
+
+    /// The stage of the curriculum the lesson just unlocked
+    fn curriculum_line(&self) -> Option<Line<'_>> {
+        if !self.unlocked {
+            return None;
+        }
+        Some(Line::from(
+            format!(
+                "Unlocked the {} tenses of the curriculum",
+                self.curriculum.name()
+            )
+            .set_style(self.theme.notice),
+        ))
+    }
+
+    /// How pushing the progress of the lesson to the sync remote went
+    #[cfg(feature = "async")]
+    fn sync_line(&self) -> Option<Line<'_>> {
+        let text = match self.synced.as_ref()? {
+            effects::Progress::Started => "Syncing...".to_string(),
+            effects::Progress::Done => "Synced".to_string(),
+            // git's errors can run over several lines
+            effects::Progress::Failed(e) => format!(
+                "Could not sync now, will on exit: {}",
+                e.lines().next().unwrap_or_default()
+            ),
+        };
+        Some(Line::from(text.set_style(self.theme.notice)))
+    }
+
+    /// Where the results of the assignment were saved
+    fn submitted_line(&self) -> Option<Line<'_>> {
+        let submitted = self.submitted.as_ref()?;
+        Some(Line::from(submitted.clone().set_style(self.theme.notice)))
+    }
+
+    /// How the step of the plan went, and what Enter does next
+    fn plan_line(&self) -> Option<Line<'_>> {
+        let plan = self.plan.as_ref()?;
+        let text = if !plan.passed(self.total_correct, self.answered()) {
+            format!(
+                "{} of {} needs {:.0}% right, so is taken again",
+                plan.position(),
+                plan.title,
+                100.0 * plan.current().pass
+            )
+        } else if plan.step + 1 == plan.steps.len() {
+            format!("Completed {}", plan.title)
+        } else {
+            format!("Passed {} of {}", plan.position(), plan.title)
+        };
+        Some(Line::from(text.set_style(self.theme.notice)))
+    }
+
+    /// How far through the marathon is, and how it went so far
+    fn marathon_line(&self) -> Option<Line<'_>> {
+        let marathon = self.marathon.as_ref()?;
+        let (correct, answered) = marathon.totals();
+        Some(Line::from(
+            format!(
+                "{} of the marathon, {} of {} right so far",
+                marathon.position(),
+                correct,
+                answered
+            )
+            .set_style(self.theme.notice),
+        ))
+    }
+
+    /// The tenses a new lesson starts with
+    fn lesson_tenses(&self) -> Vec<Tense> {
+        lesson_tenses(&self.config, self.difficulty, &self.curriculum)
+    }
+
+    /// The shortcuts of the keymap, listed below a question being typed, e.g.
+    /// "ä Alt+a  ö Alt+o"
+    fn keymap_bar(&self) -> Option<Line<'_>> {
+        let typed = !matches!(self.mode, Mode::Recognize | Mode::Assemble);
+        if !self.config.keymap_bar || !typed || self.cur_response_incorrect.is_some() {
+            return None;
+        }
+        let mut line = Line::default();
+        for (letter, modifiers, key) in self.config.keymap.shortcuts() {
+            line.push_span(format!(" {} ", letter).set_style(self.theme.prompt));
+            line.push_span(format!("{} ", keymap::label(modifiers, key)).set_style(self.theme.key));
+        }
+        Some(line).filter(|l| !l.spans.is_empty())
+    }
+
+    /// How fast the answers were typed, and how much of them was corrected
+    fn typing_line(&self) -> Option<Line<'_>> {
+        let (wpm, corrected) = self.typing.stats()?;
+        Some(Line::from(format!(
+            "Typing: {:.0} words a minute, {:.0}% of the keys corrected",
+            wpm,
+            100.0 * corrected
+        )))
+    }
+
+    /// How many of the right answers were autocompleted, which don't count
+    /// towards the review schedule and curriculum
+    fn autocomplete_line(&self) -> Option<Line<'_>> {
+        if self.total_assisted == 0 {
+            return None;
+        }
+        Some(Line::from(format!(
+            "{} of the right answers were autocompleted, so aren't counted as known",
+            self.total_assisted
+        )))
+    }
+
+    /// How often the answers tagged as sure, and as guesses, were right
+    fn calibration_lines(&self) -> Vec<Line<'_>> {
+        let mut lines = Vec::new();
+        for (confidence, label) in [(Confidence::Sure, "Sure"), (Confidence::Guess, "Guesses")] {
+            let answers: Vec<bool> = self
+                .tagged
+                .iter()
+                .filter(|(c, _)| *c == confidence)
+                .map(|&(_, correct)| correct)
+                .collect();
+            if answers.is_empty() {
+                continue;
+            }
+            let right = answers.iter().filter(|&&correct| correct).count();
+            lines.push(Line::from(format!(
+                "{}: {} of {} right ({:.0}%)",
+                label,
+                right,
+                answers.len(),
+                100.0 * right as f64 / answers.len() as f64
+            )));
+        }
+        lines
+    }
+}
+
+/// The tenses a new lesson starts with: the ones the curriculum has unlocked
+/// if it is on, else the difficulty's
+fn lesson_tenses(
+    config: &Config,
+    difficulty: Option<Difficulty>,
+    curriculum: &Curriculum,
+) -> Vec<Tense> {
+    if config.curriculum {
+        curriculum.tenses()
+    } else {
+        difficulty.unwrap_or(config.difficulty).tenses()
+    }
+}
+
+/// The keys at the bottom of a screen `width` wide. While they don't fit
+/// between its corners, the labels are cut down to their first word, and
+/// then left out, e.g. " Edit Verb <e> " to " Edit <e> " to "<e> ".
+fn key_bar(mut spans: Vec<Span<'_>>, width: u16) -> Title<'_> {
+    let fits = |spans: &[Span]| spans.iter().map(Span::width).sum::<usize>() + 2 <= width.into();
+    // the labels are unstyled, unlike the keys and the notices
+    let is_label = |span: &Span| span.style == Style::default();
+    if !fits(&spans) {
+        for span in spans.iter_mut().filter(|s| is_label(s)) {
+            if let Some(first) = span.content.split_whitespace().next() {
+                span.content = format!(" {} ", first).into();
+            }
+        }
+    }
+    if !fits(&spans) {
+        spans.retain(|s| !is_label(s));
+    }
+    Title::from(Line::from(spans))
+}
+
+/// How a conjugation is shown as a choice in recognition mode
+fn choice_label(conj: &Conjugation) -> String {
+    format!("{} | {}", conj.tense, conj.person)
 }
 
 fn main() -> Result<(), io::Error> {
     // 1. Santize the arguments
-    let args = Args::parse();
-    let n = args.number;
-    if !(1..100).contains(&n) {
-        panic!("n is either too small or too large");
-    }
-    let verb = args.verb.map(|v| Verb::from_str(&v));
-
-    // 2. Get the possible verbs
-    // get all the file names in the ./verbs directory
-    let verb_files = fs::read_dir("./verbs")
-        .expect("Could not find/read the verbs directory")
-        .map(|res| res.map(|e| e.path()))
-        .collect::<Result<Vec<_>, io::Error>>()
-        .expect("Could not collect the files");
-    // strip the file extension and ./verbs prefix if exists
-    let verbs = verb_files
-        .iter()
-        .map(|p| {
-            p.file_name()
-                .expect("Could not get the file name")
-                .to_str()
-                .expect("Could not convert the file name to a string")
-                .replace(".csv", "")
-        })
-        .collect::<Vec<String>>();
-
-    // 3. Init ratatui
-    let mut terminal = ratatui::init();
-
-    // 4. Loop for each question
-    let mut app = App {
-        cur_question: 0,
-        total_questions: n,
-        total_correct: 0,
-        total_incorrect: 0,
-        table_state: TableState::default().with_selected(0),
-        verbs,
-        verb,
-        cur_conjugation: usize::MAX, // so that things definitely panic if not updated
-        conjugations: vec![],
-        cur_response: String::new(),
-        cur_response_incorrect: None,
-        exit: None,
-    };
-    let _ = app.run(&mut terminal).expect("App failed to run");
-    ratatui::restore();
+    let mut args = Args::parse();
+    let dirs = Dirs::find();
+    let data_dir = dirs.data.as_path();
+    if let Err(e) = logging::init(args.log_file.clone(), args.verbose, data_dir) {
+        eprintln!("Error: could not start logging: {}", e);
+        process::exit(1);
+    }
+    #[cfg(feature = "serve")]
+    let mut serve_args = None;
+    match args.command.take() {
+        // the lessons run as usual, with the server alongside
+        #[cfg(feature = "serve")]
+        Some(Command::Serve(args)) => serve_args = Some(args),
+        Some(command) => {
+            let result = match command {
+                Command::Packs(command) => packs::run(command, data_dir),
+                Command::Flags => flags::list(data_dir),
+                Command::Report(args) => report::run(args, data_dir),
+                Command::Due { threshold } => match srs::due(data_dir) {
+                    // more due than the threshold fails, e.g. to nag from a prompt
+                    Ok(due) if due > threshold => process::exit(1),
+                    result => result.map(|_| ()),
+                },
+                Command::Status(args) => status::run(args, data_dir),
+                Command::Print(args) => print::run(args, data_dir),
+                Command::LintData => lint::run(data_dir),
+                Command::Backup(args) => backup::backup(args, &dirs),
+                Command::Restore(args) => backup::restore(args, &dirs),
+                Command::ImportProgress(args) => import::run(args, data_dir),
+                Command::Assign(args) => classroom::assign(args, &dirs),
+                Command::Grade(args) => classroom::grade(args, &dirs),
+                #[cfg(feature = "remind")]
+                Command::Remind(args) => remind::run(args, data_dir),
+                #[cfg(feature = "serve")]
+                Command::Serve(_) => unreachable!(),
+            };
+            if let Err(e) = result {
+                eprintln!("Error: {}", e);
+                process::exit(1);
+            }
+            return Ok(());
+        }
+        None => {}
+    }
+
+    // 2. Bring in the progress made on other machines
+    let config = logging::timed("settings", || Config::load(&dirs.config))
+        .expect("Could not read the settings");
+    if let Some(remote) = &config.sync.remote {
+        eprintln!("Syncing with {}...", remote);
+        if let Err(e) = config.sync.pull(data_dir) {
+            eprintln!("Could not sync: {}", e);
+        }
+    }
+
+    // 3. Find the verbs and start what was asked for
+    let mut app = App::new(&args, &dirs, config).unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    });
+    #[cfg(feature = "serve")]
+    {
+        app.remote = serve_args.map(|args| {
+            let remote = serve::Remote::start(&args).unwrap_or_else(|e| {
+                eprintln!("Error: could not start the server: {}", e);
+                process::exit(1);
+            });
+            eprintln!("Answer from a browser at {}", remote.url);
+            remote
+        });
+        // the address would be gone under the TUI before it could be read
+        if app.remote.is_some() && !args.screen_reader {
+            eprintln!("Press Enter to start.");
+            let _ = io::stdin().read_line(&mut String::new());
+        }
+    }
+    // a lesson left by a crash is offered back on the home screen, and
+    // otherwise just its answers are kept
+    match session::load(data_dir) {
+        Ok(Some(session)) if app.home.is_some() && !args.screen_reader => {
+            app.recovery = Some(session)
+        }
+        Ok(Some(session)) => app.discard_session(session),
+        Ok(None) => {}
+        // a damaged session only loses the answers since the last lesson
+        Err(e) => {
+            warn!(error = %e, "discarded the lesson left, as it could not be read");
+            app.commands.push(message::Command::ClearSession);
+        }
+    }
+
+    if args.screen_reader {
+        screen_reader::run(&mut app).expect("App failed to run");
+    } else {
+        // 4. Init ratatui
+        let mut terminal = ratatui::init();
+        app.light_background = theme::detect_light_background();
+        app.apply_theme();
+        let _ = app.run(&mut terminal).expect("App failed to run");
+        ratatui::restore();
+    }
+    // quitting in the middle of a lesson keeps what was answered
+    app.save_answers(true);
+    app.perform_pending();
+    session::clear(data_dir).expect("Could not clear the saved lesson");
+    // a push still running would get in the way of this one, and one
+    // waiting to run is made by this one
+    #[cfg(feature = "async")]
+    app.effects.finish();
+    if let Some(remote) = &app.config.sync.remote {
+        eprintln!("Syncing with {}...", remote);
+        if let Err(e) = app.config.sync.push(data_dir) {
+            eprintln!("Could not sync: {}", e);
+        }
+    }
+    if args.share {
+        if let Some(shared) = &app.shared {
+            println!("{}", shared);
+        }
+    }
 
     // 5. Exit
     Ok(())
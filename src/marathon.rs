@@ -0,0 +1,198 @@
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Alignment, Rect},
+    style::{Styled, Stylize},
+    symbols::border,
+    text::{Line, Text},
+    widgets::{
+        block::{Position, Title},
+        Block, Paragraph, Widget,
+    },
+};
+
+use crate::theme::Theme;
+
+/// The share of a verb's answers below which it is picked out in the report
+const WEAK: f64 = 0.5;
+
+/// How the lesson on a verb of the marathon went
+pub struct Score {
+    pub verb: String,
+    pub correct: u32,
+    pub answered: u32,
+}
+
+impl Score {
+    pub fn accuracy(&self) -> f64 {
+        match self.answered {
+            0 => 0.0,
+            answered => f64::from(self.correct) / f64::from(answered),
+        }
+    }
+}
+
+/// A short lesson on every verb in turn, back to back, e.g. to go over
+/// everything before an exam. Once done (or stopped), the report of all the
+/// lessons is shown.
+pub struct Marathon {
+    verbs: Vec<String>, // by name, in the order practiced
+    verb: usize,        // the verb being practiced
+    pub questions: u8,  // asked of each verb
+    scores: Vec<Score>, // of the verbs practiced so far
+    pub done: bool,     // if the report is shown
+    scrolled: usize,    // the first verb shown in the report
+}
+
+impl Marathon {
+    pub fn new(verbs: Vec<String>, questions: u8) -> Marathon {
+        Marathon {
+            verbs,
+            verb: 0,
+            questions,
+            scores: vec![],
+            done: false,
+            scrolled: 0,
+        }
+    }
+
+    pub fn current(&self) -> &str {
+        &self.verbs[self.verb]
+    }
+
+    /// Keeps the score of the lesson on the current verb
+    pub fn record(&mut self, correct: u32, answered: u32) {
+        self.scores.push(Score {
+            verb: self.current().to_string(),
+            correct,
+            answered,
+        });
+    }
+
+    /// Moves on to the next verb, returning false if that was the last
+    pub fn next(&mut self) -> bool {
+        if self.verb + 1 >= self.verbs.len() {
+            return false;
+        }
+        self.verb += 1;
+        true
+    }
+
+    /// Whether the verb being practiced is the last
+    pub fn last(&self) -> bool {
+        self.verb + 1 >= self.verbs.len()
+    }
+
+    /// Where the marathon is, e.g. "Verb 3 of 40"
+    pub fn position(&self) -> String {
+        format!("Verb {} of {}", self.verb + 1, self.verbs.len())
+    }
+
+    /// The right answers and the answers of all the verbs practiced so far
+    pub fn totals(&self) -> (u32, u32) {
+        self.scores.iter().fold((0, 0), |(correct, answered), s| {
+            (correct + s.correct, answered + s.answered)
+        })
+    }
+
+    /// The totals of the report, and if the marathon was stopped early
+    pub fn summary(&self) -> Vec<String> {
+        let (correct, answered) = self.totals();
+        let mut summary = vec![format!(
+            "You got {} correct out of {} over {} verbs ({:.0}%)",
+            correct,
+            answered,
+            self.scores.len(),
+            match answered {
+                0 => 0.0,
+                _ => 100.0 * f64::from(correct) / f64::from(answered),
+            }
+        )];
+        if self.scores.len() < self.verbs.len() {
+            summary.push(format!(
+                "Stopped before the last {} verbs",
+                self.verbs.len() - self.scores.len()
+            ));
+        }
+        summary
+    }
+
+    /// The scores of the verbs practiced, the weakest first
+    pub fn weakest_first(&self) -> Vec<&Score> {
+        let mut scores: Vec<&Score> = self.scores.iter().collect();
+        scores.sort_by(|a, b| a.accuracy().total_cmp(&b.accuracy()));
+        scores
+    }
+
+    /// Handles a key press on the report, returning true when it should be
+    /// closed
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> bool {
+        match key_event.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => return true,
+            KeyCode::Up | KeyCode::Char('k') => self.scrolled = self.scrolled.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.scrolled = (self.scrolled + 1).min(self.scores.len().saturating_sub(1))
+            }
+            _ => {}
+        }
+        false
+    }
+
+    pub fn render_report(&self, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        let title = match self.scores.len() < self.verbs.len() {
+            true => Title::from(" Marathon Stopped ".bold()),
+            false => Title::from(" Marathon Completed ".bold()),
+        };
+        let instructions = Title::from(Line::from(vec![
+            " Scroll ".into(),
+            "<Up/Down> ".set_style(theme.key),
+            " Back ".into(),
+            "<Esc> ".set_style(theme.key),
+        ]));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+
+        let mut text = Text::from(vec![Line::from(""), Line::from("")]);
+        for line in self.summary() {
+            text.push_line(Line::from(line));
+        }
+        text.push_line(Line::from(""));
+        if self.scores.is_empty() {
+            text.push_line(Line::from("No verbs were practiced".dim()));
+        } else {
+            text.push_line(Line::from("By verb, the weakest first".bold()));
+        }
+        let scores = self.weakest_first();
+        // padded to line the scores up, as the lines are centered
+        let width = scores
+            .iter()
+            .map(|s| s.verb.chars().count())
+            .max()
+            .unwrap_or(0);
+        for score in scores.into_iter().skip(self.scrolled) {
+            let line = format!(
+                "{:<4$}  {:>2} of {:<2}  {:>3.0}%",
+                score.verb,
+                score.correct,
+                score.answered,
+                100.0 * score.accuracy(),
+                width,
+            );
+            if score.accuracy() < WEAK {
+                text.push_line(Line::from(line.set_style(theme.incorrect)));
+            } else {
+                text.push_line(Line::from(line));
+            }
+        }
+        Paragraph::new(text)
+            .centered()
+            .block(block)
+            .render(area, buf);
+    }
+}
@@ -0,0 +1,271 @@
+use rand::{rngs::StdRng, seq::SliceRandom};
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Style, Styled, Stylize},
+    symbols::border,
+    text::Line,
+    widgets::{
+        block::{Position, Title},
+        Block, Paragraph, Widget, Wrap,
+    },
+};
+use std::time::{Duration, Instant};
+
+use crate::conjugation::Conjugation;
+use crate::theme::Theme;
+
+/// How many pairs a round deals
+const PAIRS: usize = 6;
+/// How many cards a row of the grid has
+const COLUMNS: usize = 4;
+
+/// A card of the grid: the prompt or the German of a conjugation of the pool
+struct Card {
+    conjugation: usize,
+    german: bool,
+}
+
+/// The memory game: the prompts and German forms of a few conjugations are
+/// dealt face down, and turned over two at a time to find the pairs, against
+/// the clock
+pub struct MatchGame {
+    pool: Vec<Conjugation>,
+    rng: StdRng,
+    cards: Vec<Card>,
+    matched: Vec<bool>,
+    turned: Vec<usize>, // the cards face up that aren't matched, at most two
+    selected: usize,
+    misses: u32,
+    started_at: Option<Instant>, // from the first card turned, so looking at the grid is free
+    finished_at: Option<Instant>, // once every pair is found
+}
+
+impl MatchGame {
+    /// Deals the first round, None if the pool hasn't enough different
+    /// prompts and German forms for it
+    pub fn new(pool: Vec<Conjugation>, rng: StdRng) -> Option<MatchGame> {
+        let mut game = MatchGame {
+            pool,
+            rng,
+            cards: vec![],
+            matched: vec![],
+            turned: vec![],
+            selected: 0,
+            misses: 0,
+            started_at: None,
+            finished_at: None,
+        };
+        game.deal();
+        (game.cards.len() == PAIRS * 2).then_some(game)
+    }
+
+    /// Picks the pairs of a new round and shuffles them face down. No two of
+    /// them share a prompt or a German form, so every card has one match.
+    fn deal(&mut self) {
+        let mut order: Vec<usize> = (0..self.pool.len()).collect();
+        order.shuffle(&mut self.rng);
+        let mut picked: Vec<usize> = Vec::new();
+        for i in order {
+            if picked.len() == PAIRS {
+                break;
+            }
+            let conj = &self.pool[i];
+            let clash = picked
+                .iter()
+                .any(|&p| self.pool[p].prompt == conj.prompt || self.pool[p].german == conj.german);
+            if !clash {
+                picked.push(i);
+            }
+        }
+        self.cards = picked
+            .iter()
+            .flat_map(|&conjugation| {
+                [false, true].map(|german| Card {
+                    conjugation,
+                    german,
+                })
+            })
+            .collect();
+        self.cards.shuffle(&mut self.rng);
+        self.matched = vec![false; self.cards.len()];
+        self.turned.clear();
+        self.selected = 0;
+        self.misses = 0;
+        self.started_at = None;
+        self.finished_at = None;
+    }
+
+    /// Handles a key press, returning true when the game should be closed
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> bool {
+        let last = self.cards.len() - 1;
+        match key_event.code {
+            KeyCode::Esc | KeyCode::Char('q') => return true,
+            KeyCode::Enter if self.finished_at.is_some() => self.deal(),
+            KeyCode::Left | KeyCode::Char('h') => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Right | KeyCode::Char('l') => self.selected = (self.selected + 1).min(last),
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected = self.selected.saturating_sub(COLUMNS)
+            }
+            KeyCode::Down | KeyCode::Char('j') if self.selected + COLUMNS <= last => {
+                self.selected += COLUMNS
+            }
+            KeyCode::Enter | KeyCode::Char(' ') => self.turn(),
+            _ => {}
+        }
+        false
+    }
+
+    /// Turns the selected card face up. Two cards that didn't match are
+    /// turned back down first, so they can be looked at until then.
+    fn turn(&mut self) {
+        if self.finished_at.is_some() || self.matched[self.selected] {
+            return;
+        }
+        if self.turned.len() == 2 {
+            self.turned.clear();
+        }
+        if self.turned.contains(&self.selected) {
+            return;
+        }
+        let now = Instant::now();
+        self.started_at.get_or_insert(now);
+        self.turned.push(self.selected);
+
+        let [a, b] = self.turned[..] else {
+            return;
+        };
+        let (a, b) = (&self.cards[a], &self.cards[b]);
+        if a.german == b.german {
+            // two prompts or two German forms are never a pair
+            self.misses += 1;
+        } else if a.conjugation == b.conjugation {
+            for &i in &self.turned {
+                self.matched[i] = true;
+            }
+            self.turned.clear();
+            if self.matched.iter().all(|&m| m) {
+                self.finished_at = Some(now);
+            }
+        } else {
+            self.misses += 1;
+        }
+    }
+
+    /// How long the round took (or has taken, as of the last card turned)
+    fn took(&self) -> Duration {
+        match (self.started_at, self.finished_at) {
+            (Some(start), Some(end)) => end - start,
+            (Some(start), None) => start.elapsed(),
+            _ => Duration::ZERO,
+        }
+    }
+
+    /// 100 points a pair, less 10 a miss and 1 a second
+    fn score(&self) -> u64 {
+        let pairs = self.matched.iter().filter(|&&m| m).count() as u64 / 2;
+        (pairs * 100).saturating_sub(u64::from(self.misses) * 10 + self.took().as_secs())
+    }
+
+    pub fn render(&self, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        let title = Title::from(" Match ".bold());
+        let mut instructions = vec![
+            " Move ".into(),
+            "<Arrows> ".set_style(theme.key),
+            " Turn ".into(),
+            "<Space> ".set_style(theme.key),
+        ];
+        if self.finished_at.is_some() {
+            instructions.push(" Deal Again ".into());
+            instructions.push("<Enter> ".set_style(theme.key));
+        }
+        instructions.push(" Back ".into());
+        instructions.push("<Esc> ".set_style(theme.key));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                Title::from(Line::from(instructions))
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let rows = self.cards.len().div_ceil(COLUMNS);
+        let [_, status_area, _, grid_area] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Fill(1),
+        ])
+        .areas(inner);
+
+        let took = self.took().as_secs();
+        let mut status = Line::from(format!(
+            "Pairs: {}/{} | Misses: {}",
+            self.matched.iter().filter(|&&m| m).count() / 2,
+            PAIRS,
+            self.misses
+        ));
+        if self.finished_at.is_some() {
+            status.push_span(
+                format!(
+                    " | All found in {}:{:02}, scoring {}",
+                    took / 60,
+                    took % 60,
+                    self.score()
+                )
+                .set_style(theme.notice),
+            );
+        }
+        Paragraph::new(status).centered().render(status_area, buf);
+
+        let row_areas = Layout::vertical(vec![Constraint::Length(5); rows]).split(grid_area);
+        for (row, row_area) in row_areas.iter().enumerate() {
+            let card_areas = Layout::horizontal([Constraint::Ratio(1, COLUMNS as u32); COLUMNS])
+                .split(*row_area);
+            for (column, &card_area) in card_areas.iter().enumerate() {
+                let i = row * COLUMNS + column;
+                if let Some(card) = self.cards.get(i) {
+                    self.render_card(i, card, theme, card_area, buf);
+                }
+            }
+        }
+    }
+
+    fn render_card(&self, i: usize, card: &Card, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        let conj = &self.pool[card.conjugation];
+        let mut block = Block::bordered();
+        if i == self.selected {
+            block = block.border_set(border::THICK).border_style(theme.key);
+        }
+        let face = if self.matched[i] {
+            text(conj, card).set_style(theme.correct)
+        } else if self.turned.contains(&i) {
+            let style = if card.german {
+                theme.input
+            } else {
+                theme.prompt
+            };
+            text(conj, card).set_style(style)
+        } else {
+            "?".set_style(Style::new().dim())
+        };
+        Paragraph::new(Line::from(vec![face]))
+            .centered()
+            .wrap(Wrap { trim: true })
+            .block(block)
+            .render(area, buf);
+    }
+}
+
+/// What the face of the card says
+fn text<'a>(conj: &'a Conjugation, card: &Card) -> &'a str {
+    if card.german {
+        &conj.german
+    } else {
+        &conj.prompt
+    }
+}
@@ -0,0 +1,84 @@
+use deutschland_tui::{history::Answer, lesson::Lesson};
+use ratatui::crossterm::event::KeyEvent;
+
+#[cfg(feature = "async")]
+use crate::effects::{Progress, Task};
+use crate::notes;
+use crate::placement::Level;
+use crate::scores::Score;
+#[cfg(feature = "serve")]
+use crate::serve::RemoteCommand;
+
+/// Something that happened, which the app updates its state with (see
+/// `App::update`)
+#[derive(Debug)]
+pub enum Message {
+    /// A key was pressed
+    Key(KeyEvent),
+    /// The terminal was resized
+    Resize,
+    /// The loop woke up: time went by, and the verb files may have changed
+    Tick,
+    /// The browser answered or moved on, with --serve
+    #[cfg(feature = "serve")]
+    Remote(RemoteCommand),
+    /// A task run in the background started or ended, with the async feature
+    #[cfg(feature = "async")]
+    Progress(Task, Progress),
+}
+
+/// Something an update asks to be done outside the app's state, e.g. saving
+/// or playing a sound, carried out once the update is over (see
+/// `App::perform`). An update can be tested by the commands it gives back,
+/// without any of them being carried out.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    /// Draws the screen again
+    Redraw,
+    /// Saves the settings
+    SaveConfig,
+    /// Saves where the lesson is, to pick it back up after a crash
+    Autosave,
+    /// Forgets the lesson saved, as it ended or was left
+    ClearSession,
+    /// Loads how the practice of the verb went, to show over the verbs
+    LoadVerbStats(String),
+    /// Adds the answers to the history, and reschedules their forms
+    RecordAnswers(Vec<Answer>),
+    /// Works out what to practice next from the history, once the lesson's
+    /// answers are in it, and unlocks the next stage of the curriculum
+    ReviewLesson,
+    /// Adds the score of the lesson to the scores
+    RecordScore(Score),
+    /// Keeps the arcade score as the lesson's high score if it beats it
+    RecordHighScore(Lesson, u32),
+    /// Appends the line to the event log
+    LogEvent(String),
+    /// Flags the conjugation at the index as wrong, with what was answered
+    Flag(usize, String),
+    /// Sets the note of the form, or removes it if empty
+    SaveNote(notes::Key, String),
+    /// Saves the review schedule
+    SaveSchedule,
+    /// Saves the starred verbs
+    SaveFavorites,
+    /// Saves the verb files parsed so far to the cache
+    SaveCache,
+    /// Saves the placement, and starts the curriculum from its level
+    SavePlacement {
+        level: Level,
+        right: usize,
+        asked: usize,
+    },
+    /// Says the German form aloud
+    #[cfg(feature = "audio")]
+    Speak(String),
+    /// Copies the text to the clipboard
+    #[cfg(feature = "clipboard")]
+    Copy(String),
+    /// Runs the hook's command, with the JSON on its stdin
+    RunHook(String, String),
+    /// Pushes the progress to the sync remote, in the background
+    #[cfg(feature = "async")]
+    Sync,
+}
@@ -0,0 +1,99 @@
+use clap::ValueEnum;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// What the questions of a lesson ask for
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Mode {
+    /// Translate the prompt into the conjugated German form
+    #[default]
+    Translate,
+    /// Identify the tense and person of a conjugated German form, from a
+    /// multiple-choice list
+    Recognize,
+    /// Type the conjugated German form that is spoken out loud
+    #[cfg(feature = "audio")]
+    Dictation,
+    /// Give the infinitive, Partizip II and Präteritum of a verb from its
+    /// infinitive in the source language
+    #[serde(rename = "principal-parts")]
+    PrincipalParts,
+    /// Fill in the missing forms of a verb's conjugation table in one tense
+    Table,
+    /// Guess the letters of the German form one at a time, with a limited
+    /// number of wrong guesses
+    Hangman,
+    /// Give only the ending of the German form, shown without it, by typing
+    /// it or picking it from a list, e.g. when typing on a phone over SSH
+    Ending,
+    /// Put the words of the German form in a perfect tense in order, picking
+    /// them from a shuffled list with the digit keys
+    Assemble,
+    /// Mix typed (translate), multiple-choice (recognize) and cloze (ending)
+    /// questions in one lesson, as often as the `[mix]` table of the config
+    /// says
+    Mixed,
+}
+
+impl fmt::Display for Mode {
+    /// The mode as --mode takes it, e.g. "principal-parts"
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.to_possible_value() {
+            Some(value) => write!(f, "{}", value.get_name()),
+            None => write!(f, "{:?}", self),
+        }
+    }
+}
+
+/// How many choices a recognition question offers, at most
+pub const NUM_CHOICES: usize = 4;
+
+/// How often each kind of question comes up in a mixed lesson, relative to
+/// the others, set in the `[mix]` table of the config
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Mix {
+    pub typed: u32,  // translating the prompt into the German form
+    pub choice: u32, // picking the tense and person of the German form
+    pub cloze: u32,  // giving the ending left out of the German form
+}
+
+impl Default for Mix {
+    fn default() -> Mix {
+        Mix {
+            typed: 2,
+            choice: 1,
+            cloze: 1,
+        }
+    }
+}
+
+/// The most of each kind of question in a mix, so the total can't overflow
+pub const MAX_WEIGHT: u32 = 1000;
+
+impl Mix {
+    /// How many of each there are in all, as the config is checked against
+    pub fn total(&self) -> u32 {
+        self.typed + self.choice + self.cloze
+    }
+
+    /// Picks how the next question is asked, at random in the mix's ratios.
+    /// A form without an ending to leave out is never a cloze.
+    pub fn pick(&self, cloze: bool, rng: &mut impl Rng) -> Mode {
+        let cloze = if cloze { self.cloze } else { 0 };
+        let total = self.typed + self.choice + cloze;
+        if total == 0 {
+            return Mode::Translate;
+        }
+        let n = rng.gen_range(0..total);
+        if n < self.typed {
+            Mode::Translate
+        } else if n < self.typed + self.choice {
+            Mode::Recognize
+        } else {
+            Mode::Ending
+        }
+    }
+}
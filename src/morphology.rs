@@ -0,0 +1,312 @@
+use crate::conjugation::Conjugation;
+use crate::person::Person;
+use crate::tense::Tense;
+
+/// Prefixes that split off the verb, e.g. the "auf" of "ich wache auf".
+/// Longer ones come first, so "hinaus" isn't taken for "hin".
+const SEPARABLE: [&str; 22] = [
+    "zusammen", "zurück", "heraus", "herein", "hinaus", "hinein", "nach", "fest", "fort", "auf",
+    "aus", "bei", "ein", "mit", "vor", "weg", "los", "her", "hin", "ab", "an", "zu",
+];
+
+/// Prefixes that stay on the verb and take the place of the "ge" of the
+/// participle, e.g. "verstanden". Only longer verbs have them, which keeps
+/// "gehen" from having a "ge" but not "gehören".
+const INSEPARABLE: [&str; 8] = ["miss", "emp", "ent", "ver", "zer", "be", "er", "ge"];
+
+/// The forms of haben, sein and werden that make the compound tenses
+const AUXILIARIES: [&str; 41] = [
+    "habe", "hast", "hat", "haben", "habt", "hatte", "hattest", "hatten", "hattet", "hätte",
+    "hättest", "hätten", "hättet", "bin", "bist", "ist", "sind", "seid", "war", "warst", "waren",
+    "wart", "sei", "seist", "seien", "seiet", "wäre", "wärst", "wären", "wärt", "werde", "wirst",
+    "wird", "werden", "werdet", "würde", "würdest", "würden", "würdet", "werdest", "sein",
+];
+
+/// What a piece of a verb form is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Morpheme {
+    Pronoun,
+    Auxiliary, // haben, sein or werden, making a compound tense
+    Prefix,    // separable, inseparable, or the "ge" of the participle
+    Stem,
+    Ending,
+    Other, // a word that isn't part of the verb, e.g. the "Ski" of "ich fahre Ski"
+}
+
+/// A word of the form, in its pieces
+pub type Word = Vec<(String, Morpheme)>;
+
+/// Breaks the German of the conjugation into prefixes, stems and endings,
+/// word by word, going by the usual rules of how verbs are built rather than
+/// by a dictionary
+pub fn analyze(conj: &Conjugation) -> Vec<Word> {
+    let infinitive = conj.verb.name();
+    let words: Vec<&str> = conj.german.split_whitespace().collect();
+    let mut analyzed = Vec::new();
+    let mut rest = &words[..];
+    if let Some((&first, others)) = words.split_first() {
+        if is_pronoun(first) {
+            analyzed.push(vec![(first.to_string(), Morpheme::Pronoun)]);
+            rest = others;
+        }
+    }
+
+    // with more than one verb word the first is the auxiliary, which is also
+    // how "ich werde gegessen haben" ends
+    let compound = rest.len() > 1 && AUXILIARIES.contains(&rest[0]);
+    let last = rest.len().saturating_sub(1);
+    for (i, &word) in rest.iter().enumerate() {
+        let morphemes = if compound && (i == 0 || (i == last && i > 1 && is_auxiliary(word))) {
+            vec![(word.to_string(), Morpheme::Auxiliary)]
+        } else if i == last && i > 0 && separable_prefix(infinitive) == Some(word) {
+            vec![(word.to_string(), Morpheme::Prefix)]
+        } else if word.chars().next().is_some_and(char::is_uppercase) {
+            vec![(word.to_string(), Morpheme::Other)]
+        } else if infinitive.ends_with(word) && word.ends_with('n') {
+            infinitive_pieces(word, infinitive)
+        } else if compound {
+            participle_pieces(word, infinitive)
+        } else if i == 0 {
+            finite_pieces(word, infinitive, conj.tense, conj.person)
+        } else {
+            vec![(word.to_string(), Morpheme::Other)]
+        };
+        analyzed.push(morphemes);
+    }
+    analyzed
+}
+
+/// The pieces as text, e.g. "ich bin auf|ge|wach|t"
+pub fn plain(words: &[Word]) -> String {
+    words
+        .iter()
+        .map(|word| {
+            word.iter()
+                .map(|(text, _)| text.as_str())
+                .collect::<Vec<_>>()
+                .join("|")
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn is_pronoun(word: &str) -> bool {
+    word.contains('/')
+        || ["ich", "du", "er", "sie", "es", "wir", "ihr", "man"].contains(&&*word.to_lowercase())
+}
+
+/// Whether the word ends a future perfect, e.g. the "haben" of "ich werde
+/// gegessen haben"
+fn is_auxiliary(word: &str) -> bool {
+    word == "haben" || word == "sein"
+}
+
+/// The separable prefix the infinitive starts with, if any
+pub fn separable_prefix(infinitive: &str) -> Option<&'static str> {
+    SEPARABLE
+        .into_iter()
+        .find(|p| infinitive.len() > p.len() + 2 && infinitive.starts_with(p))
+}
+
+/// The inseparable prefix the infinitive (without its separable prefix)
+/// starts with, if any, leaving at least a three letter stem
+pub fn inseparable_prefix(infinitive: &str) -> Option<&'static str> {
+    INSEPARABLE
+        .into_iter()
+        .find(|p| infinitive.len() >= p.len() + 5 && infinitive.starts_with(p))
+}
+
+/// Splits the prefixes of the infinitive off the front of the word, and the
+/// "ge" if it is a `participle`
+fn split_prefixes<'a>(
+    word: &'a str,
+    infinitive: &str,
+    participle: bool,
+    pieces: &mut Word,
+) -> &'a str {
+    let mut word = word;
+    let mut infinitive = infinitive;
+    if let Some(prefix) = separable_prefix(infinitive) {
+        if let Some(rest) = word.strip_prefix(prefix) {
+            pieces.push((prefix.to_string(), Morpheme::Prefix));
+            word = rest;
+            infinitive = &infinitive[prefix.len()..];
+        }
+    }
+    // the "ge" of the participle goes between the two kinds of prefix
+    if let Some(rest) = word
+        .strip_prefix("ge")
+        .filter(|r| participle && r.len() > 2)
+    {
+        if inseparable_prefix(infinitive).is_none() {
+            pieces.push(("ge".to_string(), Morpheme::Prefix));
+            return rest;
+        }
+    }
+    if let Some(prefix) = inseparable_prefix(infinitive) {
+        if let Some(rest) = word.strip_prefix(prefix) {
+            pieces.push((prefix.to_string(), Morpheme::Prefix));
+            word = rest;
+        }
+    }
+    word
+}
+
+/// Splits the ending off the stem, the first of `endings` that leaves at
+/// least two letters
+fn split_ending(word: &str, endings: &[&str], pieces: &mut Word) {
+    let ending = endings.iter().find(|e| {
+        word.strip_suffix(*e)
+            .is_some_and(|s| s.chars().count() >= 2)
+    });
+    match ending {
+        Some(ending) if !ending.is_empty() => {
+            let stem = &word[..word.len() - ending.len()];
+            pieces.push((stem.to_string(), Morpheme::Stem));
+            pieces.push((ending.to_string(), Morpheme::Ending));
+        }
+        _ => pieces.push((word.to_string(), Morpheme::Stem)),
+    }
+}
+
+/// e.g. auf|wach|en
+fn infinitive_pieces(word: &str, infinitive: &str) -> Word {
+    let mut pieces = Vec::new();
+    // the infinitive can be the end of the verb's name, as "fahren" is of "skifahren"
+    let rest = split_prefixes(
+        word,
+        &infinitive[infinitive.len() - word.len()..],
+        false,
+        &mut pieces,
+    );
+    split_ending(rest, &["en", "n"], &mut pieces);
+    pieces
+}
+
+/// e.g. auf|ge|wach|t, or ver|stand|en
+fn participle_pieces(word: &str, infinitive: &str) -> Word {
+    let mut pieces = Vec::new();
+    let rest = split_prefixes(word, infinitive, true, &mut pieces);
+    // "et" only after a d or t, as in "gearbeitet"
+    let ending = match rest.strip_suffix("et") {
+        Some(stem) if stem.ends_with('d') || stem.ends_with('t') => "et",
+        _ if rest.ends_with("en") => "en",
+        _ => "t",
+    };
+    split_ending(rest, &[ending], &mut pieces);
+    pieces
+}
+
+/// e.g. wach|e, or wach|te
+fn finite_pieces(word: &str, infinitive: &str, tense: Tense, person: Person) -> Word {
+    let mut pieces = Vec::new();
+    let rest = split_prefixes(word, infinitive, false, &mut pieces);
+    // a stem ending in s, ß, x or z only takes a t for du, as in "du isst"
+    let stem = infinitive.trim_end_matches('n').trim_end_matches('e');
+    let hissing = stem.ends_with(['s', 'ß', 'x', 'z']);
+    let endings: &[&str] = match (tense, person) {
+        (Tense::Present, Person::I) => &["e"],
+        (Tense::Present, Person::You) if hissing => &["t"],
+        (Tense::Present, Person::You) => &["est", "st"],
+        (Tense::Present, Person::HeSheIt | Person::YouPl) => &["et", "t"],
+        (Tense::Past, Person::I | Person::HeSheIt) => &["te"],
+        (Tense::Past, Person::You) => &["test", "est", "st", "t"],
+        (Tense::Past, Person::YouPl) => &["tet", "et", "t"],
+        (Tense::SubjectiveI, Person::I | Person::HeSheIt) => &["e"],
+        (Tense::SubjectiveI, Person::You) => &["est"],
+        (Tense::SubjectiveI, Person::YouPl) => &["et"],
+        (Tense::SubjectiveII, Person::I | Person::HeSheIt) => &["te", "e"],
+        (Tense::SubjectiveII, Person::You) => &["test", "est", "st"],
+        (Tense::SubjectiveII, Person::YouPl) => &["tet", "et", "t"],
+        (Tense::Past | Tense::SubjectiveII, Person::We | Person::They) => &["ten", "en", "n"],
+        (_, Person::We | Person::They) => &["en", "n"],
+        // the compound tenses start with an auxiliary, so aren't split here
+        _ => &[],
+    };
+    split_ending(rest, endings, &mut pieces);
+    pieces
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::conjugation::parse_conjugations_from;
+    use crate::verb::Verb;
+
+    /// The form of the verb in its pieces, as `plain` gives them
+    fn pieces(verb: &str, tense: &str, person: &str, german: &str) -> String {
+        let verb = Verb::from_path(PathBuf::from(format!("{}.csv", verb)), None).unwrap();
+        let file = format!(
+            "Tense,Person,English,German\n{},{},-,{}\n",
+            tense, person, german
+        );
+        let conjugations = parse_conjugations_from(&verb, "English", file.into_bytes()).unwrap();
+        plain(&analyze(&conjugations[0]))
+    }
+
+    #[test]
+    fn separable_prefixes_are_split_off_wherever_they_are() {
+        assert_eq!(
+            pieces("aufwachen", "Present", "I", "ich wache auf"),
+            "ich wach|e auf"
+        );
+        assert_eq!(
+            pieces("aufwachen", "PerfectPresent", "I", "ich bin aufgewacht"),
+            "ich bin auf|ge|wach|t"
+        );
+    }
+
+    #[test]
+    fn inseparable_prefixes_take_the_place_of_the_ge() {
+        assert_eq!(
+            pieces("verstehen", "PerfectPresent", "I", "ich habe verstanden"),
+            "ich habe ver|stand|en"
+        );
+        // "ge" is the start of the stem of gehen, not a prefix
+        assert_eq!(
+            pieces("gehen", "PerfectPresent", "I", "ich bin gegangen"),
+            "ich bin ge|gang|en"
+        );
+    }
+
+    #[test]
+    fn the_future_perfect_ends_with_its_auxiliary() {
+        assert_eq!(
+            pieces("essen", "PerfectFuture", "I", "ich werde gegessen haben"),
+            "ich werde ge|gess|en haben"
+        );
+        assert_eq!(
+            pieces("arbeiten", "PerfectPresent", "I", "ich habe gearbeitet"),
+            "ich habe ge|arbeit|et"
+        );
+    }
+
+    #[test]
+    fn a_hissing_stem_takes_just_a_t_for_du() {
+        assert_eq!(
+            pieces("essen", "Present", "you (singular)", "du isst"),
+            "du iss|t"
+        );
+    }
+
+    #[test]
+    fn short_stems_and_other_words_are_left_whole() {
+        // the ending would leave less than two letters of stem
+        assert_eq!(pieces("sein", "Present", "I", "ich bin"), "ich bin");
+        assert_eq!(
+            pieces("skifahren", "Present", "I", "ich fahre Ski"),
+            "ich fahr|e Ski"
+        );
+    }
+
+    #[test]
+    fn prefixes_need_a_long_enough_verb() {
+        assert_eq!(separable_prefix("anrufen"), Some("an"));
+        assert_eq!(separable_prefix("hinausgehen"), Some("hinaus"));
+        assert_eq!(separable_prefix("essen"), None);
+        assert_eq!(inseparable_prefix("gehören"), Some("ge"));
+        assert_eq!(inseparable_prefix("gehen"), None);
+    }
+}
@@ -0,0 +1,74 @@
+use csv::{ReaderBuilder, WriterBuilder};
+use std::{collections::HashMap, error::Error, path::Path, path::PathBuf};
+
+use crate::conjugation::Conjugation;
+use crate::person::Person;
+use crate::tense::Tense;
+
+/// The user's notes (e.g. mnemonics) on conjugations, kept in
+/// `<data_dir>/notes.csv` and shown whenever the conjugation is asked.
+const NOTES_FILE: &str = "notes.csv";
+
+/// Notes are attached to a form, i.e. a verb, tense and person
+pub type Key = (String, Tense, Person);
+
+pub fn key(conj: &Conjugation) -> Key {
+    (conj.verb.name().to_string(), conj.tense, conj.person)
+}
+
+pub struct Notes {
+    path: PathBuf,
+    notes: HashMap<Key, String>,
+}
+
+impl Notes {
+    /// Loads the notes, or no notes if there is no notes file yet
+    pub fn load(data_dir: &Path) -> Result<Notes, Box<dyn Error>> {
+        let path = data_dir.join(NOTES_FILE);
+        let mut notes = HashMap::new();
+        if path.exists() {
+            let mut rdr = ReaderBuilder::new().has_headers(true).from_path(&path)?;
+            for result in rdr.records() {
+                let record = result?;
+                let field = |i: usize| record.get(i).unwrap_or_default();
+                notes.insert(
+                    (field(0).to_string(), field(1).parse()?, field(2).parse()?),
+                    field(3).to_string(),
+                );
+            }
+        }
+        Ok(Notes { path, notes })
+    }
+
+    pub fn get(&self, conj: &Conjugation) -> Option<&str> {
+        self.get_form(&key(conj))
+    }
+
+    pub fn get_form(&self, form: &Key) -> Option<&str> {
+        self.notes.get(form).map(|n| n.as_str())
+    }
+
+    /// Sets (or with an empty note, removes) the note and saves the notes file
+    pub fn set_form(&mut self, form: Key, note: &str) -> Result<(), Box<dyn Error>> {
+        let note = note.trim();
+        if note.is_empty() {
+            self.notes.remove(&form);
+        } else {
+            self.notes.insert(form, note.to_string());
+        }
+        self.save()
+    }
+
+    fn save(&self) -> Result<(), Box<dyn Error>> {
+        let mut notes: Vec<_> = self.notes.iter().collect();
+        notes.sort_by_key(|((verb, tense, person), _)| (verb, *tense as u8, *person as u8));
+
+        let mut wtr = WriterBuilder::new().from_path(&self.path)?;
+        wtr.write_record(["Verb", "Tense", "Person", "Note"])?;
+        for ((verb, tense, person), note) in notes {
+            wtr.write_record([verb, &format!("{:?}", tense), &person.to_string(), note])?;
+        }
+        wtr.flush()?;
+        Ok(())
+    }
+}
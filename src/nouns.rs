@@ -0,0 +1,84 @@
+use csv::ReaderBuilder;
+use serde::{Deserialize, Serialize};
+use std::{error::Error, fmt, path::Path, str::FromStr};
+
+/// The nouns to learn, with their gender, kept in `<data_dir>/nouns.csv` with
+/// the columns `Article,Noun,English`
+const NOUNS_FILE: &str = "nouns.csv";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Gender {
+    Masculine,
+    Feminine,
+    Neuter,
+}
+
+impl Gender {
+    /// The definite article in the nominative, e.g. "der"
+    pub fn article(self) -> &'static str {
+        match self {
+            Gender::Masculine => "der",
+            Gender::Feminine => "die",
+            Gender::Neuter => "das",
+        }
+    }
+}
+
+impl FromStr for Gender {
+    type Err = String;
+
+    /// Parses the article (der/die/das) or the name of the gender
+    fn from_str(g: &str) -> Result<Gender, String> {
+        match &*g.trim().to_lowercase() {
+            "der" | "masculine" | "m" => Ok(Gender::Masculine),
+            "die" | "feminine" | "f" => Ok(Gender::Feminine),
+            "das" | "neuter" | "n" => Ok(Gender::Neuter),
+            _ => Err(format!("Gender not matched: {}", g)),
+        }
+    }
+}
+
+impl fmt::Display for Gender {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.article())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Noun {
+    pub gender: Gender,
+    pub german: String, // without the article, e.g. "Tisch"
+    pub english: String,
+}
+
+impl fmt::Display for Noun {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.gender, self.german)
+    }
+}
+
+/// Loads the nouns from the nouns file, in the order they are listed
+pub fn load(data_dir: &Path) -> Result<Vec<Noun>, Box<dyn Error>> {
+    let path = data_dir.join(NOUNS_FILE);
+    if !path.exists() {
+        return Err(format!("No nouns file: {}", path.display()).into());
+    }
+    let mut rdr = ReaderBuilder::new().has_headers(true).from_path(path)?;
+    let mut nouns = Vec::new();
+    for result in rdr.records() {
+        let record = result?;
+        let line = record.position().map_or(0, |p| p.line());
+        let field = |i: usize| {
+            record
+                .get(i)
+                .map(str::trim)
+                .ok_or_else(|| format!("Missing column {} on line {}", i + 1, line))
+        };
+        nouns.push(Noun {
+            gender: field(0)?.parse()?,
+            german: field(1)?.to_string(),
+            english: field(2)?.to_string(),
+        });
+    }
+    Ok(nouns)
+}
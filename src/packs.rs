@@ -0,0 +1,275 @@
+use clap::Subcommand;
+use csv::{ReaderBuilder, WriterBuilder};
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use crate::conjugation;
+use crate::verb::Verb;
+
+/// Data packs are installed as `<data_dir>/packs/<name>/verbs/*.csv`, and the
+/// source each pack was installed from is kept in `<data_dir>/packs/index.csv`
+/// so that it can be updated later.
+const INDEX_FILE: &str = "index.csv";
+
+/// The `packs` subcommands
+#[derive(Subcommand, Debug)]
+pub enum PacksCommand {
+    /// List the installed packs
+    List,
+
+    /// Install a pack from a Git repo (with a `verbs` directory) or a URL to a verb CSV
+    Install {
+        /// The Git repo (anything `git clone` accepts) or http(s) URL of a .csv file
+        source: String,
+
+        /// The name to install the pack as. Defaults to the repo or file name
+        #[arg(short, long)]
+        name: Option<String>,
+    },
+
+    /// Re-fetch installed packs from where they were installed from
+    Update {
+        /// The pack to update. Updates all packs if not given
+        name: Option<String>,
+    },
+}
+
+/// An entry in the packs index
+struct Pack {
+    name: String,
+    source: String,
+}
+
+/// Runs a `packs` subcommand against the data directory
+pub fn run(command: PacksCommand, data_dir: &Path) -> Result<(), Box<dyn Error>> {
+    let packs_dir = data_dir.join("packs");
+    match command {
+        PacksCommand::List => list(&packs_dir),
+        PacksCommand::Install { source, name } => {
+            let name = match name {
+                Some(name) => name,
+                None => default_name(&source)?,
+            };
+            install(&packs_dir, &name, &source)
+        }
+        PacksCommand::Update { name } => {
+            let packs = read_index(&packs_dir)?;
+            let to_update: Vec<&Pack> = packs
+                .iter()
+                .filter(|p| name.as_ref().is_none_or(|n| *n == p.name))
+                .collect();
+            if let (Some(name), true) = (&name, to_update.is_empty()) {
+                return Err(format!("No pack named '{}' is installed", name).into());
+            }
+            for pack in to_update {
+                install(&packs_dir, &pack.name, &pack.source)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+fn list(packs_dir: &Path) -> Result<(), Box<dyn Error>> {
+    let packs = read_index(packs_dir)?;
+    if packs.is_empty() {
+        println!("No packs installed");
+        return Ok(());
+    }
+    for pack in packs {
+        let verbs = Verb::find_in(&packs_dir.join(&pack.name).join("verbs"), None)
+            .map(|v| v.len())
+            .unwrap_or(0);
+        println!("{} ({} verbs) from {}", pack.name, verbs, pack.source);
+    }
+    Ok(())
+}
+
+/// Fetches the pack into a hidden staging directory, checks every verb file
+/// against the schema and only then moves it into place, so a bad pack never
+/// replaces a good one.
+fn install(packs_dir: &Path, name: &str, source: &str) -> Result<(), Box<dyn Error>> {
+    if name.is_empty() || name.starts_with('.') || name.contains(['/', '\\']) {
+        return Err(format!("'{}' is not a valid pack name", name).into());
+    }
+    check_source(source)?;
+    fs::create_dir_all(packs_dir)?;
+
+    let staging = packs_dir.join(format!(".{}.tmp", name));
+    if staging.exists() {
+        fs::remove_dir_all(&staging)?;
+    }
+
+    let result = fetch(source, &staging).and_then(|_| verify(&staging));
+    if let Err(e) = result {
+        let _ = fs::remove_dir_all(&staging);
+        return Err(format!("Could not install pack '{}': {}", name, e).into());
+    }
+
+    let target = packs_dir.join(name);
+    if target.exists() {
+        fs::remove_dir_all(&target)?;
+    }
+    fs::rename(&staging, &target)?;
+
+    let mut packs = read_index(packs_dir)?;
+    packs.retain(|p| p.name != name);
+    packs.push(Pack {
+        name: name.to_string(),
+        source: source.to_string(),
+    });
+    write_index(packs_dir, &packs)?;
+
+    let verbs = Verb::find_in(&target.join("verbs"), None)?.len();
+    println!("Installed pack '{}' ({} verbs)", name, verbs);
+    Ok(())
+}
+
+/// Rejects sources git or curl would take for an option, e.g.
+/// `--upload-pack=<command>`, as the index they come from is synced between
+/// machines
+fn check_source(source: &str) -> Result<(), Box<dyn Error>> {
+    if source.is_empty() || source.starts_with('-') {
+        return Err(format!("'{}' is not a valid pack source", source).into());
+    }
+    Ok(())
+}
+
+/// Whether the source is a single CSV file to download rather than a Git repo
+fn is_csv_url(source: &str) -> bool {
+    (source.starts_with("http://") || source.starts_with("https://")) && source.ends_with(".csv")
+}
+
+/// The name of the repo or file, e.g. `https://host/user/irregular.git` -> `irregular`
+fn default_name(source: &str) -> Result<String, Box<dyn Error>> {
+    let last = source
+        .trim_end_matches('/')
+        .rsplit(['/', '\\', ':'])
+        .next()
+        .unwrap_or_default();
+    let name = last.trim_end_matches(".git").trim_end_matches(".csv");
+    if name.is_empty() {
+        return Err(format!("Could not get a pack name from '{}', use --name", source).into());
+    }
+    Ok(name.to_lowercase())
+}
+
+/// Downloads the source into `dir`, shelling out to git or curl
+fn fetch(source: &str, dir: &Path) -> Result<(), Box<dyn Error>> {
+    if is_csv_url(source) {
+        let verbs_dir = dir.join("verbs");
+        fs::create_dir_all(&verbs_dir)?;
+        let file_name = format!("{}.csv", default_name(source)?);
+        run_command(
+            Command::new("curl")
+                .args(["--fail", "--silent", "--show-error", "--location"])
+                .arg("--output")
+                .arg(verbs_dir.join(file_name))
+                .arg(source),
+        )
+    } else {
+        run_command(
+            Command::new("git")
+                .args(["clone", "--quiet", "--depth", "1", "--", source])
+                .arg(dir),
+        )?;
+        // the history is not needed, updates re-clone from the index
+        let git_dir = dir.join(".git");
+        if git_dir.exists() {
+            fs::remove_dir_all(git_dir)?;
+        }
+        Ok(())
+    }
+}
+
+fn run_command(command: &mut Command) -> Result<(), Box<dyn Error>> {
+    let program = command.get_program().to_string_lossy().to_string();
+    let status = command
+        .status()
+        .map_err(|e| format!("Could not run {}: {}", program, e))?;
+    if !status.success() {
+        return Err(format!("{} failed ({})", program, status).into());
+    }
+    Ok(())
+}
+
+/// Checks that the fetched pack has verb files and that they all follow the schema
+fn verify(dir: &Path) -> Result<(), Box<dyn Error>> {
+    let verbs_dir = dir.join("verbs");
+    if !verbs_dir.is_dir() {
+        return Err("The pack has no verbs directory".into());
+    }
+    let verbs = Verb::find_in(&verbs_dir, None)?;
+    if verbs.is_empty() {
+        return Err("The pack has no verb files".into());
+    }
+    for verb in verbs {
+        conjugation::validate(verb.path())
+            .map_err(|e| format!("{}: {}", verb.path().display(), e))?;
+    }
+    Ok(())
+}
+
+fn index_path(packs_dir: &Path) -> PathBuf {
+    packs_dir.join(INDEX_FILE)
+}
+
+fn read_index(packs_dir: &Path) -> Result<Vec<Pack>, Box<dyn Error>> {
+    let path = index_path(packs_dir);
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    let mut rdr = ReaderBuilder::new().has_headers(true).from_path(path)?;
+    let mut packs = Vec::new();
+    for result in rdr.records() {
+        let record = result?;
+        if let (Some(name), Some(source)) = (record.get(0), record.get(1)) {
+            check_source(source)
+                .map_err(|e| format!("{}: {}", index_path(packs_dir).display(), e))?;
+            packs.push(Pack {
+                name: name.to_string(),
+                source: source.to_string(),
+            });
+        }
+    }
+    Ok(packs)
+}
+
+fn write_index(packs_dir: &Path, packs: &[Pack]) -> Result<(), Box<dyn Error>> {
+    let mut wtr = WriterBuilder::new().from_path(index_path(packs_dir))?;
+    wtr.write_record(["Name", "Source"])?;
+    for pack in packs {
+        wtr.write_record([&pack.name, &pack.source])?;
+    }
+    wtr.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sources_that_look_like_options_are_rejected() {
+        assert!(check_source("--upload-pack=touch /tmp/pwned").is_err());
+        assert!(check_source("-u").is_err());
+        assert!(check_source("").is_err());
+        assert!(check_source("https://example.com/user/irregular.git").is_ok());
+    }
+
+    #[test]
+    fn an_index_with_such_a_source_is_not_read() {
+        let dir = std::env::temp_dir().join("deutschland-packs-index");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            index_path(&dir),
+            "Name,Source\nevil,--upload-pack=touch /tmp/pwned\n",
+        )
+        .unwrap();
+        assert!(read_index(&dir).is_err());
+    }
+}
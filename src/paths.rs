@@ -0,0 +1,47 @@
+use directories::ProjectDirs;
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+/// Set to keep everything (verbs, packs, history and settings) in one
+/// directory of your choosing
+const DATA_DIR_VAR: &str = "DEUTSCHLAND_DATA_DIR";
+
+/// Where the app's files are kept
+#[derive(Debug, Clone)]
+pub struct Dirs {
+    pub data: PathBuf,   // the `verbs` directory, installed `packs` and the history
+    pub config: PathBuf, // config.toml
+}
+
+impl Dirs {
+    /// The directory in $DEUTSCHLAND_DATA_DIR if it is set, else the working
+    /// directory if it has a `verbs` directory (as a checkout of the repo
+    /// does), else the platform's: ~/.local/share/deutschland and
+    /// ~/.config/deutschland on Linux, ~/Library/Application Support/deutschland
+    /// on macOS and %APPDATA%\deutschland on Windows
+    pub fn find() -> Dirs {
+        if let Some(dir) = env::var_os(DATA_DIR_VAR).filter(|d| !d.is_empty()) {
+            return Dirs::single(PathBuf::from(dir));
+        }
+        if Path::new("verbs").is_dir() {
+            return Dirs::single(PathBuf::from("."));
+        }
+        match ProjectDirs::from("", "", "deutschland") {
+            Some(dirs) => Dirs {
+                data: dirs.data_dir().to_path_buf(),
+                config: dirs.config_dir().to_path_buf(),
+            },
+            // no home directory to put them in
+            None => Dirs::single(PathBuf::from(".")),
+        }
+    }
+
+    fn single(dir: PathBuf) -> Dirs {
+        Dirs {
+            data: dir.clone(),
+            config: dir,
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use std::fmt::Write;
+
+/// The size of an A4 page, in points
+const PAGE_WIDTH: f64 = 595.0;
+const PAGE_HEIGHT: f64 = 842.0;
+
+/// The space left around the text, in points
+const MARGIN: f64 = 56.0;
+
+/// Where each column of a row starts, from the left margin
+const COLUMN_WIDTH: f64 = 200.0;
+
+/// A PDF typeset line by line in Helvetica, one of the fonts every reader
+/// has, so nothing needs embedding. Only the characters of Latin-1 can be
+/// written, which covers German.
+pub struct Document {
+    pages: Vec<String>, // the content stream of each page
+    y: f64,             // where the next line goes on the last page
+}
+
+impl Default for Document {
+    fn default() -> Document {
+        Document {
+            pages: vec![String::new()],
+            y: PAGE_HEIGHT - MARGIN,
+        }
+    }
+}
+
+impl Document {
+    pub fn title(&mut self, text: &str) {
+        self.line(&[text], "F2", 20.0, 8.0);
+    }
+
+    pub fn heading(&mut self, text: &str) {
+        self.line(&[text], "F2", 13.0, 10.0);
+    }
+
+    /// A line of text in columns
+    pub fn row(&mut self, columns: &[&str]) {
+        self.line(columns, "F1", 10.5, 0.0);
+    }
+
+    /// Writes a line, starting a new page if it doesn't fit on this one
+    fn line(&mut self, columns: &[&str], font: &str, size: f64, space_before: f64) {
+        let height = size * 1.4;
+        if self.y - space_before - height < MARGIN {
+            self.pages.push(String::new());
+            self.y = PAGE_HEIGHT - MARGIN;
+        } else {
+            self.y -= space_before;
+        }
+        self.y -= height;
+        let page = self.pages.last_mut().unwrap();
+        for (i, text) in columns.iter().enumerate() {
+            let x = MARGIN + COLUMN_WIDTH * i as f64;
+            let _ = writeln!(
+                page,
+                "BT /{} {} Tf {:.1} {:.1} Td ({}) Tj ET",
+                font,
+                size,
+                x,
+                self.y,
+                escape(text)
+            );
+        }
+    }
+
+    /// The bytes of the PDF file
+    pub fn finish(self) -> Vec<u8> {
+        // 1 is the catalog, 2 the page tree, 3 and 4 the fonts, then each
+        // page and its content
+        let mut objects: Vec<Vec<u8>> = vec![
+            b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+            vec![],
+            font("Helvetica"),
+            font("Helvetica-Bold"),
+        ];
+        let mut kids = Vec::new();
+        for content in &self.pages {
+            let page = objects.len() + 1;
+            kids.push(format!("{} 0 R", page));
+            objects.push(
+                format!(
+                    "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] \
+                     /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {} 0 R >>",
+                    PAGE_WIDTH,
+                    PAGE_HEIGHT,
+                    page + 1
+                )
+                .into_bytes(),
+            );
+            let bytes = latin1(content);
+            let mut stream = format!("<< /Length {} >>\nstream\n", bytes.len()).into_bytes();
+            stream.extend(bytes);
+            stream.extend(b"\nendstream");
+            objects.push(stream);
+        }
+        objects[1] = format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            kids.join(" "),
+            kids.len()
+        )
+        .into_bytes();
+
+        let mut pdf = b"%PDF-1.4\n".to_vec();
+        let mut offsets = Vec::new();
+        for (i, object) in objects.iter().enumerate() {
+            offsets.push(pdf.len());
+            pdf.extend(format!("{} 0 obj\n", i + 1).into_bytes());
+            pdf.extend(object);
+            pdf.extend(b"\nendobj\n");
+        }
+        let xref = pdf.len();
+        let mut trailer = format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+        for offset in offsets {
+            let _ = writeln!(trailer, "{:010} 00000 n ", offset);
+        }
+        let _ = write!(
+            trailer,
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+            objects.len() + 1,
+            xref
+        );
+        pdf.extend(trailer.into_bytes());
+        pdf
+    }
+}
+
+fn font(name: &str) -> Vec<u8> {
+    format!(
+        "<< /Type /Font /Subtype /Type1 /BaseFont /{} /Encoding /WinAnsiEncoding >>",
+        name
+    )
+    .into_bytes()
+}
+
+/// The text as a PDF string, without the parentheses around it
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('(', "\\(")
+        .replace(')', "\\)")
+}
+
+/// The text in WinAnsi, which has the Latin-1 letters where Latin-1 does. The
+/// characters it doesn't have are written as a ?
+fn latin1(text: &str) -> Vec<u8> {
+    text.chars()
+        .map(|c| match u32::from(c) {
+            n @ (0x20..=0x7e | 0xa0..=0xff | 0x0a) => n as u8,
+            _ => b'?',
+        })
+        .collect()
+}
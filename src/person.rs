@@ -1,6 +1,7 @@
-use std::fmt;
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Person {
     I,
     You,
@@ -11,17 +12,37 @@ pub enum Person {
 }
 
 impl Person {
-    pub fn from_str(p: &str) -> Person {
-        let p = p.to_lowercase();
-        return match &*p {
-            "i" => Person::I,
-            "you (singular)" => Person::You,
-            "he/she/it" => Person::HeSheIt,
-            "we" => Person::We,
-            "you (plural)" => Person::YouPl,
-            "they" => Person::They,
-            _ => panic!("Person not matched"),
-        };
+    /// Every person, in the order they are listed
+    pub const ALL: [Person; 6] = [
+        Person::I,
+        Person::You,
+        Person::HeSheIt,
+        Person::We,
+        Person::YouPl,
+        Person::They,
+    ];
+}
+
+impl FromStr for Person {
+    type Err = String;
+
+    /// Parses the person as written in the verb files, e.g. `you (singular)`,
+    /// in any case. Every row of every verb file has one, so nothing is
+    /// allocated.
+    fn from_str(p: &str) -> Result<Person, String> {
+        let names = [
+            ("i", Person::I),
+            ("you (singular)", Person::You),
+            ("he/she/it", Person::HeSheIt),
+            ("we", Person::We),
+            ("you (plural)", Person::YouPl),
+            ("they", Person::They),
+        ];
+        names
+            .into_iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(p))
+            .map(|(_, person)| person)
+            .ok_or_else(|| format!("Person not matched: {}", p.to_lowercase()))
     }
 }
 
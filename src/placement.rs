@@ -0,0 +1,287 @@
+use chrono::Local;
+use csv::{ReaderBuilder, WriterBuilder};
+use deutschland_tui::grade::{Grader, Strictness};
+use rand::{rngs::StdRng, seq::SliceRandom};
+use std::{error::Error, fmt, fs::OpenOptions, path::Path, str::FromStr};
+
+use crate::collocations::Collocation;
+use crate::conjugation::Conjugation;
+use crate::nouns::Noun;
+use crate::politeness::Request;
+use crate::prepositions::{Case, Preposition};
+use crate::tense::Tense;
+use crate::valency::Valency;
+
+/// Every placement taken, kept in `<data_dir>/placement.csv`, the latest last
+const PLACEMENT_FILE: &str = "placement.csv";
+
+const HEADERS: [&str; 4] = ["Time", "Level", "Right", "Asked"];
+
+/// How many questions the test asks of each level, when there are enough
+pub const PER_LEVEL: usize = 3;
+
+/// The share of a level's questions to get right to be past it
+const PASS_MARK: f64 = 2.0 / 3.0;
+
+/// A level roughly as the CEFR has them, up to C1
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    A1,
+    A2,
+    B1,
+    B2,
+    C1,
+}
+
+impl Level {
+    /// Every level, from the lowest
+    pub const ALL: [Level; 5] = [Level::A1, Level::A2, Level::B1, Level::B2, Level::C1];
+
+    /// The stage of the curriculum lessons start from at the level, its
+    /// tenses being the ones to work on
+    pub fn stage(self) -> usize {
+        self as usize
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(l: &str) -> Result<Level, String> {
+        Level::ALL
+            .into_iter()
+            .find(|level| level.to_string().eq_ignore_ascii_case(l.trim()))
+            .ok_or_else(|| format!("Level not matched: {}", l))
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// A question of the test, on any of the kinds of content
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Question {
+    pub skill: &'static str, // e.g. "Verbs" or "Nouns", as the home screen has them
+    pub level: Level,
+    pub prompt: String,
+    pub answers: Vec<String>, // any of them is right, the usual one first
+}
+
+impl Question {
+    /// Whether the response is one of the answers. Umlauts and punctuation
+    /// can be left out, as the test is about the grammar.
+    pub fn is_right(&self, response: &str) -> bool {
+        let grader = Grader::new()
+            .strictness(Strictness::Lenient)
+            .fold_umlauts(true);
+        self.answers.iter().any(|a| grader.grade(response, a))
+    }
+}
+
+/// Everything the test can ask about, each loaded as its drill loads it.
+/// Whatever is missing is left out of the test.
+#[derive(Default)]
+pub struct Content {
+    pub conjugations: Vec<Conjugation>,
+    pub nouns: Vec<Noun>,
+    pub prepositions: Vec<Preposition>,
+    pub valency: Vec<Valency>,
+    pub collocations: Vec<Collocation>,
+    pub politeness: Vec<Request>,
+}
+
+/// The ways a case can be answered, e.g. "dative" or "dat"
+fn case_answers(case: Case) -> Vec<String> {
+    let answers: &[&str] = match case {
+        Case::Accusative => &["Accusative", "Akkusativ", "Akk"],
+        Case::Dative => &["Dative", "Dativ", "Dat"],
+        Case::Genitive => &["Genitive", "Genitiv", "Gen"],
+    };
+    answers.iter().map(|a| a.to_string()).collect()
+}
+
+/// The level a tense is usually learnt at
+fn tense_level(tense: Tense) -> Level {
+    match tense {
+        Tense::Present => Level::A1,
+        Tense::PerfectPresent => Level::A2,
+        Tense::Past | Tense::PerfectPast => Level::B1,
+        Tense::Future | Tense::PerfectFuture => Level::B2,
+        Tense::SubjectiveI | Tense::SubjectiveII => Level::C1,
+    }
+}
+
+/// Every question the content makes, at its level
+fn pool(content: &Content) -> Vec<Question> {
+    let mut pool = Vec::new();
+    for conj in &content.conjugations {
+        let mut prompt = format!("Translate: {}", conj.prompt);
+        if let Some(hint) = conj.prompt_hint() {
+            prompt += &format!(" {}", hint);
+        }
+        pool.push(Question {
+            skill: "Verbs",
+            level: tense_level(conj.tense),
+            prompt,
+            answers: vec![conj.german.clone()],
+        });
+    }
+    for noun in &content.nouns {
+        pool.push(Question {
+            skill: "Nouns",
+            level: Level::A1,
+            prompt: format!("The article of {} ({})", noun.german, noun.english),
+            answers: vec![noun.gender.article().to_string()],
+        });
+    }
+    for preposition in &content.prepositions {
+        // a two-way preposition is told by its sentence
+        let (level, prompt) = match &preposition.sentence {
+            Some(sentence) => (Level::B1, format!("The case in: {}", sentence)),
+            None => (
+                match preposition.case {
+                    Case::Genitive => Level::C1,
+                    _ => Level::A2,
+                },
+                format!(
+                    "The case after {} ({})",
+                    preposition.preposition, preposition.english
+                ),
+            ),
+        };
+        pool.push(Question {
+            skill: "Prepositions",
+            level,
+            prompt,
+            answers: case_answers(preposition.case),
+        });
+    }
+    for valency in &content.valency {
+        pool.push(Question {
+            skill: "Cases",
+            level: match valency.case {
+                Case::Accusative => Level::A2,
+                Case::Dative => Level::B1,
+                Case::Genitive => Level::C1,
+            },
+            prompt: format!(
+                "The case of the object of {} ({})",
+                valency.verb, valency.english
+            ),
+            answers: case_answers(valency.case),
+        });
+    }
+    for collocation in &content.collocations {
+        pool.push(Question {
+            skill: "Collocations",
+            level: Level::B2,
+            prompt: format!(
+                "The preposition after {} ({})",
+                collocation.verb, collocation.english
+            ),
+            answers: vec![collocation.preposition.clone()],
+        });
+    }
+    for request in &content.politeness {
+        pool.push(Question {
+            skill: "Politeness",
+            level: Level::B1,
+            prompt: format!("Ask politely: {}", request.direct),
+            answers: request.polite.clone(),
+        });
+    }
+    pool
+}
+
+/// The questions of a test: up to `PER_LEVEL` of each level, from as many
+/// kinds of content as the level has, the lowest level first
+pub fn questions(content: &Content, rng: &mut StdRng) -> Vec<Question> {
+    let mut pool = pool(content);
+    pool.shuffle(rng);
+    let mut questions = Vec::new();
+    for level in Level::ALL {
+        let mut at_level: Vec<Question> =
+            pool.iter().filter(|q| q.level == level).cloned().collect();
+        let mut skills: Vec<&'static str> = at_level.iter().map(|q| q.skill).collect();
+        skills.sort();
+        skills.dedup();
+        skills.shuffle(rng);
+        // a skill at a time, so one with many questions doesn't crowd out the others
+        let mut picked = 0;
+        while picked < PER_LEVEL && !at_level.is_empty() {
+            for skill in &skills {
+                if picked == PER_LEVEL {
+                    break;
+                }
+                if let Some(i) = at_level.iter().position(|q| q.skill == *skill) {
+                    questions.push(at_level.remove(i));
+                    picked += 1;
+                }
+            }
+        }
+    }
+    questions
+}
+
+/// The level to work at from how the test went: the lowest level with too
+/// few right, or the highest asked if every one was passed
+pub fn level(results: &[(Level, bool)]) -> Level {
+    let mut placed = Level::A1;
+    for level in Level::ALL {
+        let at_level: Vec<bool> = results
+            .iter()
+            .filter(|(l, _)| *l == level)
+            .map(|&(_, right)| right)
+            .collect();
+        if at_level.is_empty() {
+            continue;
+        }
+        placed = level;
+        let right = at_level.iter().filter(|&&r| r).count();
+        if (right as f64) < PASS_MARK * at_level.len() as f64 {
+            break;
+        }
+    }
+    placed
+}
+
+/// Appends the placement to the placements taken
+pub fn save(
+    data_dir: &Path,
+    level: Level,
+    right: usize,
+    asked: usize,
+) -> Result<(), Box<dyn Error>> {
+    let path = data_dir.join(PLACEMENT_FILE);
+    let is_new = !path.exists();
+    let file = OpenOptions::new().create(true).append(true).open(path)?;
+    let mut wtr = WriterBuilder::new().has_headers(false).from_writer(file);
+    if is_new {
+        wtr.write_record(HEADERS)?;
+    }
+    wtr.write_record([
+        Local::now().to_rfc3339(),
+        level.to_string(),
+        right.to_string(),
+        asked.to_string(),
+    ])?;
+    wtr.flush()?;
+    Ok(())
+}
+
+/// The level of the latest placement, if one was taken
+pub fn latest(data_dir: &Path) -> Result<Option<Level>, Box<dyn Error>> {
+    let path = data_dir.join(PLACEMENT_FILE);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let mut rdr = ReaderBuilder::new().has_headers(true).from_path(path)?;
+    let mut latest = None;
+    for result in rdr.records() {
+        latest = Some(result?.get(1).unwrap_or_default().parse()?);
+    }
+    Ok(latest)
+}
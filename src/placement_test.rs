@@ -0,0 +1,228 @@
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Alignment, Rect},
+    style::{Styled, Stylize},
+    symbols::border,
+    text::{Line, Text},
+    widgets::{
+        block::{Position, Title},
+        Block, Paragraph, Widget,
+    },
+};
+
+use crate::placement::{self, Level, Question};
+use crate::theme::Theme;
+
+/// What the test wants done after a key press
+pub enum PlacementEvent {
+    None,
+    /// The last question was answered, placing the learner at the level
+    Placed {
+        level: Level,
+        right: usize,
+        asked: usize,
+    },
+    Close,
+}
+
+/// The placement test of --placement: questions on every kind of content,
+/// from the lowest level up, then the level they put the learner at
+pub struct PlacementTest {
+    questions: Vec<Question>,
+    previous: Option<Level>, // the level of the last placement, to compare
+    response: String,
+    results: Vec<bool>, // if each question answered was right, in order
+    last: Option<(usize, bool, String)>, // the question answered before, if right, and the response
+    placed: Option<Level>, // once every question is answered
+}
+
+impl PlacementTest {
+    /// Starts the test, None if there is nothing to ask
+    pub fn new(questions: Vec<Question>, previous: Option<Level>) -> Option<PlacementTest> {
+        if questions.is_empty() {
+            return None;
+        }
+        Some(PlacementTest {
+            questions,
+            previous,
+            response: String::new(),
+            results: vec![],
+            last: None,
+            placed: None,
+        })
+    }
+
+    /// Handles a key press. Enter with nothing typed passes on the question,
+    /// which counts as wrong.
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> PlacementEvent {
+        if self.placed.is_some() {
+            return match key_event.code {
+                KeyCode::Esc | KeyCode::Enter => PlacementEvent::Close,
+                _ => PlacementEvent::None,
+            };
+        }
+        match key_event.code {
+            KeyCode::Esc => return PlacementEvent::Close,
+            KeyCode::Enter => return self.answer(),
+            KeyCode::Backspace => {
+                self.response.pop();
+            }
+            KeyCode::Char(c) => self.response.push(c),
+            _ => {}
+        }
+        PlacementEvent::None
+    }
+
+    fn answer(&mut self) -> PlacementEvent {
+        let current = self.results.len();
+        let right = self.questions[current].is_right(&self.response);
+        self.results.push(right);
+        self.last = Some((current, right, std::mem::take(&mut self.response)));
+        if self.results.len() < self.questions.len() {
+            return PlacementEvent::None;
+        }
+        let level = placement::level(&self.by_level());
+        self.placed = Some(level);
+        PlacementEvent::Placed {
+            level,
+            right: self.results.iter().filter(|&&r| r).count(),
+            asked: self.results.len(),
+        }
+    }
+
+    /// The level of each question answered, and if it was right
+    fn by_level(&self) -> Vec<(Level, bool)> {
+        self.questions
+            .iter()
+            .zip(&self.results)
+            .map(|(q, &right)| (q.level, right))
+            .collect()
+    }
+
+    pub fn render(&self, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        match self.placed {
+            Some(level) => self.render_result(level, theme, area, buf),
+            None => self.render_question(theme, area, buf),
+        }
+    }
+
+    fn render_question(&self, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        let current = self.results.len();
+        let question = &self.questions[current];
+        let title = Title::from(
+            format!(
+                " Placement Test | {} | Q{}/{} ",
+                question.skill,
+                current + 1,
+                self.questions.len()
+            )
+            .bold(),
+        );
+        let instructions = Title::from(Line::from(vec![
+            " Input Answer ".into(),
+            "<Chars> ".set_style(theme.key),
+            " Submit, or Pass if Empty ".into(),
+            "<Enter> ".set_style(theme.key),
+            " Stop ".into(),
+            "<Esc> ".set_style(theme.key),
+        ]));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+
+        let mut text = Text::from(vec![
+            Line::from(""),
+            Line::from(""),
+            Line::from(question.prompt.as_str().set_style(theme.prompt)),
+            Line::from(""),
+            Line::from(vec![
+                "Your input: ".into(),
+                format!("{}_", self.response).set_style(theme.input),
+            ]),
+            Line::from(""),
+        ]);
+        if let Some((i, right, response)) = &self.last {
+            let answer = &self.questions[*i].answers[0];
+            text.push_line(if *right {
+                Line::from(format!("{}{}", theme.correct_mark, response).set_style(theme.correct))
+            } else if response.is_empty() {
+                // passed on
+                Line::from(format!("{}{}", theme.incorrect_mark, answer).set_style(theme.incorrect))
+            } else {
+                Line::from(
+                    format!("{}{} (not {})", theme.incorrect_mark, answer, response)
+                        .set_style(theme.incorrect),
+                )
+            });
+        }
+
+        Paragraph::new(text)
+            .centered()
+            .block(block)
+            .render(area, buf);
+    }
+
+    fn render_result(&self, level: Level, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        let title = Title::from(" Placement Test ".bold());
+        let instructions = Title::from(Line::from(vec![
+            " Done ".into(),
+            "<Enter/Esc> ".set_style(theme.key),
+        ]));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+
+        let right = self.results.iter().filter(|&&r| r).count();
+        let mut text = Text::from(vec![
+            Line::from(""),
+            Line::from(""),
+            Line::from(vec![
+                "Your level: ".into(),
+                level.to_string().set_style(theme.notice).bold(),
+            ]),
+            Line::from(format!("{} of {} right", right, self.results.len())),
+            Line::from(""),
+        ]);
+        let by_level = self.by_level();
+        for at in Level::ALL {
+            let results: Vec<bool> = by_level
+                .iter()
+                .filter(|(l, _)| *l == at)
+                .map(|&(_, r)| r)
+                .collect();
+            if !results.is_empty() {
+                let right = results.iter().filter(|&&r| r).count();
+                text.push_line(Line::from(format!(
+                    "{}: {} of {}",
+                    at,
+                    right,
+                    results.len()
+                )));
+            }
+        }
+        text.push_line(Line::from(""));
+        if let Some(previous) = self.previous.filter(|&p| p != level) {
+            let change = if previous < level { "Up" } else { "Down" };
+            text.push_line(Line::from(format!("{} from {} last time", change, previous)).italic());
+        }
+        text.push_line(Line::from(
+            "The curriculum now starts lessons from the tenses of this level.",
+        ));
+
+        Paragraph::new(text)
+            .centered()
+            .block(block)
+            .render(area, buf);
+    }
+}
@@ -0,0 +1,196 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Styled, Stylize},
+    symbols::border,
+    text::{Line, Text},
+    widgets::{
+        block::{Position, Title},
+        Block, Paragraph, Widget, Wrap,
+    },
+};
+use serde::Deserialize;
+use std::{error::Error, fs, path::Path};
+
+use crate::lesson::Lesson;
+use crate::theme::Theme;
+use crate::verb::Verb;
+
+/// The lesson plans, `<data_dir>/lessons/<name>.toml`
+const PLANS_DIR: &str = "lessons";
+
+/// The share of a step's answers that have to be right to go on, unless the
+/// plan or step says otherwise
+const DEFAULT_PASS: f64 = 0.8;
+
+/// How many questions a step asks, unless it says otherwise
+const DEFAULT_QUESTIONS: u8 = 10;
+
+/// A lesson plan as it is written, e.g.
+///
+/// ```toml
+/// title = "The present tense"
+/// pass = 0.8
+///
+/// [[step]]
+/// intro = "Regular verbs add -e, -st, -t, -en, -t, -en to their stem."
+///
+/// [[step]]
+/// verbs = ["machen"]
+/// tenses = ["Present"]
+/// questions = 6
+/// ```
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PlanFile {
+    title: String,
+    pass: Option<f64>,
+    #[serde(rename = "step")]
+    steps: Vec<StepFile>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StepFile {
+    intro: Option<String>,
+    #[serde(default)]
+    verbs: Vec<String>,
+    #[serde(default)]
+    tenses: Vec<String>, // every tense if none are given
+    #[serde(default)]
+    persons: Vec<String>, // every person if none are given
+    questions: Option<u8>,
+    pass: Option<f64>,
+}
+
+/// A step of a plan: a text to read, questions to answer, or both
+pub struct Step {
+    pub intro: Option<String>,
+    pub lesson: Option<Lesson>, // None for a step that is only read
+    pub questions: u8,
+    pub pass: f64, // the share of right answers needed to go on
+}
+
+/// A course of steps taken in order, written by a teacher. A step whose
+/// questions aren't answered well enough is taken again.
+pub struct Plan {
+    pub title: String,
+    pub steps: Vec<Step>,
+    pub step: usize,      // the step being taken
+    pub intro_read: bool, // if the intro of the step was read
+}
+
+impl Plan {
+    /// Loads the plan `<data_dir>/lessons/<name>.toml`, checking that its
+    /// verbs are ones there are
+    pub fn load(data_dir: &Path, name: &str, verbs: &[Verb]) -> Result<Plan, Box<dyn Error>> {
+        let path = data_dir.join(PLANS_DIR).join(format!("{}.toml", name));
+        let text = fs::read_to_string(&path)
+            .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+        let file: PlanFile = toml::from_str(&text)
+            .map_err(|e| format!("Could not parse {}: {}", path.display(), e))?;
+
+        let mut steps = Vec::new();
+        for (i, step) in file.steps.into_iter().enumerate() {
+            let lesson = if step.verbs.is_empty() {
+                None
+            } else {
+                for name in &step.verbs {
+                    if !verbs.iter().any(|v| v.name() == name.to_lowercase()) {
+                        return Err(format!("Step {}: verb not matched: {}", i + 1, name).into());
+                    }
+                }
+                let mut lesson = Lesson::new(step.verbs.iter().map(|v| v.to_lowercase()).collect());
+                if !step.tenses.is_empty() {
+                    lesson.tenses =
+                        parse_all(&step.tenses).map_err(|e| format!("Step {}: {}", i + 1, e))?;
+                }
+                if !step.persons.is_empty() {
+                    lesson.persons =
+                        parse_all(&step.persons).map_err(|e| format!("Step {}: {}", i + 1, e))?;
+                }
+                Some(lesson)
+            };
+            if lesson.is_none() && step.intro.is_none() {
+                return Err(format!("Step {} has neither an intro nor verbs", i + 1).into());
+            }
+            steps.push(Step {
+                intro: step.intro,
+                lesson,
+                questions: step.questions.unwrap_or(DEFAULT_QUESTIONS).clamp(1, 99),
+                pass: step.pass.or(file.pass).unwrap_or(DEFAULT_PASS),
+            });
+        }
+        if steps.is_empty() {
+            return Err(format!("{} has no steps", path.display()).into());
+        }
+        Ok(Plan {
+            title: file.title,
+            steps,
+            step: 0,
+            intro_read: false,
+        })
+    }
+
+    pub fn current(&self) -> &Step {
+        &self.steps[self.step]
+    }
+
+    /// The intro of the step, until it is read
+    pub fn intro(&self) -> Option<&str> {
+        self.current().intro.as_deref().filter(|_| !self.intro_read)
+    }
+
+    /// Whether enough of the step's answers were right to go on
+    pub fn passed(&self, correct: u32, answered: u32) -> bool {
+        answered > 0 && f64::from(correct) >= self.current().pass * f64::from(answered)
+    }
+
+    /// Moves on to the next step, returning false if that was the last
+    pub fn next(&mut self) -> bool {
+        if self.step + 1 == self.steps.len() {
+            return false;
+        }
+        self.step += 1;
+        self.intro_read = false;
+        true
+    }
+
+    /// Where the plan is, e.g. "Step 2 of 5"
+    pub fn position(&self) -> String {
+        format!("Step {} of {}", self.step + 1, self.steps.len())
+    }
+
+    pub fn render_intro(&self, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        let title = Title::from(format!(" {} | {} ", self.title, self.position()).bold());
+        let instructions = Title::from(Line::from(vec![
+            " Continue ".into(),
+            "<Enter> ".set_style(theme.key),
+            " Leave ".into(),
+            "<Esc> ".set_style(theme.key),
+        ]));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+
+        let mut text = Text::from(vec![Line::from("")]);
+        for line in self.intro().unwrap_or_default().lines() {
+            text.push_line(Line::from(line.to_string()));
+        }
+        Paragraph::new(text)
+            .centered()
+            .wrap(Wrap { trim: true })
+            .block(block)
+            .render(area, buf);
+    }
+}
+
+/// Parses each of the tenses or persons of a step
+fn parse_all<T: std::str::FromStr<Err = String>>(names: &[String]) -> Result<Vec<T>, String> {
+    names.iter().map(|n| n.parse()).collect()
+}
@@ -0,0 +1,51 @@
+use csv::ReaderBuilder;
+use std::{error::Error, path::Path};
+
+/// The requests to make polite, kept in `<data_dir>/politeness.csv` with the
+/// columns `Direct,English,Polite`. The polite column lists every way of
+/// saying it that is right, separated by semicolons, the usual one first.
+const POLITENESS_FILE: &str = "politeness.csv";
+
+/// A blunt request, and the ways of asking it politely in the Konjunktiv II,
+/// e.g. "Ich will einen Kaffee." and "Ich hätte gern einen Kaffee."
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub direct: String,
+    pub english: String,     // what is asked for
+    pub polite: Vec<String>, // never empty
+}
+
+/// Loads the requests from the politeness file, in the order they are listed
+pub fn load(data_dir: &Path) -> Result<Vec<Request>, Box<dyn Error>> {
+    let path = data_dir.join(POLITENESS_FILE);
+    if !path.exists() {
+        return Err(format!("No politeness file: {}", path.display()).into());
+    }
+    let mut rdr = ReaderBuilder::new().has_headers(true).from_path(path)?;
+    let mut requests = Vec::new();
+    for result in rdr.records() {
+        let record = result?;
+        let line = record.position().map_or(0, |p| p.line());
+        let field = |i: usize| {
+            record
+                .get(i)
+                .map(str::trim)
+                .ok_or_else(|| format!("Missing column {} on line {}", i + 1, line))
+        };
+        let polite: Vec<String> = field(2)?
+            .split(';')
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .map(String::from)
+            .collect();
+        if polite.is_empty() {
+            return Err(format!("No polite request on line {}", line).into());
+        }
+        requests.push(Request {
+            direct: field(0)?.to_string(),
+            english: field(1)?.to_string(),
+            polite,
+        });
+    }
+    Ok(requests)
+}
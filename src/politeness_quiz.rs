@@ -0,0 +1,176 @@
+use deutschland_tui::grade::{Grader, Strictness};
+use rand::{rngs::StdRng, Rng};
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Alignment, Rect},
+    style::{Styled, Stylize},
+    symbols::border,
+    text::{Line, Text},
+    widgets::{
+        block::{Position, Title},
+        Block, Paragraph, Widget,
+    },
+};
+
+use crate::politeness::Request;
+use crate::theme::Theme;
+
+/// The drill on polite requests: a blunt one is shown, and it is typed again
+/// in the Konjunktiv II, e.g. with "Könnten Sie" or "Ich hätte gern". Any of
+/// the ways the politeness file lists is right.
+pub struct PolitenessQuiz {
+    requests: Vec<Request>,
+    rng: StdRng,
+    current: usize,
+    response: String,
+    correct: Option<bool>, // once answered, if the response was right
+    right: u32,
+    answered: u32,
+}
+
+impl PolitenessQuiz {
+    /// Starts the drill, None if there are no requests to ask
+    pub fn new(requests: Vec<Request>, mut rng: StdRng) -> Option<PolitenessQuiz> {
+        if requests.is_empty() {
+            return None;
+        }
+        let current = rng.gen_range(0..requests.len());
+        Some(PolitenessQuiz {
+            requests,
+            rng,
+            current,
+            response: String::new(),
+            correct: None,
+            right: 0,
+            answered: 0,
+        })
+    }
+
+    /// Handles a key press, returning true when the drill should be closed
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> bool {
+        if self.correct.is_some() {
+            match key_event.code {
+                KeyCode::Esc => return true,
+                KeyCode::Enter => self.next(),
+                _ => {}
+            }
+            return false;
+        }
+
+        match key_event.code {
+            KeyCode::Esc => return true,
+            KeyCode::Enter if !self.response.trim().is_empty() => self.check(),
+            KeyCode::Backspace => {
+                self.response.pop();
+            }
+            KeyCode::Char(c) => self.response.push(c),
+            _ => {}
+        }
+        false
+    }
+
+    fn check(&mut self) {
+        // the whole sentence is compared, but not its commas or question mark
+        let grader = Grader::new()
+            .strictness(Strictness::Lenient)
+            .fold_umlauts(true);
+        let correct = self.requests[self.current]
+            .polite
+            .iter()
+            .any(|polite| grader.grade(&self.response, polite));
+        if correct {
+            self.right += 1;
+        }
+        self.answered += 1;
+        self.correct = Some(correct);
+    }
+
+    /// Asks another request, not the same one twice in a row
+    fn next(&mut self) {
+        let mut next = self.rng.gen_range(0..self.requests.len());
+        if self.requests.len() > 1 {
+            while next == self.current {
+                next = self.rng.gen_range(0..self.requests.len());
+            }
+        }
+        self.current = next;
+        self.response.clear();
+        self.correct = None;
+    }
+
+    pub fn render(&self, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        let title = Title::from(" Polite Requests ".bold());
+        let instructions = Title::from(Line::from(match self.correct {
+            Some(_) => vec![
+                " Next ".into(),
+                "<Enter> ".set_style(theme.key),
+                " Back ".into(),
+                "<Esc> ".set_style(theme.key),
+            ],
+            None => vec![
+                " Input Answer ".into(),
+                "<Chars> ".set_style(theme.key),
+                " Submit ".into(),
+                "<Enter> ".set_style(theme.key),
+                " Back ".into(),
+                "<Esc> ".set_style(theme.key),
+            ],
+        }));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+
+        let request = &self.requests[self.current];
+        let mut text = Text::from(vec![
+            Line::from(""),
+            Line::from(""),
+            Line::from("Ask politely, in the Konjunktiv II:"),
+            Line::from(request.direct.as_str().set_style(theme.prompt)),
+            Line::from(format!("({})", request.english)),
+            Line::from(""),
+        ]);
+        match self.correct {
+            None => text.push_line(Line::from(vec![
+                "Your input: ".into(),
+                format!("{}_", self.response).set_style(theme.input),
+            ])),
+            Some(true) => text.push_line(Line::from(
+                format!("{}{}", theme.correct_mark, self.response).set_style(theme.correct),
+            )),
+            Some(false) => {
+                text.push_line(Line::from(
+                    format!("{}{}", theme.incorrect_mark, self.response).set_style(theme.incorrect),
+                ));
+                text.push_line(Line::from(vec![
+                    "Correct German: ".into(),
+                    request.polite[0].clone().set_style(theme.correct),
+                ]));
+            }
+        }
+        // the other ways of asking are worth knowing too
+        if self.correct.is_some() && request.polite.len() > 1 {
+            text.push_line(Line::from(format!(
+                "Also right: {}",
+                request.polite[1..].join(" / ")
+            )));
+        }
+        text.push_line(Line::from(""));
+        if self.answered > 0 {
+            text.push_line(Line::from(format!(
+                "{} right out of {}",
+                self.right, self.answered
+            )));
+        }
+
+        Paragraph::new(text)
+            .centered()
+            .block(block)
+            .render(area, buf);
+    }
+}
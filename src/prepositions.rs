@@ -0,0 +1,128 @@
+use chrono::Local;
+use csv::{ReaderBuilder, WriterBuilder};
+use std::{collections::BTreeMap, error::Error, fmt, fs::OpenOptions, path::Path, str::FromStr};
+
+/// The prepositions to learn the case of, kept in `<data_dir>/prepositions.csv`
+/// with the columns `Preposition,Case,Sentence,Answer,English`. Two-way
+/// prepositions have a row per case, with a sentence to tell them apart.
+const PREPOSITIONS_FILE: &str = "prepositions.csv";
+
+/// Every answered preposition, kept apart from the verb history in
+/// `<data_dir>/prepositions_history.csv`
+const HISTORY_FILE: &str = "prepositions_history.csv";
+
+const HEADERS: [&str; 4] = ["Time", "Preposition", "Case", "Correct"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Case {
+    Accusative,
+    Dative,
+    Genitive,
+}
+
+impl FromStr for Case {
+    type Err = String;
+
+    /// Parses the case by its name, or the German abbreviation (Akk, Dat, Gen)
+    fn from_str(c: &str) -> Result<Case, String> {
+        match &*c.trim().to_lowercase() {
+            "accusative" | "akkusativ" | "akk" => Ok(Case::Accusative),
+            "dative" | "dativ" | "dat" => Ok(Case::Dative),
+            "genitive" | "genitiv" | "gen" => Ok(Case::Genitive),
+            _ => Err(format!("Case not matched: {}", c)),
+        }
+    }
+}
+
+impl fmt::Display for Case {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Case::Accusative => write!(f, "Accusative"),
+            Case::Dative => write!(f, "Dative"),
+            Case::Genitive => write!(f, "Genitive"),
+        }
+    }
+}
+
+/// A preposition and the case it takes, in the sentence if it is two-way
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Preposition {
+    pub preposition: String,
+    pub case: Case,
+    pub sentence: Option<String>, // with ___ where the object goes
+    pub answer: Option<String>,   // what fills the ___
+    pub english: String,          // the preposition, or the sentence if there is one
+}
+
+/// Loads the prepositions from the prepositions file, in the order they are listed
+pub fn load(data_dir: &Path) -> Result<Vec<Preposition>, Box<dyn Error>> {
+    let path = data_dir.join(PREPOSITIONS_FILE);
+    if !path.exists() {
+        return Err(format!("No prepositions file: {}", path.display()).into());
+    }
+    let mut rdr = ReaderBuilder::new().has_headers(true).from_path(path)?;
+    let mut prepositions = Vec::new();
+    for result in rdr.records() {
+        let record = result?;
+        let line = record.position().map_or(0, |p| p.line());
+        let field = |i: usize| {
+            record
+                .get(i)
+                .map(str::trim)
+                .ok_or_else(|| format!("Missing column {} on line {}", i + 1, line))
+        };
+        let optional = |i: usize| Some(field(i).ok()?.to_string()).filter(|f| !f.is_empty());
+        prepositions.push(Preposition {
+            preposition: field(0)?.to_string(),
+            case: field(1)?.parse()?,
+            sentence: optional(2),
+            answer: optional(3),
+            english: field(4)?.to_string(),
+        });
+    }
+    Ok(prepositions)
+}
+
+/// Appends the answer to the prepositions history
+pub fn record(
+    data_dir: &Path,
+    preposition: &Preposition,
+    correct: bool,
+) -> Result<(), Box<dyn Error>> {
+    let path = data_dir.join(HISTORY_FILE);
+    let is_new = !path.exists();
+    let file = OpenOptions::new().create(true).append(true).open(path)?;
+    let mut wtr = WriterBuilder::new().has_headers(false).from_writer(file);
+    if is_new {
+        wtr.write_record(HEADERS)?;
+    }
+    wtr.write_record([
+        Local::now().to_rfc3339(),
+        preposition.preposition.clone(),
+        preposition.case.to_string(),
+        correct.to_string(),
+    ])?;
+    wtr.flush()?;
+    Ok(())
+}
+
+/// How many times each preposition was answered right, out of how many, from
+/// the prepositions history
+pub fn stats(data_dir: &Path) -> Result<BTreeMap<String, (u32, u32)>, Box<dyn Error>> {
+    let path = data_dir.join(HISTORY_FILE);
+    let mut stats: BTreeMap<String, (u32, u32)> = BTreeMap::new();
+    if !path.exists() {
+        return Ok(stats);
+    }
+    let mut rdr = ReaderBuilder::new().has_headers(true).from_path(path)?;
+    for result in rdr.records() {
+        let record = result?;
+        let field = |i: usize| record.get(i).unwrap_or_default();
+        let (right, total) = stats.entry(field(1).to_string()).or_default();
+        if field(3).parse::<bool>()? {
+            *right += 1;
+        }
+        *total += 1;
+    }
+    Ok(stats)
+}
@@ -0,0 +1,53 @@
+use crate::conjugation::Conjugation;
+use crate::person::Person;
+use crate::tense::Tense;
+use crate::verb::Verb;
+
+/// What the parts are called, in the order they are asked
+pub const NAMES: [&str; 3] = ["Infinitive", "Partizip II", "Präteritum"];
+
+/// The principal parts of a verb, asked together in the principal parts mode
+#[derive(Debug, Clone)]
+pub struct PrincipalParts {
+    pub prompt: String,     // the infinitive in the source language, e.g. "to drink"
+    pub parts: [String; 3], // e.g. trinken, getrunken, trank
+}
+
+impl PrincipalParts {
+    /// Reads the parts off the verb's ich forms: the infinitive from the
+    /// Future, the Partizip II from the Perfect Present and the Präteritum
+    /// from the Past. None if the verb doesn't have all three.
+    pub fn of(verb: &Verb, conjugations: &[Conjugation]) -> Option<PrincipalParts> {
+        let form = |tense: Tense| {
+            conjugations
+                .iter()
+                .find(|c| *c.verb == *verb && c.tense == tense && c.person == Person::I)
+        };
+        let future = form(Tense::Future)?;
+        let infinitive = future.german.strip_prefix("ich werde ")?;
+        let perfect = &form(Tense::PerfectPresent)?.german;
+        let participle = perfect
+            .strip_prefix("ich habe ")
+            .or_else(|| perfect.strip_prefix("ich bin "))?;
+        let preterite = form(Tense::Past)?.german.strip_prefix("ich ")?;
+
+        // "I will drink" is "to drink", other languages are shown as they are
+        let prompt = match future.prompt.strip_prefix("I will ") {
+            Some(infinitive) => format!("to {}", infinitive),
+            None => future.prompt.clone(),
+        };
+        Some(PrincipalParts {
+            prompt,
+            parts: [
+                infinitive.to_string(),
+                participle.to_string(),
+                preterite.to_string(),
+            ],
+        })
+    }
+
+    /// The parts as one answer, e.g. "trinken, getrunken, trank"
+    pub fn answer(&self) -> String {
+        self.parts.join(", ")
+    }
+}
@@ -0,0 +1,146 @@
+use clap::{Args, ValueEnum};
+use std::{error::Error, fs, path::Path, path::PathBuf};
+
+use crate::conjugation::{self, Conjugation};
+use crate::person::Person;
+use crate::tense::Tense;
+use crate::verb::Verb;
+
+/// The options of the `print` subcommand
+#[derive(Args, Debug)]
+pub struct PrintArgs {
+    /// The verb to print, by name
+    verb: String,
+
+    /// The source language printed next to the German
+    #[arg(short, long, default_value = "English")]
+    from: String,
+
+    /// The tenses to print, every tense if none are given
+    #[arg(short, long, num_args = 1..)]
+    tenses: Vec<String>,
+
+    /// The format to print the table in
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+
+    /// The file to write the table to, instead of printing it. A PDF goes to
+    /// <verb>.pdf if not given
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Aligned columns of plain text
+    #[default]
+    Text,
+    Markdown,
+    /// A typeset A4 page
+    #[cfg(feature = "pdf")]
+    Pdf,
+}
+
+/// The forms of a tense, in the order of the persons
+struct Section<'a> {
+    tense: Tense,
+    forms: Vec<&'a Conjugation>,
+}
+
+/// Prints the conjugation table of a verb, e.g. for a study sheet
+pub fn run(args: PrintArgs, data_dir: &Path) -> Result<(), Box<dyn Error>> {
+    let verb = Verb::find_all(data_dir)?
+        .into_iter()
+        .find(|v| v.name() == args.verb.to_lowercase())
+        .ok_or_else(|| format!("Verb not matched: {}", args.verb))?;
+    let conjugations = conjugation::parse_conjugations(&verb, &args.from)?;
+    let tenses = if args.tenses.is_empty() {
+        Tense::ALL.to_vec()
+    } else {
+        args.tenses
+            .iter()
+            .map(|t| t.parse())
+            .collect::<Result<Vec<Tense>, String>>()?
+    };
+    let sections: Vec<Section> = tenses
+        .into_iter()
+        .map(|tense| Section {
+            tense,
+            forms: Person::ALL
+                .iter()
+                .filter_map(|&person| {
+                    conjugations
+                        .iter()
+                        .find(|c| c.tense == tense && c.person == person)
+                })
+                .collect(),
+        })
+        .filter(|s| !s.forms.is_empty())
+        .collect();
+
+    let output = match args.format {
+        Format::Text => text(verb.name(), &sections).into_bytes(),
+        Format::Markdown => markdown(verb.name(), &args.from, &sections).into_bytes(),
+        #[cfg(feature = "pdf")]
+        Format::Pdf => {
+            let file = args
+                .output
+                .unwrap_or_else(|| PathBuf::from(format!("{}.pdf", verb.name())));
+            fs::write(&file, pdf(verb.name(), &sections))?;
+            println!("Wrote the table of {} to {}", verb.name(), file.display());
+            return Ok(());
+        }
+    };
+    match args.output {
+        Some(file) => fs::write(file, output)?,
+        None => print!("{}", String::from_utf8_lossy(&output)),
+    }
+    Ok(())
+}
+
+/// The table as plain text, the German and source language lined up in
+/// columns
+fn text(verb: &str, sections: &[Section]) -> String {
+    let width = sections
+        .iter()
+        .flat_map(|s| &s.forms)
+        .map(|c| c.german.chars().count())
+        .max()
+        .unwrap_or(0);
+    let mut text = format!("{}\n{}\n", verb, "=".repeat(verb.chars().count()));
+    for section in sections {
+        text += &format!("\n{}\n", section.tense);
+        for c in &section.forms {
+            text += &format!("  {:<2$}  {}\n", c.german, c.prompt, width);
+        }
+    }
+    text
+}
+
+fn markdown(verb: &str, from: &str, sections: &[Section]) -> String {
+    let cell = |s: &str| s.replace('|', "\\|");
+    let mut text = format!("# {}\n", verb);
+    for section in sections {
+        text += &format!(
+            "\n## {}\n\n| German | {} |\n| --- | --- |\n",
+            section.tense, from
+        );
+        for c in &section.forms {
+            text += &format!("| {} | {} |\n", cell(&c.german), cell(&c.prompt));
+        }
+    }
+    text
+}
+
+#[cfg(feature = "pdf")]
+fn pdf(verb: &str, sections: &[Section]) -> Vec<u8> {
+    let mut document = crate::pdf::Document::default();
+    document.title(verb);
+    for section in sections {
+        document.heading(&section.tense.to_string());
+        for c in &section.forms {
+            document.row(&[&c.german, &c.prompt]);
+        }
+    }
+    document.finish()
+}
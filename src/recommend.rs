@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+
+use crate::conjugation::Conjugation;
+use crate::history::Answer;
+use crate::srs::{self, Key};
+
+/// How many forms to recommend practicing at most
+const RECOMMENDATIONS: usize = 3;
+
+/// Only the latest answers to a form count, so forms that have since been
+/// learnt stop being recommended
+const RECENT_ANSWERS: usize = 5;
+
+/// The forms of the conjugations to practice next: the ones most often missed
+/// in their latest answers, worst first, a right answer autocompleted counting
+/// as a miss. Forms that were never missed aren't recommended.
+pub fn recommend(answers: &[Answer], conjugations: &[Conjugation]) -> Vec<Key> {
+    let mut recent: HashMap<Key, Vec<bool>> = conjugations
+        .iter()
+        .map(|conj| (srs::key(conj), Vec::new()))
+        .collect();
+    // newest first, so the first answers to each form are the latest
+    for answer in answers.iter().rev() {
+        let key = (answer.verb.clone(), answer.tense, answer.person);
+        if let Some(correct) = recent.get_mut(&key) {
+            if correct.len() < RECENT_ANSWERS {
+                correct.push(answer.correct_unaided());
+            }
+        }
+    }
+
+    let mut missed: Vec<(Key, usize, usize)> = recent
+        .into_iter()
+        .map(|(key, correct)| {
+            let misses = correct.iter().filter(|&&c| !c).count();
+            (key, misses, correct.len())
+        })
+        .filter(|&(_, misses, _)| misses > 0)
+        .collect();
+    // the highest share of misses first, then the most asked
+    missed.sort_by(|(a_key, a_misses, a_len), (b_key, b_misses, b_len)| {
+        (b_misses * a_len)
+            .cmp(&(a_misses * b_len))
+            .then(b_len.cmp(a_len))
+            .then(a_key.0.cmp(&b_key.0))
+            .then((a_key.1 as u8).cmp(&(b_key.1 as u8)))
+            .then((a_key.2 as u8).cmp(&(b_key.2 as u8)))
+    });
+    missed
+        .into_iter()
+        .take(RECOMMENDATIONS)
+        .map(|(key, _, _)| key)
+        .collect()
+}
@@ -0,0 +1,52 @@
+use chrono::Local;
+use clap::Args;
+use notify_rust::Notification;
+use std::{error::Error, path::Path, thread, time::Duration};
+
+use crate::srs::Srs;
+
+/// The options of the `remind` subcommand
+#[derive(Args, Debug)]
+pub struct RemindArgs {
+    /// If nothing is due yet, wait until something is and notify then (e.g.
+    /// to run in the background from a login script)
+    #[arg(short, long)]
+    wait: bool,
+}
+
+/// Sends a desktop notification if there are forms due for review
+pub fn run(args: RemindArgs, data_dir: &Path) -> Result<(), Box<dyn Error>> {
+    loop {
+        // re-read every time, as practicing in the meantime changes the schedule
+        let srs = Srs::load(data_dir)?;
+        let due = srs.due_count();
+        if due > 0 {
+            Notification::new()
+                .appname("deutschland")
+                .summary("Time to practice German")
+                .body(&match due {
+                    1 => "1 form is due for review".to_string(),
+                    n => format!("{} forms are due for review", n),
+                })
+                .show()?;
+            println!("{} due for review, notified", due);
+            return Ok(());
+        }
+
+        let Some(next) = srs.next_due() else {
+            println!("Nothing to review yet, practice some verbs first");
+            return Ok(());
+        };
+        if !args.wait {
+            println!(
+                "Nothing due for review until {}",
+                next.with_timezone(&Local).format("%Y-%m-%d %H:%M")
+            );
+            return Ok(());
+        }
+        let wait = (next - Local::now().fixed_offset())
+            .to_std()
+            .unwrap_or_default();
+        thread::sleep(wait + Duration::from_secs(1));
+    }
+}
@@ -0,0 +1,378 @@
+use chrono::{Duration, Local, NaiveDate};
+use clap::{Args, ValueEnum};
+use std::{
+    collections::{BTreeMap, HashMap},
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use crate::history::{self, Answer, Confidence};
+use crate::person::Person;
+use crate::tense::Tense;
+use crate::valency;
+
+/// Answers that took longer than this count as this long, as the learner was
+/// most likely away rather than practicing
+const MAX_SECONDS: f64 = 120.0;
+
+/// How many of the weakest cells to list
+const WEAKEST_CELLS: usize = 10;
+
+/// The options of the `report` subcommand
+#[derive(Args, Debug)]
+pub struct ReportArgs {
+    /// How many days back the report covers, including today
+    #[arg(short, long, default_value_t = 7)]
+    days: u32,
+
+    /// The format to write the report in
+    #[arg(short, long, value_enum, default_value_t)]
+    format: Format,
+
+    /// The file to write the report to, instead of printing it
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Open the report in the browser (written to `report.md` or `report.html`
+    /// in the data directory if no --output is given)
+    #[arg(long)]
+    open: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+enum Format {
+    #[default]
+    Markdown,
+    Html,
+}
+
+/// The totals of a group of answers
+#[derive(Debug, Default)]
+struct Stats {
+    answered: u32,
+    partial: u32, // of the answered, those in lessons left before their end
+    correct: u32,
+    seconds: f64,
+}
+
+impl Stats {
+    fn add(&mut self, answer: &Answer) {
+        self.answered += 1;
+        if answer.partial {
+            self.partial += 1;
+        }
+        if answer.correct {
+            self.correct += 1;
+        }
+        self.seconds += answer.seconds.min(MAX_SECONDS);
+    }
+
+    fn accuracy(&self) -> f64 {
+        if self.answered == 0 {
+            0.0
+        } else {
+            100.0 * self.correct as f64 / self.answered as f64
+        }
+    }
+}
+
+/// A cell of the conjugation tables: a verb, tense and person
+type Cell = (String, Tense, Person);
+
+struct Report {
+    from: NaiveDate,
+    to: NaiveDate,
+    total: Stats,
+    sure: Stats,    // the answers tagged as sure, for the calibration
+    guesses: Stats, // the answers tagged as guesses
+    days: BTreeMap<NaiveDate, Stats>,
+    weakest: Vec<(Cell, Stats)>,
+}
+
+/// Runs the `report` subcommand against the data directory
+pub fn run(args: ReportArgs, data_dir: &Path) -> Result<(), Box<dyn Error>> {
+    if args.days == 0 {
+        return Err("The report has to cover at least one day".into());
+    }
+    let report = build(&history::load(data_dir)?, args.days);
+    let text = match args.format {
+        Format::Markdown => markdown(&report),
+        Format::Html => html(&report),
+    };
+
+    let output = match (args.output, args.open) {
+        (Some(output), _) => output,
+        (None, true) => data_dir.join(match args.format {
+            Format::Markdown => "report.md",
+            Format::Html => "report.html",
+        }),
+        (None, false) => {
+            print!("{}", text);
+            return Ok(());
+        }
+    };
+    fs::write(&output, text)?;
+    println!("Wrote the report to {}", output.display());
+    if args.open {
+        open(&output)?;
+    }
+    Ok(())
+}
+
+/// The report of the last `days` days in markdown, e.g. for the stats screen
+pub fn summary(data_dir: &Path, days: u32) -> Result<String, Box<dyn Error>> {
+    let mut md = markdown(&build(&history::load(data_dir)?, days));
+    let from = Local::now().date_naive() - Duration::days(days as i64 - 1);
+    md += &verb_cases(&valency::stats(data_dir, Some(from))?);
+    Ok(md)
+}
+
+/// The verbs of the case quiz answered in the report's days, the most missed
+/// first, or nothing if none were
+fn verb_cases(stats: &BTreeMap<String, (u32, u32)>) -> String {
+    if stats.is_empty() {
+        return String::new();
+    }
+    let mut verbs: Vec<(&String, &(u32, u32))> = stats.iter().collect();
+    // the lowest share right first
+    verbs.sort_by(|(_, (a_right, a_total)), (_, (b_right, b_total))| {
+        (a_right * b_total).cmp(&(b_right * a_total))
+    });
+    verbs.truncate(WEAKEST_CELLS);
+    let mut md =
+        String::from("\n## Verb cases\n\n| Verb | Answered | Accuracy |\n| --- | ---: | ---: |\n");
+    for (verb, (right, total)) in verbs {
+        md += &format!(
+            "| {} | {} | {:.0}% |\n",
+            verb,
+            total,
+            100.0 * *right as f64 / *total as f64
+        );
+    }
+    md
+}
+
+/// Totals the answers of the last `days` days
+fn build(answers: &[Answer], days: u32) -> Report {
+    let to = Local::now().date_naive();
+    let from = to - Duration::days(days as i64 - 1);
+
+    let mut total = Stats::default();
+    let mut sure = Stats::default();
+    let mut guesses = Stats::default();
+    let mut by_day: BTreeMap<NaiveDate, Stats> = BTreeMap::new();
+    let mut by_cell: HashMap<Cell, Stats> = HashMap::new();
+    for answer in answers {
+        let day = answer.time.with_timezone(&Local).date_naive();
+        if day < from || day > to {
+            continue;
+        }
+        total.add(answer);
+        match answer.confidence {
+            Some(Confidence::Sure) => sure.add(answer),
+            Some(Confidence::Guess) => guesses.add(answer),
+            None => {}
+        }
+        by_day.entry(day).or_default().add(answer);
+        by_cell
+            .entry((answer.verb.clone(), answer.tense, answer.person))
+            .or_default()
+            .add(answer);
+    }
+
+    // the cells answered wrong most often, relative to how often they were asked
+    let mut weakest: Vec<(Cell, Stats)> = by_cell
+        .into_iter()
+        .filter(|(_, stats)| stats.correct < stats.answered)
+        .collect();
+    weakest.sort_by(|(a_cell, a), (b_cell, b)| {
+        a.accuracy()
+            .total_cmp(&b.accuracy())
+            .then(b.answered.cmp(&a.answered))
+            .then(a_cell.0.cmp(&b_cell.0))
+            .then((a_cell.1 as u8).cmp(&(b_cell.1 as u8)))
+            .then((a_cell.2 as u8).cmp(&(b_cell.2 as u8)))
+    });
+    weakest.truncate(WEAKEST_CELLS);
+
+    Report {
+        from,
+        to,
+        total,
+        sure,
+        guesses,
+        days: by_day,
+        weakest,
+    }
+}
+
+/// e.g. 1h 5m, or 42s when under a minute
+fn format_duration(seconds: f64) -> String {
+    let seconds = seconds.round() as u64;
+    match (seconds / 3600, seconds / 60 % 60) {
+        (0, 0) => format!("{}s", seconds),
+        (0, m) => format!("{}m", m),
+        (h, m) => format!("{}h {}m", h, m),
+    }
+}
+
+fn markdown(report: &Report) -> String {
+    let mut md = format!("# Review from {} to {}\n\n", report.from, report.to);
+    if report.total.answered == 0 {
+        md += "No questions were answered.\n";
+        return md;
+    }
+
+    md += &format!(
+        "- Answered: {}\n- Accuracy: {:.0}%\n- Time practiced: {}\n- Days practiced: {}\n",
+        report.total.answered,
+        report.total.accuracy(),
+        format_duration(report.total.seconds),
+        report.days.len()
+    );
+    if report.total.partial > 0 {
+        md += &format!("- In lessons left early: {}\n", report.total.partial);
+    }
+    if let Some(calibration) = calibration(report) {
+        md += &format!("- Calibration: {}\n", calibration);
+    }
+
+    md += "\n## Accuracy by day\n\n| Day | Answered | Left early | Accuracy | Time |\n| --- | ---: | ---: | ---: | ---: |\n";
+    for (day, stats) in &report.days {
+        md += &format!(
+            "| {} | {} | {} | {:.0}% | {} |\n",
+            day.format("%a %Y-%m-%d"),
+            stats.answered,
+            stats.partial,
+            stats.accuracy(),
+            format_duration(stats.seconds)
+        );
+    }
+
+    md += "\n## Weakest cells\n\n";
+    if report.weakest.is_empty() {
+        md += "Every question was answered correctly.\n";
+        return md;
+    }
+    md += "| Verb | Tense | Person | Answered | Accuracy |\n| --- | --- | --- | ---: | ---: |\n";
+    for ((verb, tense, person), stats) in &report.weakest {
+        md += &format!(
+            "| {} | {} | {} | {} | {:.0}% |\n",
+            verb,
+            tense,
+            person,
+            stats.answered,
+            stats.accuracy()
+        );
+    }
+    md
+}
+
+fn html(report: &Report) -> String {
+    let mut body = format!("<h1>Review from {} to {}</h1>\n", report.from, report.to);
+    if report.total.answered == 0 {
+        body += "<p>No questions were answered.</p>\n";
+    } else {
+        body += &format!(
+            "<ul>\n<li>Answered: {}</li>\n<li>Accuracy: {:.0}%</li>\n<li>Time practiced: {}</li>\n<li>Days practiced: {}</li>\n{}{}</ul>\n",
+            report.total.answered,
+            report.total.accuracy(),
+            format_duration(report.total.seconds),
+            report.days.len(),
+            match report.total.partial {
+                0 => String::new(),
+                n => format!("<li>In lessons left early: {}</li>\n", n),
+            },
+            calibration(report)
+                .map(|c| format!("<li>Calibration: {}</li>\n", c))
+                .unwrap_or_default()
+        );
+
+        body += "<h2>Accuracy by day</h2>\n<table>\n<tr><th>Day</th><th>Answered</th><th>Left early</th><th>Accuracy</th><th>Time</th></tr>\n";
+        for (day, stats) in &report.days {
+            // the bar makes the trend visible at a glance
+            body += &format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td><meter min=\"0\" max=\"100\" value=\"{:.0}\"></meter> {:.0}%</td><td>{}</td></tr>\n",
+                day.format("%a %Y-%m-%d"),
+                stats.answered,
+                stats.partial,
+                stats.accuracy(),
+                stats.accuracy(),
+                format_duration(stats.seconds)
+            );
+        }
+        body += "</table>\n";
+
+        body += "<h2>Weakest cells</h2>\n";
+        if report.weakest.is_empty() {
+            body += "<p>Every question was answered correctly.</p>\n";
+        } else {
+            body += "<table>\n<tr><th>Verb</th><th>Tense</th><th>Person</th><th>Answered</th><th>Accuracy</th></tr>\n";
+            for ((verb, tense, person), stats) in &report.weakest {
+                body += &format!(
+                    "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{:.0}%</td></tr>\n",
+                    escape(verb),
+                    tense,
+                    escape(&person.to_string()),
+                    stats.answered,
+                    stats.accuracy()
+                );
+            }
+            body += "</table>\n";
+        }
+    }
+
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>Deutschland review</title>\n<style>\nbody {{ font-family: sans-serif; max-width: 50em; margin: 2em auto; }}\ntable {{ border-collapse: collapse; }}\nth, td {{ padding: 0.3em 0.8em; border-bottom: 1px solid #ccc; text-align: left; }}\n</style>\n</head>\n<body>\n{}</body>\n</html>\n",
+        body
+    )
+}
+
+/// How often the answers tagged as sure (and as guesses) were right, if any
+/// were tagged
+fn calibration(report: &Report) -> Option<String> {
+    let part = |what: &str, stats: &Stats| {
+        format!(
+            "{} right {:.0}% of {}",
+            what,
+            stats.accuracy(),
+            stats.answered
+        )
+    };
+    match (report.sure.answered, report.guesses.answered) {
+        (0, 0) => None,
+        (_, 0) => Some(part("sure", &report.sure)),
+        (0, _) => Some(part("guesses", &report.guesses)),
+        _ => Some(format!(
+            "{}, {}",
+            part("sure", &report.sure),
+            part("guesses", &report.guesses)
+        )),
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Opens the file with the desktop's default program for it
+fn open(path: &Path) -> Result<(), Box<dyn Error>> {
+    let mut command = if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else if cfg!(windows) {
+        let mut command = Command::new("cmd");
+        command.args(["/C", "start", ""]);
+        command
+    } else {
+        Command::new("xdg-open")
+    };
+    let status = command.arg(path).status()?;
+    if !status.success() {
+        return Err(format!("Could not open {}", path.display()).into());
+    }
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use std::time::{Duration, Instant};
+
+/// The shortest time between two frames, capping redraws at 30 a second
+/// however fast the events come in (pasting, or holding a key down)
+const FRAME: Duration = Duration::from_nanos(1_000_000_000 / 30);
+
+/// Decides when the screen is drawn: only once something changed, and no
+/// more often than the frame rate cap. In between the app sleeps on the
+/// terminal's events.
+#[derive(Debug, Clone)]
+pub struct Scheduler {
+    pending: bool, // if something changed since the last frame
+    drawn_at: Option<Instant>,
+}
+
+impl Default for Scheduler {
+    fn default() -> Self {
+        // the first frame is drawn straight away
+        Scheduler {
+            pending: true,
+            drawn_at: None,
+        }
+    }
+}
+
+impl Scheduler {
+    /// Asks for a frame, drawn as soon as the cap allows
+    pub fn request(&mut self) {
+        self.pending = true;
+    }
+
+    /// Whether a frame should be drawn now
+    pub fn due(&self) -> bool {
+        self.pending && self.drawn_at.is_none_or(|at| at.elapsed() >= FRAME)
+    }
+
+    pub fn drawn(&mut self) {
+        self.pending = false;
+        self.drawn_at = Some(Instant::now());
+    }
+
+    /// How long to wait for an event: until the next frame if one was asked
+    /// for, else until `wake_up` if the app has to, or for as long as it
+    /// takes (None) when idle
+    pub fn timeout(&self, wake_up: Option<Duration>) -> Option<Duration> {
+        let frame = self.pending.then(|| {
+            let since = self.drawn_at.map_or(FRAME, |at| at.elapsed());
+            FRAME.saturating_sub(since)
+        });
+        match (frame, wake_up) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
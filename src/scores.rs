@@ -0,0 +1,73 @@
+use chrono::{DateTime, Local};
+use csv::{ReaderBuilder, WriterBuilder};
+use std::{error::Error, fs::OpenOptions, path::Path};
+
+/// The score of every lesson finished, in `<data_dir>/scores.csv`
+const SCORES_FILE: &str = "scores.csv";
+
+const HEADERS: [&str; 6] = ["Time", "Mode", "Verbs", "Score", "Correct", "Answered"];
+
+/// The result of a lesson
+#[derive(Debug, Clone, PartialEq)]
+pub struct Score {
+    pub time: DateTime<Local>,
+    pub mode: String, // e.g. "translate", or "translate, arcade" with --arcade
+    pub verbs: String,
+    pub score: u32, // the arcade score, or else the right answers
+    pub correct: u32,
+    pub answered: u32,
+}
+
+impl Score {
+    /// The share of the answers that were right, from 0 to 1
+    pub fn accuracy(&self) -> f64 {
+        match self.answered {
+            0 => 0.0,
+            n => f64::from(self.correct) / f64::from(n),
+        }
+    }
+}
+
+/// Adds the score of a lesson just finished to the scores file
+pub fn record(data_dir: &Path, score: &Score) -> Result<(), Box<dyn Error>> {
+    let path = data_dir.join(SCORES_FILE);
+    let is_new = !path.exists();
+    let file = OpenOptions::new().create(true).append(true).open(path)?;
+    let mut wtr = WriterBuilder::new().has_headers(false).from_writer(file);
+    if is_new {
+        wtr.write_record(HEADERS)?;
+    }
+    wtr.write_record([
+        score.time.to_rfc3339(),
+        score.mode.clone(),
+        score.verbs.clone(),
+        score.score.to_string(),
+        score.correct.to_string(),
+        score.answered.to_string(),
+    ])?;
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Every score recorded, oldest first
+pub fn load(data_dir: &Path) -> Result<Vec<Score>, Box<dyn Error>> {
+    let path = data_dir.join(SCORES_FILE);
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    let mut rdr = ReaderBuilder::new().has_headers(true).from_path(path)?;
+    let mut scores = Vec::new();
+    for result in rdr.records() {
+        let record = result?;
+        let field = |i: usize| record.get(i).unwrap_or_default();
+        scores.push(Score {
+            time: DateTime::parse_from_rfc3339(field(0))?.with_timezone(&Local),
+            mode: field(1).to_string(),
+            verbs: field(2).to_string(),
+            score: field(3).parse()?,
+            correct: field(4).parse()?,
+            answered: field(5).parse()?,
+        });
+    }
+    Ok(scores)
+}
@@ -0,0 +1,480 @@
+use std::io::{self, BufRead, Write};
+
+use crate::config::HeaderHints;
+use crate::conjugation::Conjugation;
+use crate::history::Confidence;
+use crate::lesson::Lesson;
+use crate::mode::Mode;
+use crate::{choice_label, ending, hangman, morphology, principal_parts, App};
+
+/// Runs the lessons as plain lines of text on stdin/stdout, for terminal
+/// screen readers: no box drawing, every value labelled, and nothing redrawn.
+pub fn run(app: &mut App) -> io::Result<()> {
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+
+    loop {
+        app.perform_pending();
+        if let Some(plan) = app.plan.as_mut() {
+            if let Some(intro) = plan.intro() {
+                println!();
+                println!("{}, {}.", plan.title, plan.position());
+                println!("{}", intro);
+                match ask(
+                    "Press Enter to continue, or type leave to leave the plan:",
+                    &mut lines,
+                )? {
+                    None => return Ok(()),
+                    Some(choice) if choice == "leave" => app.leave_plan(),
+                    Some(_) => {
+                        plan.intro_read = true;
+                        app.take_step();
+                    }
+                }
+                continue;
+            }
+        }
+        if let Some(marathon) = app.marathon.as_ref().filter(|m| m.done) {
+            println!();
+            for line in marathon.summary() {
+                println!("{}.", line);
+            }
+            for score in marathon.weakest_first() {
+                println!(
+                    "{}: {} correct out of {}.",
+                    score.verb, score.correct, score.answered
+                );
+            }
+            app.marathon = None;
+            continue;
+        }
+        if app.lesson.is_none() && !select_verb(app, &mut lines)? {
+            return Ok(());
+        }
+
+        while app.exit.is_none() {
+            // e.g. saying the question, or running the hook of the answer
+            app.perform_pending();
+            let conj = app.conjugations.get(app.cur_conjugation).unwrap();
+            println!();
+            let mut header = match app.time_left() {
+                Some(left) => format!(
+                    "Question {}, {}:{:02} left.",
+                    app.cur_question + 1,
+                    left.as_secs() / 60,
+                    left.as_secs() % 60
+                ),
+                None => format!(
+                    "Question {} of {}.",
+                    app.cur_question + 1,
+                    app.total_questions
+                ),
+            };
+            let hints = app.header_hints();
+            if hints != HeaderHints::Hidden {
+                header += &format!(" Verb: {}.", conj.verb);
+            }
+            if matches!(hints, HeaderHints::All | HeaderHints::NoPerson) {
+                match app.auxiliary_hint() {
+                    Some(auxiliary) => {
+                        header += &format!(" Tense: {}, with {}.", conj.tense, auxiliary)
+                    }
+                    None => header += &format!(" Tense: {}.", conj.tense),
+                }
+            }
+            if hints == HeaderHints::All {
+                header += &format!(" Person: {}.", conj.person);
+            }
+            if app.warming_up {
+                header += " Warm-up review of a missed form.";
+            }
+            if let Some(arcade) = &app.arcade {
+                header += &format!(
+                    " Score: {}, multiplier {}.",
+                    arcade.score,
+                    arcade.multiplier()
+                );
+            }
+            println!("{}", header);
+            match app.mode {
+                Mode::Translate => println!("{}: {}", app.from, prompt_with_hint(conj)),
+                Mode::Recognize => {
+                    println!("German: {}", conj.german);
+                    println!("Which tense and person is it?");
+                    for (n, &i) in app.choices.iter().enumerate() {
+                        println!("{}. {}", n + 1, choice_label(&app.conjugations[i]));
+                    }
+                }
+                #[cfg(feature = "audio")]
+                Mode::Dictation => {
+                    println!("Type the German you hear (or replay to hear it again).")
+                }
+                Mode::PrincipalParts => {
+                    let prompt = app.principal_parts().map(|p| p.prompt).unwrap_or_default();
+                    println!("{}: {}", app.from, prompt);
+                    println!(
+                        "Give the {}, separated by commas.",
+                        principal_parts::NAMES.join(", ")
+                    );
+                }
+                Mode::Table => {
+                    println!("Fill in the table of {} in the {}:", conj.verb, conj.tense);
+                    let mut blanks = Vec::new();
+                    for cell in &app.grid.cells {
+                        let form = &app.conjugations[cell.conjugation];
+                        if cell.given {
+                            println!("{}: {}", form.person, form.german);
+                        } else {
+                            println!("{}: blank", form.person);
+                            blanks.push(form.person.to_string());
+                        }
+                    }
+                    println!(
+                        "Give the forms for {}, separated by commas.",
+                        blanks.join(", ")
+                    );
+                }
+                Mode::Ending => {
+                    println!("{}: {}", app.from, prompt_with_hint(conj));
+                    if let Some(blank) = ending::blank(conj) {
+                        println!(
+                            "German, with an underscore for the ending: {}_{}",
+                            blank.before, blank.after
+                        );
+                    }
+                    let endings: Vec<String> = app
+                        .endings
+                        .iter()
+                        .enumerate()
+                        .map(|(n, ending)| format!("{}. {}", n + 1, ending))
+                        .collect();
+                    println!("Give the ending, or its number: {}.", endings.join(", "));
+                }
+                Mode::Assemble => {
+                    println!("{}: {}", app.from, prompt_with_hint(conj));
+                    let words: Vec<String> = app
+                        .words
+                        .iter()
+                        .enumerate()
+                        .map(|(n, word)| format!("{}. {}", n + 1, word))
+                        .collect();
+                    println!("Words: {}.", words.join(", "));
+                    println!("Put the words in order, or their numbers separated by spaces.");
+                }
+                Mode::Hangman => {
+                    println!("{}: {}", app.from, prompt_with_hint(conj));
+                    let masked = hangman::masked(&conj.german, &app.guesses);
+                    println!(
+                        "German, with an underscore for each hidden letter: {}",
+                        masked
+                    );
+                    let misses: Vec<String> = hangman::misses(&conj.german, &app.guesses)
+                        .iter()
+                        .map(char::to_string)
+                        .collect();
+                    println!(
+                        "Wrong guesses: {} ({} left). Guess a letter.",
+                        if misses.is_empty() {
+                            "none".to_string()
+                        } else {
+                            misses.join(", ")
+                        },
+                        app.guesses_left()
+                    );
+                }
+                // each question of a mixed lesson is one of the others
+                Mode::Mixed => {}
+            }
+            if let Some(note) = app.notes.get(conj) {
+                println!("Note: {}", note);
+            }
+
+            let Some(response) = ask(
+                "Your answer, after sure: or guess: to tag how sure you are (or skip, or quit):",
+                &mut lines,
+            )?
+            else {
+                return Ok(());
+            };
+            let (confidence, response) = match response.split_once(':') {
+                Some(("sure", response)) => (Some(Confidence::Sure), response.trim().to_string()),
+                Some(("guess", response)) => (Some(Confidence::Guess), response.trim().to_string()),
+                _ => (None, response),
+            };
+            app.confidence = confidence;
+            if response == "quit" {
+                return Ok(());
+            }
+            if response == "skip" {
+                app.skip();
+            }
+            #[cfg(feature = "audio")]
+            if app.mode == Mode::Dictation && response == "replay" {
+                app.speak();
+                continue;
+            }
+            match app.mode {
+                _ if app.skipped => {}
+                Mode::Recognize => match response.parse::<usize>() {
+                    Ok(n) if (1..=app.choices.len()).contains(&n) => app.choose(n - 1),
+                    _ => println!("Answer with a number from 1 to {}.", app.choices.len()),
+                },
+                Mode::PrincipalParts => {
+                    for (answer, part) in app.part_responses.iter_mut().zip(response.split(',')) {
+                        *answer = part.trim().to_string();
+                    }
+                    app.submit_parts();
+                }
+                Mode::Table => {
+                    app.grid.fill(&response);
+                    app.submit_grid();
+                }
+                Mode::Hangman => {
+                    for c in response.chars() {
+                        app.guess(c);
+                    }
+                }
+                Mode::Ending => {
+                    let picked = response
+                        .parse::<usize>()
+                        .ok()
+                        .and_then(|n| app.endings.get(n.checked_sub(1)?));
+                    app.cur_response = picked.cloned().unwrap_or(response);
+                    app.check_answer();
+                }
+                Mode::Assemble => {
+                    let numbers: Option<Vec<usize>> = response
+                        .split_whitespace()
+                        .map(|n| n.parse::<usize>().ok()?.checked_sub(1))
+                        .collect();
+                    match numbers {
+                        Some(numbers) if !numbers.is_empty() => {
+                            for word in numbers {
+                                app.place(word);
+                            }
+                        }
+                        _ => app.cur_response = response,
+                    }
+                    if app.cur_response_incorrect.is_none() {
+                        app.check_answer();
+                    }
+                }
+                _ => {
+                    app.cur_response = response;
+                    app.check_answer();
+                }
+            }
+
+            let conj = app.conjugations.get(app.cur_conjugation).unwrap();
+            match app.cur_response_incorrect {
+                Some(false) => match &app.cheer {
+                    Some(cheer) => println!("Correct. {}", cheer),
+                    None => println!("Correct."),
+                },
+                Some(true) if app.skipped => match app.mode {
+                    Mode::Recognize => println!("Skipped. The answer is: {}", choice_label(conj)),
+                    Mode::PrincipalParts => {
+                        println!("Skipped. The parts are: {}", app.expected_answer())
+                    }
+                    Mode::Table => println!("Skipped. The forms are: {}", app.expected_answer()),
+                    _ => println!("Skipped. The German is: {}", conj.german),
+                },
+                Some(true) if app.mode == Mode::Recognize => {
+                    println!("Incorrect. The correct answer is: {}", choice_label(conj));
+                }
+                Some(true) if app.mode == Mode::Table => {
+                    println!("Incorrect. The forms are:");
+                    for cell in app.grid.blanks() {
+                        let form = &app.conjugations[cell.conjugation];
+                        let mark = if cell.correct == Some(true) {
+                            "right"
+                        } else {
+                            "wrong"
+                        };
+                        println!("{}: {} ({})", form.person, form.german, mark);
+                    }
+                }
+                Some(true) if app.mode == Mode::PrincipalParts => {
+                    println!(
+                        "Incorrect. The correct parts are: {}",
+                        app.expected_answer()
+                    );
+                }
+                Some(true) => {
+                    println!("Incorrect. The correct German is: {}", conj.german);
+                    println!(
+                        "Structure: {}",
+                        morphology::plain(&morphology::analyze(conj))
+                    );
+                    if app.layout_mixup {
+                        println!("This looks like a keyboard layout mix-up (QWERTZ/QWERTY).");
+                    }
+                }
+                // nothing was entered, ask again
+                None => continue,
+            }
+            if let Some(ipa) = conj.ipa.as_ref().filter(|_| app.config.show_ipa) {
+                println!("Pronunciation: /{}/", ipa.trim_matches('/'));
+            }
+            if let Some(parts) = conj.stressed_parts().filter(|_| app.config.show_stress) {
+                let stressed: Vec<&str> = parts.iter().filter(|p| p.1).map(|p| p.0).collect();
+                println!("Stressed: {}", stressed.join(", "));
+            }
+            if let Some(example) = &conj.example_de {
+                println!("Example: {}", example);
+            }
+            if let Some(example) = &conj.example_en {
+                println!("Example translation: {}", example);
+            }
+            app.next_question();
+        }
+        app.perform_pending();
+
+        println!();
+        println!(
+            "Lesson completed. You got {} correct out of {}, skipped {}. Seed: {}.",
+            app.total_correct,
+            app.answered(),
+            app.total_skipped,
+            app.seed
+        );
+        for line in app.calibration_lines() {
+            println!("{}.", line);
+        }
+        if let Some(line) = app.arcade_line() {
+            println!("{}.", line);
+        }
+        if let Some(line) = app.curriculum_line() {
+            println!("{}.", line);
+        }
+        if let Some(line) = app.submitted_line() {
+            println!("{}.", line);
+        }
+        if let Some(line) = app.marathon_line() {
+            println!("{}.", line);
+            let Some(choice) = ask(
+                "Press Enter to go on, or type stop to stop the marathon:",
+                &mut lines,
+            )?
+            else {
+                return Ok(());
+            };
+            match choice.as_str() {
+                "stop" => app.finish_marathon(),
+                _ => app.continue_marathon(),
+            }
+            continue;
+        }
+        if let Some(line) = app.plan_line() {
+            println!("{}.", line);
+            let Some(choice) = ask(
+                "Press Enter to go on, or type leave to leave the plan:",
+                &mut lines,
+            )?
+            else {
+                return Ok(());
+            };
+            match choice.as_str() {
+                "leave" => app.leave_plan(),
+                _ => app.continue_plan(),
+            }
+            continue;
+        }
+        let mut prompt =
+            "Press Enter to attempt again, type new to start a new lesson, or quit:".to_string();
+        if !app.recommended.is_empty() {
+            let forms: Vec<String> = app
+                .recommended
+                .iter()
+                .map(|(verb, tense, person)| format!("{} {} {}", verb, tense, person))
+                .collect();
+            println!("Practice next: {}.", forms.join(", "));
+            prompt = "Press Enter to attempt again, type practice to practice these, new to start a new lesson, or quit:".to_string();
+        }
+        let Some(choice) = ask(&prompt, &mut lines)? else {
+            return Ok(());
+        };
+        match choice.as_str() {
+            "quit" => return Ok(()),
+            "practice" if !app.recommended.is_empty() => app.practice_recommended(),
+            "new" => app.restart(true),
+            _ => app.restart(false),
+        }
+    }
+}
+
+/// Lists the verbs and starts a lesson on the chosen ones, by number or name
+/// (several separated by commas). Returns false if the user quit.
+fn select_verb(
+    app: &mut App,
+    lines: &mut impl Iterator<Item = io::Result<String>>,
+) -> io::Result<bool> {
+    println!();
+    println!("Verbs:");
+    for (i, verb) in app.verbs.iter().enumerate() {
+        match verb.pack() {
+            Some(pack) => println!("{}. {} (from pack {})", i + 1, verb.name(), pack),
+            None => println!("{}. {}", i + 1, verb.name()),
+        }
+    }
+
+    'choose: loop {
+        let Some(choice) = ask(
+            "Select verbs by number or name, separated by commas (or quit):",
+            lines,
+        )?
+        else {
+            return Ok(false);
+        };
+        if choice == "quit" {
+            return Ok(false);
+        }
+        let mut names = Vec::new();
+        for choice in choice.split(',').map(str::trim).filter(|c| !c.is_empty()) {
+            let verb = match choice.parse::<usize>() {
+                Ok(i) => i.checked_sub(1).and_then(|i| app.verbs.get(i)),
+                Err(_) => app.verbs.iter().find(|v| v.name() == choice.to_lowercase()),
+            };
+            match verb {
+                Some(verb) => names.push(verb.name().to_string()),
+                None => {
+                    println!("There is no verb {}.", choice);
+                    continue 'choose;
+                }
+            }
+        }
+        if names.is_empty() {
+            continue;
+        }
+        let mut lesson = Lesson::new(names);
+        lesson.tenses = app.lesson_tenses();
+        let name = lesson.name();
+        if let Err(e) = app.start_lesson(lesson) {
+            println!("{}", e);
+            continue;
+        }
+        println!("Starting a lesson on {}.", name);
+        return Ok(true);
+    }
+}
+
+/// Prints the prompt and reads a trimmed line, None at the end of the input
+fn ask(
+    prompt: &str,
+    lines: &mut impl Iterator<Item = io::Result<String>>,
+) -> io::Result<Option<String>> {
+    println!("{}", prompt);
+    io::stdout().flush()?;
+    match lines.next() {
+        Some(line) => Ok(Some(line?.trim().to_string())),
+        None => Ok(None),
+    }
+}
+
+/// The prompt of the conjugation, followed by its hint if it has one
+fn prompt_with_hint(conj: &Conjugation) -> String {
+    match conj.prompt_hint() {
+        Some(hint) => format!("{} {}", conj.prompt, hint),
+        None => conj.prompt.clone(),
+    }
+}
@@ -0,0 +1,133 @@
+use clap::Parser;
+use insta::assert_snapshot;
+use ratatui::{
+    backend::TestBackend,
+    crossterm::event::{KeyCode, KeyEvent},
+    Terminal,
+};
+use std::fs;
+
+use crate::config::Config;
+use crate::paths::Dirs;
+use crate::setup;
+use crate::stats::Stats;
+use crate::{App, Args};
+
+/// The sizes every screen is rendered at: a small terminal, the usual one
+/// and a large one
+const SIZES: [(u16, u16); 3] = [(60, 20), (80, 24), (120, 40)];
+
+/// The report the stats screen is shown with, as the real one has the dates
+/// of the last week
+const REPORT: &str = "\
+# Review from 2024-03-04 to 2024-03-10
+
+- Answered: 24
+- Accuracy: 75%
+- Time practiced: 6m 30s
+- Days practiced: 2
+
+## Accuracy by day
+
+| Day | Answered | Left early | Accuracy | Time |
+| --- | ---: | ---: | ---: | ---: |
+| Mon 2024-03-04 | 10 | 0 | 70% | 2m 40s |
+| Sun 2024-03-10 | 14 | 0 | 79% | 3m 50s |
+
+## Weakest cells
+
+| Verb | Tense | Person | Answered | Accuracy |
+| --- | --- | --- | ---: | ---: |
+| essen | Past | He/she/it | 3 | 33% |
+";
+
+/// The app as launched with the arguments, on a data directory of its own
+/// with just the starter verbs, and the questions in a fixed order
+pub fn app(test: &str, args: &[&str]) -> App {
+    let dir = std::env::temp_dir().join(format!("deutschland-screens-{}", test));
+    let _ = fs::remove_dir_all(&dir);
+    let dirs = Dirs {
+        data: dir.clone(),
+        config: dir,
+    };
+    setup::install(&dirs).unwrap();
+    let args = Args::parse_from(["deutschland", "--seed", "1"].iter().chain(args));
+    let mut app = App::new(&args, &dirs, Config::default()).unwrap();
+    // as the loop does before it first waits for a key
+    app.perform_pending();
+    app
+}
+
+/// Types the response and submits it
+fn answer(app: &mut App, response: &str) {
+    for c in response.chars() {
+        app.handle_key_event(KeyEvent::from(KeyCode::Char(c)));
+    }
+    app.handle_key_event(KeyEvent::from(KeyCode::Enter));
+}
+
+/// Checks the screen against its snapshot at every size, once what the keys
+/// asked for is carried out
+fn assert_screen(name: &str, app: &mut App) {
+    app.perform_pending();
+    for (width, height) in SIZES {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal.draw(|frame| app.draw(frame)).unwrap();
+        assert_snapshot!(format!("{}_{}x{}", name, width, height), terminal.backend());
+    }
+}
+
+/// The name of the snapshots of a screen showing the keys to copy, which
+/// only the builds with the clipboard feature have
+fn copying(name: &str) -> String {
+    if cfg!(feature = "clipboard") {
+        format!("{}_clipboard", name)
+    } else {
+        name.to_string()
+    }
+}
+
+#[test]
+fn verb_select() {
+    let mut app = app("verb_select", &[]);
+    app.home = None;
+    assert_screen("verb_select", &mut app);
+}
+
+#[test]
+fn question() {
+    let mut app = app("question", &["--verb", "gehen"]);
+    assert_screen("question", &mut app);
+}
+
+#[test]
+fn correct() {
+    let mut app = app("correct", &["--verb", "gehen"]);
+    let expected = app.expected_answer();
+    answer(&mut app, &expected);
+    assert_screen(&copying("correct"), &mut app);
+}
+
+#[test]
+fn incorrect() {
+    let mut app = app("incorrect", &["--verb", "gehen"]);
+    answer(&mut app, "falsch");
+    assert_screen(&copying("incorrect"), &mut app);
+}
+
+#[test]
+fn summary() {
+    let mut app = app("summary", &["--verb", "gehen", "--number", "3"]);
+    for _ in 0..3 {
+        answer(&mut app, "x");
+        app.handle_key_event(KeyEvent::from(KeyCode::Enter));
+    }
+    assert_screen(&copying("summary"), &mut app);
+}
+
+#[test]
+fn stats() {
+    let mut app = app("stats", &[]);
+    app.stats = Some(Stats::new(REPORT.to_string()));
+    assert_screen("stats", &mut app);
+}
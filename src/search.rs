@@ -0,0 +1,197 @@
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Alignment, Rect},
+    style::{Style, Styled, Stylize},
+    symbols::border,
+    text::{Line, Text},
+    widgets::{
+        block::{Position, Title},
+        Block, Paragraph, Widget,
+    },
+};
+
+use crate::person::Person;
+use crate::tense::Tense;
+use crate::theme::Theme;
+
+/// How many characters have to be typed before searching, as one letter
+/// matches nearly everything
+const MIN_QUERY: usize = 2;
+
+/// Where a match comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// A form of a verb, by the index of the verb in the verbs table
+    Form {
+        verb: usize,
+        tense: Tense,
+        person: Person,
+    },
+    Noun,
+    Preposition,
+    /// A word of a vocabulary deck
+    Word,
+}
+
+/// A German string and its translation, searched through
+pub struct Entry {
+    pub german: String,
+    pub english: String,
+    pub source: Source,
+    pub label: String, // where it is from, e.g. "gehen, Present"
+}
+
+/// What the search wants done after a key press
+pub enum SearchEvent {
+    None,
+    Close,
+    /// Show the verb in the tense, picking out the person's form
+    Open {
+        verb: usize,
+        tense: Tense,
+        person: Person,
+    },
+}
+
+/// The screen searching the German and translations of all the data loaded:
+/// the verbs' forms, nouns, prepositions and vocabulary
+pub struct Search {
+    entries: Vec<Entry>,
+    query: String,
+    matches: Vec<usize>, // the entries matching the query, in the order they were loaded
+    selected: usize,
+}
+
+impl Search {
+    pub fn new(entries: Vec<Entry>) -> Search {
+        Search {
+            entries,
+            query: String::new(),
+            matches: vec![],
+            selected: 0,
+        }
+    }
+
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> SearchEvent {
+        match key_event.code {
+            KeyCode::Esc => return SearchEvent::Close,
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down => {
+                self.selected = (self.selected + 1).min(self.matches.len().saturating_sub(1))
+            }
+            KeyCode::Enter => {
+                let source = self
+                    .matches
+                    .get(self.selected)
+                    .map(|&i| &self.entries[i].source);
+                if let Some(&Source::Form {
+                    verb,
+                    tense,
+                    person,
+                }) = source
+                {
+                    return SearchEvent::Open {
+                        verb,
+                        tense,
+                        person,
+                    };
+                }
+            }
+            KeyCode::Backspace => {
+                self.query.pop();
+                self.find();
+            }
+            KeyCode::Char(c) => {
+                self.query.push(c);
+                self.find();
+            }
+            _ => {}
+        }
+        SearchEvent::None
+    }
+
+    /// Finds the entries with the query in their German or translation,
+    /// ignoring case
+    fn find(&mut self) {
+        self.selected = 0;
+        let query = self.query.trim().to_lowercase();
+        if query.chars().count() < MIN_QUERY {
+            self.matches.clear();
+            return;
+        }
+        self.matches = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| {
+                e.german.to_lowercase().contains(&query)
+                    || e.english.to_lowercase().contains(&query)
+            })
+            .map(|(i, _)| i)
+            .collect();
+    }
+
+    pub fn render(&self, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        let title = Title::from(" Search ".bold());
+        let instructions = Title::from(Line::from(vec![
+            " Move ".into(),
+            "<Up/Down> ".set_style(theme.key),
+            " Show the Verb ".into(),
+            "<Enter> ".set_style(theme.key),
+            " Back ".into(),
+            "<Esc> ".set_style(theme.key),
+        ]));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+
+        let mut text = Text::from(vec![
+            Line::from(""),
+            Line::from(vec![
+                "Find: ".into(),
+                self.query.clone().set_style(theme.input),
+                "_".into(),
+            ]),
+            Line::from(""),
+        ]);
+        if self.query.trim().chars().count() < MIN_QUERY {
+            text.push_line(Line::from(
+                "Type a German or English word, e.g. ging or went".dim(),
+            ));
+        } else if self.matches.is_empty() {
+            text.push_line(Line::from("Nothing found".dim()));
+        } else {
+            text.push_line(Line::from(format!("{} found", self.matches.len()).dim()));
+            text.push_line(Line::from(""));
+        }
+
+        // scrolled so the selected match is always shown
+        let rows = usize::from(area.height.saturating_sub(2)).saturating_sub(text.lines.len());
+        let first = (self.selected + 1).saturating_sub(rows);
+        for (n, &i) in self.matches.iter().enumerate().skip(first).take(rows) {
+            let entry = &self.entries[i];
+            let line = Line::from(vec![
+                entry.german.clone().set_style(theme.prompt),
+                "  ".into(),
+                entry.english.clone().into(),
+                format!("  ({})", entry.label).dim(),
+            ]);
+            if n == self.selected {
+                text.push_line(line.style(Style::new().reversed()));
+            } else {
+                text.push_line(line);
+            }
+        }
+
+        Paragraph::new(text)
+            .centered()
+            .block(block)
+            .render(area, buf);
+    }
+}
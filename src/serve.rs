@@ -0,0 +1,221 @@
+use clap::Args;
+use serde::Serialize;
+use std::{
+    error::Error,
+    io::Read,
+    sync::{
+        mpsc::{self, Receiver, Sender},
+        Arc, Mutex,
+    },
+    thread,
+};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+/// The options of the `serve` subcommand
+#[derive(Args, Debug)]
+pub struct ServeArgs {
+    /// The port to listen on
+    #[arg(long, default_value_t = 8080)]
+    port: u16,
+
+    /// The address to listen on. The default only listens on this machine;
+    /// 0.0.0.0 listens on every interface, so a phone on the same network
+    /// can connect
+    #[arg(long, default_value = "127.0.0.1")]
+    bind: String,
+}
+
+/// The longest answer taken, in bytes
+const MAX_ANSWER: u64 = 1024;
+
+/// What the remote can ask the lesson to do
+#[derive(Debug)]
+pub enum RemoteCommand {
+    /// Answer the current question (with the number of the choice in
+    /// recognition mode)
+    Answer(String),
+    /// Move on once the question was answered
+    Next,
+}
+
+/// The state of the lesson, as the remote sees it at `GET /state`
+#[derive(Debug, Default, Serialize)]
+pub struct State {
+    pub screen: &'static str, // select_verb, question, answered or score
+    pub verb: Option<String>,
+    pub tense: Option<String>,  // unless hidden by the header hints
+    pub person: Option<String>, // unless hidden by the header hints
+    pub question: u32,
+    pub questions: Option<u32>,    // None when the lesson runs for a time
+    pub seconds_left: Option<u64>, // when the lesson runs for a time
+    pub prompt: Option<String>,    // what to answer, if it is shown
+    pub choices: Vec<String>,      // in recognition mode
+    pub response: Option<String>,
+    pub correct: Option<bool>,
+    pub answer: Option<String>, // the right answer, once answered
+    pub total_correct: u32,
+}
+
+/// A small HTTP server alongside the TUI, so another device's browser can be
+/// used to answer: `GET /` is the answer pad, `GET /state` the `State` as
+/// JSON, and `POST /answer` (with the answer as the body) and `POST /next`
+/// send a `RemoteCommand`. Every request has to carry the token made for the
+/// run as `?token=`, which the URL printed at start-up does.
+pub struct Remote {
+    pub url: String, // The answer pad, with the token
+    state: Arc<Mutex<String>>,
+    commands: Receiver<RemoteCommand>,
+}
+
+impl Remote {
+    /// Starts listening in the background
+    pub fn start(args: &ServeArgs) -> Result<Remote, Box<dyn Error>> {
+        let server = Server::http((args.bind.as_str(), args.port)).map_err(|e| e.to_string())?;
+        let token: String = rand::random::<[u8; 16]>()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect();
+        let url = format!("http://{}:{}/?token={}", args.bind, args.port, token);
+        let state = Arc::new(Mutex::new(String::from("{}")));
+        let (sender, commands) = mpsc::channel();
+
+        let shared = Arc::clone(&state);
+        thread::spawn(move || {
+            for request in server.incoming_requests() {
+                // the remote going away mid-request is not our problem
+                let _ = handle(request, &token, &shared, &sender);
+            }
+        });
+        Ok(Remote {
+            url,
+            state,
+            commands,
+        })
+    }
+
+    /// Updates the state the remote sees
+    pub fn publish(&self, state: &State) {
+        if let Ok(json) = serde_json::to_string(state) {
+            *self.state.lock().unwrap() = json;
+        }
+    }
+
+    /// The commands sent since this was last called
+    pub fn commands(&self) -> Vec<RemoteCommand> {
+        self.commands.try_iter().collect()
+    }
+}
+
+fn handle(
+    mut request: Request,
+    token: &str,
+    state: &Mutex<String>,
+    sender: &Sender<RemoteCommand>,
+) -> Result<(), Box<dyn Error>> {
+    let (path, query) = request.url().split_once('?').unwrap_or((request.url(), ""));
+    let path = path.to_string();
+    if !query
+        .split('&')
+        .any(|pair| pair == format!("token={}", token))
+    {
+        request.respond(Response::from_string("Forbidden").with_status_code(403))?;
+        return Ok(());
+    }
+    let response = match (request.method(), path.as_str()) {
+        (Method::Get, "/") => {
+            Response::from_string(PAGE).with_header(content_type("text/html; charset=utf-8"))
+        }
+        (Method::Get, "/state") => Response::from_string(state.lock().unwrap().clone())
+            .with_header(content_type("application/json")),
+        (Method::Post, "/answer") => {
+            let mut answer = String::new();
+            // one byte more than taken, to tell if there was more
+            request
+                .as_reader()
+                .take(MAX_ANSWER + 1)
+                .read_to_string(&mut answer)?;
+            if answer.len() as u64 > MAX_ANSWER {
+                Response::from_string("Answer too long").with_status_code(413)
+            } else {
+                sender.send(RemoteCommand::Answer(answer.trim().to_string()))?;
+                Response::from_string("").with_status_code(204)
+            }
+        }
+        (Method::Post, "/next") => {
+            sender.send(RemoteCommand::Next)?;
+            Response::from_string("").with_status_code(204)
+        }
+        _ => Response::from_string("Not found").with_status_code(404),
+    };
+    request.respond(response)?;
+    Ok(())
+}
+
+fn content_type(value: &str) -> Header {
+    Header::from_bytes("Content-Type", value).expect("Invalid header")
+}
+
+/// The answer pad: shows the question, and sends the answers typed or tapped
+const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>Deutschland</title>
+<style>
+body { font-family: sans-serif; margin: 1em; font-size: 1.2em; }
+input, button { font-size: 1em; width: 100%; margin: 0.3em 0; padding: 0.4em; box-sizing: border-box; }
+.correct { color: #0072b2; } .incorrect { color: #d55e00; }
+</style>
+</head>
+<body>
+<p id="header"></p>
+<p id="prompt"></p>
+<form id="form"><input id="answer" autocomplete="off" autocapitalize="off"><button>Submit</button></form>
+<div id="choices"></div>
+<p id="result"></p>
+<button id="next">Continue</button>
+<script>
+const $ = (id) => document.getElementById(id);
+let shown = "";
+function send(path, body) {
+  fetch(path + location.search, { method: "POST", body: body || "" }).then(refresh);
+}
+function refresh() {
+  fetch("/state" + location.search).then((r) => r.json()).then((s) => {
+    const key = [s.screen, s.question, s.total_correct].join();
+    const header = [s.verb, s.tense, s.person].filter((x) => x).join(" | ");
+    const progress = s.seconds_left === null ? `Q${s.question}/${s.questions}`
+      : `Q${s.question}, ${Math.floor(s.seconds_left / 60)}:${String(s.seconds_left % 60).padStart(2, "0")} left`;
+    $("header").textContent = s.screen === "question" || s.screen === "answered"
+      ? `${header} (${progress})`
+      : s.screen === "score" ? `Lesson completed: ${s.total_correct} of ${s.questions} correct`
+      : "Select a verb in the terminal";
+    $("prompt").textContent = s.prompt || "";
+    $("form").hidden = s.screen !== "question" || s.choices.length > 0;
+    $("choices").hidden = s.screen !== "question";
+    $("next").hidden = s.screen !== "answered";
+    if (key !== shown) {
+      shown = key;
+      $("answer").value = "";
+      $("choices").innerHTML = "";
+      s.choices.forEach((choice, i) => {
+        const button = document.createElement("button");
+        button.textContent = choice;
+        button.onclick = () => send("/answer", String(i + 1));
+        $("choices").appendChild(button);
+      });
+    }
+    $("result").className = s.correct === null ? "" : s.correct ? "correct" : "incorrect";
+    $("result").textContent = s.correct === null ? ""
+      : s.correct ? `Correct: ${s.response}` : `Incorrect: ${s.response}. Correct: ${s.answer}`;
+  }).catch(() => { $("header").textContent = "Not connected"; });
+}
+$("form").onsubmit = (e) => { e.preventDefault(); send("/answer", $("answer").value); };
+$("next").onclick = () => send("/next");
+setInterval(refresh, 1000);
+refresh();
+</script>
+</body>
+</html>
+"#;
@@ -0,0 +1,129 @@
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use std::{error::Error, fs, path::Path};
+
+use crate::history::{Answer, Confidence};
+use crate::lesson::Lesson;
+use crate::mode::Mode;
+use crate::person::Person;
+use crate::tense::Tense;
+
+/// The lesson in progress, kept in `<data_dir>/session.json` until it ends.
+/// Still there on launch, the app wasn't shut down cleanly.
+const SESSION_FILE: &str = "session.json";
+
+/// How many answers go by between saves of the lesson in progress
+pub const AUTOSAVE_EVERY: usize = 3;
+
+/// Where a lesson was, to pick it back up after a crash
+#[derive(Serialize, Deserialize)]
+pub struct Session {
+    pub lesson: Lesson,
+    pub mode: Mode,
+    pub from: String,
+    pub questions: u32, // asked by the lesson
+    pub asked: u32,     // so far
+    pub correct: u32,
+    pub incorrect: u32,
+    pub skipped: u32,
+    pub limit: Option<u64>, // with --minutes, the seconds the lesson runs
+    pub elapsed: u64,       // the seconds the lesson ran
+    pub results: Vec<bool>, // if each answer was right, in order
+    pub answers: Vec<SavedAnswer>, // not yet recorded in the history
+}
+
+impl Session {
+    /// The answers of the lesson, as the history has them
+    pub fn answers(&self) -> Result<Vec<Answer>, Box<dyn Error>> {
+        self.answers.iter().map(SavedAnswer::answer).collect()
+    }
+}
+
+/// An answer the history doesn't have yet, as the session file has it
+#[derive(Serialize, Deserialize)]
+pub struct SavedAnswer {
+    time: String, // RFC 3339, as in the history
+    verb: String,
+    tense: Tense,
+    person: Person,
+    correct: bool,
+    seconds: f64,
+    confidence: Option<Confidence>,
+    #[serde(default)]
+    assisted: bool,
+}
+
+impl From<&Answer> for SavedAnswer {
+    fn from(answer: &Answer) -> SavedAnswer {
+        SavedAnswer {
+            time: answer.time.to_rfc3339(),
+            verb: answer.verb.clone(),
+            tense: answer.tense,
+            person: answer.person,
+            correct: answer.correct,
+            seconds: answer.seconds,
+            confidence: answer.confidence,
+            assisted: answer.assisted,
+        }
+    }
+}
+
+impl SavedAnswer {
+    fn answer(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer {
+            time: DateTime::parse_from_rfc3339(&self.time)?,
+            verb: self.verb.clone(),
+            tense: self.tense,
+            person: self.person,
+            correct: self.correct,
+            seconds: self.seconds,
+            confidence: self.confidence,
+            partial: false,
+            assisted: self.assisted,
+        })
+    }
+}
+
+/// Saves the lesson in progress, over the last save
+pub fn save(data_dir: &Path, session: &Session) -> Result<(), Box<dyn Error>> {
+    // written aside and moved over, so a crash while saving leaves the last save
+    let path = data_dir.join(SESSION_FILE);
+    let temp = path.with_extension("json.tmp");
+    fs::write(&temp, serde_json::to_string(session)?)?;
+    fs::rename(temp, path)?;
+    Ok(())
+}
+
+/// The lesson left unfinished when the app last stopped, if it crashed
+pub fn load(data_dir: &Path) -> Result<Option<Session>, Box<dyn Error>> {
+    let path = data_dir.join(SESSION_FILE);
+    if !path.exists() {
+        return Ok(None);
+    }
+    Ok(Some(parse(&fs::read_to_string(path)?)?))
+}
+
+/// Reads the lesson in the contents of a session file. The counts it goes on
+/// from have to add up, and its answers be readable.
+pub fn parse(text: &str) -> Result<Session, Box<dyn Error>> {
+    let session: Session = serde_json::from_str(text)?;
+    let answered = u64::from(session.correct) + u64::from(session.incorrect);
+    if answered > u64::from(session.asked) || session.skipped > session.incorrect {
+        return Err(format!(
+            "{} answers and {} skipped out of {} asked don't add up",
+            answered, session.skipped, session.asked
+        )
+        .into());
+    }
+    session.answers()?;
+    Ok(session)
+}
+
+/// Forgets the lesson in progress, as it ended or was left
+pub fn clear(data_dir: &Path) -> Result<(), Box<dyn Error>> {
+    let path = data_dir.join(SESSION_FILE);
+    if path.exists() {
+        fs::remove_file(path)?;
+    }
+    Ok(())
+}
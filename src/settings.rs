@@ -0,0 +1,176 @@
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Alignment, Rect},
+    style::{Style, Styled, Stylize},
+    symbols::border,
+    text::{Line, Text},
+    widgets::{
+        block::{Position, Title},
+        Block, Paragraph, Widget,
+    },
+};
+
+use crate::config::Config;
+use crate::theme::Theme;
+
+/// The names of the settings, in the order they are listed
+const SETTINGS: [&str; 16] = [
+    "Theme",
+    "Accessible",
+    "Difficulty",
+    "Header Hints",
+    "Auxiliary Hint",
+    "Autocomplete",
+    "Strictness",
+    "Warm-up Reviews",
+    "Curriculum",
+    "Pronunciation (IPA)",
+    "Stressed Syllables",
+    "Grace Period",
+    "Auto-advance",
+    "Special Letters Bar",
+    "Score in Header",
+    "Encouragement",
+];
+
+/// The grace periods to pick from, in seconds (0 for none)
+const GRACE_PERIODS: [u64; 5] = [0, 5, 10, 20, 30];
+
+/// How long a right answer can be shown before moving on, in milliseconds
+/// (0 to wait for Enter)
+const AUTO_ADVANCES: [u64; 5] = [0, 500, 1000, 1500, 3000];
+
+/// The settings screen, editing a copy of the config until it is closed
+pub struct Settings {
+    pub config: Config,
+    selected: usize,
+}
+
+impl Settings {
+    pub fn new(config: &Config) -> Settings {
+        Settings {
+            config: config.clone(),
+            selected: 0,
+        }
+    }
+
+    /// Handles a key press, returning true when the settings should be closed
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> bool {
+        match key_event.code {
+            KeyCode::Esc | KeyCode::Char('q') => return true,
+            KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected = (self.selected + 1).min(SETTINGS.len() - 1)
+            }
+            KeyCode::Enter | KeyCode::Char(' ') | KeyCode::Left | KeyCode::Right => self.change(),
+            _ => {}
+        }
+        false
+    }
+
+    /// Moves the selected setting on to its next value
+    fn change(&mut self) {
+        match self.selected {
+            0 => self.config.theme = self.config.theme.next(),
+            1 => self.config.accessible = !self.config.accessible,
+            2 => self.config.difficulty.next().apply(&mut self.config),
+            3 => self.config.hints = self.config.hints.next(),
+            4 => self.config.auxiliary_hint = !self.config.auxiliary_hint,
+            5 => self.config.autocomplete = !self.config.autocomplete,
+            6 => self.config.strictness = self.config.strictness.next(),
+            7 => self.config.warm_up = !self.config.warm_up,
+            8 => self.config.curriculum = !self.config.curriculum,
+            9 => self.config.show_ipa = !self.config.show_ipa,
+            10 => self.config.show_stress = !self.config.show_stress,
+            11 => {
+                let i = GRACE_PERIODS
+                    .iter()
+                    .position(|&s| s == self.config.grace_seconds)
+                    .map_or(0, |i| (i + 1) % GRACE_PERIODS.len());
+                self.config.grace_seconds = GRACE_PERIODS[i];
+            }
+            12 => {
+                let i = AUTO_ADVANCES
+                    .iter()
+                    .position(|&ms| ms == self.config.auto_advance_ms)
+                    .map_or(0, |i| (i + 1) % AUTO_ADVANCES.len());
+                self.config.auto_advance_ms = AUTO_ADVANCES[i];
+            }
+            13 => self.config.keymap_bar = !self.config.keymap_bar,
+            14 => self.config.score_header = !self.config.score_header,
+            15 => self.config.encouragement = !self.config.encouragement,
+            _ => {}
+        }
+    }
+
+    fn value(&self, i: usize) -> String {
+        match i {
+            0 => self.config.theme.to_string(),
+            1 => on_off(self.config.accessible),
+            2 => self.config.difficulty.to_string(),
+            3 => self.config.hints.to_string(),
+            4 => on_off(self.config.auxiliary_hint),
+            5 => on_off(self.config.autocomplete),
+            6 => self.config.strictness.to_string(),
+            7 => on_off(self.config.warm_up),
+            8 => on_off(self.config.curriculum),
+            9 => on_off(self.config.show_ipa),
+            10 => on_off(self.config.show_stress),
+            11 => match self.config.grace_seconds {
+                0 => "Off".to_string(),
+                seconds => format!("{} seconds", seconds),
+            },
+            12 => match self.config.auto_advance_ms {
+                0 => "Off".to_string(),
+                ms => format!("{} ms after a right answer", ms),
+            },
+            13 => on_off(self.config.keymap_bar),
+            14 => on_off(self.config.score_header),
+            15 => on_off(self.config.encouragement),
+            _ => String::new(),
+        }
+    }
+
+    pub fn render(&self, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        let title = Title::from(" Settings ".bold());
+        let instructions = Title::from(Line::from(vec![
+            " Move ".into(),
+            "<Up/Down> ".set_style(theme.key),
+            " Change ".into(),
+            "<Enter> ".set_style(theme.key),
+            " Save ".into(),
+            "<Esc> ".set_style(theme.key),
+        ]));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+
+        let mut text = Text::from(vec![Line::from(""), Line::from("")]);
+        for (i, name) in SETTINGS.iter().enumerate() {
+            let line = Line::from(vec![
+                format!("{}: ", name).into(),
+                self.value(i).set_style(theme.input),
+            ]);
+            if i == self.selected {
+                text.push_line(line.style(Style::new().reversed()));
+            } else {
+                text.push_line(line);
+            }
+        }
+
+        Paragraph::new(text)
+            .centered()
+            .block(block)
+            .render(area, buf);
+    }
+}
+
+fn on_off(value: bool) -> String {
+    if value { "On" } else { "Off" }.to_string()
+}
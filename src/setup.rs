@@ -0,0 +1,188 @@
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Alignment, Rect},
+    style::{Style, Styled, Stylize},
+    symbols::border,
+    text::{Line, Text},
+    widgets::{
+        block::{Position, Title},
+        Block, Paragraph, Widget, Wrap,
+    },
+};
+use std::{error::Error, fs};
+
+use crate::config::{Config, CONFIG_FILE};
+use crate::paths::Dirs;
+use crate::theme::Theme;
+
+/// The verbs that come with the app, installed on the first run
+const STARTER_VERBS: [(&str, &str); 11] = [
+    ("aufwachen", include_str!("../verbs/aufwachen.csv")),
+    ("duschen", include_str!("../verbs/duschen.csv")),
+    ("essen", include_str!("../verbs/essen.csv")),
+    ("gehen", include_str!("../verbs/gehen.csv")),
+    ("haben", include_str!("../verbs/haben.csv")),
+    ("helfen", include_str!("../verbs/helfen.csv")),
+    ("machen", include_str!("../verbs/machen.csv")),
+    ("schlafen", include_str!("../verbs/schlafen.csv")),
+    ("skifahren", include_str!("../verbs/skifahren.csv")),
+    ("treffen", include_str!("../verbs/treffen.csv")),
+    ("trinken", include_str!("../verbs/trinken.csv")),
+];
+
+/// The irregular forms of the starter verbs, for `lint-data`
+const IRREGULAR: &str = include_str!("../irregular.csv");
+
+/// The names of the choices, in the order they are listed
+const CHOICES: [&str; 4] = ["Direction", "Difficulty", "Strictness", "Theme"];
+
+/// Whether this is the first run, with no verbs to practice yet
+pub fn is_first_run(dirs: &Dirs) -> bool {
+    !dirs.data.join("verbs").is_dir()
+}
+
+/// Creates the data directory with the starter verbs, leaving any files
+/// already there as they are
+pub fn install(dirs: &Dirs) -> Result<(), Box<dyn Error>> {
+    let verbs_dir = dirs.data.join("verbs");
+    fs::create_dir_all(&verbs_dir)?;
+    for (name, csv) in STARTER_VERBS {
+        let path = verbs_dir.join(format!("{}.csv", name));
+        if !path.exists() {
+            fs::write(path, csv)?;
+        }
+    }
+    let irregular = dirs.data.join("irregular.csv");
+    if !irregular.exists() {
+        fs::write(irregular, IRREGULAR)?;
+    }
+    Ok(())
+}
+
+pub enum SetupEvent {
+    None,
+    Quit,
+    Done, // the starter verbs are installed and the settings saved
+}
+
+/// The setup wizard shown on the first run: picks the defaults, then
+/// installs the starter verbs and saves the settings
+pub struct Setup {
+    pub config: Config,
+    dirs: Dirs,
+    selected: usize,
+    error: Option<String>, // why the setup could not be finished
+}
+
+impl Setup {
+    pub fn new(config: &Config, dirs: Dirs) -> Setup {
+        Setup {
+            config: config.clone(),
+            dirs,
+            selected: 0,
+            error: None,
+        }
+    }
+
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> SetupEvent {
+        match key_event.code {
+            KeyCode::Esc | KeyCode::Char('q') => return SetupEvent::Quit,
+            KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected = (self.selected + 1).min(CHOICES.len() - 1)
+            }
+            KeyCode::Char(' ') | KeyCode::Left | KeyCode::Right => self.change(),
+            KeyCode::Enter => match self.finish() {
+                Ok(()) => return SetupEvent::Done,
+                Err(e) => self.error = Some(e.to_string()),
+            },
+            _ => {}
+        }
+        SetupEvent::None
+    }
+
+    /// Moves the selected choice on to its next value
+    fn change(&mut self) {
+        match self.selected {
+            0 => self.config.direction = self.config.direction.next(),
+            1 => self.config.difficulty.next().apply(&mut self.config),
+            2 => self.config.strictness = self.config.strictness.next(),
+            3 => self.config.theme = self.config.theme.next(),
+            _ => {}
+        }
+    }
+
+    fn value(&self, i: usize) -> String {
+        match i {
+            0 => self.config.direction.to_string(),
+            1 => self.config.difficulty.to_string(),
+            2 => self.config.strictness.to_string(),
+            3 => self.config.theme.to_string(),
+            _ => String::new(),
+        }
+    }
+
+    fn finish(&self) -> Result<(), Box<dyn Error>> {
+        install(&self.dirs)?;
+        self.config.save(&self.dirs.config)
+    }
+
+    pub fn render(&self, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        let title = Title::from(" Welcome to Deutschland ".bold());
+        let instructions = Title::from(Line::from(vec![
+            " Move ".into(),
+            "<Up/Down> ".set_style(theme.key),
+            " Change ".into(),
+            "<Space> ".set_style(theme.key),
+            " Set Up ".into(),
+            "<Enter> ".set_style(theme.key),
+            " Quit ".into(),
+            "<Esc> ".set_style(theme.key),
+        ]));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+
+        let mut text = Text::from(vec![
+            Line::from(""),
+            Line::from("There are no verbs to practice yet."),
+            Line::from(format!(
+                "Setting up puts {} starter verbs in {}",
+                STARTER_VERBS.len(),
+                self.dirs.data.join("verbs").display()
+            )),
+            Line::from(format!(
+                "and the settings in {}",
+                self.dirs.config.join(CONFIG_FILE).display()
+            )),
+            Line::from(""),
+        ]);
+        for (i, name) in CHOICES.iter().enumerate() {
+            let line = Line::from(vec![
+                format!("{}: ", name).into(),
+                self.value(i).set_style(theme.input),
+            ]);
+            if i == self.selected {
+                text.push_line(line.style(Style::new().reversed()));
+            } else {
+                text.push_line(line);
+            }
+        }
+        if let Some(error) = &self.error {
+            text.push_line("");
+            text.push_line(format!("Could not set up: {}", error).set_style(theme.incorrect));
+        }
+
+        Paragraph::new(text)
+            .centered()
+            .wrap(Wrap { trim: true })
+            .block(block)
+            .render(area, buf);
+    }
+}
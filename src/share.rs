@@ -0,0 +1,24 @@
+use chrono::{DateTime, Local};
+
+use crate::mode::Mode;
+
+/// How many answers a row of the grid has
+const ROW: usize = 5;
+
+/// A spoiler-free summary of a lesson to share, like Wordle's: the score and
+/// a grid of 🟩 for each right answer and 🟥 for each wrong one, in order
+pub fn text(results: &[bool], mode: Mode, time: DateTime<Local>) -> String {
+    let right = results.iter().filter(|&&r| r).count();
+    let mut text = format!(
+        "Deutschland {} {}/{} ({})",
+        time.format("%Y-%m-%d"),
+        right,
+        results.len(),
+        mode
+    );
+    for row in results.chunks(ROW) {
+        text.push('\n');
+        text.extend(row.iter().map(|&r| if r { '🟩' } else { '🟥' }));
+    }
+    text
+}
@@ -0,0 +1,258 @@
+/// The numbers below twenty, which don't follow a pattern
+const ONES: [&str; 20] = [
+    "null",
+    "eins",
+    "zwei",
+    "drei",
+    "vier",
+    "fünf",
+    "sechs",
+    "sieben",
+    "acht",
+    "neun",
+    "zehn",
+    "elf",
+    "zwölf",
+    "dreizehn",
+    "vierzehn",
+    "fünfzehn",
+    "sechzehn",
+    "siebzehn",
+    "achtzehn",
+    "neunzehn",
+];
+
+/// The tens from twenty, starting at index 2
+const TENS: [&str; 10] = [
+    "", "", "zwanzig", "dreißig", "vierzig", "fünfzig", "sechzig", "siebzig", "achtzig", "neunzig",
+];
+
+const MONTHS: [&str; 12] = [
+    "Januar",
+    "Februar",
+    "März",
+    "April",
+    "Mai",
+    "Juni",
+    "Juli",
+    "August",
+    "September",
+    "Oktober",
+    "November",
+    "Dezember",
+];
+
+/// Spells a number below a million, e.g. 21 is "einundzwanzig" and 1001
+/// "eintausendeins"
+pub fn number(n: u32) -> String {
+    assert!(n < 1_000_000, "Only numbers below a million are spelt");
+    match n {
+        0..=19 => ONES[n as usize].to_string(),
+        20..=99 => {
+            let (tens, ones) = (n / 10, n % 10);
+            match ones {
+                0 => TENS[tens as usize].to_string(),
+                _ => format!("{}und{}", prefix(ones), TENS[tens as usize]),
+            }
+        }
+        100..=999 => format!("{}hundert{}", prefix(n / 100), rest(n % 100)),
+        _ => format!("{}tausend{}", prefix(n / 1000), rest(n % 1000)),
+    }
+}
+
+/// A number as it comes before "und", "hundert" or "tausend": "ein", not "eins"
+fn prefix(n: u32) -> String {
+    match n {
+        1 => "ein".to_string(),
+        _ => number(n),
+    }
+}
+
+/// What comes after the hundreds or thousands, nothing for zero
+fn rest(n: u32) -> String {
+    match n {
+        0 => String::new(),
+        _ => number(n),
+    }
+}
+
+/// The ways of spelling the number that are right, e.g. both "einhundert"
+/// and "hundert" for 100
+pub fn numbers(n: u32) -> Vec<String> {
+    let spelt = number(n);
+    let mut spellings = vec![spelt.clone()];
+    for big in ["hundert", "tausend"] {
+        // only a leading "ein" can go, "zweihunderteinhundert" isn't a thing
+        if let Some(short) = spelt.strip_prefix("ein").filter(|s| s.starts_with(big)) {
+            spellings.push(short.to_string());
+        }
+    }
+    spellings
+}
+
+/// The hour as said on a 12 hour clock, e.g. 15 is "drei" and 0 "zwölf"
+fn hour(h: u32) -> String {
+    match h % 12 {
+        0 => "zwölf".to_string(),
+        h => number(h),
+    }
+}
+
+/// The ways of saying the time that are right: as in conversation ("Viertel
+/// nach drei", "fünf vor halb vier") and as on a timetable ("fünfzehn Uhr
+/// fünfzehn")
+pub fn times(h: u32, m: u32) -> Vec<String> {
+    assert!(h < 24 && m < 60, "Not a time: {}:{}", h, m);
+    let next = hour(h + 1);
+    let mut spellings = match m {
+        0 => vec![format!("{} Uhr", uhr(hour(h)))],
+        15 => vec![format!("Viertel nach {}", hour(h))],
+        30 => vec![format!("halb {}", next)],
+        45 => vec![
+            format!("Viertel vor {}", next),
+            format!("dreiviertel {}", next),
+        ],
+        1..=19 => vec![format!("{} nach {}", number(m), hour(h))],
+        20 => vec![
+            format!("zwanzig nach {}", hour(h)),
+            format!("zehn vor halb {}", next),
+        ],
+        21..=29 => vec![format!("{} vor halb {}", number(30 - m), next)],
+        31..=39 => vec![format!("{} nach halb {}", number(m - 30), next)],
+        40 => vec![
+            format!("zwanzig vor {}", next),
+            format!("zehn nach halb {}", next),
+        ],
+        _ => vec![format!("{} vor {}", number(60 - m), next)],
+    };
+    spellings.push(match m {
+        0 => format!("{} Uhr", uhr(number(h))),
+        _ => format!("{} Uhr {}", uhr(number(h)), number(m)),
+    });
+    spellings.dedup();
+    spellings
+}
+
+/// The hour as it comes before "Uhr", which is "ein Uhr" rather than "eins Uhr"
+fn uhr(hour: String) -> String {
+    match hour.as_str() {
+        "eins" => "ein".to_string(),
+        _ => hour,
+    }
+}
+
+/// The ordinal as it comes before a noun, e.g. 3 is "dritte" and 20 "zwanzigste"
+pub fn ordinal(n: u32) -> String {
+    match n {
+        1 => "erste".to_string(),
+        3 => "dritte".to_string(),
+        7 => "siebte".to_string(),
+        8 => "achte".to_string(),
+        2..=19 => format!("{}te", number(n)),
+        _ => format!("{}ste", number(n)),
+    }
+}
+
+/// The ways of saying the date that are right, e.g. "der dritte Mai", or just
+/// "dritte Mai"
+pub fn dates(day: u32, month: u32) -> Vec<String> {
+    assert!(
+        (1..=31).contains(&day) && (1..=12).contains(&month),
+        "Not a date: {}.{}.",
+        day,
+        month
+    );
+    let date = format!("{} {}", ordinal(day), MONTHS[month as usize - 1]);
+    vec![format!("der {}", date), date]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_below_twenty_and_the_tens_are_their_own_words() {
+        assert_eq!(number(0), "null");
+        assert_eq!(number(1), "eins");
+        assert_eq!(number(16), "sechzehn");
+        assert_eq!(number(17), "siebzehn");
+        assert_eq!(number(30), "dreißig");
+    }
+
+    #[test]
+    fn ones_come_before_the_tens_and_lose_their_s() {
+        assert_eq!(number(21), "einundzwanzig");
+        assert_eq!(number(101), "einhunderteins");
+        assert_eq!(number(1001), "eintausendeins");
+        assert_eq!(
+            number(999_999),
+            "neunhundertneunundneunzigtausendneunhundertneunundneunzig"
+        );
+    }
+
+    #[test]
+    fn only_a_leading_ein_can_be_left_out() {
+        assert_eq!(numbers(100), ["einhundert", "hundert"]);
+        assert_eq!(numbers(1100), ["eintausendeinhundert", "tausendeinhundert"]);
+        assert_eq!(numbers(200), ["zweihundert"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "below a million")]
+    fn a_million_is_not_spelt() {
+        number(1_000_000);
+    }
+
+    #[test]
+    fn the_hours_are_said_on_a_twelve_hour_clock_and_as_on_a_timetable() {
+        assert_eq!(times(0, 0), ["zwölf Uhr", "null Uhr"]);
+        assert_eq!(times(1, 0), ["ein Uhr"]);
+        assert_eq!(times(13, 1), ["eins nach eins", "dreizehn Uhr eins"]);
+        assert_eq!(times(23, 30), ["halb zwölf", "dreiundzwanzig Uhr dreißig"]);
+    }
+
+    #[test]
+    fn some_times_are_said_several_ways() {
+        assert_eq!(
+            times(8, 45),
+            [
+                "Viertel vor neun",
+                "dreiviertel neun",
+                "acht Uhr fünfundvierzig"
+            ]
+        );
+        assert_eq!(
+            times(8, 20),
+            [
+                "zwanzig nach acht",
+                "zehn vor halb neun",
+                "acht Uhr zwanzig"
+            ]
+        );
+        assert_eq!(
+            times(8, 35),
+            ["fünf nach halb neun", "acht Uhr fünfunddreißig"]
+        );
+        assert_eq!(times(11, 55), ["fünf vor zwölf", "elf Uhr fünfundfünfzig"]);
+    }
+
+    #[test]
+    fn ordinals_are_irregular_below_twenty_only_at_first_third_seventh_and_eighth() {
+        assert_eq!(ordinal(1), "erste");
+        assert_eq!(ordinal(3), "dritte");
+        assert_eq!(ordinal(7), "siebte");
+        assert_eq!(ordinal(8), "achte");
+        assert_eq!(ordinal(19), "neunzehnte");
+        assert_eq!(ordinal(20), "zwanzigste");
+        assert_eq!(ordinal(31), "einunddreißigste");
+    }
+
+    #[test]
+    fn dates_can_leave_out_the_article() {
+        assert_eq!(dates(3, 5), ["der dritte Mai", "dritte Mai"]);
+        assert_eq!(
+            dates(31, 12),
+            ["der einunddreißigste Dezember", "einunddreißigste Dezember"]
+        );
+    }
+}
@@ -0,0 +1,223 @@
+use deutschland_tui::grade::{Grader, Strictness};
+use rand::{rngs::StdRng, Rng};
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Alignment, Rect},
+    style::{Styled, Stylize},
+    symbols::border,
+    text::{Line, Text},
+    widgets::{
+        block::{Position, Title},
+        Block, Paragraph, Widget,
+    },
+};
+
+use crate::spell;
+use crate::theme::Theme;
+
+/// The days in each month, with a leap February
+const DAYS_IN_MONTH: [u32; 12] = [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+/// Something to spell out, made up rather than read from a file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Drill {
+    Number(u32),
+    Time(u32, u32), // hours and minutes, on a 24 hour clock
+    Date(u32, u32), // day and month
+}
+
+impl Drill {
+    /// A random number, time or date. Numbers are mostly small, as those are
+    /// the ones said the most.
+    fn random(rng: &mut StdRng) -> Drill {
+        match rng.gen_range(0..3) {
+            0 => Drill::Number(match rng.gen_range(0..4) {
+                0 => rng.gen_range(0..100),
+                1 => rng.gen_range(0..1000),
+                2 => rng.gen_range(1000..10_000),
+                _ => rng.gen_range(10_000..1_000_000),
+            }),
+            // the minutes in fives, as clocks are read
+            1 => Drill::Time(rng.gen_range(0..24), 5 * rng.gen_range(0..12)),
+            _ => {
+                let month = rng.gen_range(1..=12);
+                Drill::Date(rng.gen_range(1..=DAYS_IN_MONTH[month as usize - 1]), month)
+            }
+        }
+    }
+
+    /// What is shown to be spelt, e.g. "15:20" or "3.5."
+    fn prompt(&self) -> String {
+        match *self {
+            Drill::Number(n) => n.to_string(),
+            Drill::Time(h, m) => format!("{}:{:02}", h, m),
+            Drill::Date(day, month) => format!("{}.{}.", day, month),
+        }
+    }
+
+    /// What is asked for, above the prompt
+    fn question(&self) -> &'static str {
+        match self {
+            Drill::Number(_) => "Spell the number",
+            Drill::Time(..) => "Say the time",
+            Drill::Date(..) => "Say the date",
+        }
+    }
+
+    /// The spellings that are right, the usual one first
+    fn answers(&self) -> Vec<String> {
+        match *self {
+            Drill::Number(n) => spell::numbers(n),
+            Drill::Time(h, m) => spell::times(h, m),
+            Drill::Date(day, month) => spell::dates(day, month),
+        }
+    }
+}
+
+/// The drill on spelling out numbers, times and dates, checked against the
+/// rules of how they are said rather than against data files
+pub struct SpellingQuiz {
+    rng: StdRng,
+    drill: Drill,
+    response: String,
+    correct: Option<bool>, // once answered, if the response was right
+    right: u32,
+    answered: u32,
+}
+
+impl SpellingQuiz {
+    pub fn new(mut rng: StdRng) -> SpellingQuiz {
+        SpellingQuiz {
+            drill: Drill::random(&mut rng),
+            rng,
+            response: String::new(),
+            correct: None,
+            right: 0,
+            answered: 0,
+        }
+    }
+
+    /// Handles a key press, returning true when the quiz should be closed
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> bool {
+        if self.correct.is_some() {
+            match key_event.code {
+                KeyCode::Esc => return true,
+                KeyCode::Enter => self.next(),
+                _ => {}
+            }
+            return false;
+        }
+
+        match key_event.code {
+            KeyCode::Esc => return true,
+            KeyCode::Enter if !self.response.trim().is_empty() => self.check(),
+            KeyCode::Backspace => {
+                self.response.pop();
+            }
+            KeyCode::Char(c) => self.response.push(c),
+            _ => {}
+        }
+        false
+    }
+
+    fn check(&mut self) {
+        // no one should fail on a missing comma or ß
+        let grader = Grader::new()
+            .strictness(Strictness::Lenient)
+            .fold_umlauts(true);
+        let correct = self
+            .drill
+            .answers()
+            .iter()
+            .any(|answer| grader.grade(&self.response, answer));
+        if correct {
+            self.right += 1;
+        }
+        self.answered += 1;
+        self.correct = Some(correct);
+    }
+
+    fn next(&mut self) {
+        let mut drill = Drill::random(&mut self.rng);
+        while drill == self.drill {
+            drill = Drill::random(&mut self.rng);
+        }
+        self.drill = drill;
+        self.response.clear();
+        self.correct = None;
+    }
+
+    pub fn render(&self, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        let title = Title::from(" Numbers, Times and Dates ".bold());
+        let instructions = Title::from(Line::from(match self.correct {
+            Some(_) => vec![
+                " Next ".into(),
+                "<Enter> ".set_style(theme.key),
+                " Back ".into(),
+                "<Esc> ".set_style(theme.key),
+            ],
+            None => vec![
+                " Input Answer ".into(),
+                "<Chars> ".set_style(theme.key),
+                " Submit ".into(),
+                "<Enter> ".set_style(theme.key),
+                " Back ".into(),
+                "<Esc> ".set_style(theme.key),
+            ],
+        }));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+
+        let mut text = Text::from(vec![
+            Line::from(""),
+            Line::from(""),
+            Line::from(format!("{}:", self.drill.question())),
+            Line::from(self.drill.prompt().set_style(theme.prompt)),
+            Line::from(""),
+        ]);
+        match self.correct {
+            None => text.push_line(Line::from(vec![
+                "Your input: ".into(),
+                format!("{}_", self.response).set_style(theme.input),
+            ])),
+            Some(true) => text.push_line(Line::from(
+                format!("{}{}", theme.correct_mark, self.response).set_style(theme.correct),
+            )),
+            Some(false) => {
+                text.push_line(Line::from(
+                    format!("{}{}", theme.incorrect_mark, self.response).set_style(theme.incorrect),
+                ));
+                text.push_line(Line::from(vec![
+                    "Correct German: ".into(),
+                    self.drill.answers()[0].clone().set_style(theme.correct),
+                ]));
+            }
+        }
+        // the other ways of saying it are worth knowing too
+        if self.correct.is_some() {
+            let others = &self.drill.answers()[1..];
+            if !others.is_empty() {
+                text.push_line(Line::from(format!("Also right: {}", others.join(", "))));
+            }
+        }
+        text.push_line(Line::from(""));
+        if self.answered > 0 {
+            text.push_line(Line::from(format!(
+                "{} right out of {}",
+                self.right, self.answered
+            )));
+        }
+
+        Paragraph::new(text)
+            .centered()
+            .block(block)
+            .render(area, buf);
+    }
+}
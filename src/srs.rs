@@ -0,0 +1,414 @@
+use chrono::{DateTime, Duration, FixedOffset, Local};
+use csv::{ReaderBuilder, WriterBuilder};
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, HashMap},
+    error::Error,
+    fs::File,
+    hash::Hash,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use crate::conjugation::Conjugation;
+use crate::person::Person;
+use crate::tense::Tense;
+
+/// The review schedule of every form answered so far, kept in
+/// `<data_dir>/srs.csv`
+const SRS_FILE: &str = "srs.csv";
+
+/// The columns of the schedule, after the ones naming the card
+const HEADERS: [&str; 6] = ["Due", "Interval", "Ease", "Reps", "Lapses", "Suspended"];
+
+/// How soon a form answered wrong comes up again
+const RELEARN_MINUTES: i64 = 10;
+
+pub const START_EASE: f64 = 2.5;
+const MIN_EASE: f64 = 1.3;
+
+/// The longest interval, in days, so the due date never overflows however
+/// often a form is answered right
+pub const MAX_INTERVAL: f64 = 36500.0;
+
+/// Cards are forms, i.e. a verb, tense and person
+pub type Key = (String, Tense, Person);
+
+pub fn key(conj: &Conjugation) -> Key {
+    (conj.verb.name().to_string(), conj.tense, conj.person)
+}
+
+/// What the cards of a schedule are, and how they are written in its file
+pub trait CardKey: Clone + Eq + Hash {
+    /// The columns naming the card, before the scheduling ones
+    const COLUMNS: &'static [&'static str];
+
+    /// What the cards are sorted by in the file
+    type Order: Ord;
+
+    fn parse(fields: &[&str]) -> Result<Self, Box<dyn Error>>;
+    fn fields(&self) -> Vec<String>;
+    fn order(&self) -> Self::Order;
+}
+
+impl CardKey for Key {
+    const COLUMNS: &'static [&'static str] = &["Verb", "Tense", "Person"];
+
+    type Order = (String, u8, u8);
+
+    fn parse(fields: &[&str]) -> Result<Key, Box<dyn Error>> {
+        Ok((
+            fields[0].to_string(),
+            fields[1].parse()?,
+            fields[2].parse()?,
+        ))
+    }
+
+    fn fields(&self) -> Vec<String> {
+        let (verb, tense, person) = self;
+        vec![verb.clone(), format!("{:?}", tense), person.to_string()]
+    }
+
+    fn order(&self) -> (String, u8, u8) {
+        let (verb, tense, person) = self;
+        (verb.clone(), *tense as u8, *person as u8)
+    }
+}
+
+/// When a form is next due for review, scheduled like SM-2: every right answer
+/// in a row multiplies the interval by the ease, and a wrong one (a lapse)
+/// starts it over and makes the form less easy.
+#[derive(Debug, Clone)]
+pub struct Card {
+    pub due: DateTime<FixedOffset>,
+    pub interval: f64, // days
+    pub ease: f64,
+    pub reps: u32,       // right answers in a row
+    pub lapses: u32,     // times it was answered wrong
+    pub suspended: bool, // never asked until unsuspended
+}
+
+impl Card {
+    fn new() -> Card {
+        Card {
+            due: Local::now().fixed_offset(),
+            interval: 0.0,
+            ease: START_EASE,
+            reps: 0,
+            lapses: 0,
+            suspended: false,
+        }
+    }
+
+    /// A card brought in from another app, as if it had been reviewed `reps`
+    /// times up to `reviewed` (now if not known)
+    pub fn imported(
+        reps: u32,
+        interval: f64,
+        ease: f64,
+        lapses: u32,
+        reviewed: Option<DateTime<FixedOffset>>,
+    ) -> Card {
+        let reviewed = reviewed.unwrap_or_else(|| Local::now().fixed_offset());
+        Card {
+            due: after(reviewed, interval),
+            interval: interval.clamp(0.0, MAX_INTERVAL),
+            ease: ease.max(MIN_EASE),
+            reps,
+            lapses,
+            suspended: false,
+        }
+    }
+
+    fn review(&mut self, correct: bool) {
+        let now = Local::now().fixed_offset();
+        if correct {
+            self.reps = self.reps.saturating_add(1);
+            self.interval = match self.reps {
+                1 => 1.0,
+                2 => 3.0,
+                _ => (self.interval * self.ease).min(MAX_INTERVAL),
+            };
+            self.due = after(now, self.interval);
+        } else {
+            self.reps = 0;
+            self.lapses = self.lapses.saturating_add(1);
+            self.interval = 0.0;
+            self.ease = (self.ease - 0.2).max(MIN_EASE);
+            self.due = now + Duration::minutes(RELEARN_MINUTES);
+        }
+    }
+
+    /// About when the card was last reviewed, the interval before it is due
+    fn reviewed(&self) -> DateTime<FixedOffset> {
+        let seconds = (self.interval.clamp(0.0, MAX_INTERVAL) * 86400.0) as i64;
+        self.due
+            .checked_sub_signed(Duration::seconds(seconds))
+            .unwrap_or(self.due)
+    }
+}
+
+/// The time the interval (in days, capped) after `from`
+fn after(from: DateTime<FixedOffset>, interval: f64) -> DateTime<FixedOffset> {
+    let seconds = (interval.clamp(0.0, MAX_INTERVAL) * 86400.0) as i64;
+    from.checked_add_signed(Duration::seconds(seconds))
+        .unwrap_or(from)
+}
+
+/// The review schedule of some cards, kept in a CSV file
+pub struct Schedule<K> {
+    path: PathBuf,
+    cards: HashMap<K, Card>,
+}
+
+/// The schedule of the verb forms
+pub type Srs = Schedule<Key>;
+
+impl<K: CardKey> Schedule<K> {
+    /// Loads the schedule in the file, or an empty one if nothing was
+    /// answered yet
+    pub fn open(path: PathBuf) -> Result<Schedule<K>, Box<dyn Error>> {
+        if !path.exists() {
+            return Ok(Schedule {
+                path,
+                cards: HashMap::new(),
+            });
+        }
+        let file = File::open(&path)?;
+        Schedule::parse(path, file)
+    }
+
+    /// Reads the schedule in the contents of its file, saved to `path`
+    pub fn parse(path: PathBuf, data: impl Read) -> Result<Schedule<K>, Box<dyn Error>> {
+        let mut cards: HashMap<K, Card> = HashMap::new();
+        let mut rdr = ReaderBuilder::new().has_headers(true).from_reader(data);
+        let n = K::COLUMNS.len();
+        for result in rdr.records() {
+            let record = result?;
+            let field = |i: usize| record.get(i).unwrap_or_default();
+            let key: Vec<&str> = (0..n).map(field).collect();
+            let card = Card {
+                due: DateTime::parse_from_rfc3339(field(n))?,
+                interval: field(n + 1).parse()?,
+                ease: field(n + 2).parse()?,
+                reps: field(n + 3).parse()?,
+                lapses: field(n + 4).parse()?,
+                // older files don't have the column
+                suspended: field(n + 5) == "true",
+            };
+            // a card reviewed on two synced machines has a row from each, and
+            // the one reviewed last wins
+            match cards.entry(K::parse(&key)?) {
+                Entry::Occupied(mut entry) => {
+                    if card.reviewed() > entry.get().reviewed() {
+                        entry.insert(card);
+                    }
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(card);
+                }
+            }
+        }
+        Ok(Schedule { path, cards })
+    }
+
+    /// Reschedules the card after it was answered, and saves the schedule
+    pub fn review_card(&mut self, key: K, correct: bool) -> Result<(), Box<dyn Error>> {
+        self.cards
+            .entry(key)
+            .or_insert_with(Card::new)
+            .review(correct);
+        self.save()
+    }
+
+    /// Whether the card is due for review now. Cards never answered are.
+    pub fn is_due(&self, key: &K) -> bool {
+        self.cards
+            .get(key)
+            .is_none_or(|c| !c.suspended && c.due <= Local::now())
+    }
+
+    /// How many cards are due for review now
+    pub fn due_count(&self) -> usize {
+        let now = Local::now();
+        self.cards
+            .values()
+            .filter(|c| !c.suspended && c.due <= now)
+            .count()
+    }
+
+    /// The suspended cards, sorted
+    pub fn suspended(&self) -> Vec<K> {
+        let mut keys: Vec<K> = self
+            .cards
+            .iter()
+            .filter(|(_, card)| card.suspended)
+            .map(|(key, _)| key.clone())
+            .collect();
+        keys.sort_by_key(K::order);
+        keys
+    }
+
+    /// Adds the cards that aren't in the schedule yet, leaving the ones that
+    /// are, and saves it. Returns how many were added.
+    pub fn import(
+        &mut self,
+        cards: impl IntoIterator<Item = (K, Card)>,
+    ) -> Result<usize, Box<dyn Error>> {
+        let mut added = 0;
+        for (key, card) in cards {
+            if let Entry::Vacant(entry) = self.cards.entry(key) {
+                entry.insert(card);
+                added += 1;
+            }
+        }
+        self.save()?;
+        Ok(added)
+    }
+
+    /// Suspends or unsuspends the card, and saves the schedule
+    pub fn set_suspended(&mut self, key: K, suspended: bool) -> Result<(), Box<dyn Error>> {
+        self.suspend(key, suspended);
+        self.save()
+    }
+
+    /// Suspends or unsuspends the card, leaving the schedule to be saved
+    pub fn suspend(&mut self, key: K, suspended: bool) {
+        self.cards.entry(key).or_insert_with(Card::new).suspended = suspended;
+    }
+
+    /// When the next card that isn't due yet becomes due
+    #[cfg(feature = "remind")]
+    pub fn next_due(&self) -> Option<DateTime<FixedOffset>> {
+        let now = Local::now();
+        self.cards
+            .values()
+            .filter(|c| !c.suspended)
+            .map(|c| c.due)
+            .filter(|d| *d > now)
+            .min()
+    }
+
+    /// Writes the schedule to its file
+    pub fn save(&self) -> Result<(), Box<dyn Error>> {
+        let mut cards: Vec<_> = self.cards.iter().collect();
+        cards.sort_by_key(|(key, _)| key.order());
+
+        let mut wtr = WriterBuilder::new().from_path(&self.path)?;
+        wtr.write_record(K::COLUMNS.iter().chain(&HEADERS))?;
+        for (key, card) in cards {
+            let mut record = key.fields();
+            record.extend([
+                card.due.to_rfc3339(),
+                format!("{:.2}", card.interval),
+                format!("{:.2}", card.ease),
+                card.reps.to_string(),
+                card.lapses.to_string(),
+                card.suspended.to_string(),
+            ]);
+            wtr.write_record(record)?;
+        }
+        wtr.flush()?;
+        Ok(())
+    }
+}
+
+impl Srs {
+    /// Loads the schedule of the verb forms
+    pub fn load(data_dir: &Path) -> Result<Srs, Box<dyn Error>> {
+        Schedule::open(data_dir.join(SRS_FILE))
+    }
+
+    pub fn is_suspended(&self, conj: &Conjugation) -> bool {
+        self.cards.get(&key(conj)).is_some_and(|c| c.suspended)
+    }
+
+    /// How many times the form was missed after being learnt
+    pub fn lapses(&self, conj: &Conjugation) -> u32 {
+        self.cards.get(&key(conj)).map_or(0, |c| c.lapses)
+    }
+
+    /// The forms missed at least `lapses` times, most missed first
+    pub fn leeches(&self, lapses: u32) -> Vec<(Key, &Card)> {
+        let mut leeches: Vec<(Key, &Card)> = self
+            .cards
+            .iter()
+            .filter(|(_, card)| card.lapses >= lapses)
+            .map(|(key, card)| (key.clone(), card))
+            .collect();
+        leeches.sort_by_key(|((verb, tense, person), card)| {
+            (
+                Reverse(card.lapses),
+                verb.clone(),
+                *tense as u8,
+                *person as u8,
+            )
+        });
+        leeches
+    }
+}
+
+/// Prints how many forms are due for review, and returns it
+pub fn due(data_dir: &Path) -> Result<usize, Box<dyn Error>> {
+    let due = Srs::load(data_dir)?.due_count();
+    println!("{}", due);
+    Ok(due)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn right_answers_multiply_the_interval_by_the_ease() {
+        let mut card = Card::new();
+        card.review(true);
+        assert_eq!(card.interval, 1.0);
+        card.review(true);
+        assert_eq!(card.interval, 3.0);
+        card.review(true);
+        assert_eq!(card.interval, 3.0 * START_EASE);
+    }
+
+    #[test]
+    fn a_lapse_starts_over_and_makes_the_form_less_easy() {
+        let mut card = Card::new();
+        card.review(true);
+        card.review(false);
+        assert_eq!((card.reps, card.lapses, card.interval), (0, 1, 0.0));
+        assert_eq!(card.ease, START_EASE - 0.2);
+        for _ in 0..20 {
+            card.review(false);
+        }
+        assert_eq!(card.ease, MIN_EASE);
+    }
+
+    #[test]
+    fn intervals_stop_growing_before_the_due_date_overflows() {
+        let mut card = Card::new();
+        for _ in 0..100 {
+            card.review(true);
+        }
+        assert_eq!(card.interval, MAX_INTERVAL);
+        assert!(card.due > Local::now().fixed_offset());
+    }
+
+    #[test]
+    fn imported_intervals_are_capped() {
+        let card = Card::imported(3, f64::INFINITY, 2.5, 0, None);
+        assert_eq!(card.interval, MAX_INTERVAL);
+        let card = Card::imported(3, 1e300, 0.5, 0, None);
+        assert_eq!((card.interval, card.ease), (MAX_INTERVAL, MIN_EASE));
+    }
+
+    #[test]
+    fn of_the_rows_two_synced_machines_left_the_one_reviewed_last_wins() {
+        // reviewed on the 1st for 10 days, and on the 5th for 2 days
+        let csv = "Verb,Tense,Person,Due,Interval,Ease,Reps,Lapses,Suspended\n\
+                   gehen,Present,I,2026-01-07T00:00:00+00:00,2,2.5,2,0,false\n\
+                   gehen,Present,I,2026-01-11T00:00:00+00:00,10,2.5,3,0,false\n";
+        let srs = Srs::parse(PathBuf::from("srs.csv"), csv.as_bytes()).unwrap();
+        let card = srs.cards.values().next().unwrap();
+        assert_eq!((srs.cards.len(), card.reps), (1, 2));
+    }
+}
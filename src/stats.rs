@@ -0,0 +1,67 @@
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Alignment, Rect},
+    style::{Styled, Stylize},
+    symbols::border,
+    text::{Line, Text},
+    widgets::{
+        block::{Position, Title},
+        Block, Paragraph, Widget,
+    },
+};
+
+use crate::theme::Theme;
+
+/// How many days the stats screen covers
+pub const DAYS: u32 = 7;
+
+/// The screen showing the report of the last week, as the `report`
+/// subcommand prints it
+pub struct Stats {
+    report: String,
+    scroll: u16,
+}
+
+impl Stats {
+    pub fn new(report: String) -> Stats {
+        Stats { report, scroll: 0 }
+    }
+
+    /// Handles a key press, returning true when the screen should be closed
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> bool {
+        match key_event.code {
+            KeyCode::Esc | KeyCode::Char('q') => return true,
+            KeyCode::Up | KeyCode::Char('k') => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => {
+                let lines = self.report.lines().count() as u16;
+                self.scroll = (self.scroll + 1).min(lines.saturating_sub(1))
+            }
+            _ => {}
+        }
+        false
+    }
+
+    pub fn render(&self, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        let title = Title::from(" Stats ".bold());
+        let instructions = Title::from(Line::from(vec![
+            " Scroll ".into(),
+            "<Up/Down> ".set_style(theme.key),
+            " Back ".into(),
+            "<Esc> ".set_style(theme.key),
+        ]));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+
+        Paragraph::new(Text::from(self.report.as_str()))
+            .block(block)
+            .scroll((self.scroll, 0))
+            .render(area, buf);
+    }
+}
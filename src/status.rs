@@ -0,0 +1,63 @@
+use chrono::{Duration, Local, NaiveDate};
+use clap::Args;
+use std::{collections::HashSet, error::Error, path::Path};
+
+use crate::history;
+use crate::srs::Srs;
+
+/// The options of the `status` subcommand
+#[derive(Args, Debug)]
+pub struct StatusArgs {
+    /// The line to print, where {streak} is the days practiced in a row,
+    /// {due} the forms due for review, {today} the questions answered today
+    /// and {accuracy} today's accuracy
+    #[arg(short, long, default_value = "{streak}d {due} due")]
+    format: String,
+}
+
+/// Prints a one line summary, e.g. for tmux or a starship prompt
+pub fn run(args: StatusArgs, data_dir: &Path) -> Result<(), Box<dyn Error>> {
+    let answers = history::load(data_dir)?;
+    let today = Local::now().date_naive();
+
+    let days: HashSet<NaiveDate> = answers
+        .iter()
+        .map(|a| a.time.with_timezone(&Local).date_naive())
+        .collect();
+    let answered_today: Vec<_> = answers
+        .iter()
+        .filter(|a| a.time.with_timezone(&Local).date_naive() == today)
+        .collect();
+    let accuracy = match answered_today.len() {
+        0 => "-".to_string(),
+        n => format!(
+            "{:.0}%",
+            100.0 * answered_today.iter().filter(|a| a.correct).count() as f64 / n as f64
+        ),
+    };
+
+    let line = args
+        .format
+        .replace("{streak}", &streak(&days, today).to_string())
+        .replace("{due}", &Srs::load(data_dir)?.due_count().to_string())
+        .replace("{today}", &answered_today.len().to_string())
+        .replace("{accuracy}", &accuracy);
+    println!("{}", line);
+    Ok(())
+}
+
+/// The days practiced in a row, up to today. Not having practiced yet today
+/// doesn't break the streak.
+fn streak(days: &HashSet<NaiveDate>, today: NaiveDate) -> u32 {
+    let mut day = if days.contains(&today) {
+        today
+    } else {
+        today - Duration::days(1)
+    };
+    let mut streak = 0;
+    while days.contains(&day) {
+        streak += 1;
+        day -= Duration::days(1);
+    }
+    streak
+}
@@ -0,0 +1,89 @@
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Alignment, Rect},
+    style::{Style, Styled, Stylize},
+    symbols::border,
+    text::{Line, Text},
+    widgets::{
+        block::{Position, Title},
+        Block, Paragraph, Widget,
+    },
+};
+
+use crate::srs::{Key, Srs};
+use crate::theme::Theme;
+
+/// The screen listing the suspended forms, to unsuspend them
+pub struct Suspended {
+    forms: Vec<Key>,
+    selected: usize,
+}
+
+impl Suspended {
+    pub fn new(srs: &Srs) -> Suspended {
+        Suspended {
+            forms: srs.suspended(),
+            selected: 0,
+        }
+    }
+
+    /// Handles a key press, returning true when the screen should be closed
+    pub fn handle_key_event(&mut self, key_event: KeyEvent, srs: &mut Srs) -> bool {
+        match key_event.code {
+            KeyCode::Esc | KeyCode::Char('q') => return true,
+            KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected = (self.selected + 1).min(self.forms.len().saturating_sub(1))
+            }
+            KeyCode::Enter | KeyCode::Char('u') if !self.forms.is_empty() => {
+                let form = self.forms.remove(self.selected);
+                srs.set_suspended(form, false)
+                    .expect("Could not save the review schedule");
+                self.selected = self.selected.min(self.forms.len().saturating_sub(1));
+            }
+            _ => {}
+        }
+        false
+    }
+
+    pub fn render(&self, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        let title = Title::from(" Suspended Forms ".bold());
+        let instructions = Title::from(Line::from(vec![
+            " Move ".into(),
+            "<Up/Down> ".set_style(theme.key),
+            " Unsuspend ".into(),
+            "<Enter> ".set_style(theme.key),
+            " Back ".into(),
+            "<Esc> ".set_style(theme.key),
+        ]));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+
+        let mut text = Text::from(vec![Line::from(""), Line::from("")]);
+        if self.forms.is_empty() {
+            text.push_line(Line::from(
+                "Nothing is suspended. Suspend a form with <x> once it is answered.",
+            ));
+        }
+        for (i, (verb, tense, person)) in self.forms.iter().enumerate() {
+            let line = Line::from(format!("{} | {} | {}", verb, tense, person));
+            if i == self.selected {
+                text.push_line(line.style(Style::new().reversed()));
+            } else {
+                text.push_line(line);
+            }
+        }
+
+        Paragraph::new(text)
+            .centered()
+            .block(block)
+            .render(area, buf);
+    }
+}
@@ -0,0 +1,201 @@
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use std::{error::Error, fs, path::Path, process::Command};
+
+/// Set in the repos made for syncing, so one that holds something else (as a
+/// checkout of this repo does) is never committed to
+const MARKER: &str = "deutschland.sync";
+
+/// The lines kept from both sides when the two machines changed a file: the
+/// history, events and scores are only ever added to, and of the rows of a
+/// form in the review schedule the one reviewed last is read, and of a
+/// lesson's high scores the highest
+const ATTRIBUTES: &str = "*.csv merge=union\n*.jsonl merge=union\n";
+
+/// What stays on the machine: the caches, the lesson in progress, the log
+/// (which was once kept beside the progress) and the teacher's secret, which
+/// is beside the config when it shares the data directory
+const IGNORED: [&str; 4] = [".cache/", "session.json", "deutschland.log", "teacher.key"];
+
+/// Syncing the data directory through a Git repo, set in the `[sync]` table
+/// of the config: pulled when the app starts and pushed when it exits (and
+/// after each lesson, with the async feature)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GitSync {
+    /// The remote to sync with (anything `git push` accepts). Off if not set
+    pub remote: Option<String>,
+    /// The branch on the remote, main if not set
+    pub branch: Option<String>,
+}
+
+impl GitSync {
+    fn branch(&self) -> &str {
+        self.branch.as_deref().unwrap_or("main")
+    }
+
+    /// Brings in the progress pushed from other machines. On the first sync
+    /// of a machine, the remote's files take the place of the ones there are.
+    pub fn pull(&self, data_dir: &Path) -> Result<(), Box<dyn Error>> {
+        let Some(remote) = &self.remote else {
+            return Ok(());
+        };
+        let new = open(data_dir, remote, self.branch())?;
+        if !has_branch(data_dir, self.branch()) {
+            return Ok(());
+        }
+        git(data_dir, &["fetch", "--quiet", "origin", self.branch()])?;
+        if new {
+            git(data_dir, &["reset", "--quiet", "--hard", "FETCH_HEAD"])?;
+        } else {
+            commit(data_dir)?;
+            merge(data_dir)?;
+        }
+        Ok(())
+    }
+
+    /// Commits the progress made and pushes it, merging in what was pushed
+    /// from other machines in the meantime
+    pub fn push(&self, data_dir: &Path) -> Result<(), Box<dyn Error>> {
+        let Some(remote) = &self.remote else {
+            return Ok(());
+        };
+        open(data_dir, remote, self.branch())?;
+        commit(data_dir)?;
+        if has_branch(data_dir, self.branch()) {
+            git(data_dir, &["fetch", "--quiet", "origin", self.branch()])?;
+            merge(data_dir)?;
+        }
+        let refspec = format!("HEAD:{}", self.branch());
+        git(data_dir, &["push", "--quiet", "origin", &refspec])?;
+        Ok(())
+    }
+
+    /// Commits the progress made and pushes it, while the app runs. Nothing
+    /// is merged in, as the app has the files loaded, so if another machine
+    /// pushed in the meantime it fails, and the push on exit merges.
+    pub fn push_progress(&self, data_dir: &Path) -> Result<(), Box<dyn Error>> {
+        let Some(remote) = &self.remote else {
+            return Ok(());
+        };
+        open(data_dir, remote, self.branch())?;
+        commit(data_dir)?;
+        let refspec = format!("HEAD:{}", self.branch());
+        git(data_dir, &["push", "--quiet", "origin", &refspec])?;
+        Ok(())
+    }
+}
+
+/// Makes the data directory a repo syncing with the remote if it isn't one
+/// yet, returning true if it wasn't
+fn open(data_dir: &Path, remote: &str, branch: &str) -> Result<bool, Box<dyn Error>> {
+    fs::create_dir_all(data_dir)?;
+    if data_dir.join(".git").exists() {
+        if git(data_dir, &["config", "--get", MARKER]).is_err() {
+            return Err(format!(
+                "{} is a Git repo that wasn't made for syncing, so is left alone",
+                data_dir.display()
+            )
+            .into());
+        }
+        // the remote in the config can have changed since
+        git(data_dir, &["remote", "set-url", "origin", remote])?;
+        ignore(data_dir)?;
+        return Ok(false);
+    }
+    if git(data_dir, &["rev-parse", "--show-toplevel"]).is_ok() {
+        return Err(format!(
+            "{} is inside a Git repo, so can't be synced",
+            data_dir.display()
+        )
+        .into());
+    }
+    git(data_dir, &["init", "--quiet", "--initial-branch", branch])?;
+    git(data_dir, &["config", MARKER, "true"])?;
+    git(data_dir, &["remote", "add", "origin", remote])?;
+    ignore(data_dir)?;
+    fs::write(data_dir.join(".gitattributes"), ATTRIBUTES)?;
+    Ok(true)
+}
+
+/// Adds what stays on the machine to the `.gitignore` if it isn't there yet,
+/// and stops tracking it if a repo made before committed it
+fn ignore(data_dir: &Path) -> Result<(), Box<dyn Error>> {
+    let path = data_dir.join(".gitignore");
+    let mut gitignore = fs::read_to_string(&path).unwrap_or_default();
+    let missing: Vec<&str> = IGNORED
+        .into_iter()
+        .filter(|i| !gitignore.lines().any(|l| l.trim() == *i))
+        .collect();
+    if missing.is_empty() {
+        return Ok(());
+    }
+    if !gitignore.is_empty() && !gitignore.ends_with('\n') {
+        gitignore.push('\n');
+    }
+    for i in &missing {
+        gitignore.push_str(i);
+        gitignore.push('\n');
+    }
+    fs::write(path, gitignore)?;
+    let mut args = vec!["rm", "-r", "--cached", "--quiet", "--ignore-unmatch", "--"];
+    args.extend(missing);
+    git(data_dir, &args)?;
+    Ok(())
+}
+
+fn has_branch(data_dir: &Path, branch: &str) -> bool {
+    git(
+        data_dir,
+        &["ls-remote", "--exit-code", "--heads", "origin", branch],
+    )
+    .is_ok()
+}
+
+/// Commits every change, if there are any
+fn commit(data_dir: &Path) -> Result<(), Box<dyn Error>> {
+    git(data_dir, &["add", "--all"])?;
+    if git(data_dir, &["status", "--porcelain"])?.trim().is_empty() {
+        return Ok(());
+    }
+    let message = format!("Practice of {}", Local::now().format("%Y-%m-%d %H:%M"));
+    git(data_dir, &["commit", "--quiet", "--message", &message])?;
+    Ok(())
+}
+
+/// Merges the fetched branch, leaving things as they were if it can't be
+fn merge(data_dir: &Path) -> Result<(), Box<dyn Error>> {
+    let merged = git(
+        data_dir,
+        &[
+            "merge",
+            "--quiet",
+            "--no-edit",
+            "--allow-unrelated-histories",
+            "FETCH_HEAD",
+        ],
+    );
+    if merged.is_err() {
+        let _ = git(data_dir, &["merge", "--abort"]);
+    }
+    merged.map(|_| ())
+}
+
+/// Runs git in the data directory, returning what it printed
+fn git(data_dir: &Path, args: &[&str]) -> Result<String, Box<dyn Error>> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(data_dir)
+        .args(args)
+        .output()
+        .map_err(|e| format!("Could not run git: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "git {} failed: {}",
+            args[0],
+            String::from_utf8_lossy(&output.stderr).trim()
+        )
+        .into());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
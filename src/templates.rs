@@ -0,0 +1,145 @@
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Alignment, Rect},
+    style::{Style, Styled, Stylize},
+    symbols::border,
+    text::{Line, Text},
+    widgets::{
+        block::{Position, Title},
+        Block, Paragraph, Widget,
+    },
+};
+
+use crate::config::Config;
+use crate::lesson::Lesson;
+use crate::person::Person;
+use crate::tense::Tense;
+use crate::theme::Theme;
+
+/// What the templates screen wants done after a key press
+pub enum TemplatesEvent {
+    None,
+    Close,
+    Start(Lesson),
+    Delete(String),
+}
+
+/// The screen listing the saved lesson templates, to start or delete them
+pub struct Templates {
+    templates: Vec<(String, Lesson)>,
+    verbs: Vec<String>, // the verbs there are, as templates can outlive theirs
+    selected: usize,
+}
+
+impl Templates {
+    pub fn new(config: &Config, verbs: Vec<String>) -> Templates {
+        Templates {
+            templates: config
+                .lessons
+                .iter()
+                .map(|(name, lesson)| (name.clone(), lesson.clone()))
+                .collect(),
+            verbs,
+            selected: 0,
+        }
+    }
+
+    /// The verbs of the template that no longer exist
+    fn missing_verbs(&self, lesson: &Lesson) -> Vec<String> {
+        lesson
+            .verbs
+            .iter()
+            .filter(|v| !self.verbs.contains(v))
+            .cloned()
+            .collect()
+    }
+
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> TemplatesEvent {
+        match key_event.code {
+            KeyCode::Esc | KeyCode::Char('q') => return TemplatesEvent::Close,
+            KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected = (self.selected + 1).min(self.templates.len().saturating_sub(1))
+            }
+            KeyCode::Enter if !self.templates.is_empty() => {
+                let (_, lesson) = &self.templates[self.selected];
+                if self.missing_verbs(lesson).is_empty() {
+                    return TemplatesEvent::Start(lesson.clone());
+                }
+            }
+            KeyCode::Char('d') if !self.templates.is_empty() => {
+                let (name, _) = self.templates.remove(self.selected);
+                self.selected = self.selected.min(self.templates.len().saturating_sub(1));
+                return TemplatesEvent::Delete(name);
+            }
+            _ => {}
+        }
+        TemplatesEvent::None
+    }
+
+    pub fn render(&self, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        let title = Title::from(" Saved Lessons ".bold());
+        let instructions = Title::from(Line::from(vec![
+            " Move ".into(),
+            "<Up/Down> ".set_style(theme.key),
+            " Start ".into(),
+            "<Enter> ".set_style(theme.key),
+            " Delete ".into(),
+            "<d> ".set_style(theme.key),
+            " Back ".into(),
+            "<Esc> ".set_style(theme.key),
+        ]));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+
+        let mut text = Text::from(vec![Line::from(""), Line::from("")]);
+        if self.templates.is_empty() {
+            text.push_line(Line::from(
+                "No saved lessons. Save one from the lesson builder with <s>.",
+            ));
+        }
+        for (i, (name, lesson)) in self.templates.iter().enumerate() {
+            let mut line = Line::from(vec![
+                name.as_str().bold(),
+                format!(": {} ({})", lesson.name(), coverage(lesson)).into(),
+            ]);
+            let missing = self.missing_verbs(lesson);
+            if !missing.is_empty() {
+                line.push_span(
+                    format!("  missing {}", missing.join(", ")).set_style(theme.incorrect),
+                );
+            }
+            if i == self.selected {
+                text.push_line(line.style(Style::new().reversed()));
+            } else {
+                text.push_line(line);
+            }
+        }
+
+        Paragraph::new(text)
+            .centered()
+            .block(block)
+            .render(area, buf);
+    }
+}
+
+/// Which tenses and persons the lesson asks, e.g. "2 tenses, all persons"
+fn coverage(lesson: &Lesson) -> String {
+    let count = |n: usize, all: usize, what: &str| match n {
+        _ if n == all => format!("all {}s", what),
+        1 => format!("1 {}", what),
+        n => format!("{} {}s", n, what),
+    };
+    format!(
+        "{}, {}",
+        count(lesson.tenses.len(), Tense::ALL.len(), "tense"),
+        count(lesson.persons.len(), Person::ALL.len(), "person")
+    )
+}
@@ -1,6 +1,7 @@
-use std::fmt;
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Tense {
     Present,
     PerfectPresent,
@@ -13,19 +14,46 @@ pub enum Tense {
 }
 
 impl Tense {
-    pub fn from_str(t: &str) -> Tense {
-        let t = t.to_lowercase();
-        match &*t {
-            "present" => Tense::Present,
-            "perfectpresent" => Tense::PerfectPresent,
-            "past" => Tense::Past,
-            "perfectpast" => Tense::PerfectPast,
-            "future" => Tense::Future,
-            "perfectfuture" => Tense::PerfectFuture,
-            "subjectivei" => Tense::SubjectiveI,
-            "subjectiveii" => Tense::SubjectiveII,
-            _ => panic!("Tense not matched: {}", t),
-        }
+    /// Every tense, in the order they are listed
+    pub const ALL: [Tense; 8] = [
+        Tense::Present,
+        Tense::PerfectPresent,
+        Tense::Past,
+        Tense::PerfectPast,
+        Tense::Future,
+        Tense::PerfectFuture,
+        Tense::SubjectiveI,
+        Tense::SubjectiveII,
+    ];
+}
+
+impl FromStr for Tense {
+    type Err = String;
+
+    /// Parses the tense as written in the verb files, e.g. `PerfectPresent`.
+    /// Spaces are ignored so the displayed form (`Perfect Present`) parses too.
+    /// Every row of every verb file has one, so nothing is allocated.
+    fn from_str(t: &str) -> Result<Tense, String> {
+        let names = [
+            ("present", Tense::Present),
+            ("perfectpresent", Tense::PerfectPresent),
+            ("past", Tense::Past),
+            ("perfectpast", Tense::PerfectPast),
+            ("future", Tense::Future),
+            ("perfectfuture", Tense::PerfectFuture),
+            ("subjectivei", Tense::SubjectiveI),
+            ("subjectiveii", Tense::SubjectiveII),
+        ];
+        let letters = || {
+            t.chars()
+                .filter(|&c| c != ' ')
+                .map(|c| c.to_ascii_lowercase())
+        };
+        names
+            .into_iter()
+            .find(|(name, _)| letters().eq(name.chars()))
+            .map(|(_, tense)| tense)
+            .ok_or_else(|| format!("Tense not matched: {}", t.to_lowercase().replace(' ', "")))
     }
 }
 
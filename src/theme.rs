@@ -0,0 +1,203 @@
+use ratatui::style::{Color, Modifier, Style};
+use serde::{Deserialize, Serialize};
+use std::{env, fmt};
+
+/// Which theme to use, as chosen in the settings
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemeChoice {
+    /// Pick the theme from the terminal's background colour
+    #[default]
+    Auto,
+    Dark,
+    Light,
+}
+
+impl ThemeChoice {
+    pub fn next(self) -> ThemeChoice {
+        match self {
+            ThemeChoice::Auto => ThemeChoice::Dark,
+            ThemeChoice::Dark => ThemeChoice::Light,
+            ThemeChoice::Light => ThemeChoice::Auto,
+        }
+    }
+}
+
+impl fmt::Display for ThemeChoice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ThemeChoice::Auto => write!(f, "Auto"),
+            ThemeChoice::Dark => write!(f, "Dark"),
+            ThemeChoice::Light => write!(f, "Light"),
+        }
+    }
+}
+
+/// The styles used across the screens, so they can be swapped for ones with
+/// enough contrast on the terminal's background.
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    pub key: Style,       // the keys in the instructions, e.g. <Enter>
+    pub prompt: Style,    // the form to translate
+    pub input: Style,     // what the user is typing
+    pub correct: Style,   // correct answers
+    pub incorrect: Style, // incorrect answers and errors
+    pub notice: Style,    // messages, e.g. "Flagged"
+
+    // marks put before correct and incorrect answers, so they don't rely on colour alone
+    pub correct_mark: &'static str,
+    pub incorrect_mark: &'static str,
+}
+
+impl Theme {
+    /// For dark backgrounds, the original colours
+    pub fn dark() -> Theme {
+        Theme {
+            key: Style::new().fg(Color::Blue).add_modifier(Modifier::BOLD),
+            prompt: Style::new().fg(Color::Blue),
+            input: Style::new().fg(Color::Yellow),
+            correct: Style::new().fg(Color::Green),
+            incorrect: Style::new().fg(Color::Red),
+            notice: Style::new().fg(Color::Yellow),
+            correct_mark: "",
+            incorrect_mark: "",
+        }
+    }
+
+    /// For light backgrounds, where yellow (and light colours) are unreadable
+    pub fn light() -> Theme {
+        Theme {
+            key: Style::new()
+                .fg(Color::Rgb(0, 0, 160))
+                .add_modifier(Modifier::BOLD),
+            prompt: Style::new().fg(Color::Rgb(0, 0, 160)),
+            input: Style::new().fg(Color::Rgb(130, 60, 0)),
+            correct: Style::new().fg(Color::Rgb(0, 110, 0)),
+            incorrect: Style::new().fg(Color::Rgb(170, 0, 0)),
+            notice: Style::new().fg(Color::Rgb(130, 60, 0)),
+            correct_mark: "",
+            incorrect_mark: "",
+        }
+    }
+
+    /// Makes the theme colourblind safe: correct and incorrect use blue and
+    /// orange from the Okabe-Ito palette instead of green and red, and are
+    /// marked with ✓ and ✗
+    pub fn accessible(self, light_background: bool) -> Theme {
+        let (correct, incorrect) = if light_background {
+            (Color::Rgb(0, 114, 178), Color::Rgb(213, 94, 0))
+        } else {
+            (Color::Rgb(86, 180, 233), Color::Rgb(230, 159, 0))
+        };
+        Theme {
+            correct: Style::new().fg(correct).add_modifier(Modifier::BOLD),
+            incorrect: Style::new().fg(incorrect).add_modifier(Modifier::BOLD),
+            correct_mark: "✓ ",
+            incorrect_mark: "✗ ",
+            ..self
+        }
+    }
+
+    /// The theme for the choice, where `light_background` is what was detected
+    pub fn from_choice(choice: ThemeChoice, light_background: bool, accessible: bool) -> Theme {
+        let light = match choice {
+            ThemeChoice::Dark => false,
+            ThemeChoice::Light => true,
+            ThemeChoice::Auto => light_background,
+        };
+        let theme = if light { Theme::light() } else { Theme::dark() };
+        if accessible {
+            theme.accessible(light)
+        } else {
+            theme
+        }
+    }
+}
+
+/// Detects whether the terminal has a light background, assuming dark if it
+/// can't tell. Must be called in raw mode, before any events are read.
+///
+/// `COLORFGBG` (set by e.g. rxvt and Konsole) is checked first, then the
+/// terminal is asked for its background colour with an OSC 11 query.
+pub fn detect_light_background() -> bool {
+    if let Some(light) = env::var("COLORFGBG")
+        .ok()
+        .and_then(|v| light_from_colorfgbg(&v))
+    {
+        return light;
+    }
+    query_background().is_some_and(|(r, g, b)| luminance(r, g, b) > 0.5)
+}
+
+/// `COLORFGBG` is `<fg>;<bg>` (sometimes `<fg>;<other>;<bg>`) in ANSI colour
+/// numbers, where 7 and 9 to 15 are the light colours
+fn light_from_colorfgbg(value: &str) -> Option<bool> {
+    let bg: u8 = value.rsplit(';').next()?.parse().ok()?;
+    Some(bg == 7 || bg >= 9)
+}
+
+/// The relative luminance (0 to 1) of a colour with 0 to 1 components
+fn luminance(r: f32, g: f32, b: f32) -> f32 {
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+/// Parses a reply to OSC 11, e.g. `\x1b]11;rgb:ffff/ffff/ffff\x07`
+fn parse_osc11_reply(reply: &str) -> Option<(f32, f32, f32)> {
+    let rgb = reply.split("rgb:").nth(1)?;
+    let rgb = rgb.trim_end_matches(['\x07', '\x1b', '\\']);
+    let mut parts = rgb.split('/').map(|c| {
+        let c = &c[..c.len().min(4)];
+        let max = (1u32 << (4 * c.len())) - 1;
+        u32::from_str_radix(c, 16)
+            .ok()
+            .map(|v| v as f32 / max as f32)
+    });
+    Some((parts.next()??, parts.next()??, parts.next()??))
+}
+
+/// Asks the terminal for its background colour, waiting briefly for a reply
+#[cfg(unix)]
+fn query_background() -> Option<(f32, f32, f32)> {
+    use std::{
+        fs::File,
+        io::{Read, Write},
+        os::fd::AsRawFd,
+    };
+
+    let mut tty = File::options()
+        .read(true)
+        .write(true)
+        .open("/dev/tty")
+        .ok()?;
+    tty.write_all(b"\x1b]11;?\x07").ok()?;
+    tty.flush().ok()?;
+
+    let mut reply = Vec::new();
+    let mut buf = [0u8; 64];
+    loop {
+        let mut fd = libc::pollfd {
+            fd: tty.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        // terminals that don't support the query never reply
+        if unsafe { libc::poll(&mut fd, 1, 100) } <= 0 {
+            return None;
+        }
+        let n = tty.read(&mut buf).ok()?;
+        if n == 0 {
+            return None;
+        }
+        reply.extend_from_slice(&buf[..n]);
+        // the reply ends with BEL or ST (ESC \)
+        if reply.ends_with(b"\x07") || reply.ends_with(b"\x1b\\") || reply.len() > 256 {
+            break;
+        }
+    }
+    parse_osc11_reply(&String::from_utf8_lossy(&reply))
+}
+
+#[cfg(not(unix))]
+fn query_background() -> Option<(f32, f32, f32)> {
+    None
+}
@@ -0,0 +1,53 @@
+use std::time::{Duration, Instant};
+
+/// How many characters a lesson needs typed for its speed to mean anything
+const MIN_CHARS: usize = 10;
+
+/// The keystrokes of the answers typed in a lesson, to show how fluently
+/// German is typed alongside how well it is known
+#[derive(Debug, Default)]
+pub struct Typing {
+    chars: usize,             // the characters typed, including the ones deleted again
+    corrections: usize,       // the characters deleted
+    time: Duration,           // from the first key of each answer to its submission
+    started: Option<Instant>, // when the first key of the answer being typed was pressed
+}
+
+impl Typing {
+    /// Counts a character typed into an answer
+    pub fn key(&mut self) {
+        self.started.get_or_insert_with(Instant::now);
+        self.chars += 1;
+    }
+
+    /// Counts a character deleted from an answer
+    pub fn correction(&mut self) {
+        self.started.get_or_insert_with(Instant::now);
+        self.corrections += 1;
+    }
+
+    /// Stops timing the answer, as it was submitted (or skipped)
+    pub fn submit(&mut self) {
+        if let Some(started) = self.started.take() {
+            self.time += started.elapsed();
+        }
+    }
+
+    /// Leaves the time paused out of the answer being typed
+    pub fn shift(&mut self, paused: Duration) {
+        if let Some(started) = &mut self.started {
+            *started += paused;
+        }
+    }
+
+    /// The speed in words a minute, a word being five characters, and the
+    /// share of the characters typed that were deleted again. None if too
+    /// little was typed.
+    pub fn stats(&self) -> Option<(f64, f64)> {
+        if self.chars < MIN_CHARS || self.time.is_zero() {
+            return None;
+        }
+        let wpm = self.chars as f64 / 5.0 / (self.time.as_secs_f64() / 60.0);
+        Some((wpm, self.corrections as f64 / self.chars as f64))
+    }
+}
@@ -0,0 +1,465 @@
+use std::{fs, time::Instant};
+
+use deutschland_tui::{lesson::Lesson, mode::Mode, tense::Tense};
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::message::{Command, Message};
+use crate::placement;
+use crate::screen_tests::app;
+use crate::App;
+
+/// Presses the key, giving back the commands of the update
+fn press(app: &mut App, code: KeyCode) -> Vec<Command> {
+    app.update(Message::Key(KeyEvent::from(code)))
+}
+
+/// Types the response and submits it, giving back the commands of the
+/// updates
+fn answer(app: &mut App, response: &str) -> Vec<Command> {
+    let mut commands = Vec::new();
+    for c in response.chars() {
+        commands.extend(press(app, KeyCode::Char(c)));
+    }
+    commands.extend(press(app, KeyCode::Enter));
+    commands
+}
+
+#[test]
+fn typing_only_redraws() {
+    let mut app = app("update_typing", &["--verb", "gehen"]);
+    assert_eq!(press(&mut app, KeyCode::Char('x')), vec![Command::Redraw]);
+    assert_eq!(app.cur_response, "x");
+}
+
+#[test]
+fn answers_are_autosaved_every_few() {
+    let mut app = app("update_autosave", &["--verb", "gehen"]);
+    for _ in 0..2 {
+        assert!(!answer(&mut app, "x").contains(&Command::Autosave));
+        press(&mut app, KeyCode::Enter);
+    }
+    assert!(answer(&mut app, "x").contains(&Command::Autosave));
+    // asked for, not carried out
+    assert!(!app.data_dir.join("session.json").exists());
+}
+
+#[test]
+fn timed_lessons_count_past_255_answers() {
+    let mut app = app("update_timed_count", &["--verb", "gehen", "--minutes", "5"]);
+    for _ in 0..300 {
+        answer(&mut app, "x");
+        press(&mut app, KeyCode::Enter);
+    }
+    assert_eq!(app.total_incorrect, 300);
+    assert_eq!(app.answered(), 300);
+    assert_eq!(app.cur_question, 300);
+}
+
+#[test]
+fn answers_run_the_hook() {
+    let mut app = app("update_hook", &["--verb", "gehen"]);
+    app.config.hooks.on_answer = Some("true".to_string());
+    let commands = answer(&mut app, "x");
+    assert!(commands.iter().any(
+        |c| matches!(c, Command::RunHook(hook, json) if hook == "true" && json.contains("gehen"))
+    ));
+}
+
+#[test]
+fn closing_the_settings_saves_them() {
+    let mut app = app("update_settings", &[]);
+    app.home = None;
+    press(&mut app, KeyCode::Char('s'));
+    press(&mut app, KeyCode::Enter);
+    let theme = app.settings.as_ref().unwrap().config.theme;
+    assert!(press(&mut app, KeyCode::Esc).contains(&Command::SaveConfig));
+    assert_eq!(app.config.theme, theme);
+}
+
+#[test]
+fn verb_stats_are_loaded_by_a_command() {
+    let mut app = app("update_verb_stats", &[]);
+    app.home = None;
+    let verb = app.verbs[0].name().to_string();
+    let commands = press(&mut app, KeyCode::Char('i'));
+    assert!(commands.contains(&Command::LoadVerbStats(verb.clone())));
+    assert!(app.verb_stats.is_none());
+    for command in commands {
+        app.perform(command);
+    }
+    assert!(app.verb_stats.is_some());
+}
+
+#[test]
+fn autocompleted_answers_are_not_unaided() {
+    let mut app = app(
+        "update_autocomplete",
+        &["--verb", "gehen", "--difficulty", "beginner"],
+    );
+    let expected = app.expected_answer();
+    let first = expected.chars().next().unwrap().to_string();
+    assert_eq!(app.autocomplete(), Some(first.clone()));
+    press(&mut app, KeyCode::Right);
+    assert_eq!(app.cur_response, first);
+    answer(&mut app, &expected[first.len()..]);
+    assert_eq!(app.cur_response_incorrect, Some(false));
+    assert_eq!(app.total_assisted, 1);
+    assert!(!app.pending[0].correct_unaided());
+}
+
+#[test]
+fn autocomplete_is_off_by_default() {
+    let app = app("update_no_autocomplete", &["--verb", "gehen"]);
+    assert_eq!(app.autocomplete(), None);
+}
+
+#[test]
+fn finished_lessons_record_their_answers_by_command() {
+    let mut app = app("update_record", &["--verb", "gehen", "--number", "1"]);
+    answer(&mut app, "x");
+    let commands = press(&mut app, KeyCode::Enter);
+    let recorded = commands
+        .iter()
+        .position(|c| matches!(c, Command::RecordAnswers(answers) if answers.len() == 1));
+    let reviewed = commands.iter().position(|c| *c == Command::ReviewLesson);
+    assert!(recorded.is_some() && recorded < reviewed);
+    assert!(commands
+        .iter()
+        .any(|c| matches!(c, Command::RecordScore(score) if score.answered == 1)));
+    // asked for, not carried out
+    assert!(!app.data_dir.join("history.csv").exists());
+}
+
+#[test]
+fn resizing_redraws() {
+    let mut app = app("update_resize", &[]);
+    assert_eq!(app.update(Message::Resize), vec![Command::Redraw]);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn finished_lessons_are_synced_in_the_background() {
+    use crate::effects::{Progress, Task};
+
+    let mut app = app("update_sync", &["--verb", "gehen", "--number", "1"]);
+    app.config.sync.remote = Some("/nowhere".to_string());
+    assert!(!answer(&mut app, "x").contains(&Command::Sync));
+    // the lesson ends on moving on from its last answer
+    assert!(press(&mut app, KeyCode::Enter).contains(&Command::Sync));
+    let failed = Progress::Failed("offline".to_string());
+    app.update(Message::Progress(Task::Sync, failed.clone()));
+    assert_eq!(app.synced, Some(failed));
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn syncs_asked_for_while_one_runs_are_run_once_after_it() {
+    use crate::effects::{Effects, Task};
+    use std::{
+        sync::{mpsc, Arc, Mutex},
+        thread,
+        time::Duration,
+    };
+
+    let effects = Effects::new().unwrap();
+    let runs = Arc::new(Mutex::new(Vec::new()));
+    // asks for three syncs while the first is held up until released
+    let sync_three = || {
+        let (release, held) = mpsc::channel::<()>();
+        let first = Arc::clone(&runs);
+        effects.spawn(Task::Sync, move || {
+            held.recv().unwrap();
+            first.lock().unwrap().push(1);
+            Ok(())
+        });
+        for n in [2, 3] {
+            let runs = Arc::clone(&runs);
+            effects.spawn(Task::Sync, move || {
+                runs.lock().unwrap().push(n);
+                Ok(())
+            });
+        }
+        release
+    };
+
+    let release = sync_three();
+    release.send(()).unwrap();
+    while effects.busy() {
+        thread::sleep(Duration::from_millis(10));
+    }
+    assert_eq!(*runs.lock().unwrap(), vec![1, 3]);
+
+    // quitting waits for the one running but drops the one waiting
+    runs.lock().unwrap().clear();
+    let release = sync_three();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        release.send(()).unwrap();
+    });
+    effects.finish();
+    assert_eq!(*runs.lock().unwrap(), vec![1]);
+}
+
+#[test]
+fn assembled_words_are_checked_once_all_are_put() {
+    let mut app = app(
+        "update_assemble",
+        &["--verb", "gehen", "--mode", "assemble"],
+    );
+    let german = app.conjugations[app.cur_conjugation].german.clone();
+    let mut order: Vec<usize> = Vec::new();
+    for word in german.split_whitespace() {
+        let i = (0..app.words.len())
+            .find(|i| app.words[*i] == word && !order.contains(i))
+            .unwrap();
+        order.push(i);
+    }
+    let digit = |i: usize| KeyCode::Char(char::from(b'1' + i as u8));
+
+    // a word put by mistake is taken back
+    press(&mut app, digit(order[1]));
+    press(&mut app, KeyCode::Backspace);
+    assert_eq!(app.cur_response, "");
+    for &i in &order {
+        press(&mut app, digit(i));
+    }
+    assert_eq!(app.cur_response, german);
+    assert_eq!(app.cur_response_incorrect, Some(false));
+}
+
+#[test]
+fn only_right_answers_move_on_by_themselves() {
+    let mut app = app("update_auto_advance", &["--verb", "gehen"]);
+    app.config.auto_advance_ms = 500;
+    answer(&mut app, "x");
+    assert_eq!(app.advance_at, None);
+    press(&mut app, KeyCode::Enter);
+
+    let expected = app.expected_answer();
+    answer(&mut app, &expected);
+    assert!(app.advance_at.is_some());
+    // not yet
+    app.update(Message::Tick);
+    assert_eq!(app.cur_response_incorrect, Some(false));
+    app.advance_at = Some(Instant::now());
+    assert!(app.update(Message::Tick).contains(&Command::Redraw));
+    assert_eq!(app.cur_response_incorrect, None);
+}
+
+#[test]
+fn mixed_lessons_ask_every_kind_of_question() {
+    let mut app = app(
+        "update_mixed",
+        &["--verb", "gehen", "--mode", "mixed", "-n", "30"],
+    );
+    let mut modes = Vec::new();
+    for _ in 0..30 {
+        if !modes.contains(&app.mode) {
+            modes.push(app.mode);
+        }
+        app.update(Message::Key(KeyEvent::new(
+            KeyCode::Char('s'),
+            KeyModifiers::CONTROL,
+        )));
+        press(&mut app, KeyCode::Enter);
+    }
+    assert_eq!(modes.len(), 3);
+    assert_eq!(app.lesson_mode(), Mode::Mixed);
+}
+
+#[test]
+fn recognition_offers_the_distractors_of_the_verb_file_first() {
+    let mut app = app("update_distractors", &["--mode", "recognize"]);
+    fs::write(
+        app.data_dir.join("verbs").join("gehen.csv"),
+        "Tense,Person,English,German,distractors\n\
+         Past,I,I went,ich ging,Subjective II | I; Present | I; Perfect Past | I\n\
+         Present,I,I go,ich gehe,\n\
+         SubjectiveII,I,I would go,ich ginge,\n\
+         PerfectPast,I,I had gone,ich war gegangen,\n\
+         Future,I,I will go,ich werde gehen,\n\
+         PerfectFuture,I,I will have gone,ich werde gegangen sein,\n",
+    )
+    .unwrap();
+    app.start_lesson(Lesson::new(vec!["gehen".to_string()]))
+        .unwrap();
+    while app.conjugations[app.cur_conjugation].tense != Tense::Past {
+        app.pick_question();
+    }
+    let offered: Vec<Tense> = app
+        .choices
+        .iter()
+        .map(|&i| app.conjugations[i].tense)
+        .collect();
+    assert_eq!(offered.len(), 4);
+    for tense in [Tense::SubjectiveII, Tense::Present, Tense::PerfectPast] {
+        assert!(offered.contains(&tense), "{:?} not offered", tense);
+    }
+}
+
+#[test]
+fn passing_on_every_placement_question_places_at_a1() {
+    let mut app = app("update_placement", &["--placement"]);
+    app.curriculum.place(2).unwrap();
+    // as many questions as the starter verbs have levels for
+    let placed = loop {
+        let commands = press(&mut app, KeyCode::Enter);
+        if let Some(placed) = commands
+            .into_iter()
+            .find(|c| matches!(c, Command::SavePlacement { .. }))
+        {
+            break placed;
+        }
+    };
+    assert!(matches!(
+        placed,
+        Command::SavePlacement {
+            level: placement::Level::A1,
+            ..
+        }
+    ));
+    // asked for, not carried out
+    assert_eq!(placement::latest(&app.data_dir).unwrap(), None);
+    app.perform(placed);
+    assert_eq!(app.curriculum.stage, 0);
+    press(&mut app, KeyCode::Enter);
+    assert!(app.placement.is_none() && app.home.is_some());
+}
+
+#[test]
+fn assignment_results_edited_by_hand_score_what_their_responses_are_worth() {
+    use crate::classroom::{Assignment, Attempt, Results};
+    use deutschland_tui::grade::Strictness;
+    use std::time::Duration;
+
+    let app = app("update_regrade", &["--verb", "gehen"]);
+    let assignment: Assignment = serde_json::from_value(serde_json::json!({
+        "id": "0badcafe",
+        "title": "Homework",
+        "lesson": Lesson::new(vec!["gehen".to_string()]),
+        "mode": Mode::Translate,
+        "questions": 2,
+        "strictness": Strictness::Normal,
+        "seed": 1,
+    }))
+    .unwrap();
+    let conjugations = &app.conjugations;
+    let mut attempt = Attempt::new(assignment.clone(), "Anna".to_string());
+    attempt.start(conjugations);
+    // every student is asked the same questions
+    let questions = assignment.questions(conjugations);
+    assert_eq!(questions.len(), 2);
+    assert_eq!(questions, assignment.questions(conjugations));
+    let (first, second) = (&conjugations[questions[0]], &conjugations[questions[1]]);
+    attempt.answer(first, Mode::Translate, &first.german, false, Duration::ZERO);
+    attempt.answer(second, Mode::Translate, "geht nicht", false, Duration::ZERO);
+    let results = attempt.results();
+    assert_eq!(
+        results.regrade(&assignment, conjugations),
+        vec![true, false]
+    );
+
+    let mut json = serde_json::to_value(&results).unwrap();
+    // the wrong answer made out to be right, for a verb that doesn't exist
+    json["answers"][1]["verb"] = "nichtda".into();
+    json["answers"][1]["expected"] = "geht nicht".into();
+    json["answers"][1]["correct"] = true.into();
+    // and the right answer handed in over and over
+    let right = json["answers"][0].clone();
+    for _ in 0..100 {
+        json["answers"].as_array_mut().unwrap().push(right.clone());
+    }
+    let edited: Results = serde_json::from_value(json).unwrap();
+    assert_eq!(edited.regrade(&assignment, conjugations), vec![true, false]);
+}
+
+#[test]
+fn verbs_of_the_same_name_from_two_packs_take_turns() {
+    use deutschland_tui::verb::Verb;
+    use std::sync::Arc;
+
+    let mut app = app("update_interleave", &["--verb", "gehen"]);
+    let path = app.data_dir.join("packs/extra/verbs/gehen.csv");
+    let other = Arc::new(Verb::from_path(path, Some("extra".to_string())).unwrap());
+    let built_in = app.conjugations.len();
+    let others: Vec<_> = app
+        .conjugations
+        .iter()
+        .map(|c| {
+            let mut c = c.clone();
+            c.verb = Arc::clone(&other);
+            c
+        })
+        .collect();
+    app.conjugations.extend(others);
+
+    // the built-in gehen was just asked, so the pack's comes next
+    let mut candidates: Vec<usize> = (0..app.conjugations.len()).collect();
+    app.interleave(&mut candidates);
+    assert!(candidates.iter().all(|&i| i >= built_in));
+}
+
+#[test]
+fn a_lesson_with_nothing_to_ask_in_the_mode_says_so_on_the_verbs_table() {
+    let mut app = app(
+        "update_nothing_to_ask",
+        &[
+            "--verb", "gehen", "--mode", "assemble", "--tense", "Present",
+        ],
+    );
+    assert!(app.lesson.is_none());
+    let error = app.lesson_error.clone().unwrap();
+    assert!(error.contains("perfect tense"), "{}", error);
+    press(&mut app, KeyCode::Down);
+    assert!(app.lesson_error.is_none());
+}
+
+#[test]
+fn verbs_without_the_source_language_are_left_out_of_the_lesson() {
+    let mut app = app(
+        "update_from_missing",
+        &["--verb", "gehen", "--from", "French"],
+    );
+    assert!(app.lesson.is_none());
+    let error = app.lesson_error.clone().unwrap();
+    assert!(error.contains("'French'"), "{}", error);
+    fs::write(
+        app.data_dir.join("verbs").join("essen.csv"),
+        "Tense,Person,English,French,German\nPresent,I,I eat,je mange,ich esse\n",
+    )
+    .unwrap();
+    app.start_lesson(Lesson::new(vec!["gehen".to_string(), "essen".to_string()]))
+        .unwrap();
+    assert!(app.conjugations.iter().all(|c| c.prompt == "je mange"));
+}
+
+#[test]
+fn a_lesson_edited_down_to_nothing_the_mode_can_ask_goes_back_to_the_verbs() {
+    let mut app = app(
+        "update_edited_away",
+        &["--verb", "gehen", "--mode", "assemble"],
+    );
+    assert!(app.lesson.is_some());
+    fs::write(
+        app.data_dir.join("verbs").join("gehen.csv"),
+        "Tense,Person,English,German\nPresent,I,I go,ich gehe\n",
+    )
+    .unwrap();
+    app.reload_lesson();
+    assert!(app.lesson.is_none());
+    assert!(app.lesson_error.is_some());
+}
+
+#[test]
+fn a_seeded_lesson_has_no_warm_up() {
+    let mut app = app("update_seeded_warm_up", &["--verb", "gehen"]);
+    answer(&mut app, "x");
+    app.save_answers(false);
+    app.perform_pending();
+    app.config.warm_up = true;
+    let lesson = Lesson::new(vec!["gehen".to_string()]);
+    app.seeded = false;
+    assert_eq!(app.warm_up_questions(&lesson).len(), 1);
+    app.seeded = true;
+    assert!(app.warm_up_questions(&lesson).is_empty());
+}
@@ -0,0 +1,103 @@
+use chrono::{DateTime, Local, NaiveDate};
+use csv::{ReaderBuilder, WriterBuilder};
+use std::{collections::BTreeMap, error::Error, fs::OpenOptions, path::Path};
+
+use crate::prepositions::Case;
+
+/// The case each verb governs, kept in `<data_dir>/valency.csv` with the
+/// columns `Verb,Case,Example,English`, next to the other facts about the
+/// verbs in irregular.csv
+const VALENCY_FILE: &str = "valency.csv";
+
+/// Every answered verb, kept apart from the verb history in
+/// `<data_dir>/valency_history.csv`
+const HISTORY_FILE: &str = "valency_history.csv";
+
+const HEADERS: [&str; 4] = ["Time", "Verb", "Case", "Correct"];
+
+/// A verb and the case of its object, e.g. helfen and the dative
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Valency {
+    pub verb: String,
+    pub case: Case,
+    pub example: Option<String>, // a sentence with the object in the case
+    pub english: String,
+}
+
+/// Loads the verbs from the valency file, in the order they are listed
+pub fn load(data_dir: &Path) -> Result<Vec<Valency>, Box<dyn Error>> {
+    let path = data_dir.join(VALENCY_FILE);
+    if !path.exists() {
+        return Err(format!("No valency file: {}", path.display()).into());
+    }
+    let mut rdr = ReaderBuilder::new().has_headers(true).from_path(path)?;
+    let mut verbs = Vec::new();
+    for result in rdr.records() {
+        let record = result?;
+        let line = record.position().map_or(0, |p| p.line());
+        let field = |i: usize| {
+            record
+                .get(i)
+                .map(str::trim)
+                .ok_or_else(|| format!("Missing column {} on line {}", i + 1, line))
+        };
+        verbs.push(Valency {
+            verb: field(0)?.to_string(),
+            case: field(1)?.parse()?,
+            example: Some(field(2)?.to_string()).filter(|e| !e.is_empty()),
+            english: field(3)?.to_string(),
+        });
+    }
+    Ok(verbs)
+}
+
+/// Appends the answer to the valency history
+pub fn record(data_dir: &Path, valency: &Valency, correct: bool) -> Result<(), Box<dyn Error>> {
+    let path = data_dir.join(HISTORY_FILE);
+    let is_new = !path.exists();
+    let file = OpenOptions::new().create(true).append(true).open(path)?;
+    let mut wtr = WriterBuilder::new().has_headers(false).from_writer(file);
+    if is_new {
+        wtr.write_record(HEADERS)?;
+    }
+    wtr.write_record([
+        Local::now().to_rfc3339(),
+        valency.verb.clone(),
+        valency.case.to_string(),
+        correct.to_string(),
+    ])?;
+    wtr.flush()?;
+    Ok(())
+}
+
+/// How many times each verb was answered right, out of how many, from the
+/// valency history. With `since`, only the answers from that day on count.
+pub fn stats(
+    data_dir: &Path,
+    since: Option<NaiveDate>,
+) -> Result<BTreeMap<String, (u32, u32)>, Box<dyn Error>> {
+    let path = data_dir.join(HISTORY_FILE);
+    let mut stats: BTreeMap<String, (u32, u32)> = BTreeMap::new();
+    if !path.exists() {
+        return Ok(stats);
+    }
+    let mut rdr = ReaderBuilder::new().has_headers(true).from_path(path)?;
+    for result in rdr.records() {
+        let record = result?;
+        let field = |i: usize| record.get(i).unwrap_or_default();
+        if let Some(since) = since {
+            let day = DateTime::parse_from_rfc3339(field(0))?
+                .with_timezone(&Local)
+                .date_naive();
+            if day < since {
+                continue;
+            }
+        }
+        let (right, total) = stats.entry(field(1).to_string()).or_default();
+        if field(3).parse::<bool>()? {
+            *right += 1;
+        }
+        *total += 1;
+    }
+    Ok(stats)
+}
@@ -0,0 +1,172 @@
+use rand::{rngs::StdRng, Rng};
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Alignment, Rect},
+    style::{Styled, Stylize},
+    symbols::border,
+    text::{Line, Text},
+    widgets::{
+        block::{Position, Title},
+        Block, Paragraph, Widget,
+    },
+};
+use std::{collections::BTreeMap, path::PathBuf};
+
+use crate::prepositions::Case;
+use crate::theme::Theme;
+use crate::valency::{self, Valency};
+
+/// How many of the weakest verbs to list under the question
+const WEAKEST: usize = 3;
+
+/// The quiz on which case each verb governs: each key press answers,
+/// records the answer in the valency history and asks the next one
+pub struct ValencyQuiz {
+    verbs: Vec<Valency>,
+    stats: BTreeMap<String, (u32, u32)>, // per verb, right answers out of all
+    data_dir: PathBuf,
+    rng: StdRng,
+    current: usize,
+    last: Option<(usize, Case)>, // the verb asked before, and what was answered
+}
+
+impl ValencyQuiz {
+    /// Starts the quiz, None if there are no verbs to ask
+    pub fn new(
+        verbs: Vec<Valency>,
+        stats: BTreeMap<String, (u32, u32)>,
+        data_dir: PathBuf,
+        mut rng: StdRng,
+    ) -> Option<ValencyQuiz> {
+        if verbs.is_empty() {
+            return None;
+        }
+        let current = rng.gen_range(0..verbs.len());
+        Some(ValencyQuiz {
+            verbs,
+            stats,
+            data_dir,
+            rng,
+            current,
+            last: None,
+        })
+    }
+
+    /// Handles a key press, returning true when the quiz should be closed
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> bool {
+        let case = match key_event.code {
+            KeyCode::Esc | KeyCode::Char('q') => return true,
+            KeyCode::Char('a') | KeyCode::Char('1') => Case::Accusative,
+            KeyCode::Char('d') | KeyCode::Char('2') => Case::Dative,
+            KeyCode::Char('g') | KeyCode::Char('3') => Case::Genitive,
+            _ => return false,
+        };
+        self.answer(case);
+        false
+    }
+
+    fn answer(&mut self, case: Case) {
+        let verb = &self.verbs[self.current];
+        let correct = verb.case == case;
+        valency::record(&self.data_dir, verb, correct).expect("Could not record the answer");
+        let (right, total) = self.stats.entry(verb.verb.clone()).or_default();
+        if correct {
+            *right += 1;
+        }
+        *total += 1;
+        self.last = Some((self.current, case));
+
+        // not the same verb twice in a row, as the answer would be on screen
+        let mut next = self.rng.gen_range(0..self.verbs.len());
+        if self.verbs.len() > 1 {
+            while next == self.current {
+                next = self.rng.gen_range(0..self.verbs.len());
+            }
+        }
+        self.current = next;
+    }
+
+    /// The verbs most often missed, worst first
+    fn weakest(&self) -> Vec<(&str, u32, u32)> {
+        let mut weakest: Vec<(&str, u32, u32)> = self
+            .stats
+            .iter()
+            .filter(|(_, &(right, total))| right < total)
+            .map(|(verb, &(right, total))| (verb.as_str(), right, total))
+            .collect();
+        // the lowest share right first
+        weakest.sort_by(|(_, a_right, a_total), (_, b_right, b_total)| {
+            (a_right * b_total).cmp(&(b_right * a_total))
+        });
+        weakest.truncate(WEAKEST);
+        weakest
+    }
+
+    pub fn render(&self, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        let title = Title::from(" Which Case Does the Verb Take? ".bold());
+        let instructions = Title::from(Line::from(vec![
+            " Accusative ".into(),
+            "<a/1> ".set_style(theme.key),
+            " Dative ".into(),
+            "<d/2> ".set_style(theme.key),
+            " Genitive ".into(),
+            "<g/3> ".set_style(theme.key),
+            " Back ".into(),
+            "<Esc> ".set_style(theme.key),
+        ]));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+
+        let asked = &self.verbs[self.current];
+        let mut text = Text::from(vec![Line::from(""), Line::from("")]);
+        text.push_line(Line::from(asked.verb.as_str().set_style(theme.prompt)));
+        text.push_line(Line::from(format!("({})", asked.english)));
+        if let Some((right, total)) = self.stats.get(&asked.verb) {
+            text.push_line(Line::from(format!(
+                "{}: {} of {} right so far",
+                asked.verb, right, total
+            )));
+        } else {
+            text.push_line(Line::from(""));
+        }
+        text.push_line(Line::from(""));
+
+        if let Some((i, answered)) = self.last {
+            let last = &self.verbs[i];
+            let mut told = format!("{} + {}", last.verb, last.case);
+            if let Some(example) = &last.example {
+                told += &format!(": {}", example);
+            }
+            text.push_line(if last.case == answered {
+                Line::from(format!("{}{}", theme.correct_mark, told).set_style(theme.correct))
+            } else {
+                Line::from(
+                    format!("{}{} (not {})", theme.incorrect_mark, told, answered)
+                        .set_style(theme.incorrect),
+                )
+            });
+        }
+
+        let weakest = self.weakest();
+        if !weakest.is_empty() {
+            text.push_line(Line::from(""));
+            let weakest: Vec<String> = weakest
+                .iter()
+                .map(|(verb, right, total)| format!("{} {}/{}", verb, right, total))
+                .collect();
+            text.push_line(Line::from(format!("Weakest: {}", weakest.join(", "))));
+        }
+
+        Paragraph::new(text)
+            .centered()
+            .block(block)
+            .render(area, buf);
+    }
+}
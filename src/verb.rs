@@ -1,54 +1,85 @@
-use std::fmt;
-
-#[derive(Debug, Clone, Copy)]
-pub enum Verb {
-    Aufwachen,
-    Duschen,
-    Essen,
-    Gehen,
-    Haben,
-    Machen,
-    Helfen,
-    Schlafen,
-    Skifahren,
-    Treffen,
-    Trinken,
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+/// A verb, backed by the CSV file holding its conjugations.
+///
+/// Verbs are discovered from the `verbs` directory and from any installed
+/// data packs (see `packs.rs`), rather than being hard-coded.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Verb {
+    name: String,         // the infinitive, i.e. the file name without `.csv`
+    pack: Option<String>, // the pack the verb came from, None if built in
+    path: PathBuf,        // the CSV file with the conjugations
 }
 
 impl Verb {
-    pub fn from_str(v: &str) -> Verb {
-        let v = v.to_lowercase();
-        return match &*v {
-            "aufwachen" => Verb::Aufwachen,
-            "duschen" => Verb::Duschen,
-            "essen" => Verb::Essen,
-            "gehen" => Verb::Gehen,
-            "haben" => Verb::Haben,
-            "helfen" => Verb::Helfen,
-            "machen" => Verb::Machen,
-            "schlafen" => Verb::Schlafen,
-            "skifahren" => Verb::Skifahren,
-            "treffen" => Verb::Treffen,
-            "trinken" => Verb::Trinken,
-            _ => panic!("Verb not matched"),
-        };
+    /// Creates the verb for a CSV file, None if the path is not a CSV file
+    pub fn from_path(path: PathBuf, pack: Option<String>) -> Option<Verb> {
+        // as Windows has them, the extension can be in capitals
+        if !path.extension()?.eq_ignore_ascii_case("csv") {
+            return None;
+        }
+        let name = path.file_stem()?.to_str()?.to_lowercase();
+        Some(Verb { name, pack, path })
+    }
+
+    /// Finds all the verbs in `<data_dir>/verbs` and `<data_dir>/packs/*/verbs`
+    pub fn find_all(data_dir: &Path) -> io::Result<Vec<Verb>> {
+        let mut verbs = Verb::find_in(&data_dir.join("verbs"), None)?;
+
+        let packs_dir = data_dir.join("packs");
+        if packs_dir.is_dir() {
+            for entry in fs::read_dir(packs_dir)? {
+                let path = entry?.path();
+                let name = match path.file_name().and_then(|n| n.to_str()) {
+                    // skip files and hidden (e.g. half installed) packs
+                    Some(n) if path.is_dir() && !n.starts_with('.') => n.to_string(),
+                    _ => continue,
+                };
+                let pack_verbs = path.join("verbs");
+                if pack_verbs.is_dir() {
+                    verbs.append(&mut Verb::find_in(&pack_verbs, Some(name))?);
+                }
+            }
+        }
+
+        verbs.sort_by(|a, b| (&a.name, &a.pack).cmp(&(&b.name, &b.pack)));
+        Ok(verbs)
+    }
+
+    /// Finds the verbs in a single directory of CSV files
+    pub fn find_in(dir: &Path, pack: Option<String>) -> io::Result<Vec<Verb>> {
+        let mut verbs = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            if let Some(verb) = Verb::from_path(entry?.path(), pack.clone()) {
+                verbs.push(verb);
+            }
+        }
+        Ok(verbs)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn pack(&self) -> Option<&str> {
+        self.pack.as_deref()
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
     }
 }
 
 impl fmt::Display for Verb {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            Verb::Aufwachen => write!(f, "Aufwachen"),
-            Verb::Duschen => write!(f, "Duschen"),
-            Verb::Essen => write!(f, "Essen"),
-            Verb::Gehen => write!(f, "Gehen"),
-            Verb::Haben => write!(f, "Haben"),
-            Verb::Helfen => write!(f, "Helfen"),
-            Verb::Machen => write!(f, "Machen"),
-            Verb::Schlafen => write!(f, "Schlafen"),
-            Verb::Skifahren => write!(f, "Skifahren"),
-            Verb::Treffen => write!(f, "Treffen"),
-            Verb::Trinken => write!(f, "Trinken"),
+        let mut chars = self.name.chars();
+        match chars.next() {
+            Some(c) => write!(f, "{}{}", c.to_uppercase(), chars.as_str()),
+            None => Ok(()),
         }
     }
 }
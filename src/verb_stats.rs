@@ -0,0 +1,162 @@
+use chrono::{DateTime, FixedOffset};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Styled, Stylize},
+    symbols::border,
+    text::{Line, Text},
+    widgets::{
+        block::{Position, Title},
+        Block, Clear, Paragraph, Sparkline, Widget,
+    },
+};
+
+use crate::history::Answer;
+use crate::tense::Tense;
+use crate::theme::Theme;
+
+/// How many of the days the verb was practiced the sparkline covers, the
+/// latest ones
+const SPARKLINE_DAYS: usize = 30;
+
+/// How many answers a tense needs to be called the weakest, so one miss
+/// doesn't make it
+const MIN_ANSWERS: usize = 3;
+
+/// The popup over the verbs table with how the practice of a verb went
+pub struct VerbStats {
+    verb: String,
+    answered: usize,
+    correct: usize,
+    days: Vec<f64>, // the accuracy of each day practiced, oldest first
+    last: Option<DateTime<FixedOffset>>,
+    weakest: Option<(Tense, f64)>, // the tense with the lowest accuracy
+}
+
+impl VerbStats {
+    pub fn new(verb: &str, answers: &[Answer]) -> VerbStats {
+        let answers: Vec<&Answer> = answers.iter().filter(|a| a.verb == verb).collect();
+
+        // the history is in the order answered, so the days are too
+        let mut days: Vec<(chrono::NaiveDate, usize, usize)> = Vec::new();
+        for answer in &answers {
+            let date = answer.time.date_naive();
+            match days.last_mut() {
+                Some((day, correct, total)) if *day == date => {
+                    *correct += usize::from(answer.correct);
+                    *total += 1;
+                }
+                _ => days.push((date, usize::from(answer.correct), 1)),
+            }
+        }
+        let days = days[days.len().saturating_sub(SPARKLINE_DAYS)..]
+            .iter()
+            .map(|&(_, correct, total)| correct as f64 / total as f64)
+            .collect();
+
+        let weakest = Tense::ALL
+            .iter()
+            .filter_map(|&tense| {
+                let in_tense: Vec<_> = answers.iter().filter(|a| a.tense == tense).collect();
+                if in_tense.len() < MIN_ANSWERS {
+                    return None;
+                }
+                let correct = in_tense.iter().filter(|a| a.correct).count();
+                Some((tense, correct as f64 / in_tense.len() as f64))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+
+        VerbStats {
+            verb: verb.to_string(),
+            answered: answers.len(),
+            correct: answers.iter().filter(|a| a.correct).count(),
+            days,
+            last: answers.last().map(|a| a.time),
+            weakest,
+        }
+    }
+
+    /// Renders the popup in the middle of the area
+    pub fn render(&self, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        let width = 52.min(area.width);
+        let height = 12.min(area.height);
+        let popup = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+        let title = Title::from(format!(" {} ", self.verb).bold());
+        let instructions = Title::from(Line::from(vec![
+            " Close ".into(),
+            "<Esc> ".set_style(theme.key),
+        ]));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+        let inner = block.inner(popup);
+        Clear.render(popup, buf);
+        block.render(popup, buf);
+
+        if self.answered == 0 {
+            Paragraph::new(vec![Line::from(""), Line::from("Not practiced yet".dim())])
+                .centered()
+                .render(inner, buf);
+            return;
+        }
+
+        let mut text = Text::from(vec![
+            Line::from(format!(
+                "{} of {} right ({:.0}%)",
+                self.correct,
+                self.answered,
+                100.0 * self.correct as f64 / self.answered as f64
+            )),
+            Line::from(format!(
+                "Last practiced: {}",
+                self.last
+                    .map(|t| t.format("%Y-%m-%d").to_string())
+                    .unwrap_or_default()
+            )),
+        ]);
+        match self.weakest {
+            Some((tense, accuracy)) => text.push_line(Line::from(vec![
+                "Weakest tense: ".into(),
+                format!("{} ({:.0}%)", tense, 100.0 * accuracy).set_style(theme.incorrect),
+            ])),
+            None => text.push_line(Line::from("Weakest tense: not enough answers yet".dim())),
+        }
+        text.push_line(Line::from(""));
+        text.push_line(Line::from(
+            "Accuracy by day practiced, the latest last".dim(),
+        ));
+
+        let [lines, sparkline] =
+            Layout::vertical([Constraint::Length(5), Constraint::Fill(1)]).areas(inner);
+        Paragraph::new(text).centered().render(lines, buf);
+        let data: Vec<u64> = self
+            .days
+            .iter()
+            .map(|accuracy| (accuracy * 100.0).round() as u64)
+            .collect();
+        // a column of its own for each day, centered, and the latest days if
+        // they don't all fit
+        let data = &data[data.len().saturating_sub(usize::from(sparkline.width))..];
+        let width = data.len() as u16;
+        let sparkline = Rect {
+            x: sparkline.x + (sparkline.width - width) / 2,
+            width,
+            ..sparkline
+        };
+        Sparkline::default()
+            .data(data)
+            .max(100)
+            .style(theme.correct)
+            .render(sparkline, buf);
+    }
+}
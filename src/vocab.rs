@@ -0,0 +1,171 @@
+use csv::{ReaderBuilder, StringRecord};
+use std::{
+    error::Error,
+    fmt,
+    fs::{self, File},
+    io::Read,
+    path::Path,
+    str::FromStr,
+};
+
+use crate::srs::{CardKey, Schedule};
+
+/// The review schedule of the vocabulary, kept apart from the verb forms in
+/// `<data_dir>/vocab_srs.csv`
+const VOCAB_SRS_FILE: &str = "vocab_srs.csv";
+
+/// Which way a word is asked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    ToGerman,   // the translation is shown, the German typed
+    FromGerman, // the German is shown, the translation typed
+}
+
+impl Direction {
+    pub fn flip(self) -> Direction {
+        match self {
+            Direction::ToGerman => Direction::FromGerman,
+            Direction::FromGerman => Direction::ToGerman,
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(d: &str) -> Result<Direction, String> {
+        match d {
+            "ToGerman" => Ok(Direction::ToGerman),
+            "FromGerman" => Ok(Direction::FromGerman),
+            _ => Err(format!("Direction not matched: {}", d)),
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Cards are words of a deck, asked one way
+pub type VocabKey = (String, String, Direction);
+
+impl CardKey for VocabKey {
+    const COLUMNS: &'static [&'static str] = &["Deck", "German", "Direction"];
+
+    type Order = (String, String, bool);
+
+    fn parse(fields: &[&str]) -> Result<VocabKey, Box<dyn Error>> {
+        Ok((
+            fields[0].to_string(),
+            fields[1].to_string(),
+            fields[2].parse()?,
+        ))
+    }
+
+    fn fields(&self) -> Vec<String> {
+        let (deck, german, direction) = self;
+        vec![deck.clone(), german.clone(), direction.to_string()]
+    }
+
+    fn order(&self) -> (String, String, bool) {
+        let (deck, german, direction) = self;
+        (
+            deck.clone(),
+            german.clone(),
+            *direction == Direction::FromGerman,
+        )
+    }
+}
+
+/// The schedule of the vocabulary
+pub type VocabSrs = Schedule<VocabKey>;
+
+pub fn load_srs(data_dir: &Path) -> Result<VocabSrs, Box<dyn Error>> {
+    Schedule::open(data_dir.join(VOCAB_SRS_FILE))
+}
+
+/// A German word (or phrase) and its translation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Word {
+    pub german: String,
+    pub translation: String, // meanings separated by semicolons, any of them is right
+}
+
+impl Word {
+    /// The card of the word asked in the direction
+    pub fn key(&self, deck: &Deck, direction: Direction) -> VocabKey {
+        (deck.name.clone(), self.german.clone(), direction)
+    }
+}
+
+/// A list of word pairs, without tenses or persons, from a CSV file in
+/// `<data_dir>/vocab` with a `German` column and one per source language
+#[derive(Debug, Clone)]
+pub struct Deck {
+    pub name: String, // the file name without `.csv`
+    pub words: Vec<Word>,
+}
+
+/// Finds the index of the column named `name` (case insensitive) in the headers
+fn column_index(headers: &StringRecord, name: &str) -> Result<usize, Box<dyn Error>> {
+    headers
+        .iter()
+        .position(|h| h.trim().eq_ignore_ascii_case(name))
+        .ok_or_else(|| format!("No '{}' column in the deck", name).into())
+}
+
+impl Deck {
+    /// Loads the deck in the file, with the translations into `from`
+    pub fn load(path: &Path, from: &str) -> Result<Deck, Box<dyn Error>> {
+        let name = path
+            .file_stem()
+            .and_then(|n| n.to_str())
+            .ok_or("Not a deck file")?
+            .to_string();
+        Deck::parse(name, File::open(path)?, from)
+    }
+
+    /// Reads the deck named `name` in the contents of its file
+    pub fn parse(name: String, data: impl Read, from: &str) -> Result<Deck, Box<dyn Error>> {
+        let mut rdr = ReaderBuilder::new().has_headers(true).from_reader(data);
+        let headers = rdr.headers()?.clone();
+        let german_col = column_index(&headers, "German")?;
+        let from_col = column_index(&headers, from)?;
+
+        let mut words = Vec::new();
+        for result in rdr.records() {
+            let record = result?;
+            let field = |i: usize| record.get(i).map(str::trim).unwrap_or_default();
+            // rows without a translation yet are left out
+            if field(german_col).is_empty() || field(from_col).is_empty() {
+                continue;
+            }
+            words.push(Word {
+                german: field(german_col).to_string(),
+                translation: field(from_col).to_string(),
+            });
+        }
+        Ok(Deck { name, words })
+    }
+}
+
+/// Loads every deck in `<data_dir>/vocab`, sorted by name. No directory means
+/// no decks.
+pub fn find_decks(data_dir: &Path, from: &str) -> Result<Vec<Deck>, Box<dyn Error>> {
+    let dir = data_dir.join("vocab");
+    if !dir.is_dir() {
+        return Ok(vec![]);
+    }
+    let mut decks = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|e| e == "csv") {
+            let deck = Deck::load(&path, from).map_err(|e| format!("{}: {}", path.display(), e))?;
+            decks.push(deck);
+        }
+    }
+    decks.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(decks)
+}
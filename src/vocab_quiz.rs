@@ -0,0 +1,297 @@
+use deutschland_tui::grade::{Grader, Strictness};
+use rand::{rngs::StdRng, seq::SliceRandom};
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Alignment, Rect},
+    style::{Style, Styled, Stylize},
+    symbols::border,
+    text::{Line, Text},
+    widgets::{
+        block::{Position, Title},
+        Block, Paragraph, Widget,
+    },
+};
+
+use crate::theme::Theme;
+use crate::vocab::{Deck, Direction, VocabSrs};
+
+/// A word being asked, by its index in the deck
+struct Question {
+    word: usize,
+    response: String,
+    correct: Option<bool>, // once answered, if the response was right
+}
+
+/// The flashcards of the vocabulary decks: pick a deck and a direction, then
+/// the words due for review are asked, scheduled like the verb forms
+pub struct VocabQuiz {
+    decks: Vec<Deck>,
+    srs: VocabSrs,
+    from: String, // the source language, for the labels
+    rng: StdRng,
+    direction: Direction,
+    selected: usize,            // the highlighted deck
+    question: Option<Question>, // None while picking a deck
+    right: u32,
+    answered: u32,
+}
+
+impl VocabQuiz {
+    pub fn new(decks: Vec<Deck>, srs: VocabSrs, from: String, rng: StdRng) -> VocabQuiz {
+        VocabQuiz {
+            decks,
+            srs,
+            from,
+            rng,
+            direction: Direction::ToGerman,
+            selected: 0,
+            question: None,
+            right: 0,
+            answered: 0,
+        }
+    }
+
+    /// The words of the deck due for review in the current direction
+    fn due(&self, deck: &Deck) -> Vec<usize> {
+        (0..deck.words.len())
+            .filter(|&i| self.srs.is_due(&deck.words[i].key(deck, self.direction)))
+            .collect()
+    }
+
+    /// Asks a due word of the selected deck, other than the one just asked.
+    /// With nothing due, any word that isn't suspended is practiced ahead.
+    fn next(&mut self) {
+        let deck = &self.decks[self.selected];
+        let last = self.question.as_ref().map(|q| q.word);
+        let mut candidates = self.due(deck);
+        if candidates.is_empty() {
+            let suspended = self.srs.suspended();
+            candidates = (0..deck.words.len())
+                .filter(|&i| !suspended.contains(&deck.words[i].key(deck, self.direction)))
+                .collect();
+        }
+        if candidates.len() > 1 {
+            candidates.retain(|&i| Some(i) != last);
+        }
+        self.question = candidates.choose(&mut self.rng).map(|&word| Question {
+            word,
+            response: String::new(),
+            correct: None,
+        });
+    }
+
+    /// Handles a key press, returning true when the screen should be closed
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> bool {
+        let Some(question) = &mut self.question else {
+            match key_event.code {
+                KeyCode::Esc | KeyCode::Char('q') => return true,
+                KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+                KeyCode::Down | KeyCode::Char('j') => {
+                    self.selected = (self.selected + 1).min(self.decks.len().saturating_sub(1))
+                }
+                KeyCode::Tab => self.direction = self.direction.flip(),
+                KeyCode::Enter if !self.decks.is_empty() => self.next(),
+                _ => {}
+            }
+            return false;
+        };
+
+        if question.correct.is_some() {
+            match key_event.code {
+                KeyCode::Esc => self.question = None,
+                KeyCode::Enter => self.next(),
+                _ => {}
+            }
+            return false;
+        }
+        match key_event.code {
+            KeyCode::Esc => self.question = None,
+            KeyCode::Enter if !question.response.trim().is_empty() => self.check(),
+            KeyCode::Backspace => {
+                question.response.pop();
+            }
+            KeyCode::Char(c) => question.response.push(c),
+            _ => {}
+        }
+        false
+    }
+
+    fn check(&mut self) {
+        let Some(question) = &mut self.question else {
+            return;
+        };
+        let deck = &self.decks[self.selected];
+        let word = &deck.words[question.word];
+        let grader = Grader::new().strictness(Strictness::Lenient);
+        let correct = match self.direction {
+            Direction::ToGerman => grader.grade(&question.response, &word.german),
+            // any of the meanings will do
+            Direction::FromGerman => word
+                .translation
+                .split(';')
+                .any(|meaning| grader.grade(&question.response, meaning)),
+        };
+        question.correct = Some(correct);
+        if correct {
+            self.right += 1;
+        }
+        self.answered += 1;
+        self.srs
+            .review_card(word.key(deck, self.direction), correct)
+            .expect("Could not save the review schedule");
+    }
+
+    /// The direction as the labels say it, e.g. "English → German"
+    fn direction_label(&self) -> String {
+        match self.direction {
+            Direction::ToGerman => format!("{} → German", self.from),
+            Direction::FromGerman => format!("German → {}", self.from),
+        }
+    }
+
+    pub fn render(&self, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        match &self.question {
+            Some(question) => self.render_question(question, theme, area, buf),
+            None => self.render_decks(theme, area, buf),
+        }
+    }
+
+    fn render_decks(&self, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        let title = Title::from(" Vocabulary ".bold());
+        let instructions = Title::from(Line::from(vec![
+            " Move ".into(),
+            "<Up/Down> ".set_style(theme.key),
+            " Direction ".into(),
+            "<Tab> ".set_style(theme.key),
+            " Start ".into(),
+            "<Enter> ".set_style(theme.key),
+            " Back ".into(),
+            "<Esc> ".set_style(theme.key),
+        ]));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+
+        let mut text = Text::from(vec![
+            Line::from(""),
+            Line::from(""),
+            Line::from(vec![
+                "Direction: ".into(),
+                self.direction_label().set_style(theme.prompt),
+            ]),
+            Line::from(""),
+        ]);
+        if self.decks.is_empty() {
+            text.push_line(Line::from(
+                "No decks. Add CSV files with a German column to the vocab directory.",
+            ));
+        }
+        for (i, deck) in self.decks.iter().enumerate() {
+            let line = Line::from(format!(
+                "{} ({} words, {} due)",
+                deck.name,
+                deck.words.len(),
+                self.due(deck).len()
+            ));
+            if i == self.selected {
+                text.push_line(line.style(Style::new().reversed()));
+            } else {
+                text.push_line(line);
+            }
+        }
+
+        Paragraph::new(text)
+            .centered()
+            .block(block)
+            .render(area, buf);
+    }
+
+    fn render_question(&self, question: &Question, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        let deck = &self.decks[self.selected];
+        let word = &deck.words[question.word];
+        let title = Title::from(
+            format!(
+                " {} | {} | {} due ",
+                deck.name,
+                self.direction_label(),
+                self.due(deck).len()
+            )
+            .bold(),
+        );
+        let instructions = Title::from(Line::from(match question.correct {
+            Some(_) => vec![
+                " Next ".into(),
+                "<Enter> ".set_style(theme.key),
+                " Decks ".into(),
+                "<Esc> ".set_style(theme.key),
+            ],
+            None => vec![
+                " Input Answer ".into(),
+                "<Chars> ".set_style(theme.key),
+                " Submit ".into(),
+                "<Enter> ".set_style(theme.key),
+                " Decks ".into(),
+                "<Esc> ".set_style(theme.key),
+            ],
+        }));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::THICK);
+
+        let (label, prompt, answer) = match self.direction {
+            Direction::ToGerman => (self.from.as_str(), &word.translation, &word.german),
+            Direction::FromGerman => ("German", &word.german, &word.translation),
+        };
+        let mut text = Text::from(vec![
+            Line::from(""),
+            Line::from(""),
+            Line::from(vec![
+                format!("{}: ", label).into(),
+                prompt.as_str().set_style(theme.prompt),
+            ]),
+            Line::from(""),
+        ]);
+        match question.correct {
+            None => text.push_line(Line::from(vec![
+                "Your input: ".into(),
+                format!("{}_", question.response).set_style(theme.input),
+            ])),
+            Some(true) => text.push_line(Line::from(
+                format!("{}{}", theme.correct_mark, question.response).set_style(theme.correct),
+            )),
+            Some(false) => {
+                text.push_line(Line::from(
+                    format!("{}{}", theme.incorrect_mark, question.response)
+                        .set_style(theme.incorrect),
+                ));
+                text.push_line(Line::from(vec![
+                    "Correct answer: ".into(),
+                    answer.as_str().set_style(theme.correct),
+                ]));
+            }
+        }
+        text.push_line(Line::from(""));
+        if self.answered > 0 {
+            text.push_line(Line::from(format!(
+                "{} right out of {}",
+                self.right, self.answered
+            )));
+        }
+
+        Paragraph::new(text)
+            .centered()
+            .block(block)
+            .render(area, buf);
+    }
+}
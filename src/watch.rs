@@ -0,0 +1,46 @@
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::{
+    path::Path,
+    sync::mpsc::{self, Receiver},
+};
+
+/// Watches the verbs and packs directories for edits to the verb files, so
+/// they can be hot-reloaded without restarting the app.
+pub struct VerbWatcher {
+    _watcher: RecommendedWatcher, // stops watching when dropped
+    rx: Receiver<notify::Result<Event>>,
+}
+
+impl VerbWatcher {
+    /// Starts watching `<data_dir>/verbs` and `<data_dir>/packs` (if it exists)
+    pub fn new(data_dir: &Path) -> notify::Result<VerbWatcher> {
+        let (tx, rx) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(tx)?;
+        for dir in ["verbs", "packs"] {
+            let dir = data_dir.join(dir);
+            if dir.is_dir() {
+                watcher.watch(&dir, RecursiveMode::Recursive)?;
+            }
+        }
+        Ok(VerbWatcher {
+            _watcher: watcher,
+            rx,
+        })
+    }
+
+    /// Drains the pending events, returning whether any verb file was created,
+    /// modified or removed since the last call
+    pub fn has_changes(&self) -> bool {
+        let mut changed = false;
+        for event in self.rx.try_iter().flatten() {
+            if matches!(event.kind, EventKind::Access(_)) {
+                continue;
+            }
+            changed |= event
+                .paths
+                .iter()
+                .any(|p| p.extension().is_some_and(|e| e == "csv"));
+        }
+        changed
+    }
+}
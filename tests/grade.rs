@@ -0,0 +1,92 @@
+use deutschland_tui::grade::{Grader, Strictness};
+use proptest::prelude::*;
+
+/// How the umlauts and ß are written without a German keyboard, as the
+/// grader folds them
+const UMLAUTS: [(char, &str); 7] = [
+    ('ä', "ae"),
+    ('ö', "oe"),
+    ('ü', "ue"),
+    ('Ä', "Ae"),
+    ('Ö', "Oe"),
+    ('Ü', "Ue"),
+    ('ß', "ss"),
+];
+
+/// Every way the grader can be set up
+fn graders() -> impl Strategy<Value = Grader> {
+    let strictness = prop::sample::select(vec![
+        Strictness::Exact,
+        Strictness::Normal,
+        Strictness::Lenient,
+    ]);
+    (strictness, any::<bool>(), any::<bool>()).prop_map(
+        |(strictness, fold_umlauts, pronoun_optional)| {
+            Grader::new()
+                .strictness(strictness)
+                .fold_umlauts(fold_umlauts)
+                .pronoun_optional(pronoun_optional)
+        },
+    )
+}
+
+/// Text like the answers and what is typed for them: letters, umlauts, the
+/// slashes of the pronouns, punctuation and uneven spacing
+fn german() -> impl Strategy<Value = String> {
+    "[a-zA-ZäöüÄÖÜß/ .,!?]{0,30}"
+}
+
+/// Writes the umlauts and ß of the text without them
+fn fold(text: &str) -> String {
+    let mut text = text.to_string();
+    for (umlaut, folded) in UMLAUTS {
+        text = text.replace(umlaut, folded);
+    }
+    text
+}
+
+proptest! {
+    #[test]
+    fn normalizing_twice_changes_nothing(grader in graders(), text in any::<String>()) {
+        let normalized = grader.normalize(&text);
+        prop_assert_eq!(grader.normalize(&normalized), normalized);
+    }
+
+    #[test]
+    fn normalizing_german_twice_changes_nothing(grader in graders(), text in german()) {
+        let normalized = grader.normalize(&text);
+        prop_assert_eq!(grader.normalize(&normalized), normalized);
+    }
+
+    #[test]
+    fn grading_odd_input_never_panics(
+        grader in graders(),
+        response in any::<String>(),
+        answer in any::<String>(),
+    ) {
+        grader.grade(&response, &answer);
+    }
+
+    #[test]
+    fn an_answer_is_right_for_itself(grader in graders(), answer in any::<String>()) {
+        prop_assert!(grader.grade(&answer, &answer));
+    }
+
+    #[test]
+    fn folded_umlauts_are_right_either_way(grader in graders(), text in german()) {
+        let grader = grader.fold_umlauts(true);
+        let folded = fold(&text);
+        prop_assert!(grader.grade(&folded, &text));
+        prop_assert!(grader.grade(&text, &folded));
+    }
+
+    #[test]
+    fn grading_without_optional_pronouns_is_symmetric(
+        grader in graders(),
+        response in german(),
+        answer in german(),
+    ) {
+        let grader = grader.pronoun_optional(false);
+        prop_assert_eq!(grader.grade(&response, &answer), grader.grade(&answer, &response));
+    }
+}